@@ -20,6 +20,14 @@ pub enum DeprecatedType {
         since: Option<Cow<'static, str>>,
         note: Cow<'static, str>,
     },
+    /// A type that has been replaced by another.
+    ///
+    /// Eg. `#[specta(deprecated(since = "1.0.0", note = "Use NewType", renamed = "NewType"))]`
+    DeprecatedRenamed {
+        since: Option<Cow<'static, str>>,
+        note: Cow<'static, str>,
+        renamed: Cow<'static, str>,
+    },
 }
 
 #[derive(Default, Clone)]
@@ -42,8 +50,22 @@ impl CommonAttr {
             },
         )?;
 
+        // `#[specta(deprecated(...))]` takes precedence, as it also accepts `renamed`. It's
+        // removed so the container, variant and field parsers don't reject it.
+        let mut specta_deprecated = None;
+        for attr in attrs.iter_mut().filter(|attr| attr.key == "specta") {
+            if let Some(AttributeValue::Attribute { attr, .. }) = &mut attr.value {
+                if let Some(i) = attr.iter().position(|attr| attr.key == "deprecated") {
+                    specta_deprecated = specta_deprecated.or(Some(attr.remove(i)));
+                }
+            }
+        }
+
         let mut deprecated = None;
-        if let Some(attr_value) = attrs.iter().filter(|attr| attr.key == "deprecated").next() {
+        if let Some(attr_value) = specta_deprecated
+            .as_ref()
+            .or_else(|| attrs.iter().filter(|attr| attr.key == "deprecated").next())
+        {
             match &attr_value.value {
                 Some(AttributeValue::Lit(lit)) => {
                     deprecated = Some(DeprecatedType::DeprecatedWithSince {
@@ -72,6 +94,14 @@ impl CommonAttr {
                             _ => None, // TODO: This should probs be an error
                         });
 
+                    let renamed = attr
+                        .iter()
+                        .find(|attr| attr.key == "renamed")
+                        .and_then(|v| match v.value.as_ref() {
+                            Some(AttributeValue::Lit(syn::Lit::Str(s))) => Some(s.value()),
+                            _ => None, // TODO: This should probs be an error
+                        });
+
                     let note = attr
                         .iter()
                         .filter(|attr| attr.key == "note")
@@ -86,10 +116,17 @@ impl CommonAttr {
                         })
                         .unwrap_or_default();
 
-                    deprecated = Some(DeprecatedType::DeprecatedWithSince {
-                        // TODO: Use Cow's earlier rather than later
-                        since: since.map(Into::into),
-                        note: note.into(),
+                    deprecated = Some(match renamed {
+                        Some(renamed) => DeprecatedType::DeprecatedRenamed {
+                            since: since.map(Into::into),
+                            note: note.into(),
+                            renamed: renamed.into(),
+                        },
+                        None => DeprecatedType::DeprecatedWithSince {
+                            // TODO: Use Cow's earlier rather than later
+                            since: since.map(Into::into),
+                            note: note.into(),
+                        },
                     });
                 }
                 None => deprecated = Some(DeprecatedType::Deprecated),
@@ -110,7 +147,7 @@ impl CommonAttr {
             Some(DeprecatedType::DeprecatedWithSince { since, note }) => {
                 let since = since
                     .as_ref()
-                    .map(|v| quote!(#v.into()))
+                    .map(|v| quote!(Some(#v.into())))
                     .unwrap_or(quote!(None));
 
                 quote!(Some(#crate_ref::datatype::DeprecatedType::DeprecatedWithSince {
@@ -118,6 +155,22 @@ impl CommonAttr {
                     note: #note.into(),
                 }))
             }
+            Some(DeprecatedType::DeprecatedRenamed {
+                since,
+                note,
+                renamed,
+            }) => {
+                let since = since
+                    .as_ref()
+                    .map(|v| quote!(Some(#v.into())))
+                    .unwrap_or(quote!(None));
+
+                quote!(Some(#crate_ref::datatype::DeprecatedType::DeprecatedRenamed {
+                    since: #since,
+                    note: #note.into(),
+                    renamed: #renamed.into(),
+                }))
+            }
             None => quote!(None),
         }
     }
@@ -19,11 +19,15 @@ pub struct ContainerAttr {
 
     // Struct ony (we pass it anyway so enums get nice errors)
     pub transparent: bool,
+
+    // Enum only
+    pub rename_all_fields: Option<Inflection>,
 }
 
 impl_parse! {
     ContainerAttr(attr, out) {
         "rename_all" => out.rename_all = out.rename_all.take().or(Some(attr.parse_inflection()?)),
+        "rename_all_fields" => out.rename_all_fields = out.rename_all_fields.take().or(Some(attr.parse_inflection()?)),
         "rename" => {
             let attr = attr.parse_string()?;
             out.rename = out.rename.take().or_else(|| Some(attr.to_token_stream()))
@@ -93,7 +93,9 @@ pub fn parse_enum(
                             let field_ident_str =
                                 unraw_raw_ident(field.ident.as_ref().unwrap());
 
-                            let field_name = match (field_attrs.rename.clone(), attrs.rename_all) {
+                            // A variant's own `rename_all` takes precedence over the enum's `rename_all_fields`
+                            let rename_all = attrs.rename_all.or(container_attrs.rename_all_fields);
+                            let field_name = match (field_attrs.rename.clone(), rename_all) {
                                 (Some(name), _) => name,
                                 (_, Some(inflection)) => {
                                     let name = inflection.apply(&field_ident_str);
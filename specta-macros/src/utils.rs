@@ -120,10 +120,21 @@ fn parse_attribute(content: ParseStream) -> Result<Vec<Attribute>> {
         //  ^^^^
         let key = content.call(Ident::parse_any)?;
         let key_span = key.span();
+        let is_deprecated = key == "deprecated";
 
         result.push(Attribute {
             key,
             value: match false {
+                // `(deprecated(...))`
+                //             ^^^^^
+                _ if is_deprecated && content.peek(Paren) => {
+                    let inner;
+                    syn::parenthesized!(inner in content);
+                    Some(AttributeValue::Attribute {
+                        span: key_span,
+                        attr: parse_attribute(&inner)?,
+                    })
+                }
                 // `(demo(...))`
                 //       ^^^^^
                 _ if content.peek(Paren) => Some(AttributeValue::Attribute {
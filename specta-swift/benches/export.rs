@@ -0,0 +1,60 @@
+//! Export throughput on synthetic collections.
+//!
+//! Run with `cargo bench -p specta-swift`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use specta_swift::stress::Synthetic;
+use specta_swift::Swift;
+
+fn collection_size(c: &mut Criterion) {
+    let mut group = c.benchmark_group("types");
+    group.sample_size(10);
+    for size in [1_000, 10_000] {
+        let types = Synthetic::new(size).build();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &types, |b, types| {
+            b.iter(|| Swift::default().export(types).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn deep_nesting(c: &mut Criterion) {
+    let mut group = c.benchmark_group("depth");
+    for depth in [2, 16, 64] {
+        let types = Synthetic::new(512).depth(depth).build();
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &types, |b, types| {
+            b.iter(|| Swift::default().export(types).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn wide_enums(c: &mut Criterion) {
+    let mut group = c.benchmark_group("variants");
+    for variants in [4, 64, 256] {
+        let types = Synthetic::new(128).variants(variants).build();
+        group.bench_with_input(BenchmarkId::from_parameter(variants), &types, |b, types| {
+            b.iter(|| Swift::default().export(types).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn features(c: &mut Criterion) {
+    let types = Synthetic::new(1_000).build();
+    let mut group = c.benchmark_group("features");
+    group.sample_size(10);
+    group.bench_function("default", |b| {
+        b.iter(|| Swift::default().export(&types).unwrap())
+    });
+    group.bench_function("initializers_and_mocks", |b| {
+        let mut swift = Swift::default().with_mocks();
+        swift.generate_initializers = true;
+        b.iter(|| swift.export(&types).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, collection_size, deep_nesting, wide_enums, features);
+criterion_main!(benches);
@@ -0,0 +1,27 @@
+//! A command line binary for the Swift exporter.
+//!
+//! Run with:
+//! `cargo run --example cli --features cli -- export ./examples/generated/Cli.swift --initializers`
+//! `cargo run --example cli --features cli -- check ./examples/generated/Cli.swift --initializers`
+use specta::{Type, TypeCollection};
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+    email: Option<String>,
+}
+
+#[derive(Type)]
+enum Status {
+    Active,
+    Suspended { reason: String },
+}
+
+fn main() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Status>();
+
+    specta_swift::cli::run(&types);
+}
@@ -0,0 +1,691 @@
+//! A small Swift syntax tree
+//!
+//! The declaration of each exported type is built as a [`TypeDefinition`] and only rendered
+//! to text at the end, with the configured [`IndentStyle`]. Post-processors registered with
+//! [`Swift::with_ast_post_processor`](crate::Swift::with_ast_post_processor) receive the tree,
+//! so they can add conformances, members or attributes without parsing Swift:
+//!
+//! ```rust
+//! use specta_swift::ast::{Member, Property};
+//! use specta_swift::Swift;
+//!
+//! let swift = Swift::new().with_ast_post_processor(|_, definition| {
+//!     if let Some(decl) = definition.declaration_mut() {
+//!         decl.conformances.push("Sendable".into());
+//!         decl.members.push(Member::Property(Property::constant("version", "Int").static_value("1")));
+//!     }
+//! });
+//! ```
+//!
+//! Function bodies are kept as lines. Their indentation is written with four spaces per level
+//! and converted to the configured style when rendered.
+//!
+//! The layout is chosen by a [`Printer`]: the indentation, where opening braces go, the
+//! spacing around the colons of type annotations and conformances, and how long array
+//! literals are wrapped.
+
+use std::fmt::{self, Write};
+use std::ops::Range;
+
+use crate::export::{offset_declarations, parse_declarations, DeclarationKind};
+use crate::swift::{BraceStyle, IndentStyle};
+use crate::utils::formatting::escape_string;
+
+/// The layout of rendered Swift code.
+///
+/// Only what the tree models is restyled: the braces of declarations, extensions and
+/// functions, and the colons of conformance clauses and property types. Function bodies and
+/// raw code keep their own layout, apart from indentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Printer {
+    /// Indentation of nested code.
+    pub indent: IndentStyle,
+    /// Whether opening braces go on the same line as the declaration or on the next one.
+    pub braces: BraceStyle,
+    /// Write `name : Type` instead of `name: Type`.
+    pub space_before_colon: bool,
+    /// Add a comma after the last element of wrapped array literals.
+    pub trailing_commas: bool,
+    /// Wrap array literals with one element per line when a line would be longer.
+    pub line_width: Option<usize>,
+}
+
+impl Printer {
+    /// A printer with the default style and the given indentation.
+    pub fn new(indent: IndentStyle) -> Self {
+        Self {
+            indent,
+            ..Self::default()
+        }
+    }
+
+    /// The separator between a name and its type or conformances.
+    fn colon(&self) -> &'static str {
+        if self.space_before_colon {
+            " : "
+        } else {
+            ": "
+        }
+    }
+}
+
+/// Code being printed in the style of a [`Printer`].
+struct Output<'a> {
+    printer: &'a Printer,
+    unit: String,
+    text: String,
+}
+
+impl<'a> Output<'a> {
+    fn new(printer: &'a Printer) -> Self {
+        Self {
+            printer,
+            unit: printer.indent.unit(),
+            text: String::new(),
+        }
+    }
+
+    fn indent(&mut self, level: usize) {
+        for _ in 0..level {
+            self.text.push_str(&self.unit);
+        }
+    }
+
+    /// Write lines (doc comments or attributes) at a nesting level.
+    fn leading_lines(&mut self, lines: &[String], level: usize) {
+        for line in lines {
+            self.indent(level);
+            self.text.push_str(line);
+            self.text.push('\n');
+        }
+    }
+
+    /// Open the body of the declaration written on the current line.
+    fn open_brace(&mut self, level: usize) {
+        match self.printer.braces {
+            BraceStyle::SameLine => self.text.push_str(" {\n"),
+            BraceStyle::NextLine => {
+                self.text.push('\n');
+                self.indent(level);
+                self.text.push_str("{\n");
+            }
+        }
+    }
+
+    fn close_brace(&mut self, level: usize) {
+        self.indent(level);
+        self.text.push('}');
+    }
+
+    /// Write the conformance clause of a declaration.
+    fn conformances(&mut self, conformances: &[String]) {
+        if !conformances.is_empty() {
+            self.text.push_str(self.printer.colon());
+            self.text.push_str(&conformances.join(", "));
+        }
+    }
+
+    /// Write members at a nesting level, one per line.
+    fn members(&mut self, members: &[Member], level: usize) {
+        for (i, member) in members.iter().enumerate() {
+            if i > 0 && !members[i - 1].groups_with(member) {
+                self.text.push('\n');
+            }
+            match member {
+                Member::Property(property) => property.print(self, level),
+                Member::Case(case) => case.print(self, level),
+                Member::Function(function) => function.print(self, level),
+                Member::Decl(decl) => decl.print(self, level),
+                Member::Raw(code) => self.lines(code.trim_end().lines(), level),
+            }
+            self.text.push('\n');
+        }
+    }
+
+    /// Write lines indented with four spaces per level at a nesting level, without a
+    /// trailing newline. Blank lines are left empty.
+    fn lines<'l>(&mut self, lines: impl Iterator<Item = &'l str>, level: usize) {
+        for (i, line) in lines.enumerate() {
+            if i > 0 {
+                self.text.push('\n');
+            }
+            if !line.trim().is_empty() {
+                self.indent(level);
+                let line = reindent_line(line.trim_end(), &self.unit);
+                self.text.push_str(&line);
+            }
+        }
+    }
+
+    /// The width of the current line so far, counting a tab as four columns.
+    fn column(&self) -> usize {
+        let line = self.text.rsplit('\n').next().unwrap_or("");
+        line.chars().map(|c| if c == '\t' { 4 } else { 1 }).sum()
+    }
+}
+
+/// The generated code of a single type: its declaration followed by extensions and helper
+/// declarations (e.g. the structs of enum variants).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeDefinition {
+    /// The top-level items, in output order.
+    pub items: Vec<Item>,
+}
+
+impl TypeDefinition {
+    /// The main declaration of the type, if it is a struct, enum or class.
+    pub fn declaration(&self) -> Option<&Decl> {
+        self.items.iter().find_map(|item| match item {
+            Item::Decl(decl) => Some(decl),
+            _ => None,
+        })
+    }
+
+    /// The main declaration of the type, for modification.
+    pub fn declaration_mut(&mut self) -> Option<&mut Decl> {
+        self.items.iter_mut().find_map(|item| match item {
+            Item::Decl(decl) => Some(decl),
+            _ => None,
+        })
+    }
+
+    /// The extensions of the type.
+    pub fn extensions_mut(&mut self) -> impl Iterator<Item = &mut Extension> {
+        self.items.iter_mut().filter_map(|item| match item {
+            Item::Extension(extension) => Some(extension),
+            _ => None,
+        })
+    }
+
+    /// Render the items with the default style and the given indentation.
+    pub fn render(&self, indent: IndentStyle) -> String {
+        self.print(&Printer::new(indent))
+    }
+
+    /// Render the items in the style of a printer, separated by blank lines.
+    pub fn print(&self, printer: &Printer) -> String {
+        self.print_declarations(printer).0
+    }
+
+    /// Render the items like [`TypeDefinition::print`], along with the name, kind and range
+    /// of every top-level declaration in the rendered code.
+    pub(crate) fn print_declarations(
+        &self,
+        printer: &Printer,
+    ) -> (String, Vec<(String, DeclarationKind, Range<usize>)>) {
+        let mut out = Output::new(printer);
+        let mut declarations = Vec::new();
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                out.text.push_str("\n\n");
+            }
+            let start = out.text.len();
+            match item {
+                Item::Decl(decl) => {
+                    decl.print(&mut out, 0);
+                    declarations.push((decl.name.clone(), decl.kind, start..out.text.len()));
+                }
+                Item::Extension(extension) => {
+                    extension.print(&mut out);
+                    declarations.push((
+                        extension.name.clone(),
+                        DeclarationKind::Extension,
+                        start..out.text.len(),
+                    ));
+                }
+                Item::Raw(code) => {
+                    let code = printer.indent.reindent(code.trim_end());
+                    declarations.extend(offset_declarations(parse_declarations(&code), start));
+                    out.text.push_str(&code);
+                }
+            }
+        }
+        (out.text, declarations)
+    }
+}
+
+/// A top-level item of a [`TypeDefinition`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    /// A struct, enum or class.
+    Decl(Decl),
+    /// An extension.
+    Extension(Extension),
+    /// Code which isn't modelled by the tree, indented with four spaces per level.
+    Raw(String),
+}
+
+/// A struct, enum or class declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decl {
+    /// Doc comment lines, including the leading `///`.
+    pub docs: Vec<String>,
+    /// Attributes written on their own line above the declaration, e.g. `@objc(Name)`.
+    pub attributes: Vec<String>,
+    /// Modifiers before the keyword, e.g. `public` or `final`.
+    pub modifiers: Vec<String>,
+    /// Whether this is a struct, enum or class.
+    pub kind: DeclarationKind,
+    /// The name of the type.
+    pub name: String,
+    /// Generic parameters, written between angle brackets.
+    pub generics: Vec<String>,
+    /// Raw type, superclass and protocols, in order.
+    pub conformances: Vec<String>,
+    /// The members of the body.
+    pub members: Vec<Member>,
+}
+
+impl Decl {
+    /// A declaration with the given modifiers and no members.
+    pub fn new(kind: DeclarationKind, modifiers: &[&str], name: impl Into<String>) -> Self {
+        Self {
+            docs: vec![],
+            attributes: vec![],
+            modifiers: modifiers.iter().map(|m| m.to_string()).collect(),
+            kind,
+            name: name.into(),
+            generics: vec![],
+            conformances: vec![],
+            members: vec![],
+        }
+    }
+
+    /// A `public struct`.
+    pub fn public_struct(name: impl Into<String>) -> Self {
+        Self::new(DeclarationKind::Struct, &["public"], name)
+    }
+
+    /// A `public enum`.
+    pub fn public_enum(name: impl Into<String>) -> Self {
+        Self::new(DeclarationKind::Enum, &["public"], name)
+    }
+
+    /// The `private enum CodingKeys: String, CodingKey` of a Codable implementation.
+    pub fn coding_keys(cases: Vec<EnumCase>) -> Self {
+        let mut decl = Self::new(DeclarationKind::Enum, &["private"], "CodingKeys");
+        decl.conformances = vec!["String".into(), "CodingKey".into()];
+        decl.members = cases.into_iter().map(Member::Case).collect();
+        decl
+    }
+
+    /// Add a conformance, unless the declaration already has it.
+    pub fn conform_to(&mut self, protocol: impl Into<String>) {
+        let protocol = protocol.into();
+        if !self.conformances.contains(&protocol) {
+            self.conformances.push(protocol);
+        }
+    }
+
+    /// The stored properties of the declaration.
+    pub fn properties(&self) -> impl Iterator<Item = &Property> {
+        self.members.iter().filter_map(|member| match member {
+            Member::Property(property) => Some(property),
+            _ => None,
+        })
+    }
+
+    /// The cases of the declaration.
+    pub fn cases(&self) -> impl Iterator<Item = &EnumCase> {
+        self.members.iter().filter_map(|member| match member {
+            Member::Case(case) => Some(case),
+            _ => None,
+        })
+    }
+
+    fn print(&self, out: &mut Output, level: usize) {
+        out.leading_lines(&self.docs, level);
+        out.leading_lines(&self.attributes, level);
+        out.indent(level);
+        for modifier in &self.modifiers {
+            out.text.push_str(modifier);
+            out.text.push(' ');
+        }
+        let _ = write!(out.text, "{} {}", self.kind, self.name);
+        if !self.generics.is_empty() {
+            let _ = write!(out.text, "<{}>", self.generics.join(", "));
+        }
+        out.conformances(&self.conformances);
+        out.open_brace(level);
+        out.members(&self.members, level + 1);
+        out.close_brace(level);
+    }
+}
+
+/// An extension of a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extension {
+    /// The `// MARK: -` comment above the extension.
+    pub mark: Option<String>,
+    /// The extended type.
+    pub name: String,
+    /// Protocols the extension conforms the type to.
+    pub conformances: Vec<String>,
+    /// The members of the body.
+    pub members: Vec<Member>,
+}
+
+impl Extension {
+    /// An empty extension of a type.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            mark: None,
+            name: name.into(),
+            conformances: vec![],
+            members: vec![],
+        }
+    }
+
+    /// Set the `// MARK: -` comment above the extension.
+    pub fn mark(mut self, mark: impl Into<String>) -> Self {
+        self.mark = Some(mark.into());
+        self
+    }
+
+    /// Add a conformance to the extension.
+    pub fn conforming_to(mut self, protocol: impl Into<String>) -> Self {
+        self.conformances.push(protocol.into());
+        self
+    }
+
+    fn print(&self, out: &mut Output) {
+        if let Some(mark) = &self.mark {
+            let _ = writeln!(out.text, "// MARK: - {}", mark);
+        }
+        let _ = write!(out.text, "extension {}", self.name);
+        out.conformances(&self.conformances);
+        out.open_brace(0);
+        out.members(&self.members, 1);
+        out.close_brace(0);
+    }
+}
+
+/// A member of a declaration or extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Member {
+    /// A stored or static property.
+    Property(Property),
+    /// An enum case.
+    Case(EnumCase),
+    /// An initializer or method.
+    Function(Function),
+    /// A nested declaration, e.g. `CodingKeys`.
+    Decl(Decl),
+    /// Code which isn't modelled by the tree, indented with four spaces per level relative
+    /// to the member.
+    Raw(String),
+}
+
+impl Member {
+    /// Whether no blank line is needed between this member and the next one.
+    fn groups_with(&self, next: &Member) -> bool {
+        match (self, next) {
+            (Member::Property(a), Member::Property(b)) => a.is_static() == b.is_static(),
+            (Member::Case(_), Member::Case(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Render members one nesting level deep with the default style.
+pub(crate) fn render_members(members: &[Member]) -> String {
+    let printer = Printer::default();
+    let mut out = Output::new(&printer);
+    out.members(members, 1);
+    out.text
+}
+
+/// A property, e.g. `public let name: String`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Property {
+    /// Doc comment lines, including the leading `///`.
+    pub docs: Vec<String>,
+    /// Attributes written before the modifiers, e.g. a property wrapper or `@NSManaged`.
+    pub attributes: Vec<String>,
+    /// Modifiers, e.g. `public` and `static`.
+    pub modifiers: Vec<String>,
+    /// Whether the property is declared with `var` instead of `let`.
+    pub mutable: bool,
+    /// The name of the property.
+    pub name: String,
+    /// The Swift type of the property.
+    pub ty: String,
+    /// The initial value.
+    pub value: Option<Value>,
+}
+
+/// The initial value of a property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// Any expression, written as is.
+    Expr(String),
+    /// An array literal, wrapped with one element per line when it is too long.
+    Array(Vec<String>),
+}
+
+impl Property {
+    /// A `public let` property.
+    pub fn constant(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            docs: vec![],
+            attributes: vec![],
+            modifiers: vec!["public".into()],
+            mutable: false,
+            name: name.into(),
+            ty: ty.into(),
+            value: None,
+        }
+    }
+
+    /// A `public var` property.
+    pub fn variable(name: impl Into<String>, ty: impl Into<String>) -> Self {
+        Self {
+            mutable: true,
+            ..Self::constant(name, ty)
+        }
+    }
+
+    /// Make the property `static` with an initial value.
+    pub fn static_value(mut self, value: impl Into<String>) -> Self {
+        self.modifiers.push("static".into());
+        self.value = Some(Value::Expr(value.into()));
+        self
+    }
+
+    /// Make the property `static` with an array literal as initial value.
+    pub fn static_array(mut self, elements: Vec<String>) -> Self {
+        self.modifiers.push("static".into());
+        self.value = Some(Value::Array(elements));
+        self
+    }
+
+    /// Add a doc comment line, without the leading `///`.
+    pub fn doc(mut self, line: &str) -> Self {
+        self.docs.push(format!("/// {}", line));
+        self
+    }
+
+    /// Add an attribute, e.g. `@NSManaged`.
+    pub fn attribute(mut self, attribute: impl Into<String>) -> Self {
+        self.attributes.push(attribute.into());
+        self
+    }
+
+    /// Whether the property is declared `static`.
+    pub fn is_static(&self) -> bool {
+        self.modifiers.iter().any(|modifier| modifier == "static")
+    }
+
+    fn print(&self, out: &mut Output, level: usize) {
+        out.leading_lines(&self.docs, level);
+        out.indent(level);
+        for word in self.attributes.iter().chain(&self.modifiers) {
+            out.text.push_str(word);
+            out.text.push(' ');
+        }
+        let keyword = if self.mutable { "var" } else { "let" };
+        let _ = write!(
+            out.text,
+            "{} {}{}{}",
+            keyword,
+            self.name,
+            out.printer.colon(),
+            self.ty
+        );
+        match &self.value {
+            None => {}
+            Some(Value::Expr(value)) => {
+                let _ = write!(out.text, " = {}", value);
+            }
+            Some(Value::Array(elements)) => {
+                let inline = format!("[{}]", elements.join(", "));
+                let fits = out
+                    .printer
+                    .line_width
+                    .is_none_or(|width| out.column() + 3 + inline.len() <= width);
+                if fits || elements.is_empty() {
+                    let _ = write!(out.text, " = {}", inline);
+                } else {
+                    out.text.push_str(" = [\n");
+                    for (i, element) in elements.iter().enumerate() {
+                        out.indent(level + 1);
+                        out.text.push_str(element);
+                        if i + 1 < elements.len() || out.printer.trailing_commas {
+                            out.text.push(',');
+                        }
+                        out.text.push('\n');
+                    }
+                    out.indent(level);
+                    out.text.push(']');
+                }
+            }
+        }
+    }
+}
+
+/// An enum case, e.g. `case failure(String)` or `case active = "active"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnumCase {
+    /// Doc comment lines, including the leading `///`.
+    pub docs: Vec<String>,
+    /// Whether the case is `indirect`.
+    pub indirect: bool,
+    /// The name of the case.
+    pub name: String,
+    /// The types of the associated values.
+    pub associated: Vec<String>,
+    /// The string raw value.
+    pub raw_value: Option<String>,
+}
+
+impl EnumCase {
+    /// A case without associated or raw value.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            docs: vec![],
+            indirect: false,
+            name: name.into(),
+            associated: vec![],
+            raw_value: None,
+        }
+    }
+
+    /// A case with a string raw value.
+    pub fn with_raw_value(name: impl Into<String>, raw_value: impl Into<String>) -> Self {
+        Self {
+            raw_value: Some(raw_value.into()),
+            ..Self::new(name)
+        }
+    }
+
+    /// A case with associated values.
+    pub fn with_associated(name: impl Into<String>, associated: Vec<String>) -> Self {
+        Self {
+            associated,
+            ..Self::new(name)
+        }
+    }
+
+    fn print(&self, out: &mut Output, level: usize) {
+        out.leading_lines(&self.docs, level);
+        out.indent(level);
+        if self.indirect {
+            out.text.push_str("indirect ");
+        }
+        let _ = write!(out.text, "case {}", self.name);
+        if !self.associated.is_empty() {
+            let _ = write!(out.text, "({})", self.associated.join(", "));
+        }
+        if let Some(raw_value) = &self.raw_value {
+            let _ = write!(out.text, " = \"{}\"", escape_string(raw_value));
+        }
+    }
+}
+
+/// An initializer or method with its body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    /// Doc comment lines, including the leading `///`.
+    pub docs: Vec<String>,
+    /// Everything before the opening brace, e.g. `public func encode(to encoder: Encoder) throws`.
+    pub signature: String,
+    /// The lines of the body, indented with four spaces per level relative to the function.
+    pub body: Vec<String>,
+}
+
+impl Function {
+    /// A function with a body.
+    ///
+    /// The body may be indented by any amount, its common indentation is removed.
+    pub fn new(signature: impl Into<String>, body: &str) -> Self {
+        let common = body
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start_matches(' ').len())
+            .min()
+            .unwrap_or(0);
+        Self {
+            docs: vec![],
+            signature: signature.into(),
+            body: body
+                .lines()
+                .map(|line| line.get(common..).unwrap_or("").trim_end().to_string())
+                .collect(),
+        }
+    }
+
+    /// Add a doc comment line, without the leading `///`.
+    pub fn doc(mut self, line: &str) -> Self {
+        self.docs.push(format!("/// {}", line));
+        self
+    }
+
+    fn print(&self, out: &mut Output, level: usize) {
+        out.leading_lines(&self.docs, level);
+        out.indent(level);
+        out.text.push_str(&self.signature);
+        out.open_brace(level);
+        if !self.body.is_empty() {
+            out.lines(self.body.iter().map(String::as_str), level + 1);
+            out.text.push('\n');
+        }
+        out.close_brace(level);
+    }
+}
+
+impl fmt::Display for TypeDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(IndentStyle::default()))
+    }
+}
+
+/// Convert the leading groups of four spaces of a line to `unit`.
+pub(crate) fn reindent_line(line: &str, unit: &str) -> String {
+    let spaces = line.len() - line.trim_start_matches(' ').len();
+    format!(
+        "{}{}{}",
+        unit.repeat(spaces / 4),
+        " ".repeat(spaces % 4),
+        &line[spaces..]
+    )
+}
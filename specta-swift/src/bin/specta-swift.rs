@@ -0,0 +1,7 @@
+//! Export Swift bindings from a JSON dump of the types.
+//!
+//! Write the dump with the `dump` command of a binary calling [`specta_swift::cli::run`], then:
+//! `specta-swift export ./Types.swift --types ./types.json`
+fn main() {
+    specta_swift::cli::run_from_dump();
+}
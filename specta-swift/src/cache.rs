@@ -0,0 +1,156 @@
+//! Fingerprint cache for incremental exports.
+//!
+//! When [`Swift::cache_file`](crate::Swift::cache_file) is set, the generated code of every
+//! type is stored alongside a fingerprint of everything it was generated from. On the next
+//! export, types whose fingerprint is unchanged reuse the cached code instead of being
+//! rendered again.
+//!
+//! A fingerprint covers the configuration, the type, the Swift names chosen for it and the
+//! contents and Swift names of every type it references, directly or through other types.
+//! Options holding closures can't be fingerprinted, so the cache is bypassed while any is
+//! set, see [`is_cacheable`].
+//!
+//! Fingerprints use the standard library hasher, which is not guaranteed to be stable
+//! across Rust releases. A changed hasher only causes cache misses.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+use specta::datatype::NamedDataType;
+use specta::TypeCollection;
+
+use crate::datatype::reference::reference_name;
+use crate::naming::resolver::VariantStructNames;
+use crate::swift::{DuplicateNameStrategy, NamingConvention, Swift};
+use crate::utils::validation::collect_references;
+
+/// First line of every cache file. Changing the format or the crate version invalidates the cache.
+const HEADER: &str = concat!("specta-swift-cache v1 ", env!("CARGO_PKG_VERSION"));
+
+/// Cached generated code keyed by Swift type name.
+#[derive(Debug, Default)]
+pub(crate) struct ExportCache {
+    entries: BTreeMap<String, (u64, String)>,
+}
+
+impl ExportCache {
+    /// Load a cache file. A missing, outdated or malformed file results in an empty cache.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Write the cache to a file.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let size = self
+            .entries
+            .iter()
+            .map(|(name, (_, code))| name.len() + code.len() + 32)
+            .sum::<usize>();
+        let mut content = String::with_capacity(HEADER.len() + 1 + size);
+        let _ = writeln!(content, "{}", HEADER);
+        for (name, (fingerprint, code)) in &self.entries {
+            let _ = writeln!(content, "{:016x} {} {}", fingerprint, code.len(), name);
+            content.push_str(code);
+            content.push('\n');
+        }
+        std::fs::write(path, content)
+    }
+
+    /// Get the cached code for a type if its fingerprint is unchanged.
+    pub fn get(&self, swift_name: &str, fingerprint: u64) -> Option<&str> {
+        self.entries
+            .get(swift_name)
+            .filter(|(cached, _)| *cached == fingerprint)
+            .map(|(_, code)| code.as_str())
+    }
+
+    /// Store the generated code for a type.
+    pub fn insert(&mut self, swift_name: String, fingerprint: u64, code: String) {
+        self.entries.insert(swift_name, (fingerprint, code));
+    }
+
+    fn parse(content: &str) -> Option<Self> {
+        let mut rest = content.strip_prefix(HEADER)?.strip_prefix('\n')?;
+        let mut entries = BTreeMap::new();
+
+        while !rest.is_empty() {
+            let (line, after) = rest.split_once('\n')?;
+            let mut parts = line.splitn(3, ' ');
+            let fingerprint = u64::from_str_radix(parts.next()?, 16).ok()?;
+            let len = parts.next()?.parse::<usize>().ok()?;
+            let name = parts.next()?.to_string();
+
+            let code = after.get(..len)?.to_string();
+            rest = after.get(len..)?.strip_prefix('\n')?;
+            entries.insert(name, (fingerprint, code));
+        }
+
+        Some(Self { entries })
+    }
+}
+
+/// Check whether generated code can be cached with the configuration of `swift`.
+///
+/// Custom naming, custom duplicate name strategies, type filters, special type handlers and
+/// syntax tree hooks are closures whose behavior a fingerprint can't capture. String
+/// post-processors run on the cached declarations, so they don't matter.
+pub(crate) fn is_cacheable(swift: &Swift) -> bool {
+    !matches!(swift.naming, NamingConvention::Custom(_))
+        && !matches!(
+            swift.duplicate_name_strategy,
+            DuplicateNameStrategy::Custom(_) | DuplicateNameStrategy::CustomWithContext(_)
+        )
+        && swift.filters.is_empty()
+        && swift.special_types.is_empty()
+        && swift.ast_post_processors.is_empty()
+}
+
+/// Fingerprint everything the generated code of a type depends on.
+///
+/// This covers the exporter configuration, the type itself, the names chosen for it and its
+/// variant structs, and the contents and Swift names of the types it references. Rendering
+/// looks into referenced types, e.g. whether they are string enums, which in turn depends on
+/// their own references, so they are followed transitively.
+pub(crate) fn fingerprint(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &NamedDataType,
+    swift_name: &str,
+    variant_structs: &VariantStructNames,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    format!("{:?}", swift).hash(&mut hasher);
+    format!("{:?}", ndt).hash(&mut hasher);
+    swift_name.hash(&mut hasher);
+    variant_structs
+        .all_for(swift, ndt, swift_name)
+        .hash(&mut hasher);
+
+    let mut visited = HashSet::from([ndt.sid()]);
+    let mut pending = Vec::new();
+    collect_references(ndt.ty(), &mut pending);
+    while let Some(sid) = pending.pop() {
+        if !visited.insert(sid) {
+            continue;
+        }
+        let name = reference_name(swift, types, sid);
+        name.hash(&mut hasher);
+        if let Some(referenced) = types.get(sid) {
+            format!("{:?}", referenced).hash(&mut hasher);
+            if let Some(name) = &name {
+                variant_structs
+                    .all_for(swift, referenced, name)
+                    .hash(&mut hasher);
+            }
+            collect_references(referenced.ty(), &mut pending);
+        }
+    }
+
+    hasher.finish()
+}
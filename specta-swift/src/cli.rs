@@ -0,0 +1,363 @@
+//! Command line interface for running the exporter outside of a build script.
+//!
+//! Specta types only exist inside the binary which defines them, so the CLI is a small
+//! runner which you call from your own binary with the types to export:
+//!
+//! ```rust,no_run
+//! use specta::TypeCollection;
+//!
+//! fn main() {
+//!     let types = TypeCollection::default();
+//!     // or `specta::export()` with the `export` feature of `specta`
+//!     specta_swift::cli::run(&types);
+//! }
+//! ```
+//!
+//! The binary then supports the following subcommands:
+//!
+//! - `export [PATH]`: write the bindings to `PATH`, or stdout when omitted
+//! - `diff PATH`: show the declarations which would change in `PATH`
+//! - `check PATH`: like `diff`, but exit with a non-zero status if `PATH` is out of date
+//! - `manifest PATH`: write a JSON manifest of the generated type names to `PATH`
+//! - `dump PATH`: write the types to `PATH` as JSON, see [`dump_types`]
+//!
+//! Every builder option of [`Swift`] which can be expressed as text is available as a flag.
+//! Run with `--help` for the full list.
+//!
+//! Teams which generate code outside of the Rust build can `dump` the types once and run the
+//! `specta-swift` binary of this crate on the dump, which takes the same commands and flags:
+//!
+//! ```bash
+//! my-exporter dump ./types.json
+//! specta-swift export ./Types.swift --types ./types.json --naming camel-case
+//! ```
+
+use std::path::PathBuf;
+
+use specta::TypeCollection;
+
+use crate::error::{Error, Result};
+use crate::swift::{QualifyOptions, Swift};
+
+mod dump;
+
+pub use dump::{dump_types, load_types};
+
+/// Usage text printed by `--help`.
+pub const USAGE: &str = "\
+Usage: <binary> <COMMAND> [OPTIONS]
+
+Commands:
+  export [PATH]   Write the Swift bindings to PATH (stdout if omitted)
+  diff PATH       Show the declarations which would change in PATH
+  check PATH      Exit with an error if PATH is out of date
+  manifest PATH   Write a JSON manifest of the generated type names to PATH
+  dump PATH       Write the types to PATH as JSON
+
+Options:
+  --types PATH                  Load the types from a JSON dump instead
+  --header TEXT                 Header comment for the generated file
+  --indent tabs|N               Indent with tabs or N spaces
+  --brace-style STYLE           same-line or next-line
+  --space-before-colon          Write `name : Type` in declarations
+  --trailing-commas             End wrapped array literals with a comma
+  --line-width N                Wrap array literals longer than N columns
+  --naming STYLE                pascal-case, camel-case or snake-case
+  --unicode-identifiers MODE    normalize or transliterate
+  --shadow-prefix PREFIX        Prefix type names shadowing Swift types, e.g. Data
+  --generics STYLE              protocol or typealias
+  --monomorphize                Declare each instantiation of a generic type
+  --optionals STYLE             question-mark or optional
+  --protocol NAME               Import an additional module (repeatable)
+  --serde                       Validate types for Serde compatibility
+  --struct-naming STRATEGY      auto-rename or keep-original
+  --initializers                Generate public memberwise initializers
+  --initializer NAME            Generate an initializer for this type (repeatable)
+  --no-initializer NAME         Skip the initializer of this type (repeatable)
+  --always-emit-codable         Emit explicit Codable implementations for every struct
+  --no-codable                  Emit plain types without Codable conformance
+  --codable-format FORMAT       json, message-pack or property-list
+  --key-strategy STRATEGY       coding-keys or convert-from-snake-case
+  --missing-collections MODE    error or empty
+  --optional-collections MODE   optional or empty
+  --missing-reference MODE      error or stub
+  --resilient-decoding          Decode arrays, strings and booleans with lenient property wrappers
+  --validate-array-lengths      Reject fixed-size arrays of the wrong length when decoding
+  --char-type TYPE              character or string
+  --newtypes STYLE              struct or raw-representable
+  --variant-ordering ORDER      declaration or alphabetical
+  --box-variants-larger-than N  Declare enum cases with payloads over N bytes indirect
+  --box-variants NAME           Declare every data case of this enum indirect (repeatable)
+  --no-box-variants NAME        Never declare cases of this enum indirect (repeatable)
+  --date-strategy STRATEGY      deferred-to-date, iso8601, seconds-since-1970 or
+                                milliseconds-since-1970
+  --data-strategy STRATEGY      base64 or deferred-to-data
+  --coders                      Emit JSONEncoder.specta and JSONDecoder.specta
+  --linux-support               Make the output compile with Foundation on Linux
+  --string-enum-helpers         Emit allRawValues and init?(caseInsensitive:) for string enums
+  --json-helpers                Emit init?(jsonData:) and jsonData() for every type
+  --dictionary-helpers          Emit init(dictionary:) and dictionary for every struct
+  --copy-modifiers              Emit with(field:) copy-modifiers for every struct
+  --change-tracking             Emit allKeyPaths and changedFields(from:) for every struct
+  --cloudkit-record NAME        Emit CKRecord conversions for this struct (repeatable)
+  --core-data-entity NAME       Emit an NSManagedObject subclass for this struct (repeatable)
+  --mocks                       Generate mock values for previews and tests
+  --preview-data                Generate a PreviewData namespace of samples
+  --schema-hash                 Emit a SpectaSchema.hash constant
+  --duplicates STRATEGY         warn, error, qualify or suffix
+  --qualify-separator TEXT      Separator used in qualified names
+  --qualify-depth N             Module segments kept in qualified names
+  --strip-prefix PATH           Module prefix removed from qualified names (repeatable)
+  --include-module PATH         Only export types from this module (repeatable)
+  --exclude-module PATH         Never export types from this module (repeatable)
+  --root NAME                   Only export this type and its dependencies (repeatable)
+  --assume-external NAME        Treat this Swift type as declared elsewhere (repeatable)
+  --cache PATH                  Cache generated code between runs
+  -h, --help                    Print this message";
+
+/// The subcommand to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Write the bindings to a file, or stdout when no path is given.
+    Export(Option<PathBuf>),
+    /// Show the declarations which would change in a file.
+    Diff(PathBuf),
+    /// Fail if a file is out of date.
+    Check(PathBuf),
+    /// Write the manifest of generated names to a file.
+    Manifest(PathBuf),
+    /// Write the types as a JSON dump to a file.
+    Dump(PathBuf),
+    /// Print the usage text.
+    Help,
+}
+
+/// Parsed command line arguments.
+#[derive(Debug, Clone)]
+pub struct Cli {
+    /// The subcommand to run.
+    pub command: Command,
+    /// The exporter configured from the flags.
+    pub swift: Swift,
+    /// The JSON dump given with `--types`, used instead of the types of the binary.
+    pub types: Option<PathBuf>,
+}
+
+impl Cli {
+    /// Parse command line arguments, excluding the binary name.
+    pub fn parse<I, S>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut args = args.into_iter().map(Into::into);
+        let mut swift = Swift::new();
+        let mut qualify = QualifyOptions::default();
+        let mut include_modules = Vec::new();
+        let mut exclude_modules = Vec::new();
+        let mut command_name = None;
+        let mut path = None;
+        let mut types = None;
+
+        while let Some(arg) = args.next() {
+            let mut value = |flag: &str| {
+                args.next()
+                    .ok_or_else(|| Error::Configuration(format!("missing value for '{}'", flag)))
+            };
+
+            match arg.as_str() {
+                "-h" | "--help" => {
+                    return Ok(Self {
+                        command: Command::Help,
+                        swift,
+                        types,
+                    })
+                }
+                "--types" => types = Some(PathBuf::from(value(&arg)?)),
+                "--header" => swift = swift.header(value(&arg)?),
+                "--indent" => swift = swift.indent(value(&arg)?.parse()?),
+                "--brace-style" => swift = swift.brace_style(value(&arg)?.parse()?),
+                "--space-before-colon" => swift = swift.space_before_colon(true),
+                "--trailing-commas" => swift = swift.trailing_commas(true),
+                "--line-width" => {
+                    let width = value(&arg)?;
+                    swift = swift.line_width(width.parse().map_err(|_| {
+                        Error::Configuration(format!("invalid line width '{}'", width))
+                    })?);
+                }
+                "--naming" => swift = swift.naming(value(&arg)?.parse()?),
+                "--unicode-identifiers" => swift = swift.unicode_identifiers(value(&arg)?.parse()?),
+                "--shadow-prefix" => swift = swift.prefix_shadowed_names(value(&arg)?),
+                "--generics" => swift = swift.generics(value(&arg)?.parse()?),
+                "--monomorphize" => swift = swift.monomorphize(true),
+                "--optionals" => swift = swift.optionals(value(&arg)?.parse()?),
+                "--protocol" => swift = swift.add_protocol(value(&arg)?),
+                "--serde" => swift = swift.with_serde(),
+                "--struct-naming" => swift = swift.struct_naming(value(&arg)?.parse()?),
+                "--initializers" => swift.generate_initializers = true,
+                "--initializer" => swift = swift.initializers_for([value(&arg)?]),
+                "--no-initializer" => swift = swift.skip_initializers_for([value(&arg)?]),
+                "--always-emit-codable" => swift = swift.always_emit_codable(true),
+                "--no-codable" => swift = swift.emit_codable(false),
+                "--codable-format" => swift = swift.codable_format(value(&arg)?.parse()?),
+                "--key-strategy" => swift = swift.key_strategy(value(&arg)?.parse()?),
+                "--missing-collections" => swift = swift.missing_collections(value(&arg)?.parse()?),
+                "--optional-collections" => {
+                    swift = swift.optional_collections(value(&arg)?.parse()?)
+                }
+                "--missing-reference" => swift = swift.missing_reference(value(&arg)?.parse()?),
+                "--resilient-decoding" => swift = swift.resilient_decoding(true),
+                "--validate-array-lengths" => swift = swift.validate_array_lengths(true),
+                "--char-type" => swift = swift.char_type(value(&arg)?.parse()?),
+                "--newtypes" => swift = swift.newtypes(value(&arg)?.parse()?),
+                "--variant-ordering" => swift = swift.variant_ordering(value(&arg)?.parse()?),
+                "--box-variants-larger-than" => {
+                    let bytes = value(&arg)?;
+                    swift =
+                        swift.box_variants_larger_than(bytes.parse().map_err(|_| {
+                            Error::Configuration(format!("invalid size '{}'", bytes))
+                        })?);
+                }
+                "--box-variants" => swift = swift.box_variants_of([value(&arg)?]),
+                "--no-box-variants" => swift = swift.never_box_variants_of([value(&arg)?]),
+                "--date-strategy" => swift = swift.date_strategy(value(&arg)?.parse()?),
+                "--data-strategy" => swift = swift.data_strategy(value(&arg)?.parse()?),
+                "--coders" => swift = swift.with_coders(),
+                "--linux-support" => swift = swift.with_linux_support(),
+                "--string-enum-helpers" => swift = swift.with_string_enum_helpers(),
+                "--json-helpers" => swift = swift.with_json_helpers(),
+                "--dictionary-helpers" => swift = swift.with_dictionary_helpers(),
+                "--copy-modifiers" => swift = swift.with_copy_modifiers(),
+                "--change-tracking" => swift = swift.with_change_tracking(),
+                "--cloudkit-record" => swift = swift.cloudkit_records_for([value(&arg)?]),
+                "--core-data-entity" => swift = swift.core_data_entities_for([value(&arg)?]),
+                "--mocks" => swift = swift.with_mocks(),
+                "--preview-data" => swift = swift.with_preview_data(),
+                "--schema-hash" => swift = swift.with_schema_hash(),
+                "--duplicates" => swift = swift.duplicate_name_strategy(value(&arg)?.parse()?),
+                "--qualify-separator" => qualify = qualify.separator(value(&arg)?),
+                "--qualify-depth" => {
+                    let depth = value(&arg)?;
+                    qualify = qualify.depth(depth.parse().map_err(|_| {
+                        Error::Configuration(format!("invalid qualify depth '{}'", depth))
+                    })?);
+                }
+                "--strip-prefix" => qualify = qualify.strip_prefix(value(&arg)?),
+                "--include-module" => include_modules.push(value(&arg)?),
+                "--exclude-module" => exclude_modules.push(value(&arg)?),
+                "--root" => swift = swift.roots([value(&arg)?]),
+                "--assume-external" => swift = swift.assume_external([value(&arg)?]),
+                "--cache" => swift = swift.cache_file(value(&arg)?),
+                flag if flag.starts_with('-') && flag != "-" => {
+                    return Err(Error::Configuration(format!("unknown option '{}'", flag)));
+                }
+                _ if command_name.is_none() => command_name = Some(arg),
+                _ if path.is_none() => path = Some(PathBuf::from(arg)),
+                _ => {
+                    return Err(Error::Configuration(format!(
+                        "unexpected argument '{}'",
+                        arg
+                    )))
+                }
+            }
+        }
+
+        swift = swift.qualify_options(qualify);
+        if !include_modules.is_empty() {
+            swift = swift.include_modules(include_modules);
+        }
+        if !exclude_modules.is_empty() {
+            swift = swift.exclude_modules(exclude_modules);
+        }
+
+        let required_path = |command: &str| {
+            path.clone()
+                .ok_or_else(|| Error::Configuration(format!("'{}' requires a path", command)))
+        };
+        let command = match command_name.as_deref() {
+            Some("export") => Command::Export(path.clone().filter(|path| path.as_os_str() != "-")),
+            Some("diff") => Command::Diff(required_path("diff")?),
+            Some("check") => Command::Check(required_path("check")?),
+            Some("manifest") => Command::Manifest(required_path("manifest")?),
+            Some("dump") => Command::Dump(required_path("dump")?),
+            Some(other) => {
+                return Err(Error::Configuration(format!("unknown command '{}'", other)))
+            }
+            None => Command::Help,
+        };
+
+        Ok(Self {
+            command,
+            swift,
+            types,
+        })
+    }
+
+    /// Run the command, printing its output to stdout.
+    ///
+    /// The types are loaded from the `--types` dump when one was given.
+    /// Returns `false` if a `check` found the file to be out of date.
+    pub fn execute(&self, types: &TypeCollection) -> Result<bool> {
+        match &self.types {
+            Some(path) => self.execute_with(&load_types(&std::fs::read_to_string(path)?)?),
+            None => self.execute_with(types),
+        }
+    }
+
+    fn execute_with(&self, types: &TypeCollection) -> Result<bool> {
+        match &self.command {
+            Command::Export(Some(path)) => self.swift.export_to(path, types)?,
+            Command::Export(None) => print!("{}", self.swift.export(types)?),
+            Command::Diff(path) => println!("{}", self.swift.diff(path, types)?),
+            Command::Check(path) => {
+                let diff = self.swift.diff(path, types)?;
+                if !diff.is_empty() {
+                    println!(
+                        "{} is out of date, regenerate it with `export`:\n{}",
+                        path.display(),
+                        diff
+                    );
+                    return Ok(false);
+                }
+                println!("{}", diff);
+            }
+            Command::Manifest(path) => self.swift.export_manifest(path, types)?,
+            Command::Dump(path) => std::fs::write(path, dump_types(types)?)?,
+            Command::Help => println!("{}", USAGE),
+        }
+
+        Ok(true)
+    }
+}
+
+/// Parse the process arguments and run the requested command.
+///
+/// Exits the process with status `1` if the command fails or a `check` finds the file out of date.
+pub fn run(types: &TypeCollection) {
+    exit_on_failure(Cli::parse(std::env::args().skip(1)).and_then(|cli| cli.execute(types)));
+}
+
+/// Like [`run`], but for binaries without types of their own, which require `--types`.
+///
+/// This is the entry point of the `specta-swift` binary.
+pub fn run_from_dump() {
+    exit_on_failure(Cli::parse(std::env::args().skip(1)).and_then(|cli| {
+        if cli.types.is_none() && cli.command != Command::Help {
+            return Err(Error::Configuration(
+                "'--types' is required, write it with the `dump` command of your exporter".into(),
+            ));
+        }
+        cli.execute(&TypeCollection::default())
+    }));
+}
+
+fn exit_on_failure(result: Result<bool>) {
+    match result {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
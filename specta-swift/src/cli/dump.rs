@@ -0,0 +1,604 @@
+//! A JSON dump of a [`TypeCollection`].
+//!
+//! Types only exist inside the binary which defines them, so the `dump` command writes them
+//! to a file which the `specta-swift` binary loads again. Named types are stored in a list
+//! and references point into it by index, so recursive and forward references survive the
+//! round trip.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use specta::builder::NamedDataTypeBuilder;
+use specta::datatype::{
+    DataType, DeprecatedType, Enum, EnumRepr, EnumVariant, Field, Fields, Generic, List, Literal,
+    Map, Primitive, Reference, Struct, Tuple,
+};
+use specta::{SpectaID, TypeCollection};
+
+use crate::error::{Error, Result};
+
+/// The version written to new dumps. Loading rejects any other version.
+const VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Dump {
+    version: u32,
+    types: Vec<DumpNamed>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DumpNamed {
+    name: String,
+    module_path: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    docs: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deprecated: Option<DumpDeprecated>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    generics: Vec<String>,
+    ty: DumpType,
+}
+
+#[derive(Serialize, Deserialize)]
+enum DumpType {
+    Primitive(String),
+    Literal(DumpLiteral),
+    List {
+        ty: Box<DumpType>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        length: Option<usize>,
+        #[serde(default, skip_serializing_if = "is_false")]
+        unique: bool,
+    },
+    Map {
+        key: Box<DumpType>,
+        value: Box<DumpType>,
+    },
+    Nullable(Box<DumpType>),
+    Struct(DumpFields),
+    Enum {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        repr: Option<DumpRepr>,
+        variants: Vec<DumpVariant>,
+    },
+    Tuple(Vec<DumpType>),
+    Reference {
+        /// Index of the referenced type in [`Dump::types`].
+        index: usize,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        generics: Vec<(String, DumpType)>,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inline: bool,
+    },
+    Generic(String),
+}
+
+#[derive(Serialize, Deserialize)]
+#[allow(non_camel_case_types)]
+enum DumpLiteral {
+    i8(i8),
+    i16(i16),
+    i32(i32),
+    u8(u8),
+    u16(u16),
+    u32(u32),
+    f32(f32),
+    f64(f64),
+    bool(bool),
+    String(String),
+    char(char),
+    None,
+}
+
+#[derive(Serialize, Deserialize)]
+enum DumpFields {
+    Unit,
+    Unnamed(Vec<DumpField>),
+    Named {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+        fields: Vec<(String, DumpField)>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct DumpField {
+    /// `None` for skipped fields.
+    ty: Option<DumpType>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    optional: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    flatten: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    inline: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    docs: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deprecated: Option<DumpDeprecated>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DumpVariant {
+    name: String,
+    #[serde(default, skip_serializing_if = "is_false")]
+    skip: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    docs: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deprecated: Option<DumpDeprecated>,
+    fields: DumpFields,
+}
+
+#[derive(Serialize, Deserialize)]
+enum DumpRepr {
+    Untagged,
+    External,
+    Internal { tag: String },
+    Adjacent { tag: String, content: String },
+    String { rename_all: Option<String> },
+}
+
+#[derive(Serialize, Deserialize)]
+enum DumpDeprecated {
+    Deprecated,
+    DeprecatedWithSince {
+        since: Option<String>,
+        note: String,
+    },
+    DeprecatedRenamed {
+        since: Option<String>,
+        note: String,
+        renamed: String,
+    },
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// Write the types of a collection as a JSON dump which [`load_types`] reads back.
+///
+/// Fails if a type references a type which is missing from the collection.
+pub fn dump_types(types: &TypeCollection) -> Result<String> {
+    let named = types.into_sorted_iter().collect::<Vec<_>>();
+    let indices = named
+        .iter()
+        .enumerate()
+        .map(|(i, ndt)| (ndt.sid(), i))
+        .collect::<HashMap<_, _>>();
+    let writer = Writer { indices: &indices };
+
+    let dump = Dump {
+        version: VERSION,
+        types: named
+            .iter()
+            .map(|ndt| {
+                Ok(DumpNamed {
+                    name: ndt.name().to_string(),
+                    module_path: ndt.module_path().to_string(),
+                    docs: ndt.docs().to_string(),
+                    deprecated: ndt.deprecated().map(dump_deprecated),
+                    generics: ndt.generics().iter().map(ToString::to_string).collect(),
+                    ty: writer.ty(ndt.ty())?,
+                })
+            })
+            .collect::<Result<_>>()?,
+    };
+
+    serde_json::to_string_pretty(&dump)
+        .map_err(|err| Error::Configuration(format!("failed to write type dump: {}", err)))
+}
+
+/// Load the types written by [`dump_types`].
+///
+/// Loaded types keep their names, module paths, documentation and deprecation notes, but
+/// are given new [`SpectaID`]s.
+pub fn load_types(json: &str) -> Result<TypeCollection> {
+    let dump: Dump = serde_json::from_str(json)
+        .map_err(|err| Error::Configuration(format!("invalid type dump: {}", err)))?;
+    if dump.version != VERSION {
+        return Err(Error::Configuration(format!(
+            "unsupported type dump version {}, expected {}",
+            dump.version, VERSION
+        )));
+    }
+
+    // References need the id of their target before it is created. `create` hands out ids
+    // in sequence, so a scratch collection tells us the ids a fresh collection will assign.
+    let mut scratch = TypeCollection::default();
+    let sids = (0..dump.types.len())
+        .map(|_| placeholder(&mut scratch))
+        .collect::<Result<Vec<_>>>()?;
+    let reader = Reader { sids: &sids };
+
+    let mut types = TypeCollection::default();
+    for (named, expected) in dump.types.into_iter().zip(&sids) {
+        let mut builder = NamedDataTypeBuilder::new(
+            named.name,
+            named
+                .generics
+                .into_iter()
+                .map(|generic| Generic::from(Cow::Owned(generic)))
+                .collect(),
+            reader.ty(named.ty)?,
+        )
+        .module_path(named.module_path)
+        .docs(named.docs);
+        if let Some(deprecated) = named.deprecated {
+            builder = builder.deprecated(load_deprecated(deprecated));
+        }
+
+        let sid = types
+            .create(builder)
+            .map_err(|_| Error::Configuration("too many types in type dump".into()))?
+            .sid();
+        if sid != *expected {
+            return Err(Error::Configuration(
+                "type ids were not assigned in order while loading the type dump".into(),
+            ));
+        }
+    }
+
+    Ok(types)
+}
+
+fn placeholder(types: &mut TypeCollection) -> Result<SpectaID> {
+    types
+        .create(NamedDataTypeBuilder::new(
+            "",
+            vec![],
+            DataType::Tuple(Tuple::new(vec![])),
+        ))
+        .map(|reference| reference.sid())
+        .map_err(|_| Error::Configuration("too many types in type dump".into()))
+}
+
+struct Writer<'a> {
+    indices: &'a HashMap<SpectaID, usize>,
+}
+
+impl Writer<'_> {
+    fn ty(&self, ty: &DataType) -> Result<DumpType> {
+        Ok(match ty {
+            DataType::Primitive(p) => DumpType::Primitive(p.to_rust_str().into()),
+            DataType::Literal(l) => DumpType::Literal(dump_literal(l)?),
+            DataType::List(list) => DumpType::List {
+                ty: Box::new(self.ty(list.ty())?),
+                length: list.length(),
+                unique: list.unique(),
+            },
+            DataType::Map(map) => DumpType::Map {
+                key: Box::new(self.ty(map.key_ty())?),
+                value: Box::new(self.ty(map.value_ty())?),
+            },
+            DataType::Nullable(inner) => DumpType::Nullable(Box::new(self.ty(inner)?)),
+            DataType::Struct(s) => DumpType::Struct(self.fields(s.fields())?),
+            DataType::Enum(e) => DumpType::Enum {
+                repr: e.repr().map(dump_repr),
+                variants: e
+                    .variants()
+                    .iter()
+                    .map(|(name, variant)| {
+                        Ok(DumpVariant {
+                            name: name.to_string(),
+                            skip: variant.skip(),
+                            docs: variant.docs().to_string(),
+                            deprecated: variant.deprecated().map(dump_deprecated),
+                            fields: self.fields(variant.fields())?,
+                        })
+                    })
+                    .collect::<Result<_>>()?,
+            },
+            DataType::Tuple(t) => DumpType::Tuple(
+                t.elements()
+                    .iter()
+                    .map(|ty| self.ty(ty))
+                    .collect::<Result<_>>()?,
+            ),
+            DataType::Reference(r) => DumpType::Reference {
+                index: *self.indices.get(&r.sid()).ok_or_else(|| {
+                    Error::Configuration(format!(
+                        "a reference points to a type missing from the collection ({:?})",
+                        r.sid()
+                    ))
+                })?,
+                generics: r
+                    .generics()
+                    .iter()
+                    .map(|(generic, ty)| Ok((generic.to_string(), self.ty(ty)?)))
+                    .collect::<Result<_>>()?,
+                inline: r.inline(),
+            },
+            DataType::Generic(g) => DumpType::Generic(g.to_string()),
+        })
+    }
+
+    fn fields(&self, fields: &Fields) -> Result<DumpFields> {
+        Ok(match fields {
+            Fields::Unit => DumpFields::Unit,
+            Fields::Unnamed(unnamed) => DumpFields::Unnamed(
+                unnamed
+                    .fields()
+                    .iter()
+                    .map(|field| self.field(field))
+                    .collect::<Result<_>>()?,
+            ),
+            Fields::Named(named) => DumpFields::Named {
+                tag: named.tag().map(ToString::to_string),
+                fields: named
+                    .fields()
+                    .iter()
+                    .map(|(name, field)| Ok((name.to_string(), self.field(field)?)))
+                    .collect::<Result<_>>()?,
+            },
+        })
+    }
+
+    fn field(&self, field: &Field) -> Result<DumpField> {
+        Ok(DumpField {
+            ty: field.ty().map(|ty| self.ty(ty)).transpose()?,
+            optional: field.optional(),
+            flatten: field.flatten(),
+            inline: field.inline(),
+            docs: field.docs().to_string(),
+            deprecated: field.deprecated().map(dump_deprecated),
+        })
+    }
+}
+
+struct Reader<'a> {
+    sids: &'a [SpectaID],
+}
+
+impl Reader<'_> {
+    fn ty(&self, ty: DumpType) -> Result<DataType> {
+        Ok(match ty {
+            DumpType::Primitive(p) => DataType::Primitive(load_primitive(&p)?),
+            DumpType::Literal(l) => DataType::Literal(load_literal(l)),
+            DumpType::List { ty, length, unique } => {
+                let mut list = List::new(self.ty(*ty)?);
+                list.set_length(length);
+                list.set_unique(unique);
+                DataType::List(list)
+            }
+            DumpType::Map { key, value } => {
+                DataType::Map(Map::new(self.ty(*key)?, self.ty(*value)?))
+            }
+            DumpType::Nullable(inner) => DataType::Nullable(Box::new(self.ty(*inner)?)),
+            DumpType::Struct(fields) => {
+                let mut s = Struct::unit();
+                s.set_fields(self.fields(fields)?);
+                DataType::Struct(s)
+            }
+            DumpType::Enum { repr, variants } => {
+                let mut e = Enum::new();
+                if let Some(repr) = repr {
+                    e.set_repr(load_repr(repr));
+                }
+                for variant in variants {
+                    let mut v = EnumVariant::unit();
+                    v.set_skip(variant.skip);
+                    v.set_docs(variant.docs.into());
+                    v.set_deprecated(variant.deprecated.map(load_deprecated));
+                    v.set_fields(self.fields(variant.fields)?);
+                    e.variants_mut().push((variant.name.into(), v));
+                }
+                DataType::Enum(e)
+            }
+            DumpType::Tuple(elements) => DataType::Tuple(Tuple::new(
+                elements
+                    .into_iter()
+                    .map(|ty| self.ty(ty))
+                    .collect::<Result<_>>()?,
+            )),
+            DumpType::Reference {
+                index,
+                generics,
+                inline,
+            } => {
+                let sid = *self.sids.get(index).ok_or_else(|| {
+                    Error::Configuration(format!(
+                        "a reference points to type {} but the dump has {} types",
+                        index,
+                        self.sids.len()
+                    ))
+                })?;
+                let generics = generics
+                    .into_iter()
+                    .map(|(generic, ty)| Ok((Generic::from(Cow::Owned(generic)), self.ty(ty)?)))
+                    .collect::<Result<BTreeMap<_, _>>>()?;
+                DataType::Reference(Reference::construct(sid, generics, inline))
+            }
+            DumpType::Generic(g) => DataType::Generic(Generic::from(Cow::Owned(g))),
+        })
+    }
+
+    fn fields(&self, fields: DumpFields) -> Result<Fields> {
+        Ok(match fields {
+            DumpFields::Unit => Fields::Unit,
+            DumpFields::Unnamed(fields) => {
+                let mut builder = Struct::unnamed();
+                for field in fields {
+                    builder.field_mut(self.field(field)?);
+                }
+                struct_fields(builder.build())
+            }
+            DumpFields::Named { tag, fields } => {
+                let mut builder = Struct::named();
+                for (name, field) in fields {
+                    builder.field_mut(name, self.field(field)?);
+                }
+                let mut fields = struct_fields(builder.build());
+                if let (Fields::Named(named), Some(tag)) = (&mut fields, tag) {
+                    named.set_tag(tag.into());
+                }
+                fields
+            }
+        })
+    }
+
+    fn field(&self, field: DumpField) -> Result<Field> {
+        let mut result = Field::default();
+        if let Some(ty) = field.ty {
+            result.set_ty(self.ty(ty)?);
+        }
+        result.set_optional(field.optional);
+        result.set_flatten(field.flatten);
+        result.set_inline(field.inline);
+        result.set_docs(field.docs.into());
+        result.set_deprecated(field.deprecated.map(load_deprecated));
+        Ok(result)
+    }
+}
+
+/// The fields of a struct made by the `specta` builders, which are the only way to create them.
+fn struct_fields(ty: DataType) -> Fields {
+    match ty {
+        DataType::Struct(s) => s.fields().clone(),
+        _ => unreachable!("struct builders build structs"),
+    }
+}
+
+fn dump_literal(literal: &Literal) -> Result<DumpLiteral> {
+    Ok(match literal {
+        Literal::i8(v) => DumpLiteral::i8(*v),
+        Literal::i16(v) => DumpLiteral::i16(*v),
+        Literal::i32(v) => DumpLiteral::i32(*v),
+        Literal::u8(v) => DumpLiteral::u8(*v),
+        Literal::u16(v) => DumpLiteral::u16(*v),
+        Literal::u32(v) => DumpLiteral::u32(*v),
+        Literal::f32(v) => DumpLiteral::f32(*v),
+        Literal::f64(v) => DumpLiteral::f64(*v),
+        Literal::bool(v) => DumpLiteral::bool(*v),
+        Literal::String(v) => DumpLiteral::String(v.to_string()),
+        Literal::char(v) => DumpLiteral::char(*v),
+        Literal::None => DumpLiteral::None,
+        other => {
+            return Err(Error::Configuration(format!(
+                "literal {:?} can not be written to a type dump",
+                other
+            )))
+        }
+    })
+}
+
+fn load_literal(literal: DumpLiteral) -> Literal {
+    match literal {
+        DumpLiteral::i8(v) => v.into(),
+        DumpLiteral::i16(v) => v.into(),
+        DumpLiteral::i32(v) => v.into(),
+        DumpLiteral::u8(v) => v.into(),
+        DumpLiteral::u16(v) => v.into(),
+        DumpLiteral::u32(v) => v.into(),
+        DumpLiteral::f32(v) => v.into(),
+        DumpLiteral::f64(v) => v.into(),
+        DumpLiteral::bool(v) => v.into(),
+        DumpLiteral::String(v) => v.into(),
+        DumpLiteral::char(v) => v.into(),
+        DumpLiteral::None => Literal::None,
+    }
+}
+
+fn load_primitive(name: &str) -> Result<Primitive> {
+    Ok(match name {
+        "i8" => Primitive::i8,
+        "i16" => Primitive::i16,
+        "i32" => Primitive::i32,
+        "i64" => Primitive::i64,
+        "i128" => Primitive::i128,
+        "isize" => Primitive::isize,
+        "u8" => Primitive::u8,
+        "u16" => Primitive::u16,
+        "u32" => Primitive::u32,
+        "u64" => Primitive::u64,
+        "u128" => Primitive::u128,
+        "usize" => Primitive::usize,
+        "f16" => Primitive::f16,
+        "f32" => Primitive::f32,
+        "f64" => Primitive::f64,
+        "bool" => Primitive::bool,
+        "char" => Primitive::char,
+        "String" => Primitive::String,
+        other => {
+            return Err(Error::Configuration(format!(
+                "unknown primitive '{}' in type dump",
+                other
+            )))
+        }
+    })
+}
+
+fn dump_repr(repr: &EnumRepr) -> DumpRepr {
+    match repr {
+        EnumRepr::Untagged => DumpRepr::Untagged,
+        EnumRepr::External => DumpRepr::External,
+        EnumRepr::Internal { tag } => DumpRepr::Internal {
+            tag: tag.to_string(),
+        },
+        EnumRepr::Adjacent { tag, content } => DumpRepr::Adjacent {
+            tag: tag.to_string(),
+            content: content.to_string(),
+        },
+        EnumRepr::String { rename_all } => DumpRepr::String {
+            rename_all: rename_all.as_ref().map(ToString::to_string),
+        },
+    }
+}
+
+fn load_repr(repr: DumpRepr) -> EnumRepr {
+    match repr {
+        DumpRepr::Untagged => EnumRepr::Untagged,
+        DumpRepr::External => EnumRepr::External,
+        DumpRepr::Internal { tag } => EnumRepr::Internal { tag: tag.into() },
+        DumpRepr::Adjacent { tag, content } => EnumRepr::Adjacent {
+            tag: tag.into(),
+            content: content.into(),
+        },
+        DumpRepr::String { rename_all } => EnumRepr::String {
+            rename_all: rename_all.map(Into::into),
+        },
+    }
+}
+
+fn dump_deprecated(deprecated: &DeprecatedType) -> DumpDeprecated {
+    match deprecated {
+        DeprecatedType::DeprecatedWithSince { since, note } => {
+            DumpDeprecated::DeprecatedWithSince {
+                since: since.as_ref().map(ToString::to_string),
+                note: note.to_string(),
+            }
+        }
+        DeprecatedType::DeprecatedRenamed {
+            since,
+            note,
+            renamed,
+        } => DumpDeprecated::DeprecatedRenamed {
+            since: since.as_ref().map(ToString::to_string),
+            note: note.to_string(),
+            renamed: renamed.to_string(),
+        },
+        _ => DumpDeprecated::Deprecated,
+    }
+}
+
+fn load_deprecated(deprecated: DumpDeprecated) -> DeprecatedType {
+    match deprecated {
+        DumpDeprecated::Deprecated => DeprecatedType::Deprecated,
+        DumpDeprecated::DeprecatedWithSince { since, note } => {
+            DeprecatedType::DeprecatedWithSince {
+                since: since.map(Into::into),
+                note: note.into(),
+            }
+        }
+        DumpDeprecated::DeprecatedRenamed {
+            since,
+            note,
+            renamed,
+        } => DeprecatedType::DeprecatedRenamed {
+            since: since.map(Into::into),
+            note: note.into(),
+            renamed: renamed.into(),
+        },
+    }
+}
@@ -0,0 +1,147 @@
+//! `CKRecord` conversions for iCloud-synced structs.
+//!
+//! Structs flagged with [`Swift::cloudkit_records_for`] get an `init(record: CKRecord)` and a
+//! `toRecord()` which map every field to the record key of the same serialized name. Only the
+//! values CloudKit stores natively are supported (strings, numbers, booleans, `Date` and
+//! `Data`), so the export fails for any other field instead of generating lossy code.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use specta::datatype::{DataType, Fields, NamedDataType};
+use specta::TypeCollection;
+
+use crate::datatype::export::datatype_to_swift;
+use crate::datatype::optional::field_optionality;
+use crate::error::{Error, Result};
+use crate::naming::fields::field_names;
+use crate::swift::Swift;
+
+/// Swift types which can be stored in a `CKRecord` field directly.
+const RECORD_VALUE_TYPES: &[&str] = &[
+    "String", "Bool", "Int", "Int8", "Int16", "Int32", "Int64", "UInt", "UInt8", "UInt16",
+    "UInt32", "UInt64", "Float", "Double", "Date", "Data",
+];
+
+/// The error thrown by the generated `init(record:)`, emitted once before the types.
+pub(crate) const RECORD_ERROR: &str = "// MARK: - CloudKit
+/// An error converting a `CKRecord` into a generated type.
+public enum SpectaRecordError: Error {
+    /// The record has a different record type.
+    case unexpectedRecordType(expected: String, found: String)
+    /// A required field is missing or holds a value of another type.
+    case invalidField(String)
+}
+
+";
+
+/// Generate the `CKRecord` conversions of a flagged struct.
+///
+/// Fails with [`Error::UnsupportedType`] if the type isn't a struct with named fields or one
+/// of its fields can't be stored in a record.
+pub(crate) fn generate_record_conversions(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &NamedDataType,
+    swift_name: &str,
+) -> Result<String> {
+    let fields = match ndt.ty() {
+        DataType::Struct(s) => match s.fields() {
+            Fields::Named(fields) => fields,
+            _ => {
+                return Err(Error::UnsupportedType {
+                    path: ndt.name().to_string(),
+                    reason: "CloudKit records can only be generated for structs with named fields"
+                        .into(),
+                })
+            }
+        },
+        _ => {
+            return Err(Error::UnsupportedType {
+                path: ndt.name().to_string(),
+                reason: "CloudKit records can only be generated for structs".into(),
+            })
+        }
+    };
+    if !ndt.generics().is_empty() {
+        return Err(Error::UnsupportedType {
+            path: ndt.name().to_string(),
+            reason: "CloudKit records can't be generated for generic structs".into(),
+        });
+    }
+
+    let mut decode = String::new();
+    let mut encode = String::new();
+    let names = field_names(swift, fields);
+    for ((name, field), swift_field) in fields.fields().iter().zip(names) {
+        let Some((inner, optionality)) = field_optionality(field) else {
+            continue;
+        };
+        let optional = optionality.is_optional();
+
+        let location: Vec<Cow<'static, str>> = vec![ndt.name().clone(), name.clone()];
+        let field_type = datatype_to_swift(swift, types, inner, location, false, None)?;
+        if !RECORD_VALUE_TYPES.contains(&field_type.as_str()) {
+            return Err(Error::UnsupportedType {
+                path: format!("{}.{}", ndt.name(), name),
+                reason: format!(
+                    "'{}' can't be stored in a CloudKit record, only strings, numbers, booleans, Date and Data can",
+                    field_type
+                ),
+            });
+        }
+
+        if optional {
+            writeln!(
+                decode,
+                "        self.{} = record[\"{}\"] as? {}",
+                swift_field, name, field_type
+            )?;
+        } else {
+            writeln!(
+                decode,
+                "        guard let {} = record[\"{}\"] as? {} else {{",
+                swift_field, name, field_type
+            )?;
+            writeln!(
+                decode,
+                "            throw SpectaRecordError.invalidField(\"{}\")",
+                name
+            )?;
+            decode.push_str("        }\n");
+            writeln!(decode, "        self.{} = {}", swift_field, swift_field)?;
+        }
+        writeln!(encode, "        record[\"{}\"] = {}", name, swift_field)?;
+    }
+
+    let mut result = String::new();
+    writeln!(result, "// MARK: - {} CloudKit Record", swift_name)?;
+    writeln!(result, "extension {} {{", swift_name)?;
+    writeln!(
+        result,
+        "    /// The record type used for `{}` records.",
+        swift_name
+    )?;
+    writeln!(
+        result,
+        "    public static let recordType: CKRecord.RecordType = \"{}\"\n",
+        swift_name
+    )?;
+    result.push_str("    /// Read the value from a CloudKit record.\n");
+    result.push_str("    public init(record: CKRecord) throws {\n");
+    result.push_str("        guard record.recordType == Self.recordType else {\n");
+    result.push_str("            throw SpectaRecordError.unexpectedRecordType(expected: Self.recordType, found: record.recordType)\n");
+    result.push_str("        }\n");
+    result.push_str(&decode);
+    result.push_str("    }\n\n");
+    result.push_str("    /// Write the value into a new CloudKit record.\n");
+    result.push_str("    public func toRecord(recordID: CKRecord.ID? = nil) -> CKRecord {\n");
+    result.push_str("        let record = recordID.map { CKRecord(recordType: Self.recordType, recordID: $0) }\n");
+    result.push_str("            ?? CKRecord(recordType: Self.recordType)\n");
+    result.push_str(&encode);
+    result.push_str("        return record\n");
+    result.push_str("    }\n");
+    result.push('}');
+
+    Ok(result)
+}
@@ -19,10 +19,28 @@
 //!   "Success": { "value": 42, "message": "OK" }
 //! }
 //! ```
+//!
+//! Serde omits the content of unit variants (`{ "type": "Idle" }`), while other servers send
+//! `{ "type": "Idle", "data": null }`. Unit variants never read the content key, so both decode,
+//! and they are encoded without it like serde does.
+//!
+//! With `#[serde(rename_all = "...")]` serde renames the variants, which specta stores under
+//! their serialized names, but writes the `tag` and `content` keys exactly as given. The
+//! `TypeKeys` therefore use the attribute values verbatim and `VariantType` the variant names.
+//!
+//! Newtype variants store their value as the content, and tuple variants an array of their
+//! values, which is read and written through the `Tuple2` to `Tuple12` helpers.
 
-use specta::datatype::{Enum, EnumRepr, Fields};
+use std::fmt::Write;
 
+use specta::datatype::{DataType, Enum, EnumRepr, Fields};
+
+use crate::ast::{Decl, EnumCase, Extension, Function, Member};
+use crate::codable::exhaustive::check_switches;
+use crate::codable::plan::coding_keys;
 use crate::error::{Error, Result};
+use crate::export::DeclarationKind;
+use crate::special_types::helpers::{tuple_helper_name, MAX_TUPLE_ARITY};
 use crate::swift::Swift;
 
 /// Generate custom Codable implementation for adjacently tagged enums.
@@ -36,22 +54,25 @@ use crate::swift::Swift;
 /// * `e` - The enum to generate Codable for
 /// * `enum_name` - The Swift enum name
 /// * `generate_variant_struct_name` - Function to generate struct names for variants
+/// * `get_field_type` - Function to get the Swift type of tuple variant fields
 ///
 /// # Returns
 ///
-/// The complete Codable extension code
+/// The complete Codable extension
 ///
 /// # Errors
 ///
 /// Returns error if the enum is not adjacently tagged
-pub fn generate_adjacently_tagged_codable<F>(
+pub fn generate_adjacently_tagged_codable<F, G>(
     swift: &Swift,
     e: &Enum,
     enum_name: &str,
     generate_variant_struct_name: F,
-) -> Result<String>
+    get_field_type: G,
+) -> Result<Extension>
 where
     F: Fn(&str) -> String,
+    G: Fn(&DataType) -> Result<String>,
 {
     let mut result = String::new();
 
@@ -59,46 +80,50 @@ where
     let (tag_field, content_field) = if let Some(EnumRepr::Adjacent { tag, content }) = e.repr() {
         (tag.as_ref(), content.as_ref())
     } else {
-        return Err(Error::UnsupportedType(
-            "Expected adjacently tagged enum".to_string(),
-        ));
+        return Err(Error::unsupported_type("Expected adjacently tagged enum"));
     };
 
-    result.push_str(&format!(
-        "\n// MARK: - {} Adjacently Tagged Codable Implementation\n",
-        enum_name
-    ));
-    result.push_str(&format!("extension {}: Codable {{\n", enum_name));
+    let mut extension = Extension::new(enum_name)
+        .mark(format!(
+            "{} Adjacently Tagged Codable Implementation",
+            enum_name
+        ))
+        .conforming_to("Codable");
 
-    // Generate TypeKeys enum for the tag and content fields - make name unique per enum
-    result.push_str(&format!(
-        "    private enum {}TypeKeys: String, CodingKey {{\n",
-        enum_name
-    ));
-    result.push_str(&format!("        case tag = \"{}\"\n", tag_field));
-    result.push_str(&format!("        case content = \"{}\"\n", content_field));
-    result.push_str("    }\n\n");
+    // Generate TypeKeys enum for the tag and content fields - make name unique per enum.
+    // `rename_all` doesn't apply to them, so they are serialized exactly as written.
+    let mut type_keys = coding_keys(
+        swift,
+        vec![
+            EnumCase::with_raw_value("tag", tag_field),
+            EnumCase::with_raw_value("content", content_field),
+        ],
+    );
+    type_keys.name = format!("{}TypeKeys", enum_name);
+    extension.members.push(Member::Decl(type_keys));
 
     // Generate VariantType enum for variant names
-    result.push_str("    private enum VariantType: String, Codable {\n");
+    let mut variant_type = Decl::new(DeclarationKind::Enum, &["private"], "VariantType");
+    variant_type.conformances = vec!["String".into(), "Codable".into()];
     for (original_variant_name, variant) in e.variants() {
         if variant.skip() {
             continue;
         }
-        let swift_case_name = swift.naming.convert_enum_case(original_variant_name);
-        result.push_str(&format!(
-            "        case {} = \"{}\"\n",
-            swift_case_name, original_variant_name
-        ));
+        variant_type
+            .members
+            .push(Member::Case(EnumCase::with_raw_value(
+                swift.case_name(original_variant_name),
+                original_variant_name.as_ref(),
+            )));
     }
-    result.push_str("    }\n\n");
+    extension.members.push(Member::Decl(variant_type));
 
     // Generate init(from decoder:)
-    result.push_str("    public init(from decoder: Decoder) throws {\n");
-    result.push_str(&format!(
-        "        let container = try decoder.container(keyedBy: {}TypeKeys.self)\n",
+    writeln!(
+        result,
+        "        let container = try decoder.container(keyedBy: {}TypeKeys.self)",
         enum_name
-    ));
+    )?;
     result.push_str(
         "        let variantType = try container.decode(VariantType.self, forKey: .tag)\n",
     );
@@ -110,46 +135,102 @@ where
             continue;
         }
 
-        let swift_case_name = swift.naming.convert_enum_case(original_variant_name);
+        let swift_case_name = swift.case_name(original_variant_name);
 
         match variant.fields() {
+            // The content key may be absent or `null`, so it isn't read
             Fields::Unit => {
-                result.push_str(&format!("        case .{}:\n", swift_case_name));
-                result.push_str(&format!("            self = .{}\n", swift_case_name));
+                writeln!(result, "        case .{}:", swift_case_name)?;
+                writeln!(result, "            self = .{}", swift_case_name)?;
             }
             Fields::Unnamed(fields) => {
                 if fields.fields().is_empty() {
                     // Empty tuple variant - treat as unit variant
-                    result.push_str(&format!("        case .{}:\n", swift_case_name));
-                    result.push_str(&format!("            self = .{}\n", swift_case_name));
+                    writeln!(result, "        case .{}:", swift_case_name)?;
+                    writeln!(result, "            self = .{}", swift_case_name)?;
                 } else {
-                    // TODO: Handle non-empty tuple variants for adjacently tagged
-                    result.push_str(&format!("        case .{}:\n", swift_case_name));
-                    result.push_str("            fatalError(\"Adjacently tagged tuple variants not implemented\")\n");
+                    let tuple_types = fields
+                        .fields()
+                        .iter()
+                        .filter_map(|f| f.ty())
+                        .map(&get_field_type)
+                        .collect::<Result<Vec<_>>>()?;
+
+                    writeln!(result, "        case .{}:", swift_case_name)?;
+                    match tuple_types.len() {
+                        1 => writeln!(
+                            result,
+                            "            self = .{}(try container.decode({}.self, forKey: .content))",
+                            swift_case_name, tuple_types[0]
+                        )?,
+                        arity if arity <= MAX_TUPLE_ARITY => {
+                            writeln!(
+                                result,
+                                "            let data = try container.decode({}<{}>.self, forKey: .content)",
+                                tuple_helper_name(arity),
+                                tuple_types.join(", ")
+                            )?;
+                            writeln!(
+                                result,
+                                "            self = .{}({})",
+                                swift_case_name,
+                                (0..arity)
+                                    .map(|i| format!("data._{}", i))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )?;
+                        }
+                        // Longer than any tuple helper, so the array is read directly
+                        arity => {
+                            result.push_str("            var arrayContainer = try container.nestedUnkeyedContainer(forKey: .content)\n");
+                            for (i, ty) in tuple_types.iter().enumerate() {
+                                writeln!(
+                                    result,
+                                    "            let value{} = try arrayContainer.decode({}.self)",
+                                    i, ty
+                                )?;
+                            }
+                            writeln!(
+                                result,
+                                "            self = .{}({})",
+                                swift_case_name,
+                                (0..arity)
+                                    .map(|i| format!("value{}", i))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            )?;
+                        }
+                    }
                 }
             }
             Fields::Named(_) => {
                 let struct_name = generate_variant_struct_name(original_variant_name);
 
-                result.push_str(&format!("        case .{}:\n", swift_case_name));
-                result.push_str(&format!(
-                    "            let data = try container.decode({}.self, forKey: .content)\n",
+                writeln!(result, "        case .{}:", swift_case_name)?;
+                writeln!(
+                    result,
+                    "            let data = try container.decode({}.self, forKey: .content)",
                     struct_name
-                ));
-                result.push_str(&format!("            self = .{}(data)\n", swift_case_name));
+                )?;
+                writeln!(result, "            self = .{}(data)", swift_case_name)?;
             }
         }
     }
 
     result.push_str("        }\n");
-    result.push_str("    }\n\n");
+    let decode = result;
+    extension.members.push(Member::Function(Function::new(
+        "public init(from decoder: Decoder) throws",
+        &decode,
+    )));
 
     // Generate encode(to encoder:)
-    result.push_str("    public func encode(to encoder: Encoder) throws {\n");
-    result.push_str(&format!(
-        "        var container = encoder.container(keyedBy: {}TypeKeys.self)\n",
+    let mut result = String::new();
+    writeln!(
+        result,
+        "        var container = encoder.container(keyedBy: {}TypeKeys.self)",
         enum_name
-    ));
+    )?;
     result.push_str("        \n");
     result.push_str("        switch self {\n");
 
@@ -158,46 +239,90 @@ where
             continue;
         }
 
-        let swift_case_name = swift.naming.convert_enum_case(original_variant_name);
+        let swift_case_name = swift.case_name(original_variant_name);
 
         match variant.fields() {
             Fields::Unit => {
-                result.push_str(&format!("        case .{}:\n", swift_case_name));
-                result.push_str(&format!(
-                    "            try container.encode(VariantType.{}, forKey: .tag)\n",
+                writeln!(result, "        case .{}:", swift_case_name)?;
+                writeln!(
+                    result,
+                    "            try container.encode(VariantType.{}, forKey: .tag)",
                     swift_case_name
-                ));
+                )?;
             }
             Fields::Unnamed(fields) => {
                 if fields.fields().is_empty() {
                     // Empty tuple variant - treat as unit variant
-                    result.push_str(&format!("        case .{}:\n", swift_case_name));
-                    result.push_str(&format!(
-                        "            try container.encode(VariantType.{}, forKey: .tag)\n",
+                    writeln!(result, "        case .{}:", swift_case_name)?;
+                    writeln!(
+                        result,
+                        "            try container.encode(VariantType.{}, forKey: .tag)",
                         swift_case_name
-                    ));
+                    )?;
                 } else {
-                    // TODO: Handle non-empty tuple variants
-                    result.push_str(&format!("        case .{}:\n", swift_case_name));
-                    result.push_str("            fatalError(\"Adjacently tagged tuple variants not implemented\")\n");
+                    let arity = fields.fields().iter().filter(|f| f.ty().is_some()).count();
+                    let values = (0..arity)
+                        .map(|i| format!("value{}", i))
+                        .collect::<Vec<_>>();
+
+                    writeln!(
+                        result,
+                        "        case .{}({}):",
+                        swift_case_name,
+                        values
+                            .iter()
+                            .map(|value| format!("let {}", value))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )?;
+                    writeln!(
+                        result,
+                        "            try container.encode(VariantType.{}, forKey: .tag)",
+                        swift_case_name
+                    )?;
+                    match arity {
+                        1 => result.push_str(
+                            "            try container.encode(value0, forKey: .content)\n",
+                        ),
+                        arity if arity <= MAX_TUPLE_ARITY => writeln!(
+                            result,
+                            "            try container.encode({}({}), forKey: .content)",
+                            tuple_helper_name(arity),
+                            values.join(", ")
+                        )?,
+                        _ => {
+                            result.push_str("            var arrayContainer = container.nestedUnkeyedContainer(forKey: .content)\n");
+                            for value in &values {
+                                writeln!(
+                                    result,
+                                    "            try arrayContainer.encode({})",
+                                    value
+                                )?;
+                            }
+                        }
+                    }
                 }
             }
             Fields::Named(_) => {
-                result.push_str(&format!("        case .{}(let data):\n", swift_case_name));
-                result.push_str(&format!(
-                    "            try container.encode(VariantType.{}, forKey: .tag)\n",
+                writeln!(result, "        case .{}(let data):", swift_case_name)?;
+                writeln!(
+                    result,
+                    "            try container.encode(VariantType.{}, forKey: .tag)",
                     swift_case_name
-                ));
+                )?;
                 result.push_str("            try container.encode(data, forKey: .content)\n");
             }
         }
     }
 
     result.push_str("        }\n");
-    result.push_str("    }\n");
-    result.push_str("}\n");
+    check_switches(swift, e, enum_name, &decode, &result)?;
+    extension.members.push(Member::Function(Function::new(
+        "public func encode(to encoder: Encoder) throws",
+        &result,
+    )));
 
-    Ok(result)
+    Ok(extension)
 }
 
 #[cfg(test)]
@@ -13,15 +13,27 @@
 //! }
 //! ```
 //!
+//! Newtype variants hold their value directly and tuple variants an array of their values:
+//! ```json
+//! { "Pending": "queued" }
+//! { "Error": ["timeout", 504] }
+//! ```
+//!
 //! With a fallback for unit variants as strings:
 //! ```json
 //! "Loading"
 //! ```
 
+use std::fmt::Write;
+
 use specta::datatype::{Enum, Fields};
 
+use crate::ast::{EnumCase, Extension, Function, Member};
+use crate::codable::exhaustive::check_switches;
+use crate::codable::plan::coding_keys;
 use crate::error::Result;
-use crate::swift::Swift;
+use crate::swift::{CodableFormat, Swift};
+use crate::utils::formatting::escape_string;
 
 /// Generate custom Codable implementation for enums with struct-like variants.
 ///
@@ -37,7 +49,7 @@ use crate::swift::Swift;
 ///
 /// # Returns
 ///
-/// The complete Codable extension code including:
+/// The complete Codable extension including:
 /// - CodingKeys enum
 /// - init(from decoder:) with externally-tagged and string fallback support
 /// - encode(to encoder:) method
@@ -52,35 +64,38 @@ pub fn generate_enum_codable_impl<F, G>(
     enum_name: &str,
     generate_variant_struct_name: F,
     get_field_type: G,
-) -> Result<String>
+) -> Result<Extension>
 where
     F: Fn(&str) -> String,
     G: Fn(&specta::datatype::DataType) -> Result<String>,
 {
     let mut result = String::new();
+    // Outside of JSON, unit variants are encoded as plain strings exactly like serde (property
+    // lists can't store the `null` of `{"Variant": null}` at all). A unit variant can't be
+    // encoded as a single value once a keyed container was requested, so each case requests
+    // its own container. Newtype variants hold their value directly in every format.
+    let follows_serde = swift.codable_format != CodableFormat::Json;
 
-    result.push_str(&format!(
-        "\n// MARK: - {} Codable Implementation\n",
-        enum_name
-    ));
-    result.push_str(&format!("extension {}: Codable {{\n", enum_name));
+    let mut extension = Extension::new(enum_name)
+        .mark(format!("{} Codable Implementation", enum_name))
+        .conforming_to("Codable");
 
     // Generate CodingKeys enum
-    result.push_str("    private enum CodingKeys: String, CodingKey {\n");
-    for (original_variant_name, variant) in e.variants() {
-        if variant.skip() {
-            continue;
-        }
-        let swift_case_name = swift.naming.convert_enum_case(original_variant_name);
-        result.push_str(&format!(
-            "        case {} = \"{}\"\n",
-            swift_case_name, original_variant_name
-        ));
-    }
-    result.push_str("    }\n\n");
+    extension.members.push(Member::Decl(coding_keys(
+        swift,
+        e.variants()
+            .iter()
+            .filter(|(_, variant)| !variant.skip())
+            .map(|(original_variant_name, _)| {
+                EnumCase::with_raw_value(
+                    swift.case_name(original_variant_name),
+                    original_variant_name.as_ref(),
+                )
+            })
+            .collect(),
+    )));
 
     // Generate init(from decoder:)
-    result.push_str("    public init(from decoder: Decoder) throws {\n");
     result.push_str(
         "        // Try externally-tagged format first (e.g., {\"WaitingForConnection\": null})\n",
     );
@@ -96,28 +111,22 @@ where
             continue;
         }
 
-        let swift_case_name = swift.naming.convert_enum_case(original_variant_name);
+        let swift_case_name = swift.case_name(original_variant_name);
 
         match variant.fields() {
             Fields::Unit => {
-                result.push_str(&format!("                case .{}:\n", swift_case_name));
-                result.push_str(&format!(
-                    "                    self = .{}\n",
-                    swift_case_name
-                ));
+                writeln!(result, "                case .{}:", swift_case_name)?;
+                writeln!(result, "                    self = .{}", swift_case_name)?;
                 result.push_str("                    return\n");
             }
             Fields::Unnamed(fields) => {
                 if fields.fields().is_empty() {
-                    result.push_str(&format!("                case .{}:\n", swift_case_name));
-                    result.push_str(&format!(
-                        "                    self = .{}\n",
-                        swift_case_name
-                    ));
+                    writeln!(result, "                case .{}:", swift_case_name)?;
+                    writeln!(result, "                    self = .{}", swift_case_name)?;
                     result.push_str("                    return\n");
                 } else {
                     // For tuple variants, decode as array: {"Variant": [value1, value2, ...]}
-                    result.push_str(&format!("                case .{}:\n", swift_case_name));
+                    writeln!(result, "                case .{}:", swift_case_name)?;
 
                     // Get the Swift types for the tuple elements
                     let tuple_types: Vec<String> = fields
@@ -127,18 +136,30 @@ where
                         .map(|ty| get_field_type(ty))
                         .collect::<std::result::Result<Vec<_>, _>>()?;
 
+                    // serde writes newtype variants without the array: {"Variant": value}
+                    if tuple_types.len() == 1 {
+                        writeln!(
+                            result,
+                            "                    self = .{}(try container.decode({}.self, forKey: .{}))",
+                            swift_case_name, tuple_types[0], swift_case_name
+                        )?;
+                        result.push_str("                    return\n");
+                        continue;
+                    }
+
                     // Decode as array and destructure
-                    result.push_str(&format!(
-                        "                    var arrayContainer = try container.nestedUnkeyedContainer(forKey: .{})\n",
+                    writeln!(result,
+                        "                    var arrayContainer = try container.nestedUnkeyedContainer(forKey: .{})",
                         swift_case_name
-                    ));
+                    )?;
 
                     // Decode each element
                     for (i, type_str) in tuple_types.iter().enumerate() {
-                        result.push_str(&format!(
-                            "                    let value{} = try arrayContainer.decode({}.self)\n",
+                        writeln!(
+                            result,
+                            "                    let value{} = try arrayContainer.decode({}.self)",
                             i, type_str
-                        ));
+                        )?;
                     }
 
                     // Construct the enum case with all values
@@ -146,25 +167,28 @@ where
                         .map(|i| format!("value{}", i))
                         .collect::<Vec<_>>()
                         .join(", ");
-                    result.push_str(&format!(
-                        "                    self = .{}({})\n",
+                    writeln!(
+                        result,
+                        "                    self = .{}({})",
                         swift_case_name, value_list
-                    ));
+                    )?;
                     result.push_str("                    return\n");
                 }
             }
             Fields::Named(_) => {
                 let struct_name = generate_variant_struct_name(original_variant_name);
 
-                result.push_str(&format!("                case .{}:\n", swift_case_name));
-                result.push_str(&format!(
-                    "                    let data = try container.decode({}.self, forKey: .{})\n",
+                writeln!(result, "                case .{}:", swift_case_name)?;
+                writeln!(
+                    result,
+                    "                    let data = try container.decode({}.self, forKey: .{})",
                     struct_name, swift_case_name
-                ));
-                result.push_str(&format!(
-                    "                    self = .{}(data)\n",
+                )?;
+                writeln!(
+                    result,
+                    "                    self = .{}(data)",
                     swift_case_name
-                ));
+                )?;
                 result.push_str("                    return\n");
             }
         }
@@ -190,29 +214,25 @@ where
             continue;
         }
 
-        let swift_case_name = swift.naming.convert_enum_case(original_variant_name);
+        let swift_case_name = swift.case_name(original_variant_name);
 
         match variant.fields() {
             Fields::Unit => {
-                result.push_str(&format!(
-                    "                case \"{}\":\n",
-                    original_variant_name
-                ));
-                result.push_str(&format!(
-                    "                    self = .{}\n",
-                    swift_case_name
-                ));
+                writeln!(
+                    result,
+                    "                case \"{}\":",
+                    escape_string(original_variant_name)
+                )?;
+                writeln!(result, "                    self = .{}", swift_case_name)?;
                 result.push_str("                    return\n");
             }
             Fields::Unnamed(fields) if fields.fields().is_empty() => {
-                result.push_str(&format!(
-                    "                case \"{}\":\n",
-                    original_variant_name
-                ));
-                result.push_str(&format!(
-                    "                    self = .{}\n",
-                    swift_case_name
-                ));
+                writeln!(
+                    result,
+                    "                case \"{}\":",
+                    escape_string(original_variant_name)
+                )?;
+                writeln!(result, "                    self = .{}", swift_case_name)?;
                 result.push_str("                    return\n");
             }
             _ => {
@@ -230,37 +250,60 @@ where
     result.push_str("        throw DecodingError.dataCorrupted(\n");
     result.push_str("            DecodingError.Context(codingPath: decoder.codingPath, debugDescription: \"Could not decode enum - expected externally-tagged object or string for unit variants\")\n");
     result.push_str("        )\n");
-    result.push_str("    }\n\n");
+    let decode = result;
+    extension.members.push(Member::Function(Function::new(
+        "public init(from decoder: Decoder) throws",
+        &decode,
+    )));
 
     // Generate encode(to encoder:)
-    result.push_str("    public func encode(to encoder: Encoder) throws {\n");
-    result.push_str("        var container = encoder.container(keyedBy: CodingKeys.self)\n");
-    result.push_str("        \n");
+    let mut result = String::new();
+    if !follows_serde {
+        result.push_str("        var container = encoder.container(keyedBy: CodingKeys.self)\n");
+        result.push_str("        \n");
+    }
     result.push_str("        switch self {\n");
+    let keyed_container = if follows_serde {
+        "            var container = encoder.container(keyedBy: CodingKeys.self)\n"
+    } else {
+        ""
+    };
 
     for (original_variant_name, variant) in e.variants() {
         if variant.skip() {
             continue;
         }
 
-        let swift_case_name = swift.naming.convert_enum_case(original_variant_name);
+        let swift_case_name = swift.case_name(original_variant_name);
 
         match variant.fields() {
             Fields::Unit => {
-                result.push_str(&format!("        case .{}:\n", swift_case_name));
-                result.push_str(&format!(
-                    "            try container.encodeNil(forKey: .{})\n",
-                    swift_case_name
-                ));
+                writeln!(result, "        case .{}:", swift_case_name)?;
+                write_unit_encoding(
+                    &mut result,
+                    follows_serde,
+                    &swift_case_name,
+                    original_variant_name,
+                )?;
             }
             Fields::Unnamed(fields) => {
                 if fields.fields().is_empty() {
                     // Empty tuple - encode as nil
-                    result.push_str(&format!("        case .{}:\n", swift_case_name));
-                    result.push_str(&format!(
-                        "            try container.encodeNil(forKey: .{})\n",
+                    writeln!(result, "        case .{}:", swift_case_name)?;
+                    write_unit_encoding(
+                        &mut result,
+                        follows_serde,
+                        &swift_case_name,
+                        original_variant_name,
+                    )?;
+                } else if fields.fields().len() == 1 {
+                    writeln!(result, "        case .{}(let value):", swift_case_name)?;
+                    result.push_str(keyed_container);
+                    writeln!(
+                        result,
+                        "            try container.encode(value, forKey: .{})",
                         swift_case_name
-                    ));
+                    )?;
                 } else {
                     // For tuple variants, encode as array
                     let tuple_count = fields.fields().len();
@@ -271,39 +314,64 @@ where
                         .collect::<Vec<_>>()
                         .join(", ");
 
-                    result.push_str(&format!(
-                        "        case .{}({}):\n",
-                        swift_case_name, bindings
-                    ));
-                    result.push_str(&format!(
-                        "            var arrayContainer = container.nestedUnkeyedContainer(forKey: .{})\n",
+                    writeln!(result, "        case .{}({}):", swift_case_name, bindings)?;
+                    result.push_str(keyed_container);
+                    writeln!(result,
+                        "            var arrayContainer = container.nestedUnkeyedContainer(forKey: .{})",
                         swift_case_name
-                    ));
+                    )?;
 
                     // Encode each value
                     for i in 0..tuple_count {
-                        result.push_str(&format!(
-                            "            try arrayContainer.encode(value{})\n",
-                            i
-                        ));
+                        writeln!(result, "            try arrayContainer.encode(value{})", i)?;
                     }
                 }
             }
             Fields::Named(_) => {
-                result.push_str(&format!("        case .{}(let data):\n", swift_case_name));
-                result.push_str(&format!(
-                    "            try container.encode(data, forKey: .{})\n",
+                writeln!(result, "        case .{}(let data):", swift_case_name)?;
+                result.push_str(keyed_container);
+                writeln!(
+                    result,
+                    "            try container.encode(data, forKey: .{})",
                     swift_case_name
-                ));
+                )?;
             }
         }
     }
 
     result.push_str("        }\n");
-    result.push_str("    }\n");
-    result.push_str("}\n");
+    check_switches(swift, e, enum_name, &decode, &result)?;
+    extension.members.push(Member::Function(Function::new(
+        "public func encode(to encoder: Encoder) throws",
+        &result,
+    )));
 
-    Ok(result)
+    Ok(extension)
+}
+
+/// Encode a unit variant as `{"Variant": null}`, or as the plain `"Variant"` string serde
+/// writes when `as_string` is set.
+fn write_unit_encoding(
+    result: &mut String,
+    as_string: bool,
+    swift_case_name: &str,
+    original_variant_name: &str,
+) -> Result<()> {
+    if as_string {
+        result.push_str("            var container = encoder.singleValueContainer()\n");
+        writeln!(
+            result,
+            "            try container.encode(\"{}\")",
+            escape_string(original_variant_name)
+        )?;
+    } else {
+        writeln!(
+            result,
+            "            try container.encodeNil(forKey: .{})",
+            swift_case_name
+        )?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
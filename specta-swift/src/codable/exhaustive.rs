@@ -0,0 +1,56 @@
+//! Exhaustiveness of generated enum switches
+//!
+//! The enum Codable generators write one `case` per variant and shape of its fields into the
+//! `switch` of `init(from:)` and `encode(to:)`. A variant which ends up in neither, e.g. after a
+//! new [`Fields`](specta::datatype::Fields) shape was added without updating a generator,
+//! would only surface as a Swift compile error (`switch must be exhaustive`) in the app, so the
+//! generated bodies are checked before they are emitted and generation fails instead.
+
+use std::collections::HashSet;
+
+use specta::datatype::Enum;
+
+use crate::error::{Error, Result};
+use crate::swift::Swift;
+
+/// Check that every non-skipped variant of `e` has a case in the decode and encode switches.
+///
+/// Fails with [`Error::UnsupportedType`] naming the first variant missing from a switch.
+pub fn check_switches(
+    swift: &Swift,
+    e: &Enum,
+    enum_name: &str,
+    decode: &str,
+    encode: &str,
+) -> Result<()> {
+    let decoded = switch_cases(decode);
+    let encoded = switch_cases(encode);
+
+    for (variant_name, variant) in e.variants() {
+        if variant.skip() {
+            continue;
+        }
+        let case_name = swift.case_name(variant_name);
+        let missing = match (decoded.contains(&*case_name), encoded.contains(&*case_name)) {
+            (true, true) => continue,
+            (false, _) => "init(from:)",
+            (_, false) => "encode(to:)",
+        };
+        return Err(Error::UnsupportedType {
+            path: format!("{}.{}", enum_name, variant_name),
+            reason: format!(
+                "the generated {} has no case for this variant, its fields aren't supported by the Codable implementation",
+                missing
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// The enum cases matched by the `case .name` lines of a function body.
+fn switch_cases(body: &str) -> HashSet<&str> {
+    body.lines()
+        .filter_map(|line| line.trim_start().strip_prefix("case ."))
+        .filter_map(|case| case.split([':', '(']).next())
+        .collect()
+}
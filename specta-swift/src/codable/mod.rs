@@ -6,7 +6,9 @@
 //! - `struct_codable`: Codable for struct types
 //! - `enum_codable`: Codable for enum types
 //! - `adjacently_tagged`: Adjacently tagged enum Codable implementation
-//! - `coding_keys`: CodingKeys enum generation for custom serialization keys
+//! - `exhaustive`: Checks that the generated enum switches cover every variant
+//! - `plan`: The Codable decisions made once per type and consumed by the generators
+//! - `wrappers`: Property wrappers used for resilient decoding
 //!
 //! # Architecture
 //!
@@ -21,12 +23,17 @@
 // Submodules
 pub mod adjacently_tagged;
 pub mod enum_codable;
+pub mod exhaustive;
+pub mod plan;
 pub mod struct_codable;
+pub mod wrappers;
 
 // Re-export commonly used functions
 pub use adjacently_tagged::generate_adjacently_tagged_codable;
 pub use enum_codable::generate_enum_codable_impl;
+pub use plan::{needs_coding_keys, CodableImpl, CodablePlan, CodingKeysPlacement};
 pub use struct_codable::generate_enum_variant_structs;
+pub use wrappers::{property_wrapper, PROPERTY_WRAPPERS};
 
 // Re-export public types from submodules once they're created
 // pub use coding_keys::*;
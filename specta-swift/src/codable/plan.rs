@@ -0,0 +1,322 @@
+//! Codable planning
+//!
+//! Every decision about how a type conforms to `Codable` is made here, once per type:
+//! where its `CodingKeys` live, whether Swift can synthesize `init(from:)` and
+//! `encode(to:)` and which struct-like enum variants get their own struct. The emitters in
+//! [`datatype::export`](crate::datatype::export) and the name resolver consume the
+//! resulting [`CodablePlan`] instead of re-deriving these rules.
+
+use specta::datatype::{DataType, Enum, EnumRepr, Fields, NamedDataType, NamedFields};
+use specta::TypeCollection;
+
+use std::fmt::Write;
+
+use crate::ast::{Decl, EnumCase, Member};
+use crate::coders::{converts_key, from_snake_case_key, needs_verbatim_key};
+use crate::datatype::collections::validated_length;
+use crate::datatype::export::is_character_field;
+use crate::datatype::optional::{empty_collection, field_optionality};
+use crate::naming::fields::field_names;
+use crate::special_types::{is_duration_struct, is_special_std_type};
+use crate::swift::{KeyStrategy, MissingCollectionStrategy, Swift};
+use crate::utils::formatting::escape_string;
+
+/// Where the `CodingKeys` enum mapping Swift property names to JSON keys is generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodingKeysPlacement {
+    /// Property names match the JSON keys, no `CodingKeys` are needed.
+    None,
+    /// Inside the type declaration, used by the synthesized implementation.
+    Declaration,
+    /// In the extension holding the custom implementation.
+    Extension,
+}
+
+/// How `init(from:)` and `encode(to:)` are provided.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodableImpl {
+    /// Swift synthesizes the implementation.
+    Synthesized,
+    /// An explicit struct implementation, which encodes `nil` as `null` to match serde.
+    Custom,
+    /// An externally tagged enum implementation (`{"Variant": data}`).
+    ExternallyTagged,
+    /// An adjacently tagged enum implementation (`{"tag": "Variant", "content": data}`).
+    AdjacentlyTagged {
+        /// The key holding the variant name.
+        tag: String,
+        /// The key holding the variant data.
+        content: String,
+    },
+    /// String enums with data-carrying variants, which are not supported yet.
+    Unsupported,
+    /// The type doesn't conform to `Codable`, see [`Swift::emit_codable`].
+    None,
+}
+
+/// The Codable decisions for a single type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodablePlan {
+    /// Where the type's `CodingKeys` are generated.
+    pub coding_keys: CodingKeysPlacement,
+    /// How the type's Codable implementation is provided.
+    pub implementation: CodableImpl,
+    /// Whether struct-like enum variants are generated as separate structs.
+    pub variant_structs: bool,
+}
+
+impl CodablePlan {
+    /// A synthesized implementation without `CodingKeys`.
+    pub fn synthesized() -> Self {
+        Self {
+            coding_keys: CodingKeysPlacement::None,
+            implementation: CodableImpl::Synthesized,
+            variant_structs: false,
+        }
+    }
+
+    /// A plain type without any Codable conformance.
+    pub fn plain(variant_structs: bool) -> Self {
+        Self {
+            coding_keys: CodingKeysPlacement::None,
+            implementation: CodableImpl::None,
+            variant_structs,
+        }
+    }
+
+    /// Plan the Codable implementation of a named type.
+    pub fn for_type(swift: &Swift, types: &TypeCollection, ndt: &NamedDataType) -> Self {
+        if !swift.emit_codable {
+            // Struct-like variants keep their structs, so the shape of the type is unchanged
+            let variant_structs = match ndt.ty() {
+                DataType::Enum(e) => Self::for_enum(e).variant_structs,
+                _ => false,
+            };
+            return Self::plain(variant_structs);
+        }
+
+        match ndt.ty() {
+            // Special types are mapped to helpers which bring their own implementation
+            DataType::Struct(_) if is_special_std_type(types, Some(ndt.sid())).is_some() => {
+                Self::synthesized()
+            }
+            DataType::Struct(s) if is_duration_struct(s) => Self::synthesized(),
+            DataType::Struct(s) => match s.fields() {
+                Fields::Named(fields) => Self::for_fields(swift, fields),
+                _ => Self::synthesized(),
+            },
+            DataType::Enum(e) => Self::for_enum(e),
+            _ => Self::synthesized(),
+        }
+    }
+
+    /// Plan the Codable implementation of a struct with named fields.
+    pub fn for_fields(swift: &Swift, fields: &NamedFields) -> Self {
+        if !swift.emit_codable {
+            return Self::plain(false);
+        }
+
+        let names = field_names(swift, fields);
+        let fields = fields
+            .fields()
+            .iter()
+            .zip(names)
+            .filter_map(|((name, field), swift_name)| {
+                let (inner, optionality) = field_optionality(field)?;
+                let length = validated_length(swift, inner);
+                let empty_optional =
+                    length.is_none() && empty_collection(swift, inner, optionality).is_some();
+                Some((
+                    name,
+                    swift_name,
+                    field.ty()?,
+                    optionality,
+                    empty_optional,
+                    length,
+                ))
+            });
+
+        let mut has_fields = false;
+        let mut nullable = false;
+        let mut keys = Vec::new();
+        let mut collections = false;
+        let mut literals = false;
+        let mut characters = false;
+        let mut empty_optionals = false;
+        let mut lengths = false;
+        for (name, swift_name, ty, optionality, empty_optional, length) in fields {
+            has_fields = true;
+            keys.push((swift_name, name.to_string()));
+            nullable |= optionality.nullable;
+            empty_optionals |= empty_optional;
+            lengths |= length.is_some();
+            // Lists are defaulted by their property wrapper with resilient decoding
+            collections |= match ty {
+                DataType::List(_) => !swift.resilient_decoding,
+                DataType::Map(_) => true,
+                _ => false,
+            };
+            literals |= matches!(ty, DataType::Literal(_));
+            characters |= is_character_field(swift, ty);
+        }
+        let renamed = keys.iter().any(|(key, json_key)| key != json_key);
+        let declared_keys = needs_coding_keys(
+            swift,
+            keys.iter()
+                .map(|(key, json_key)| (key.as_str(), json_key.as_str())),
+        );
+
+        // Synthesized implementations drop `nil` values, so nullable fields need a custom
+        // implementation. Its extension uses the synthesized CodingKeys when every property is
+        // named after its JSON key and Codable, i.e. isn't a `Character`. Structs without fields have
+        // nothing to implement and no CodingKeys cases, so they are always synthesized.
+        // Defaulting missing or optional collections, validating literal fields and decoding
+        // `Character`s, which aren't Codable, and checking the length of fixed-size arrays also
+        // need a custom implementation.
+        let empty_collections =
+            collections && swift.missing_collections == MissingCollectionStrategy::Empty;
+        let (coding_keys, implementation) = if nullable
+            || literals
+            || characters
+            || empty_collections
+            || empty_optionals
+            || lengths
+            || (swift.always_emit_codable && has_fields)
+        {
+            let coding_keys = if renamed || characters || swift.always_emit_codable {
+                CodingKeysPlacement::Extension
+            } else {
+                CodingKeysPlacement::None
+            };
+            (coding_keys, CodableImpl::Custom)
+        } else if declared_keys {
+            (CodingKeysPlacement::Declaration, CodableImpl::Synthesized)
+        } else {
+            (CodingKeysPlacement::None, CodableImpl::Synthesized)
+        };
+
+        Self {
+            coding_keys,
+            implementation,
+            variant_structs: false,
+        }
+    }
+
+    /// Plan the Codable implementation of an enum.
+    pub fn for_enum(e: &Enum) -> Self {
+        let is_string_enum = e.repr().map(|repr| repr.is_string()).unwrap_or(false);
+        let has_data_variants = e.variants().iter().any(|(_, variant)| {
+            matches!(variant.fields(), Fields::Named(fields) if !fields.fields().is_empty())
+                || matches!(variant.fields(), Fields::Unnamed(fields) if !fields.fields().is_empty())
+        });
+        let has_struct_variants = e.variants().iter().any(|(_, variant)| {
+            matches!(variant.fields(), Fields::Named(fields) if !fields.fields().is_empty())
+        });
+
+        let implementation = match e.repr() {
+            _ if is_string_enum && has_data_variants => CodableImpl::Unsupported,
+            _ if is_string_enum => CodableImpl::Synthesized,
+            Some(EnumRepr::Adjacent { tag, content }) => CodableImpl::AdjacentlyTagged {
+                tag: tag.to_string(),
+                content: content.to_string(),
+            },
+            _ if has_data_variants => CodableImpl::ExternallyTagged,
+            _ => CodableImpl::Synthesized,
+        };
+
+        Self {
+            coding_keys: CodingKeysPlacement::None,
+            implementation,
+            variant_structs: !is_string_enum && has_struct_variants,
+        }
+    }
+
+    /// Whether the declaration itself conforms to `Codable`, rather than an extension.
+    pub fn conforms_in_declaration(&self) -> bool {
+        matches!(
+            self.implementation,
+            CodableImpl::Synthesized | CodableImpl::Custom
+        )
+    }
+}
+
+/// Whether properties named `(swift_name, json_key)` need an explicit `CodingKeys` enum.
+///
+/// Swift synthesizes `CodingKeys` named after the properties, so they are only spelled out
+/// when a property isn't coded as its JSON key by its name alone, see [`coding_key_case`].
+pub fn needs_coding_keys<'a>(
+    swift: &Swift,
+    keys: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> bool {
+    keys.into_iter()
+        .any(|(swift_name, json_key)| needs_raw_value(swift, swift_name, json_key))
+}
+
+/// The `CodingKeys` enum of `cases`, whose raw values are their JSON keys.
+///
+/// With the snake_case key strategy, the coders convert the case names themselves, so raw
+/// values they reproduce are dropped. Keys which don't survive the round trip are decoded by
+/// their converted raw value and spelled out for the encoder by conforming to
+/// `SpectaVerbatimKey`, see [`needs_verbatim_key`].
+pub(crate) fn coding_keys(swift: &Swift, mut cases: Vec<EnumCase>) -> Decl {
+    let mut verbatim = Vec::new();
+    if swift.key_strategy == KeyStrategy::ConvertFromSnakeCase {
+        for case in &mut cases {
+            let Some(json_key) = case.raw_value.take() else {
+                continue;
+            };
+            let name = case.name.trim_matches('`');
+            if converts_key(name, &json_key) {
+                continue;
+            }
+            if needs_verbatim_key(name, &json_key) {
+                case.raw_value = Some(from_snake_case_key(&json_key));
+                verbatim.push((case.name.clone(), json_key));
+            } else {
+                case.raw_value = Some(json_key);
+            }
+        }
+    }
+
+    let all_verbatim = verbatim.len() == cases.len();
+    let mut decl = Decl::coding_keys(cases);
+    if !verbatim.is_empty() {
+        decl.conform_to("SpectaVerbatimKey");
+        let mut property = String::from("var verbatimKey: String? {\n    switch self {\n");
+        for (name, json_key) in verbatim {
+            let _ = writeln!(
+                property,
+                "    case .{}: return \"{}\"",
+                name,
+                escape_string(&json_key)
+            );
+        }
+        if !all_verbatim {
+            property.push_str("    default: return nil\n");
+        }
+        property.push_str("    }\n}");
+        decl.members.push(Member::Raw(property));
+    }
+    decl
+}
+
+/// The `CodingKeys` case of a property, with a raw value only when its name isn't coded as
+/// its JSON key.
+pub(crate) fn coding_key_case(swift: &Swift, swift_name: &str, json_key: &str) -> EnumCase {
+    if needs_raw_value(swift, swift_name, json_key) {
+        EnumCase::with_raw_value(swift_name, json_key)
+    } else {
+        EnumCase::new(swift_name)
+    }
+}
+
+/// Whether the `CodingKeys` case of a property needs a raw value to be coded as `json_key`.
+///
+/// The coders of the snake_case key strategy convert the names themselves, so only keys they
+/// don't convert to their JSON key, e.g. explicit renames, keep a raw value.
+fn needs_raw_value(swift: &Swift, swift_name: &str, json_key: &str) -> bool {
+    match swift.key_strategy {
+        KeyStrategy::CodingKeys => swift_name != json_key,
+        KeyStrategy::ConvertFromSnakeCase => !converts_key(swift_name.trim_matches('`'), json_key),
+    }
+}
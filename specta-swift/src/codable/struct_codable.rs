@@ -23,10 +23,16 @@
 //! }
 //! ```
 
+use std::borrow::Cow;
+
 use specta::datatype::{Enum, Fields};
 use specta::TypeCollection;
 
+use crate::ast::{Decl, Member, Property};
+use crate::codable::plan::{coding_key_case, coding_keys, needs_coding_keys};
+use crate::datatype::optional::{field_optionality, optional_type};
 use crate::error::Result;
+use crate::naming::fields::field_names;
 use crate::swift::Swift;
 
 /// Generate struct definitions for enum variants with named fields.
@@ -42,11 +48,12 @@ use crate::swift::Swift;
 /// * `e` - The enum containing the variants
 /// * `enum_name` - The name of the parent enum
 /// * `generate_variant_struct_name` - Function to generate struct names
-/// * `datatype_to_swift` - Function to convert field types
+/// * `datatype_to_swift` - Function to convert field types, given the field's location
+///   (`[variant, field]`) within the enum
 ///
 /// # Returns
 ///
-/// Swift struct definitions for all variants with named fields
+/// The struct declarations of all variants with named fields
 ///
 /// # Examples
 ///
@@ -59,55 +66,61 @@ pub fn generate_enum_variant_structs<F, G>(
     enum_name: &str,
     generate_variant_struct_name: F,
     datatype_to_swift: G,
-) -> Result<String>
+) -> Result<Vec<Decl>>
 where
     F: Fn(&str) -> String,
-    G: Fn(&specta::datatype::DataType) -> Result<String>,
+    G: Fn(&specta::datatype::DataType, Vec<Cow<'static, str>>) -> Result<String>,
 {
-    let mut result = String::new();
+    let mut structs = Vec::new();
 
     for (variant_name, variant) in e.variants() {
         if let Fields::Named(fields) = variant.fields() {
             if !fields.fields().is_empty() {
-                let struct_name = generate_variant_struct_name(variant_name);
-
-                result.push_str(&format!("public struct {}: Codable {{\n", struct_name));
+                let mut decl = Decl::public_struct(generate_variant_struct_name(variant_name));
+                if swift.emit_codable {
+                    decl.conformances.push("Codable".into());
+                }
 
                 let mut field_mappings = Vec::new();
 
-                for (field_name, field) in fields.fields() {
-                    let swift_field_name = swift.naming.convert_field(field_name);
-                    if let Some(ty) = field.ty() {
-                        let field_type = datatype_to_swift(ty)?;
-                        result.push_str(&format!(
-                            "    public let {}: {}\n",
-                            swift_field_name, field_type
-                        ));
+                let names = field_names(swift, fields);
+                for ((field_name, field), swift_field_name) in fields.fields().iter().zip(names) {
+                    if let Some((ty, optionality)) = field_optionality(field) {
+                        let mut field_type =
+                            datatype_to_swift(ty, vec![variant_name.clone(), field_name.clone()])?;
+                        if optionality.is_optional() {
+                            field_type = optional_type(swift, field_type);
+                        }
+                        decl.members.push(Member::Property(Property::constant(
+                            &swift_field_name,
+                            field_type,
+                        )));
                         field_mappings.push((swift_field_name, field_name.to_string()));
                     }
                 }
 
                 // Generate custom CodingKeys if field names were converted
-                let needs_custom_coding_keys = field_mappings
+                let keys = field_mappings
                     .iter()
-                    .any(|(swift_name, rust_name)| swift_name != rust_name);
-                if needs_custom_coding_keys {
-                    result.push_str("\n    private enum CodingKeys: String, CodingKey {\n");
-                    for (swift_name, rust_name) in &field_mappings {
-                        result.push_str(&format!(
-                            "        case {} = \"{}\"\n",
-                            swift_name, rust_name
-                        ));
-                    }
-                    result.push_str("    }\n");
+                    .map(|(swift_name, rust_name)| (swift_name.as_str(), rust_name.as_str()));
+                if swift.emit_codable && needs_coding_keys(swift, keys) {
+                    decl.members.push(Member::Decl(coding_keys(
+                        swift,
+                        field_mappings
+                            .iter()
+                            .map(|(swift_name, rust_name)| {
+                                coding_key_case(swift, swift_name, rust_name)
+                            })
+                            .collect(),
+                    )));
                 }
 
-                result.push_str("}\n\n");
+                structs.push(decl);
             }
         }
     }
 
-    Ok(result)
+    Ok(structs)
 }
 
 #[cfg(test)]
@@ -0,0 +1,123 @@
+//! Property wrappers for resilient decoding
+//!
+//! With [`Swift::resilient_decoding`](crate::Swift::resilient_decoding) fields are wrapped
+//! instead of decoded by a hand-written `init(from:)`:
+//!
+//! ```swift
+//! public struct Feed: Codable {
+//!     @LossyArray public var items: [Item]
+//!     @DefaultEmptyString public var title: String
+//!     @DefaultFalse public var archived: Bool
+//! }
+//! ```
+//!
+//! The wrappers are emitted once before the generated types. Their `KeyedDecodingContainer`
+//! overloads make the synthesized implementation accept missing keys.
+
+use specta::datatype::{DataType, Primitive};
+
+/// The Swift source of the property wrappers.
+pub const PROPERTY_WRAPPERS: &str = r#"// MARK: - Resilient Decoding
+/// An array which skips elements that fail to decode. A missing or `null` array is empty.
+@propertyWrapper
+public struct LossyArray<Element: Codable>: Codable {
+    public var wrappedValue: [Element]
+
+    public init(wrappedValue: [Element]) {
+        self.wrappedValue = wrappedValue
+    }
+
+    public init(from decoder: Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        var elements: [Element] = []
+        while !container.isAtEnd {
+            if let element = try? container.decode(Element.self) {
+                elements.append(element)
+            } else {
+                _ = try container.decode(Skipped.self)
+            }
+        }
+        wrappedValue = elements
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        try wrappedValue.encode(to: encoder)
+    }
+
+    /// Consumes an element without decoding it.
+    private struct Skipped: Decodable {
+        init(from decoder: Decoder) throws {}
+    }
+}
+
+extension LossyArray: Equatable where Element: Equatable {}
+extension LossyArray: Hashable where Element: Hashable {}
+
+/// A string which decodes as `""` when it is missing or `null`.
+@propertyWrapper
+public struct DefaultEmptyString: Codable, Hashable {
+    public var wrappedValue: String
+
+    public init(wrappedValue: String) {
+        self.wrappedValue = wrappedValue
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        wrappedValue = container.decodeNil() ? "" : try container.decode(String.self)
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        try wrappedValue.encode(to: encoder)
+    }
+}
+
+/// A boolean which decodes as `false` when it is missing or `null`.
+@propertyWrapper
+public struct DefaultFalse: Codable, Hashable {
+    public var wrappedValue: Bool
+
+    public init(wrappedValue: Bool) {
+        self.wrappedValue = wrappedValue
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        wrappedValue = container.decodeNil() ? false : try container.decode(Bool.self)
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        try wrappedValue.encode(to: encoder)
+    }
+}
+
+extension KeyedDecodingContainer {
+    public func decode<Element>(_ type: LossyArray<Element>.Type, forKey key: Key) throws -> LossyArray<Element> {
+        try decodeIfPresent(type, forKey: key) ?? LossyArray(wrappedValue: [])
+    }
+
+    public func decode(_ type: DefaultEmptyString.Type, forKey key: Key) throws -> DefaultEmptyString {
+        try decodeIfPresent(type, forKey: key) ?? DefaultEmptyString(wrappedValue: "")
+    }
+
+    public func decode(_ type: DefaultFalse.Type, forKey key: Key) throws -> DefaultFalse {
+        try decodeIfPresent(type, forKey: key) ?? DefaultFalse(wrappedValue: false)
+    }
+}
+
+"#;
+
+/// The property wrapper of a required field of type `swift_type`, if its type has one.
+///
+/// The wrapper is returned as the type the field is decoded as, e.g. `LossyArray<Item>`.
+pub fn property_wrapper(ty: &DataType, swift_type: &str) -> Option<String> {
+    match ty {
+        DataType::List(_) => {
+            let element = swift_type.strip_prefix('[')?.strip_suffix(']')?;
+            Some(format!("LossyArray<{}>", element))
+        }
+        DataType::Primitive(Primitive::String) => Some("DefaultEmptyString".into()),
+        DataType::Primitive(Primitive::bool) => Some("DefaultFalse".into()),
+        _ => None,
+    }
+}
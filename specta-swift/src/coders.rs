@@ -0,0 +1,473 @@
+//! Shared `JSONEncoder` and `JSONDecoder` configuration.
+//!
+//! The generated `JSONEncoder.specta` and `JSONDecoder.specta` are configured with the key,
+//! date and data strategies chosen on the [`Swift`] builder, so every consumer encodes and
+//! decodes the types the same way as the Rust side. Some options depend on them, e.g.
+//! [`KeyStrategy::ConvertFromSnakeCase`] drops the `CodingKeys` which map JSON keys to
+//! property names.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use specta::datatype::{DataType, Fields, NamedDataType};
+use specta::TypeCollection;
+
+use crate::codable::{CodableImpl, CodablePlan};
+use crate::datatype::inline::hoisted_types;
+use crate::error::{Error, Result};
+use crate::naming::fields::field_names;
+use crate::report::ExportWarning;
+use crate::swift::{DataStrategy, DateStrategy, KeyStrategy, Swift};
+
+/// Check whether the coders are emitted, either explicitly, for the JSON or dictionary helpers
+/// or for a non-default strategy.
+pub(crate) fn needs_coders(swift: &Swift) -> bool {
+    swift.generate_coders
+        || swift.json_helpers
+        || swift.dictionary_helpers
+        || swift.key_strategy != KeyStrategy::default()
+        || swift.date_strategy != DateStrategy::default()
+        || swift.data_strategy != DataStrategy::default()
+}
+
+/// Explicit ISO 8601 and base64 coding used by the coders on Linux.
+///
+/// swift-corelibs-foundation's `ISO8601DateFormatter` and built-in strategies don't behave
+/// like Apple's Foundation in every version, so the coders use a fixed-format
+/// `DateFormatter` there, which also accepts fractional seconds like chrono writes them.
+const LINUX_CODING: &str = r#"#if os(Linux)
+/// ISO 8601 and base64 coding for Foundation on Linux.
+enum SpectaLinuxCoding {
+    private static func formatter(_ format: String) -> DateFormatter {
+        let formatter = DateFormatter()
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.timeZone = TimeZone(secondsFromGMT: 0)
+        formatter.dateFormat = format
+        return formatter
+    }
+
+    private static let iso8601 = formatter("yyyy-MM-dd'T'HH:mm:ssXXXXX")
+    private static let iso8601Fractional = formatter("yyyy-MM-dd'T'HH:mm:ss.SSSSSSXXXXX")
+
+    static func encodeDate(_ date: Date, to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(iso8601.string(from: date))
+    }
+
+    static func decodeDate(from decoder: Decoder) throws -> Date {
+        let container = try decoder.singleValueContainer()
+        let string = try container.decode(String.self)
+        guard let date = iso8601.date(from: string) ?? iso8601Fractional.date(from: string) else {
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "Expected an ISO 8601 date, found \(string)")
+        }
+        return date
+    }
+
+    static func encodeData(_ data: Data, to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(data.base64EncodedString())
+    }
+
+    static func decodeData(from decoder: Decoder) throws -> Data {
+        let container = try decoder.singleValueContainer()
+        let string = try container.decode(String.self)
+        guard let data = Data(base64Encoded: string, options: .ignoreUnknownCharacters) else {
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "Expected base64 data")
+        }
+        return data
+    }
+}
+#endif
+
+"#;
+
+/// The encoder's key conversion when some `CodingKeys` spell out their JSON key.
+///
+/// `.convertToSnakeCase` can't be told to leave a key alone, so the encoder uses a port of it
+/// which writes the keys of `SpectaVerbatimKey` as they are.
+const VERBATIM_KEYS: &str = r#"/// `CodingKeys` with JSON keys which the snake_case conversion of the coders doesn't reproduce.
+protocol SpectaVerbatimKey: CodingKey {
+    /// The JSON key of the case, if the encoder must write it as it is.
+    var verbatimKey: String? { get }
+}
+
+/// `.convertToSnakeCase`, except for the keys spelled out by `SpectaVerbatimKey`.
+enum SpectaKeyEncoding {
+    private struct Key: CodingKey {
+        let stringValue: String
+        var intValue: Int? { nil }
+
+        init(stringValue: String) {
+            self.stringValue = stringValue
+        }
+
+        init?(intValue: Int) {
+            return nil
+        }
+    }
+
+    static func encode(_ path: [CodingKey]) -> CodingKey {
+        let key = path[path.count - 1]
+        if let verbatim = (key as? SpectaVerbatimKey)?.verbatimKey {
+            return Key(stringValue: verbatim)
+        }
+        return Key(stringValue: snakeCase(key.stringValue))
+    }
+
+    private static func snakeCase(_ key: String) -> String {
+        guard !key.isEmpty else { return key }
+        var words: [Range<String.Index>] = []
+        var wordStart = key.startIndex
+        var searchRange = key.index(after: wordStart)..<key.endIndex
+        while let upper = key.rangeOfCharacter(from: .uppercaseLetters, range: searchRange) {
+            words.append(wordStart..<upper.lowerBound)
+            searchRange = upper.lowerBound..<searchRange.upperBound
+            guard let lower = key.rangeOfCharacter(from: .lowercaseLetters, range: searchRange) else {
+                wordStart = searchRange.lowerBound
+                break
+            }
+            if lower.lowerBound == key.index(after: upper.lowerBound) {
+                wordStart = upper.lowerBound
+            } else {
+                let beforeLower = key.index(before: lower.lowerBound)
+                words.append(upper.lowerBound..<beforeLower)
+                wordStart = beforeLower
+            }
+            searchRange = lower.upperBound..<searchRange.upperBound
+        }
+        words.append(wordStart..<searchRange.upperBound)
+        return words.map { key[$0].lowercased() }.joined(separator: "_")
+    }
+}
+
+"#;
+
+/// Generate the `JSONEncoder.specta` and `JSONDecoder.specta` extensions.
+///
+/// `verbatim_keys` is set when some `CodingKeys` spell out JSON keys which the snake_case
+/// conversion doesn't reproduce, see [`unconvertible_keys`].
+pub(crate) fn generate_coders(swift: &Swift, verbatim_keys: bool) -> Result<String> {
+    let mut result = String::new();
+
+    result.push_str("// MARK: - Coders\n");
+    let linux_date = swift.linux_support && swift.date_strategy == DateStrategy::Iso8601;
+    let linux_data = swift.linux_support && swift.data_strategy == DataStrategy::Base64;
+    if linux_date || linux_data {
+        result.push_str(LINUX_CODING);
+    }
+    let verbatim_keys = verbatim_keys && swift.key_strategy == KeyStrategy::ConvertFromSnakeCase;
+    if verbatim_keys {
+        result.push_str(VERBATIM_KEYS);
+    }
+
+    for (coder, variable, direction, encoding) in [
+        ("JSONEncoder", "encoder", "Encoding", true),
+        ("JSONDecoder", "decoder", "Decoding", false),
+    ] {
+        writeln!(result, "extension {} {{", coder)?;
+        writeln!(
+            result,
+            "    /// The {} configured for the JSON of the Rust side.",
+            variable
+        )?;
+        writeln!(result, "    public static var specta: {} {{", coder)?;
+        writeln!(result, "        let {} = {}()", variable, coder)?;
+
+        let strategy = if verbatim_keys && encoding {
+            Some(".custom(SpectaKeyEncoding.encode)")
+        } else {
+            key_strategy(swift.key_strategy, encoding)
+        };
+        if let Some(strategy) = strategy {
+            writeln!(
+                result,
+                "        {}.key{}Strategy = {}",
+                variable, direction, strategy
+            )?;
+        }
+        for (kind, strategy, on_linux) in [
+            ("date", date_strategy(swift.date_strategy), linux_date),
+            ("data", data_strategy(swift.data_strategy), linux_data),
+        ] {
+            let assignment = format!("        {}.{}{}Strategy = ", variable, kind, direction);
+            if on_linux {
+                let function = if encoding { "encode" } else { "decode" };
+                let kind = if kind == "date" { "Date" } else { "Data" };
+                writeln!(
+                    result,
+                    "#if os(Linux)\n{}.custom(SpectaLinuxCoding.{}{})\n#else\n{}{}\n#endif",
+                    assignment, function, kind, assignment, strategy
+                )?;
+            } else {
+                writeln!(result, "{}{}", assignment, strategy)?;
+            }
+        }
+
+        writeln!(result, "        return {}", variable)?;
+        result.push_str("    }\n");
+        result.push_str("}\n\n");
+    }
+
+    Ok(result)
+}
+
+/// Generate the `init?(jsonData:)` and `jsonData()` convenience members of a struct or enum.
+pub(crate) fn generate_json_helpers(
+    ndt: &NamedDataType,
+    swift_name: &str,
+) -> Result<Option<String>> {
+    if !matches!(ndt.ty(), DataType::Struct(_) | DataType::Enum(_)) {
+        return Ok(None);
+    }
+
+    let mut result = String::new();
+    writeln!(result, "// MARK: - {} JSON Helpers", swift_name)?;
+    writeln!(result, "extension {} {{", swift_name)?;
+    result.push_str(
+        "    /// Decode a value with `JSONDecoder.specta`, returning `nil` if the JSON is invalid.\n",
+    );
+    result.push_str("    public init?(jsonData: Data) {\n");
+    result.push_str(
+        "        guard let value = try? JSONDecoder.specta.decode(Self.self, from: jsonData) else {\n",
+    );
+    result.push_str("            return nil\n");
+    result.push_str("        }\n");
+    result.push_str("        self = value\n");
+    result.push_str("    }\n\n");
+    result.push_str("    /// Encode the value with `JSONEncoder.specta`.\n");
+    result.push_str("    public func jsonData() throws -> Data {\n");
+    result.push_str("        try JSONEncoder.specta.encode(self)\n");
+    result.push_str("    }\n");
+    result.push('}');
+
+    Ok(Some(result))
+}
+
+/// Generate the `init(dictionary:)` and `dictionary` members of a struct with named fields.
+pub(crate) fn generate_dictionary_helpers(
+    ndt: &NamedDataType,
+    swift_name: &str,
+) -> Result<Option<String>> {
+    let DataType::Struct(s) = ndt.ty() else {
+        return Ok(None);
+    };
+    if !matches!(s.fields(), Fields::Named(_)) {
+        return Ok(None);
+    }
+
+    let mut result = String::new();
+    writeln!(result, "// MARK: - {} Dictionary Bridging", swift_name)?;
+    writeln!(result, "extension {} {{", swift_name)?;
+    result.push_str(
+        "    /// Decode a value from a JSON-compatible dictionary, e.g. from an Objective-C API.\n",
+    );
+    result.push_str("    public init(dictionary: [String: Any]) throws {\n");
+    result.push_str("        let data = try JSONSerialization.data(withJSONObject: dictionary)\n");
+    result.push_str("        self = try JSONDecoder.specta.decode(Self.self, from: data)\n");
+    result.push_str("    }\n\n");
+    result.push_str(
+        "    /// The value as a JSON-compatible dictionary, empty if it can't be encoded.\n",
+    );
+    result.push_str("    public var dictionary: [String: Any] {\n");
+    result.push_str("        guard let data = try? JSONEncoder.specta.encode(self),\n");
+    result.push_str("              let object = try? JSONSerialization.jsonObject(with: data),\n");
+    result.push_str("              let dictionary = object as? [String: Any] else {\n");
+    result.push_str("            return [:]\n");
+    result.push_str("        }\n");
+    result.push_str("        return dictionary\n");
+    result.push_str("    }\n");
+    result.push('}');
+
+    Ok(Some(result))
+}
+
+/// Check whether the coders' key conversion codes the `CodingKeys` case `key` as `json_key`.
+///
+/// `.convertToSnakeCase` also converts raw values, so a key only survives the round trip when
+/// it converts to its JSON key and back.
+pub(crate) fn converts_key(key: &str, json_key: &str) -> bool {
+    to_snake_case_key(key) == json_key && from_snake_case_key(json_key) == key
+}
+
+/// Check whether the JSON key of the property `key` doesn't survive the coders' round trip,
+/// neither as the property name nor as a raw value, so its `CodingKeys` case spells it out.
+///
+/// E.g. the encoder writes the tag `LoggedIn` as `logged_in`, and the decoder reads the key
+/// `user_id` of a property renamed to `identifier` as `userId`.
+pub(crate) fn needs_verbatim_key(key: &str, json_key: &str) -> bool {
+    !converts_key(key, json_key) && !converts_key(json_key, json_key)
+}
+
+/// The keys of the exported types which the coders' key conversion doesn't reproduce.
+///
+/// Their `CodingKeys` spell them out for the encoder, so the returned warnings only inform
+/// about them. Keys of a type which `.convertFromSnakeCase` decodes as the same name can't be
+/// told apart at all and are an error.
+pub(crate) fn unconvertible_keys(
+    swift: &Swift,
+    types: &TypeCollection,
+) -> Result<Vec<ExportWarning>> {
+    let mut warnings = Vec::new();
+    let mut check = |name: &str, keys: Vec<(String, String)>| -> Result<()> {
+        let mut decoded = HashMap::new();
+        for (swift_name, key) in keys {
+            if let Some(other) = decoded.insert(from_snake_case_key(&key), key.clone()) {
+                return Err(Error::Configuration(format!(
+                    "'{}' has the keys '{}' and '{}', which the convert-from-snake-case key strategy decodes as the same key",
+                    name, other, key
+                )));
+            }
+            let swift_name = swift_name.trim_matches('`');
+            if needs_verbatim_key(swift_name, &key) {
+                warnings.push(ExportWarning::UnconvertibleKey {
+                    name: name.to_string(),
+                    key,
+                    swift_name: swift_name.to_string(),
+                });
+            }
+        }
+        Ok(())
+    };
+    let fields = |fields: &Fields| match fields {
+        Fields::Named(fields) => fields
+            .fields()
+            .iter()
+            .zip(field_names(swift, fields))
+            .filter(|((_, field), _)| field.ty().is_some())
+            .map(|((json_key, _), name)| (name, json_key.to_string()))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    for ndt in types
+        .into_sorted_iter()
+        .filter(|ndt| swift.is_included(ndt) && !swift.is_external(ndt))
+    {
+        // Inline structs are hoisted into types with their own CodingKeys
+        let mut pending = vec![ndt.clone()];
+        while let Some(ndt) = pending.pop() {
+            pending.extend(
+                hoisted_types(swift, types, &ndt, ndt.name())
+                    .into_iter()
+                    .map(|(_, hoisted)| hoisted),
+            );
+            match ndt.ty() {
+                DataType::Struct(s) => check(ndt.name(), fields(s.fields()))?,
+                DataType::Enum(e) => {
+                    let plan = CodablePlan::for_enum(e);
+                    let variants = e.variants().iter().filter(|(_, variant)| !variant.skip());
+                    // The tags are keys of the enum's own CodingKeys
+                    let tags = match plan.implementation {
+                        CodableImpl::ExternallyTagged => variants
+                            .clone()
+                            .map(|(name, _)| (swift.case_name(name), name.to_string()))
+                            .collect(),
+                        CodableImpl::AdjacentlyTagged { tag, content } => {
+                            vec![("tag".into(), tag), ("content".into(), content)]
+                        }
+                        _ => Vec::new(),
+                    };
+                    check(ndt.name(), tags)?;
+                    if plan.variant_structs {
+                        for (variant_name, variant) in variants {
+                            let name = format!("{}.{}", ndt.name(), variant_name);
+                            check(&name, fields(variant.fields()))?;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// `JSONEncoder.KeyEncodingStrategy.convertToSnakeCase`: words start at an uppercase letter,
+/// runs of uppercase letters are a word of their own, e.g. `myURLKey` becomes `my_url_key`.
+fn to_snake_case_key(key: &str) -> String {
+    let chars = key.chars().collect::<Vec<_>>();
+    if chars.is_empty() {
+        return String::new();
+    }
+
+    let mut words = Vec::new();
+    let mut word_start = 0;
+    let mut search = 1;
+    while let Some(upper) = (search..chars.len()).find(|&i| chars[i].is_uppercase()) {
+        words.push(word_start..upper);
+        let Some(lower) = (upper..chars.len()).find(|&i| chars[i].is_lowercase()) else {
+            word_start = upper;
+            break;
+        };
+        if lower == upper + 1 {
+            word_start = upper;
+        } else {
+            words.push(upper..lower - 1);
+            word_start = lower - 1;
+        }
+        search = lower + 1;
+    }
+    words.push(word_start..chars.len());
+
+    words
+        .into_iter()
+        .map(|range| chars[range].iter().collect::<String>().to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// `JSONDecoder.KeyDecodingStrategy.convertFromSnakeCase`: the words between underscores are
+/// joined in camelCase, leading and trailing underscores are kept.
+pub(crate) fn from_snake_case_key(key: &str) -> String {
+    let trimmed = key.trim_matches('_');
+    if trimmed.is_empty() {
+        return key.to_string();
+    }
+    let leading = &key[..key.len() - key.trim_start_matches('_').len()];
+    let trailing = &key[key.trim_end_matches('_').len()..];
+
+    let words = trimmed
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>();
+    let joined = if words.len() == 1 {
+        trimmed.to_string()
+    } else {
+        let mut joined = words[0].to_lowercase();
+        for word in &words[1..] {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                joined.extend(first.to_uppercase());
+                joined.push_str(&chars.as_str().to_lowercase());
+            }
+        }
+        joined
+    };
+    format!("{}{}{}", leading, joined, trailing)
+}
+
+/// The key strategy of the encoder or decoder, if the default doesn't fit.
+fn key_strategy(strategy: KeyStrategy, encoding: bool) -> Option<&'static str> {
+    match (strategy, encoding) {
+        (KeyStrategy::CodingKeys, _) => None,
+        (KeyStrategy::ConvertFromSnakeCase, true) => Some(".convertToSnakeCase"),
+        (KeyStrategy::ConvertFromSnakeCase, false) => Some(".convertFromSnakeCase"),
+    }
+}
+
+fn date_strategy(strategy: DateStrategy) -> &'static str {
+    match strategy {
+        DateStrategy::DeferredToDate => ".deferredToDate",
+        DateStrategy::Iso8601 => ".iso8601",
+        DateStrategy::SecondsSince1970 => ".secondsSince1970",
+        DateStrategy::MillisecondsSince1970 => ".millisecondsSince1970",
+    }
+}
+
+fn data_strategy(strategy: DataStrategy) -> &'static str {
+    match strategy {
+        DataStrategy::Base64 => ".base64",
+        DataStrategy::DeferredToData => ".deferredToData",
+    }
+}
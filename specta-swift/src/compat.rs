@@ -0,0 +1,331 @@
+//! Detecting breaking changes between releases.
+//!
+//! [`check`] compares previously generated bindings (e.g. the file shipped in the last app
+//! release) against the current [`TypeCollection`] and lists the changes which would break
+//! decoding between the two versions:
+//!
+//! ```rust,no_run
+//! # use specta::TypeCollection;
+//! let types = TypeCollection::default();
+//! let released = std::fs::read_to_string("./Released/Types.swift").unwrap();
+//!
+//! let changes = specta_swift::compat::check(&released, &types).unwrap();
+//! for change in &changes {
+//!     eprintln!("{}", change);
+//! }
+//! assert!(changes.is_empty(), "breaking changes to the Swift bindings");
+//! ```
+//!
+//! Types are compared by their generated Swift declarations, so the bindings must have been
+//! exported with the same [`Swift`] configuration (see [`check_with`]).
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use specta::TypeCollection;
+
+use crate::diff::declarations_by_name;
+use crate::error::Result;
+use crate::export::Export;
+use crate::swift::Swift;
+
+/// A change which breaks decoding between the old and new bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BreakingChange {
+    /// A type was removed.
+    RemovedType {
+        /// Swift name of the type.
+        name: String,
+    },
+    /// A type changed from a struct to an enum or the other way around.
+    ChangedKind {
+        /// Swift name of the type.
+        name: String,
+    },
+    /// A struct field was removed, so old clients fail to decode new data.
+    RemovedField {
+        /// Swift name of the struct.
+        type_name: String,
+        /// Name of the field.
+        field: String,
+    },
+    /// A required field was added, so new clients fail to decode old data.
+    AddedRequiredField {
+        /// Swift name of the struct.
+        type_name: String,
+        /// Name of the field.
+        field: String,
+    },
+    /// The type of a struct field changed.
+    ChangedFieldType {
+        /// Swift name of the struct.
+        type_name: String,
+        /// Name of the field.
+        field: String,
+        /// The previous Swift type.
+        old: String,
+        /// The new Swift type.
+        new: String,
+    },
+    /// An enum case was removed.
+    RemovedVariant {
+        /// Swift name of the enum.
+        type_name: String,
+        /// Name of the case.
+        variant: String,
+    },
+    /// An enum case was added, so old clients fail to decode it.
+    AddedVariant {
+        /// Swift name of the enum.
+        type_name: String,
+        /// Name of the case.
+        variant: String,
+    },
+    /// An enum case was renamed (a single case was replaced by one with the same payload).
+    RenamedVariant {
+        /// Swift name of the enum.
+        type_name: String,
+        /// The previous name of the case.
+        from: String,
+        /// The new name of the case.
+        to: String,
+    },
+    /// The payload of an enum case changed.
+    ChangedVariant {
+        /// Swift name of the enum.
+        type_name: String,
+        /// Name of the case.
+        variant: String,
+        /// The previous declaration of the case.
+        old: String,
+        /// The new declaration of the case.
+        new: String,
+    },
+}
+
+impl fmt::Display for BreakingChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RemovedType { name } => write!(f, "type `{}` was removed", name),
+            Self::ChangedKind { name } => {
+                write!(f, "type `{}` changed between struct and enum", name)
+            }
+            Self::RemovedField { type_name, field } => {
+                write!(f, "field `{}.{}` was removed", type_name, field)
+            }
+            Self::AddedRequiredField { type_name, field } => {
+                write!(f, "required field `{}.{}` was added", type_name, field)
+            }
+            Self::ChangedFieldType {
+                type_name,
+                field,
+                old,
+                new,
+            } => write!(
+                f,
+                "field `{}.{}` changed type from `{}` to `{}`",
+                type_name, field, old, new
+            ),
+            Self::RemovedVariant { type_name, variant } => {
+                write!(f, "case `{}.{}` was removed", type_name, variant)
+            }
+            Self::AddedVariant { type_name, variant } => {
+                write!(f, "case `{}.{}` was added", type_name, variant)
+            }
+            Self::RenamedVariant {
+                type_name,
+                from,
+                to,
+            } => write!(f, "case `{}.{}` was renamed to `{}`", type_name, from, to),
+            Self::ChangedVariant {
+                type_name,
+                variant,
+                old,
+                new,
+            } => write!(
+                f,
+                "case `{}.{}` changed from `{}` to `{}`",
+                type_name, variant, old, new
+            ),
+        }
+    }
+}
+
+/// Compare previously generated bindings against the current types, using the default
+/// [`Swift`] configuration.
+pub fn check(old_export: &str, new_types: &TypeCollection) -> Result<Vec<BreakingChange>> {
+    check_with(&Swift::default(), old_export, new_types)
+}
+
+/// Compare previously generated bindings against the current types exported with `swift`.
+pub fn check_with(
+    swift: &Swift,
+    old_export: &str,
+    new_types: &TypeCollection,
+) -> Result<Vec<BreakingChange>> {
+    let old = parse_types(&Export::parse(old_export));
+    let new = parse_types(&swift.export_declarations(new_types)?);
+
+    let mut changes = Vec::new();
+    for (name, old_type) in &old {
+        let Some(new_type) = new.get(name) else {
+            changes.push(BreakingChange::RemovedType { name: name.clone() });
+            continue;
+        };
+
+        match (old_type, new_type) {
+            (ParsedType::Struct(old_fields), ParsedType::Struct(new_fields)) => {
+                compare_structs(name, old_fields, new_fields, &mut changes)
+            }
+            (ParsedType::Enum(old_cases), ParsedType::Enum(new_cases)) => {
+                compare_enums(name, old_cases, new_cases, &mut changes)
+            }
+            _ => changes.push(BreakingChange::ChangedKind { name: name.clone() }),
+        }
+    }
+
+    Ok(changes)
+}
+
+/// The members of a generated declaration which affect its JSON representation.
+#[derive(Debug)]
+enum ParsedType {
+    /// Field names and Swift types.
+    Struct(Vec<(String, String)>),
+    /// Case names and the rest of the case declaration (payload or raw value).
+    Enum(Vec<(String, String)>),
+}
+
+/// Parse the structs and enums out of generated Swift.
+fn parse_types(export: &Export) -> BTreeMap<String, ParsedType> {
+    declarations_by_name(export)
+        .into_iter()
+        .filter_map(|(name, code)| {
+            let mut lines = code.lines().skip_while(|line| {
+                line.starts_with("//") || line.starts_with('@') || line.starts_with("#")
+            });
+            let declaration = lines.next()?;
+            let body = lines
+                .take_while(|line| *line != "}")
+                .filter_map(|line| line.strip_prefix("    "))
+                .filter(|line| !line.starts_with(char::is_whitespace));
+
+            let parsed = if declaration.contains("struct ") {
+                ParsedType::Struct(
+                    body.filter_map(|line| {
+                        let field = line
+                            .strip_prefix("public let ")
+                            .or_else(|| line.strip_prefix("let "))?;
+                        let (field, ty) = field.split_once(": ")?;
+                        Some((field.to_string(), ty.to_string()))
+                    })
+                    .collect(),
+                )
+            } else if declaration.contains("enum ") {
+                ParsedType::Enum(
+                    body.filter_map(|line| {
+                        let case = line.strip_prefix("case ")?;
+                        let end = case.find(['(', ' ']).unwrap_or(case.len());
+                        Some((case[..end].to_string(), case[end..].to_string()))
+                    })
+                    .collect(),
+                )
+            } else {
+                return None;
+            };
+
+            Some((name, parsed))
+        })
+        .collect()
+}
+
+fn is_optional(ty: &str) -> bool {
+    ty.ends_with('?') || ty.starts_with("Optional<")
+}
+
+fn compare_structs(
+    type_name: &str,
+    old: &[(String, String)],
+    new: &[(String, String)],
+    changes: &mut Vec<BreakingChange>,
+) {
+    for (field, old_ty) in old {
+        match new.iter().find(|(name, _)| name == field) {
+            None => changes.push(BreakingChange::RemovedField {
+                type_name: type_name.to_string(),
+                field: field.clone(),
+            }),
+            Some((_, new_ty)) if new_ty != old_ty => {
+                changes.push(BreakingChange::ChangedFieldType {
+                    type_name: type_name.to_string(),
+                    field: field.clone(),
+                    old: old_ty.clone(),
+                    new: new_ty.clone(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (field, ty) in new {
+        if !is_optional(ty) && !old.iter().any(|(name, _)| name == field) {
+            changes.push(BreakingChange::AddedRequiredField {
+                type_name: type_name.to_string(),
+                field: field.clone(),
+            });
+        }
+    }
+}
+
+fn compare_enums(
+    type_name: &str,
+    old: &[(String, String)],
+    new: &[(String, String)],
+    changes: &mut Vec<BreakingChange>,
+) {
+    let removed = old
+        .iter()
+        .filter(|(case, _)| !new.iter().any(|(name, _)| name == case))
+        .collect::<Vec<_>>();
+    let added = new
+        .iter()
+        .filter(|(case, _)| !old.iter().any(|(name, _)| name == case))
+        .collect::<Vec<_>>();
+
+    match (removed.as_slice(), added.as_slice()) {
+        ([(from, old_payload)], [(to, new_payload)]) if old_payload == new_payload => {
+            changes.push(BreakingChange::RenamedVariant {
+                type_name: type_name.to_string(),
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+        _ => {
+            for (variant, _) in &removed {
+                changes.push(BreakingChange::RemovedVariant {
+                    type_name: type_name.to_string(),
+                    variant: variant.clone(),
+                });
+            }
+            for (variant, _) in &added {
+                changes.push(BreakingChange::AddedVariant {
+                    type_name: type_name.to_string(),
+                    variant: variant.clone(),
+                });
+            }
+        }
+    }
+
+    for (case, old_payload) in old {
+        if let Some((_, new_payload)) = new.iter().find(|(name, _)| name == case) {
+            if old_payload != new_payload {
+                changes.push(BreakingChange::ChangedVariant {
+                    type_name: type_name.to_string(),
+                    variant: case.clone(),
+                    old: format!("{}{}", case, old_payload),
+                    new: format!("{}{}", case, new_payload),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! Loading the exporter configuration from a TOML file.
+//!
+//! This allows the exporter setup to live next to the Xcode project and be shared
+//! between several export binaries:
+//!
+//! ```toml
+//! header = "// Generated by my-app. DO NOT EDIT."
+//! indent = 2
+//! brace_style = "same-line"
+//! space_before_colon = false
+//! trailing_commas = true
+//! line_width = 100
+//! naming = "pascal-case"
+//! unicode_identifiers = "transliterate"
+//! prefix_shadowed_names = "App"
+//! monomorphize = false
+//! optionals = "question-mark"
+//! protocols = ["Combine"]
+//! generate_initializers = true
+//! skip_initializers_for = ["Settings"]
+//! always_emit_codable = true
+//! emit_codable = true
+//! codable_format = "json"
+//! key_strategy = "coding-keys"
+//! missing_collections = "empty"
+//! optional_collections = "empty"
+//! missing_reference = "stub"
+//! resilient_decoding = true
+//! validate_array_lengths = true
+//! char_type = "string"
+//! newtypes = "raw-representable"
+//! variant_ordering = "alphabetical"
+//! box_variants_larger_than = 256
+//! never_box_variants = ["Token"]
+//! date_strategy = "iso8601"
+//! data_strategy = "base64"
+//! coders = true
+//! linux_support = true
+//! string_enum_helpers = true
+//! json_helpers = true
+//! dictionary_helpers = true
+//! copy_modifiers = true
+//! change_tracking = true
+//! cloudkit_records = ["Note"]
+//! core_data_entities = ["Note"]
+//! generate_mocks = true
+//! duplicate_name_strategy = "qualify"
+//! exclude_modules = ["my_app::internal"]
+//! roots = ["MobileApi"]
+//! assume_external = ["Money"]
+//! cache_file = "target/specta-swift.cache"
+//!
+//! [qualify]
+//! separator = "_"
+//! depth = 2
+//! strip_prefixes = ["my_app"]
+//!
+//! [extensions]
+//! User = "extension User: Identifiable {}"
+//!
+//! [option_sets]
+//! Permissions = { READ = 1, WRITE = 2, EXECUTE = 4 }
+//! ```
+//!
+//! Every key is optional and unknown keys are rejected.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::swift::{IndentStyle, QualifyOptions, Swift};
+
+/// The contents of a configuration file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    header: Option<String>,
+    indent: Option<Indent>,
+    brace_style: Option<String>,
+    space_before_colon: bool,
+    trailing_commas: bool,
+    line_width: Option<usize>,
+    naming: Option<String>,
+    unicode_identifiers: Option<String>,
+    prefix_shadowed_names: Option<String>,
+    generics: Option<String>,
+    monomorphize: bool,
+    optionals: Option<String>,
+    protocols: Vec<String>,
+    serde: bool,
+    struct_naming: Option<String>,
+    generate_initializers: Option<bool>,
+    initializers_for: Vec<String>,
+    skip_initializers_for: Vec<String>,
+    always_emit_codable: bool,
+    emit_codable: Option<bool>,
+    codable_format: Option<String>,
+    key_strategy: Option<String>,
+    missing_collections: Option<String>,
+    optional_collections: Option<String>,
+    missing_reference: Option<String>,
+    resilient_decoding: bool,
+    validate_array_lengths: bool,
+    char_type: Option<String>,
+    newtypes: Option<String>,
+    variant_ordering: Option<String>,
+    box_variants_larger_than: Option<usize>,
+    box_variants: Vec<String>,
+    never_box_variants: Vec<String>,
+    date_strategy: Option<String>,
+    data_strategy: Option<String>,
+    coders: bool,
+    linux_support: bool,
+    string_enum_helpers: bool,
+    json_helpers: bool,
+    dictionary_helpers: bool,
+    copy_modifiers: bool,
+    change_tracking: bool,
+    cloudkit_records: Vec<String>,
+    core_data_entities: Vec<String>,
+    generate_mocks: bool,
+    generate_preview_data: bool,
+    schema_hash: bool,
+    duplicate_name_strategy: Option<String>,
+    qualify: Option<Qualify>,
+    include_modules: Vec<String>,
+    exclude_modules: Vec<String>,
+    roots: Vec<String>,
+    assume_external: Vec<String>,
+    cache_file: Option<PathBuf>,
+    extensions: BTreeMap<String, String>,
+    option_sets: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+/// `indent` accepts either a number of spaces or `"tabs"`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Indent {
+    Spaces(usize),
+    Named(String),
+}
+
+/// The `[qualify]` table.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Qualify {
+    separator: Option<String>,
+    depth: Option<usize>,
+    full_path: bool,
+    strip_prefixes: Vec<String>,
+}
+
+impl Swift {
+    /// Load the exporter configuration from a TOML file.
+    ///
+    /// Relative paths in the file (e.g. `cache_file`) are resolved against the directory
+    /// containing it. See the [`config`](crate::config) module for the supported keys.
+    pub fn from_config_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let mut swift = Self::from_config_str(&content)
+            .map_err(|err| Error::Configuration(format!("{}: {}", path.display(), err)))?;
+
+        if let (Some(cache_path), Some(dir)) = (&swift.cache_path, path.parent()) {
+            swift.cache_path = Some(dir.join(cache_path));
+        }
+
+        Ok(swift)
+    }
+
+    /// Load the exporter configuration from a TOML string.
+    pub fn from_config_str(content: &str) -> Result<Self> {
+        let config: Config =
+            toml::from_str(content).map_err(|err| Error::Configuration(err.to_string()))?;
+
+        let mut swift = Self::new();
+        if let Some(header) = config.header {
+            swift = swift.header(header);
+        }
+        if let Some(indent) = config.indent {
+            swift = swift.indent(match indent {
+                Indent::Spaces(spaces) => IndentStyle::Spaces(spaces),
+                Indent::Named(name) => name.parse()?,
+            });
+        }
+        if let Some(brace_style) = config.brace_style {
+            swift = swift.brace_style(brace_style.parse()?);
+        }
+        swift = swift
+            .space_before_colon(config.space_before_colon)
+            .trailing_commas(config.trailing_commas);
+        if let Some(line_width) = config.line_width {
+            swift = swift.line_width(line_width);
+        }
+        if let Some(naming) = config.naming {
+            swift = swift.naming(naming.parse()?);
+        }
+        if let Some(unicode_identifiers) = config.unicode_identifiers {
+            swift = swift.unicode_identifiers(unicode_identifiers.parse()?);
+        }
+        if let Some(prefix) = config.prefix_shadowed_names {
+            swift = swift.prefix_shadowed_names(prefix);
+        }
+        if let Some(generics) = config.generics {
+            swift = swift.generics(generics.parse()?);
+        }
+        swift = swift.monomorphize(config.monomorphize);
+        if let Some(optionals) = config.optionals {
+            swift = swift.optionals(optionals.parse()?);
+        }
+        for protocol in config.protocols {
+            swift = swift.add_protocol(protocol);
+        }
+        if config.serde {
+            swift = swift.with_serde();
+        }
+        if let Some(struct_naming) = config.struct_naming {
+            swift = swift.struct_naming(struct_naming.parse()?);
+        }
+        if let Some(generate_initializers) = config.generate_initializers {
+            swift.generate_initializers = generate_initializers;
+        }
+        swift = swift
+            .initializers_for(config.initializers_for)
+            .skip_initializers_for(config.skip_initializers_for);
+        swift = swift.always_emit_codable(config.always_emit_codable);
+        if let Some(emit_codable) = config.emit_codable {
+            swift = swift.emit_codable(emit_codable);
+        }
+        if let Some(codable_format) = config.codable_format {
+            swift = swift.codable_format(codable_format.parse()?);
+        }
+        if let Some(key_strategy) = config.key_strategy {
+            swift = swift.key_strategy(key_strategy.parse()?);
+        }
+        if let Some(missing_collections) = config.missing_collections {
+            swift = swift.missing_collections(missing_collections.parse()?);
+        }
+        if let Some(optional_collections) = config.optional_collections {
+            swift = swift.optional_collections(optional_collections.parse()?);
+        }
+        if let Some(missing_reference) = config.missing_reference {
+            swift = swift.missing_reference(missing_reference.parse()?);
+        }
+        swift = swift.resilient_decoding(config.resilient_decoding);
+        swift = swift.validate_array_lengths(config.validate_array_lengths);
+        if let Some(char_type) = config.char_type {
+            swift = swift.char_type(char_type.parse()?);
+        }
+        if let Some(newtypes) = config.newtypes {
+            swift = swift.newtypes(newtypes.parse()?);
+        }
+        if let Some(variant_ordering) = config.variant_ordering {
+            swift = swift.variant_ordering(variant_ordering.parse()?);
+        }
+        if let Some(bytes) = config.box_variants_larger_than {
+            swift = swift.box_variants_larger_than(bytes);
+        }
+        swift = swift
+            .box_variants_of(config.box_variants)
+            .never_box_variants_of(config.never_box_variants);
+        if let Some(date_strategy) = config.date_strategy {
+            swift = swift.date_strategy(date_strategy.parse()?);
+        }
+        if let Some(data_strategy) = config.data_strategy {
+            swift = swift.data_strategy(data_strategy.parse()?);
+        }
+        if config.coders {
+            swift = swift.with_coders();
+        }
+        if config.linux_support {
+            swift = swift.with_linux_support();
+        }
+        if config.string_enum_helpers {
+            swift = swift.with_string_enum_helpers();
+        }
+        if config.json_helpers {
+            swift = swift.with_json_helpers();
+        }
+        if config.dictionary_helpers {
+            swift = swift.with_dictionary_helpers();
+        }
+        if config.copy_modifiers {
+            swift = swift.with_copy_modifiers();
+        }
+        if config.change_tracking {
+            swift = swift.with_change_tracking();
+        }
+        if !config.cloudkit_records.is_empty() {
+            swift = swift.cloudkit_records_for(config.cloudkit_records);
+        }
+        if !config.core_data_entities.is_empty() {
+            swift = swift.core_data_entities_for(config.core_data_entities);
+        }
+        if config.generate_mocks {
+            swift = swift.with_mocks();
+        }
+        if config.generate_preview_data {
+            swift = swift.with_preview_data();
+        }
+        if config.schema_hash {
+            swift = swift.with_schema_hash();
+        }
+        if let Some(strategy) = config.duplicate_name_strategy {
+            swift = swift.duplicate_name_strategy(strategy.parse()?);
+        }
+        if let Some(qualify) = config.qualify {
+            let mut options = QualifyOptions::new();
+            if let Some(separator) = qualify.separator {
+                options = options.separator(separator);
+            }
+            if let Some(depth) = qualify.depth {
+                options = options.depth(depth);
+            }
+            if qualify.full_path {
+                options = options.full_path();
+            }
+            for prefix in qualify.strip_prefixes {
+                options = options.strip_prefix(prefix);
+            }
+            swift = swift.qualify_options(options);
+        }
+        if !config.include_modules.is_empty() {
+            swift = swift.include_modules(config.include_modules);
+        }
+        if !config.exclude_modules.is_empty() {
+            swift = swift.exclude_modules(config.exclude_modules);
+        }
+        if !config.roots.is_empty() {
+            swift = swift.roots(config.roots);
+        }
+        swift = swift.assume_external(config.assume_external);
+        if let Some(cache_file) = config.cache_file {
+            swift = swift.cache_file(cache_file);
+        }
+        for (type_name, code) in config.extensions {
+            swift = swift.extend_type(type_name, code);
+        }
+        for (type_name, flags) in config.option_sets {
+            // Tables are unordered, so flags are declared in the order of their bits
+            let mut flags = flags.into_iter().collect::<Vec<_>>();
+            flags.sort_by_key(|(_, bits)| *bits);
+            swift = swift.option_set(type_name, flags);
+        }
+
+        Ok(swift)
+    }
+}
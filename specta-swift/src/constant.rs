@@ -0,0 +1,116 @@
+//! Constants shared between Rust and Swift.
+
+use std::borrow::Cow;
+
+use crate::utils::formatting::escape_string;
+
+/// A constant emitted in the `Constants` namespace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constant {
+    /// Name of the constant in Rust (converted with the field naming convention).
+    pub name: Cow<'static, str>,
+    /// The Swift type of the constant.
+    pub swift_type: Cow<'static, str>,
+    /// The value as a Swift literal.
+    pub value: String,
+}
+
+/// A Rust value which can be emitted as a Swift constant.
+pub trait SwiftConstant {
+    /// The Swift type of the value.
+    fn swift_type() -> &'static str;
+
+    /// The value as a Swift literal.
+    fn swift_literal(&self) -> String;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty => $swift:literal),* $(,)?) => {$(
+        impl SwiftConstant for $ty {
+            fn swift_type() -> &'static str {
+                $swift
+            }
+
+            fn swift_literal(&self) -> String {
+                self.to_string()
+            }
+        }
+    )*};
+}
+
+impl_integer!(
+    i8 => "Int8",
+    i16 => "Int16",
+    i32 => "Int32",
+    i64 => "Int64",
+    isize => "Int",
+    u8 => "UInt8",
+    u16 => "UInt16",
+    u32 => "UInt32",
+    u64 => "UInt64",
+    usize => "UInt",
+);
+
+macro_rules! impl_float {
+    ($($ty:ty => $swift:literal),* $(,)?) => {$(
+        impl SwiftConstant for $ty {
+            fn swift_type() -> &'static str {
+                $swift
+            }
+
+            fn swift_literal(&self) -> String {
+                if self.is_nan() {
+                    format!("{}.nan", $swift)
+                } else if self.is_infinite() {
+                    let sign = if self.is_sign_negative() { "-" } else { "" };
+                    format!("{}{}.infinity", sign, $swift)
+                } else {
+                    // `Debug` always includes a decimal point or exponent
+                    format!("{:?}", self)
+                }
+            }
+        }
+    )*};
+}
+
+impl_float!(f32 => "Float", f64 => "Double");
+
+impl SwiftConstant for bool {
+    fn swift_type() -> &'static str {
+        "Bool"
+    }
+
+    fn swift_literal(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl SwiftConstant for char {
+    fn swift_type() -> &'static str {
+        "Character"
+    }
+
+    fn swift_literal(&self) -> String {
+        format!("\"{}\"", escape_string(&self.to_string()))
+    }
+}
+
+impl SwiftConstant for &str {
+    fn swift_type() -> &'static str {
+        "String"
+    }
+
+    fn swift_literal(&self) -> String {
+        format!("\"{}\"", escape_string(self))
+    }
+}
+
+impl SwiftConstant for String {
+    fn swift_type() -> &'static str {
+        "String"
+    }
+
+    fn swift_literal(&self) -> String {
+        self.as_str().swift_literal()
+    }
+}
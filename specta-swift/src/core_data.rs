@@ -0,0 +1,236 @@
+//! `NSManagedObject` skeletons for offline caches.
+//!
+//! Structs flagged with [`Swift::core_data_entities_for`] get a `<Name>Entity` subclass of
+//! `NSManagedObject` with an `@NSManaged` property per field, `update(from:)` to copy a value
+//! into it and `init(entity:)` to read it back. The matching entity still has to be added to
+//! the data model, with attributes of the types documented on each property.
+//!
+//! Core Data has no unsigned integers, so they are widened to the next signed attribute type.
+//! Optional numbers are stored as `NSNumber?`, like Xcode's generated classes.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use specta::datatype::{DataType, Fields, NamedDataType};
+use specta::TypeCollection;
+
+use crate::datatype::export::datatype_to_swift;
+use crate::datatype::optional::field_optionality;
+use crate::error::{Error, Result};
+use crate::naming::fields::field_names;
+use crate::swift::Swift;
+
+/// The error thrown by the generated `init(entity:)`, emitted once before the types.
+pub(crate) const ENTITY_ERROR: &str = "// MARK: - Core Data
+/// An error converting a managed object into a generated type.
+public enum SpectaEntityError: Error {
+    /// A required attribute is `nil`.
+    case missingAttribute(String)
+}
+
+";
+
+/// How a Swift field type is stored in a Core Data attribute.
+struct Attribute {
+    /// The Swift type of the `@NSManaged` property.
+    stored: &'static str,
+    /// The `NSNumber` accessor of optional numbers, `None` for object types.
+    number_value: Option<&'static str>,
+}
+
+impl Attribute {
+    fn for_type(field_type: &str) -> Option<Self> {
+        let object = |stored| Self {
+            stored,
+            number_value: None,
+        };
+        let number = |stored, number_value| Self {
+            stored,
+            number_value: Some(number_value),
+        };
+        Some(match field_type {
+            "String" => object("String"),
+            "Date" => object("Date"),
+            "Data" => object("Data"),
+            "UUID" => object("UUID"),
+            "Bool" => number("Bool", "boolValue"),
+            "Int8" | "UInt8" | "Int16" => number("Int16", "int16Value"),
+            "UInt16" | "Int32" => number("Int32", "int32Value"),
+            "UInt32" | "Int" | "Int64" => number("Int64", "int64Value"),
+            "Float" => number("Float", "floatValue"),
+            "Double" => number("Double", "doubleValue"),
+            _ => return None,
+        })
+    }
+}
+
+/// Generate the managed object subclass and converters of a flagged struct.
+///
+/// Fails with [`Error::UnsupportedType`] if the type isn't a struct with named fields or one
+/// of its fields has no matching attribute type.
+pub(crate) fn generate_managed_object(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &NamedDataType,
+    swift_name: &str,
+) -> Result<String> {
+    let unsupported = |path: String, reason: String| Error::UnsupportedType { path, reason };
+    let fields = match ndt.ty() {
+        DataType::Struct(s) if ndt.generics().is_empty() => match s.fields() {
+            Fields::Named(fields) => fields,
+            _ => {
+                return Err(unsupported(
+                    ndt.name().to_string(),
+                    "Core Data entities can only be generated for structs with named fields".into(),
+                ))
+            }
+        },
+        _ => {
+            return Err(unsupported(
+                ndt.name().to_string(),
+                "Core Data entities can only be generated for non-generic structs".into(),
+            ))
+        }
+    };
+
+    let entity_name = format!("{}Entity", swift_name);
+    let mut properties = String::new();
+    let mut update = String::new();
+    let mut read = String::new();
+    let names = field_names(swift, fields);
+    for ((name, field), property) in fields.fields().iter().zip(names) {
+        let Some((inner, optionality)) = field_optionality(field) else {
+            continue;
+        };
+        let optional = optionality.is_optional();
+
+        let location: Vec<Cow<'static, str>> = vec![ndt.name().clone(), name.clone()];
+        let field_type = datatype_to_swift(swift, types, inner, location, false, None)?;
+        let Some(attribute) = Attribute::for_type(&field_type) else {
+            return Err(unsupported(
+                format!("{}.{}", ndt.name(), name),
+                format!(
+                    "'{}' has no Core Data attribute type, only strings, numbers, booleans, UUID, Date and Data do",
+                    field_type
+                ),
+            ));
+        };
+
+        let widened = attribute.stored != field_type;
+        match (attribute.number_value, optional) {
+            // Object attributes are optional in Swift, whatever the model says
+            (None, _) => {
+                writeln!(
+                    properties,
+                    "    @NSManaged public var {}: {}?",
+                    property, attribute.stored
+                )?;
+                writeln!(update, "        {} = value.{}", property, property)?;
+                if optional {
+                    writeln!(read, "        self.{} = entity.{}", property, property)?;
+                } else {
+                    writeln!(
+                        read,
+                        "        guard let {} = entity.{} else {{",
+                        property, property
+                    )?;
+                    writeln!(
+                        read,
+                        "            throw SpectaEntityError.missingAttribute(\"{}\")",
+                        property
+                    )?;
+                    read.push_str("        }\n");
+                    writeln!(read, "        self.{} = {}", property, property)?;
+                }
+            }
+            (Some(number_value), true) => {
+                writeln!(
+                    properties,
+                    "    /// Stored as `{}`.\n    @NSManaged public var {}: NSNumber?",
+                    attribute.stored, property
+                )?;
+                writeln!(
+                    update,
+                    "        {} = value.{}.map {{ NSNumber(value: {}($0)) }}",
+                    property, property, attribute.stored
+                )?;
+                writeln!(
+                    read,
+                    "        self.{} = entity.{}.map {{ {}($0.{}) }}",
+                    property, property, field_type, number_value
+                )?;
+            }
+            (Some(_), false) => {
+                writeln!(
+                    properties,
+                    "    @NSManaged public var {}: {}",
+                    property, attribute.stored
+                )?;
+                if widened {
+                    writeln!(
+                        update,
+                        "        {} = {}(value.{})",
+                        property, attribute.stored, property
+                    )?;
+                    writeln!(
+                        read,
+                        "        self.{} = {}(entity.{})",
+                        property, field_type, property
+                    )?;
+                } else {
+                    writeln!(update, "        {} = value.{}", property, property)?;
+                    writeln!(read, "        self.{} = entity.{}", property, property)?;
+                }
+            }
+        }
+    }
+
+    let mut result = String::new();
+    writeln!(result, "// MARK: - {} Core Data", swift_name)?;
+    writeln!(result, "@objc({})", entity_name)?;
+    writeln!(
+        result,
+        "public final class {}: NSManagedObject {{",
+        entity_name
+    )?;
+    writeln!(
+        result,
+        "    @nonobjc public class func fetchRequest() -> NSFetchRequest<{}> {{",
+        entity_name
+    )?;
+    writeln!(
+        result,
+        "        NSFetchRequest<{}>(entityName: \"{}\")",
+        entity_name, entity_name
+    )?;
+    result.push_str("    }\n");
+    if !properties.is_empty() {
+        result.push('\n');
+        result.push_str(&properties);
+    }
+    result.push_str("}\n\n");
+
+    writeln!(result, "extension {} {{", entity_name)?;
+    writeln!(
+        result,
+        "    /// Copy the fields of a `{}` into the managed object.",
+        swift_name
+    )?;
+    writeln!(
+        result,
+        "    public func update(from value: {}) {{",
+        swift_name
+    )?;
+    result.push_str(&update);
+    result.push_str("    }\n");
+    result.push_str("}\n\n");
+
+    writeln!(result, "extension {} {{", swift_name)?;
+    result.push_str("    /// Read the value from a managed object.\n");
+    writeln!(result, "    public init(entity: {}) throws {{", entity_name)?;
+    result.push_str(&read);
+    result.push_str("    }\n");
+    result.push('}');
+
+    Ok(result)
+}
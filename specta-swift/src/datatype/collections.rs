@@ -4,11 +4,12 @@
 //!
 //! - `Vec<T>` → `[T]` (Swift arrays)
 //! - `HashMap<K, V>` → `[K: V]` (Swift dictionaries)
-//! - `(T, U, ...)` → `(T, U, ...)` (Swift tuples)
+//! - `(T, U, ...)` → `Tuple2<T, U>`, ... (helper structs, since Swift tuples aren't `Codable`)
 
 use specta::TypeCollection;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::special_types::helpers::{tuple_helper_name, MAX_TUPLE_ARITY};
 use crate::swift::Swift;
 
 /// Convert a Rust `Vec<T>` to Swift array syntax `[T]`.
@@ -39,6 +40,17 @@ where
     Ok(format!("[{}]", element_type))
 }
 
+/// The length checked when decoding a field of type `ty`, if it is a fixed-size array (`[T; N]`)
+/// and [`Swift::validate_array_lengths`] is enabled.
+///
+/// `ty` is the field type without its `Option`.
+pub fn validated_length(swift: &Swift, ty: &specta::datatype::DataType) -> Option<usize> {
+    match ty {
+        specta::datatype::DataType::List(list) if swift.validate_array_lengths => list.length(),
+        _ => None,
+    }
+}
+
 /// Convert a Rust `HashMap<K, V>` to Swift dictionary syntax `[K: V]`.
 ///
 /// # Arguments
@@ -67,7 +79,10 @@ where
     Ok(format!("[{}: {}]", key_type, value_type))
 }
 
-/// Convert a Rust tuple to Swift tuple syntax.
+/// Convert a Rust tuple to the Swift tuple helper struct of its arity.
+///
+/// Swift tuples can't conform to `Codable`, so tuples are represented by the `Tuple2` to
+/// `Tuple12` helpers, which are coded as arrays like serde does.
 ///
 /// # Arguments
 ///
@@ -78,15 +93,16 @@ where
 ///
 /// # Returns
 ///
-/// The Swift tuple type string, or "Void" for empty tuples
+/// The Swift tuple type string, or "Void" for empty tuples. Tuples with more elements than
+/// the largest helper are unsupported.
 ///
 /// # Examples
 ///
 /// ```rust
 /// // () → Void
 /// // (String,) → String (single element unwrapped)
-/// // (String, i32) → (String, Int32)
-/// // (String, i32, bool) → (String, Int32, Bool)
+/// // (String, i32) → Tuple2<String, Int32>
+/// // (String, i32, bool) → Tuple3<String, Int32, Bool>
 /// ```
 pub fn tuple_to_swift<F>(tuple: &specta::datatype::Tuple, convert: F) -> Result<String>
 where
@@ -97,6 +113,11 @@ where
     } else if tuple.elements().len() == 1 {
         // Single element tuple unwraps to just the type
         convert(&tuple.elements()[0])
+    } else if tuple.elements().len() > MAX_TUPLE_ARITY {
+        Err(Error::unsupported_type(format!(
+            "tuples with more than {} elements have no Swift helper",
+            MAX_TUPLE_ARITY
+        )))
     } else {
         let types_str = tuple
             .elements()
@@ -104,7 +125,11 @@ where
             .map(|e| convert(e))
             .collect::<std::result::Result<Vec<_>, _>>()?
             .join(", ");
-        Ok(format!("({})", types_str))
+        Ok(format!(
+            "{}<{}>",
+            tuple_helper_name(tuple.elements().len()),
+            types_str
+        ))
     }
 }
 
@@ -5,294 +5,346 @@
 //! complete Swift type definitions.
 
 use std::borrow::Cow;
-
-use specta::{datatype::DataType, SpectaID, TypeCollection};
-
-use crate::datatype::primitives::{literal_to_swift, primitive_to_swift};
+use std::fmt::Write;
+
+use specta::datatype::{DataType, Primitive};
+use specta::{SpectaID, TypeCollection};
+
+use crate::ast::{
+    render_members, Decl, EnumCase, Extension, Function, Item, Member, Property, TypeDefinition,
+};
+use crate::codable::plan::{coding_key_case, coding_keys};
+use crate::codable::{property_wrapper, CodableImpl, CodablePlan, CodingKeysPlacement};
+use crate::datatype::collections::validated_length;
+use crate::datatype::inline::{inline_field_type, inline_type};
+use crate::datatype::newtype::{generate_raw_value_newtype, phantom_id_type, raw_value_type};
+use crate::datatype::option_set::generate_option_set;
+use crate::datatype::optional::{empty_collection, field_optionality, optional_type, Optionality};
+use crate::datatype::primitives::{literal_to_swift, literal_type_to_swift, primitive_to_swift};
+use crate::datatype::reference::reference_name;
 use crate::error::{Error, Result};
 use crate::naming::case_conversion::to_pascal_case;
-use crate::naming::rename_rules::{generate_raw_value, generate_string_enum_raw_value};
-use crate::naming::variant_naming::generate_variant_struct_name;
+use crate::naming::fields::field_names;
+use crate::naming::rename_rules::generate_string_enum_raw_value;
+use crate::naming::resolver::VariantStructNames;
 use crate::special_types::{is_duration_struct, is_serde_json_number_enum, is_special_std_type};
-use crate::swift::Swift;
-use crate::utils::formatting::{format_deprecated, format_doc_comment};
-use crate::utils::validation::is_recursive_type_reference;
+use crate::swift::{CharType, CodableFormat, MissingCollectionStrategy, Swift};
+use crate::utils::formatting::{escape_string, format_deprecated_type, format_doc_comment};
 
 /// Export a single type to Swift with a custom name.
+///
+/// The name is used verbatim, so it should already follow the configured naming convention.
+/// Prefer [`Swift::declaration`], which names the type like an export does.
 pub fn export_type_with_name(
     swift: &Swift,
     types: &TypeCollection,
     ndt: &specta::datatype::NamedDataType,
     custom_name: &str,
 ) -> Result<String> {
-    let mut result = String::new();
+    Ok(build_type_definition(
+        swift,
+        types,
+        ndt,
+        custom_name,
+        &VariantStructNames::default(),
+    )?
+    .print(&swift.printer()))
+}
 
-    // Add JSDoc-style comments if present
-    if !ndt.docs().is_empty() {
-        result.push_str(&format_doc_comment(ndt.docs()));
+/// Build the syntax tree of a single type using the names chosen by the name resolver.
+pub(crate) fn build_type_definition(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &specta::datatype::NamedDataType,
+    custom_name: &str,
+    variant_structs: &VariantStructNames,
+) -> Result<TypeDefinition> {
+    let variant_struct_name =
+        |variant_name: &str| variant_structs.get(swift, ndt.sid(), custom_name, variant_name);
+
+    // Errors are reported relative to the Rust type name
+    let location = vec![ndt.name().clone()];
+
+    if let Some(flags) = swift.option_set_flags(ndt) {
+        let decl = generate_option_set(swift, ndt, custom_name, flags)
+            .map_err(|err| err.with_location(&location))?;
+        return Ok(TypeDefinition {
+            items: vec![Item::Decl(type_decl(swift, ndt, decl))],
+        });
     }
-
-    // Add deprecated annotation if present
-    if let Some(deprecated) = ndt.deprecated() {
-        let message = match deprecated {
-            specta::datatype::DeprecatedType::Deprecated => "This type is deprecated",
-            specta::datatype::DeprecatedType::DeprecatedWithSince { note, .. } => note.as_ref(),
-            _ => "This type is deprecated",
-        };
-        result.push_str(&format_deprecated(message));
+    if let Some(ty) = raw_value_type(swift, ndt).or_else(|| phantom_id_type(ndt)) {
+        let decl = generate_raw_value_newtype(swift, types, ndt, custom_name, ty)
+            .map_err(|err| err.with_location(&location))?;
+        return Ok(TypeDefinition {
+            items: vec![Item::Decl(type_decl(swift, ndt, decl))],
+        });
     }
 
-    // Generate the type definition
-    let type_def = datatype_to_swift(swift, types, ndt.ty(), vec![], false, Some(ndt.sid()))?;
+    // Codable is planned once per type, structs with named fields also plan their fields once
+    // for the properties and the Codable extension
+    let codable = CodablePlan::for_type(swift, types, ndt);
+    let is_special = is_special_std_type(types, Some(ndt.sid())).is_some();
+    let field_plans = match ndt.ty() {
+        DataType::Struct(s) if !is_special && !is_duration_struct(s) => match s.fields() {
+            specta::datatype::Fields::Named(fields) => Some(plan_fields(
+                swift,
+                types,
+                fields,
+                &location,
+                false,
+                Some((ndt, custom_name, variant_structs)),
+            )?),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let mut items = vec![];
 
-    // Format based on type
     match ndt.ty() {
         DataType::Struct(s) => {
-            let name = swift.naming.convert(custom_name);
-            let generics = if ndt.generics().is_empty() {
-                String::new()
-            } else {
-                format!(
-                    "<{}>",
-                    ndt.generics()
-                        .iter()
-                        .map(|g| g.to_string())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                )
-            };
-
-            result.push_str(&format!("public struct {}{}: Codable {{\n", name, generics));
+            let mut decl = type_decl(swift, ndt, Decl::public_struct(custom_name));
+            if codable.conforms_in_declaration() {
+                decl.conformances.push("Codable".into());
+            }
 
-            // Handle empty structs specially
-            match s.fields() {
-                specta::datatype::Fields::Named(fields) if fields.fields().is_empty() => {
-                    // Empty struct - just close the braces
+            decl.members = match (&field_plans, s.fields()) {
+                (Some(plans), _) => {
+                    named_struct_members(swift, plans, &codable, swift.generates_initializer(ndt))
                 }
-                specta::datatype::Fields::Unit => {
-                    // Unit struct - just close the braces
+                (None, specta::datatype::Fields::Unnamed(fields)) => {
+                    tuple_struct_members(swift, types, fields, &location, false)?
                 }
-                _ => {
-                    // Non-empty struct - use the generated type definition
-                    result.push_str(&type_def);
+                (None, specta::datatype::Fields::Named(fields)) if !fields.fields().is_empty() => {
+                    let type_def = datatype_to_swift(
+                        swift,
+                        types,
+                        ndt.ty(),
+                        location.clone(),
+                        false,
+                        Some(ndt.sid()),
+                    )?;
+                    vec![Member::Raw(type_def)]
                 }
-            }
+                _ => vec![],
+            };
+            items.push(Item::Decl(decl));
 
-            result.push_str("}");
+            // Add the custom Codable implementation of structs with optional fields, or of
+            // every struct with `always_emit_codable`
+            if let Some(plans) = &field_plans {
+                if codable.implementation == CodableImpl::Custom {
+                    items.push(Item::Extension(generate_struct_codable_impl(
+                        swift,
+                        custom_name,
+                        plans,
+                        &codable,
+                    )?));
+                }
 
-            // Add custom Codable implementation if struct has optional fields
-            // Check if any field is wrapped in Option/Nullable
-            if let specta::datatype::Fields::Named(fields) = s.fields() {
-                let has_nullable_fields = fields.fields().iter().any(|(_, field)| {
-                    if let Some(ty) = field.ty() {
-                        matches!(ty, DataType::Nullable(_))
-                    } else {
-                        false
-                    }
-                });
+                if swift.copy_modifiers && !plans.is_empty() {
+                    items.push(Item::Extension(generate_copy_modifiers(custom_name, plans)));
+                }
 
-                if has_nullable_fields {
-                    let codable_impl = generate_struct_codable_impl(swift, types, s, &name)?;
-                    result.push_str(&codable_impl);
+                if swift.change_tracking
+                    && swift.emit_codable
+                    && ndt.generics().is_empty()
+                    && !plans.is_empty()
+                {
+                    items.push(Item::Extension(generate_change_tracking(
+                        custom_name,
+                        plans,
+                    )?));
+                }
+
+                // Hoisted types of inlined fields follow their parent, variant structs are
+                // named after the hoisted enum rather than the type it was inlined from
+                for hoisted in plans.iter().filter_map(|plan| plan.inline.as_ref()) {
+                    items.extend(
+                        build_type_definition(
+                            swift,
+                            types,
+                            hoisted,
+                            hoisted.name(),
+                            &variant_structs.for_hoisted(),
+                        )?
+                        .items,
+                    );
                 }
             }
         }
         DataType::Enum(e) => {
-            let name = swift.naming.convert(custom_name);
-            let generics = if ndt.generics().is_empty() {
-                String::new()
-            } else {
-                format!(
-                    "<{}>",
-                    ndt.generics()
-                        .iter()
-                        .map(|g| g.to_string())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                )
-            };
+            let mut decl = type_decl(swift, ndt, Decl::public_enum(custom_name));
 
             // Check if this is a string enum
             let is_string_enum = e.repr().map(|repr| repr.is_string()).unwrap_or(false);
 
-            // Check if this enum has struct-like or tuple variants (needs custom Codable)
-            let has_struct_variants = e.variants().iter().any(|(_, variant)| {
-                matches!(variant.fields(), specta::datatype::Fields::Named(fields) if !fields.fields().is_empty())
-                || matches!(variant.fields(), specta::datatype::Fields::Unnamed(fields) if !fields.fields().is_empty())
-            });
-
-            // Determine protocols based on whether we'll generate custom Codable
-            let protocols = if is_string_enum {
-                if has_struct_variants {
-                    "String" // Custom Codable will be generated
-                } else {
-                    "String, Codable"
-                }
-            } else {
-                if has_struct_variants {
-                    "" // Custom Codable will be generated
-                } else {
-                    "Codable"
+            if is_string_enum {
+                // String enum with raw values - always include String raw type
+                decl.conformances.push("String".into());
+                if codable.conforms_in_declaration() {
+                    decl.conformances.push("Codable".into());
                 }
-            };
 
-            let protocol_part = if protocols.is_empty() {
-                String::new()
-            } else {
-                format!(": {}", protocols)
-            };
-
-            // Check if this enum is recursive (has variants that reference the enum itself)
-            let _is_recursive = e
-                .variants()
-                .iter()
-                .any(|(_, variant)| match variant.fields() {
-                    specta::datatype::Fields::Named(fields) => {
-                        fields.fields().iter().any(|(_, field)| {
-                            if let Some(ty) = field.ty() {
-                                is_recursive_type_reference(ty, ndt.sid())
-                            } else {
-                                false
-                            }
-                        })
-                    }
-                    specta::datatype::Fields::Unnamed(fields) => {
-                        fields.fields().iter().any(|field| {
-                            if let Some(ty) = field.ty() {
-                                is_recursive_type_reference(ty, ndt.sid())
-                            } else {
-                                false
-                            }
-                        })
+                let mut raw_values = Vec::new();
+                for (variant_name, variant) in e.variants() {
+                    if variant.skip() {
+                        continue;
                     }
-                    specta::datatype::Fields::Unit => false,
-                });
-
-            if is_string_enum {
-                // String enum with raw values - always include String raw type
-                let string_protocols = if has_struct_variants {
-                    ": String" // Custom Codable will be generated
-                } else {
-                    ": String, Codable"
-                };
-                result.push_str(&format!(
-                    "public enum {}{}{} {{\n",
-                    name, generics, string_protocols
-                ));
-
-                for (variant_name, _variant) in e.variants() {
-                    let swift_variant_name = swift.naming.convert_enum_case(variant_name);
-                    let raw_value =
-                        generate_raw_value(variant_name, e.repr().and_then(|r| r.rename_all()));
-                    result.push_str(&format!(
-                        "    case {} = \"{}\"\n",
-                        swift_variant_name, raw_value
-                    ));
+                    // Specta stores variants under their serialized name, with `rename` or
+                    // `rename_all` already applied
+                    let raw_value = variant_name.to_string();
+                    decl.members.push(Member::Case(EnumCase::with_raw_value(
+                        swift.case_name(variant_name),
+                        raw_value.clone(),
+                    )));
+                    raw_values.push(raw_value);
                 }
 
-                result.push_str("}");
+                if swift.string_enum_helpers {
+                    decl.members
+                        .extend(generate_string_enum_helpers(&raw_values));
+                }
+                items.push(Item::Decl(decl));
 
                 // Add Codable extension if needed for string enums with struct variants
-                if has_struct_variants {
-                    result.push_str(&format!("\n\nextension {}: Codable {{\n", name));
-                    result.push_str(
-                        "    // TODO: Implement string enum with struct variants Codable\n",
-                    );
-                    result.push_str("}");
+                if codable.implementation == CodableImpl::Unsupported {
+                    let mut extension = Extension::new(custom_name).conforming_to("Codable");
+                    extension.members.push(Member::Raw(
+                        "// TODO: Implement string enum with struct variants Codable".into(),
+                    ));
+                    items.push(Item::Extension(extension));
                 }
             } else {
                 // Regular tagged union enum
-                result.push_str(&format!(
-                    "public enum {}{}{} {{\n",
-                    name, generics, protocol_part
-                ));
+                if codable.conforms_in_declaration() {
+                    decl.conformances.push("Codable".into());
+                }
 
                 for (variant_name, variant) in e.variants() {
                     // Skip variants marked with #[serde(skip)] or #[specta(skip)]
                     if variant.skip() {
                         continue;
                     }
-                    let swift_variant_name = swift.naming.convert_enum_case(variant_name);
+                    let swift_variant_name = swift.case_name(variant_name);
 
-                    match variant.fields() {
-                        specta::datatype::Fields::Unit => {
-                            result.push_str(&format!("    case {}\n", swift_variant_name));
-                        }
-                        specta::datatype::Fields::Unnamed(fields) => {
-                            if fields.fields().is_empty() {
-                                // Empty tuple variant - treat as unit variant
-                                result.push_str(&format!("    case {}\n", swift_variant_name));
-                            } else {
-                                let types_str = fields
-                                    .fields()
-                                    .iter()
-                                    .filter_map(|field| {
-                                        field.ty().map(|ty| {
-                                            datatype_to_swift(swift, types, ty, vec![], false, None)
-                                        })
-                                    })
-                                    .collect::<Result<Vec<_>>>()?
-                                    .join(", ");
-
-                                if types_str.is_empty() {
-                                    // Empty tuple variant - generate as unit variant
-                                    result.push_str(&format!("    case {}\n", swift_variant_name));
-                                } else {
-                                    result.push_str(&format!(
-                                        "    case {}({})\n",
-                                        swift_variant_name, types_str
-                                    ));
-                                }
-                            }
-                        }
+                    let associated = match variant.fields() {
+                        specta::datatype::Fields::Unit => vec![],
+                        specta::datatype::Fields::Unnamed(fields) => fields
+                            .fields()
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(i, field)| {
+                                field.ty().map(|ty| {
+                                    let mut location = location.clone();
+                                    location.push(variant_name.clone());
+                                    location.push(i.to_string().into());
+                                    datatype_to_swift(swift, types, ty, location, false, None)
+                                })
+                            })
+                            .collect::<Result<Vec<_>>>()?,
                         specta::datatype::Fields::Named(fields) => {
                             if fields.fields().is_empty() {
-                                result.push_str(&format!("    case {}\n", swift_variant_name));
+                                vec![]
                             } else {
-                                // Generate a struct for this variant
-                                let struct_name =
-                                    generate_variant_struct_name(swift, &name, variant_name);
-
-                                result.push_str(&format!(
-                                    "    case {}({})\n",
-                                    swift_variant_name, struct_name
-                                ));
+                                // The fields are generated as a struct for this variant
+                                vec![variant_struct_name(variant_name)]
                             }
                         }
-                    }
-                }
+                    };
 
-                result.push_str("}\n");
+                    let mut case = EnumCase::with_associated(swift_variant_name, associated);
+                    // Large payloads are boxed so they don't grow every value of the enum
+                    case.indirect =
+                        !case.associated.is_empty() && swift.boxes_variant(types, ndt, variant);
+                    decl.members.push(Member::Case(case));
+                }
+                items.push(Item::Decl(decl));
 
                 // Generate struct definitions for variants with named fields
-                if has_struct_variants {
-                    result.push_str(&generate_enum_variant_structs(swift, types, e, &name)?);
+                if codable.variant_structs {
+                    items.extend(
+                        generate_enum_variant_structs(
+                            swift,
+                            types,
+                            e,
+                            custom_name,
+                            &variant_struct_name,
+                            &location,
+                        )?
+                        .into_iter()
+                        .map(Item::Decl),
+                    );
                 }
 
-                // Check if this is an adjacently tagged enum
-                let is_adjacently_tagged = if let Some(repr) = e.repr() {
-                    matches!(repr, specta::datatype::EnumRepr::Adjacent { .. })
-                } else {
-                    false
-                };
-
-                // Add Codable extension if needed (struct variants OR adjacently tagged)
-                if has_struct_variants || is_adjacently_tagged {
-                    // Note: generate_enum_codable_impl will handle adjacently tagged detection internally
-                    result.push_str(&generate_enum_codable_impl(swift, types, e, &name)?);
+                // Add the Codable extension for data-carrying or adjacently tagged enums
+                if let Some(extension) = generate_enum_codable_impl(
+                    swift,
+                    types,
+                    e,
+                    &codable,
+                    custom_name,
+                    &variant_struct_name,
+                    &location,
+                )? {
+                    items.push(Item::Extension(extension));
                 }
             }
         }
+        // Other types, e.g. transparent types or fields overridden with `#[specta(type = ...)]`,
+        // are aliases of the type they are coded as so references to them resolve
         _ => {
-            // For other types, just use the generated type definition
-            result.push_str(&type_def);
+            let decl = type_decl(swift, ndt, Decl::public_struct(custom_name));
+            let mut code = String::new();
+            for line in decl.docs.iter().chain(&decl.attributes) {
+                writeln!(code, "{}", line)?;
+            }
+            let generics = if decl.generics.is_empty() {
+                String::new()
+            } else {
+                format!("<{}>", decl.generics.join(", "))
+            };
+            write!(
+                code,
+                "public typealias {}{} = {}",
+                custom_name,
+                generics,
+                datatype_to_swift(swift, types, ndt.ty(), location, false, Some(ndt.sid()))?
+            )?;
+            items.push(Item::Raw(code));
         }
     }
 
-    Ok(result)
+    Ok(TypeDefinition { items })
+}
+
+/// Add the doc comment and deprecation attribute of a type to its declaration.
+fn type_decl(swift: &Swift, ndt: &specta::datatype::NamedDataType, mut decl: Decl) -> Decl {
+    // Add JSDoc-style comments if present
+    if !ndt.docs().is_empty() {
+        decl.docs = format_doc_comment(ndt.docs())
+            .lines()
+            .map(str::to_string)
+            .collect();
+    }
+
+    // Add deprecated annotation if present
+    if let Some(deprecated) = ndt.deprecated() {
+        decl.attributes = format_deprecated_type(deprecated, "This type is deprecated", |name| {
+            swift.type_name(name)
+        })
+        .lines()
+        .map(str::to_string)
+        .collect();
+    }
+
+    decl.generics = ndt.generics().iter().map(|g| g.to_string()).collect();
+    decl
 }
 
 /// Convert a DataType to Swift syntax.
+///
+/// The location and sid are internal details, [`Swift::datatype`] is the stable entry point.
 pub fn datatype_to_swift(
     swift: &Swift,
     types: &TypeCollection,
@@ -307,23 +359,25 @@ pub fn datatype_to_swift(
     }
 
     match dt {
+        DataType::Primitive(Primitive::char) if swift.char_type == CharType::String => {
+            Ok("String".to_string())
+        }
         DataType::Primitive(p) => primitive_to_swift(p),
-        DataType::Literal(l) => literal_to_swift(l),
-        DataType::List(l) => list_to_swift(swift, types, l),
-        DataType::Map(m) => map_to_swift(swift, types, m),
+        DataType::Literal(l) => literal_type_to_swift(l),
+        DataType::List(l) => list_to_swift(swift, types, l, &location),
+        DataType::Map(m) => map_to_swift(swift, types, m, &location),
+        // `sid` identifies the type being converted, so it isn't passed to nested types, which
+        // would otherwise resolve as that type
         DataType::Nullable(def) => {
-            let inner = datatype_to_swift(swift, types, def, location, is_export, sid)?;
-            Ok(match swift.optionals {
-                crate::swift::OptionalStyle::QuestionMark => format!("{}?", inner),
-                crate::swift::OptionalStyle::Optional => format!("Optional<{}>", inner),
-            })
+            let inner = datatype_to_swift(swift, types, def, location.clone(), is_export, None)?;
+            Ok(optional_type(swift, inner))
         }
         DataType::Struct(s) => {
             // Check if this is a Duration struct by looking at its fields
             if is_duration_struct(s) {
                 return Ok("RustDuration".to_string());
             }
-            struct_to_swift(swift, types, s, location, is_export, sid)
+            struct_to_swift(swift, types, s, location.clone(), is_export)
         }
         DataType::Enum(e) => {
             // If this is a recursive call (no enum_name), we need to generate a reference
@@ -334,17 +388,19 @@ pub fn datatype_to_swift(
                     if ndt.name() == "Number" && ndt.module_path().contains("serde_json") {
                         return Ok("Double".to_string());
                     }
-                    let name = swift.naming.convert(ndt.name());
+                }
+                if let Some(name) = reference_name(swift, types, sid) {
                     return Ok(name);
                 }
             }
             // Fallback: try to generate inline (this shouldn't happen in normal cases)
-            enum_to_swift(swift, types, e, location, is_export, sid, None)
+            enum_to_swift(swift, types, e, location.clone(), is_export, sid, None)
         }
-        DataType::Tuple(t) => tuple_to_swift(swift, types, t),
-        DataType::Reference(r) => reference_to_swift(swift, types, r),
+        DataType::Tuple(t) => tuple_to_swift(swift, types, t, &location),
+        DataType::Reference(r) => reference_to_swift(swift, types, r, &location),
         DataType::Generic(g) => generic_to_swift(swift, g),
     }
+    .map_err(|err| err.with_location(&location))
 }
 
 // Special type functions now imported from special_types module
@@ -354,9 +410,10 @@ fn list_to_swift(
     swift: &Swift,
     types: &TypeCollection,
     list: &specta::datatype::List,
+    location: &[Cow<'static, str>],
 ) -> Result<String> {
     crate::datatype::collections::list_to_swift(list, |ty| {
-        datatype_to_swift(swift, types, ty, vec![], false, None)
+        datatype_to_swift(swift, types, ty, location.to_vec(), false, None)
     })
 }
 
@@ -365,9 +422,10 @@ fn map_to_swift(
     swift: &Swift,
     types: &TypeCollection,
     map: &specta::datatype::Map,
+    location: &[Cow<'static, str>],
 ) -> Result<String> {
     crate::datatype::collections::map_to_swift(map, |ty| {
-        datatype_to_swift(swift, types, ty, vec![], false, None)
+        datatype_to_swift(swift, types, ty, location.to_vec(), false, None)
     })
 }
 
@@ -378,135 +436,339 @@ fn struct_to_swift(
     s: &specta::datatype::Struct,
     location: Vec<Cow<'static, str>>,
     is_export: bool,
-    sid: Option<SpectaID>,
 ) -> Result<String> {
-    match s.fields() {
-        specta::datatype::Fields::Unit => Ok("Void".to_string()),
+    let members = match s.fields() {
+        specta::datatype::Fields::Unit => return Ok("Void".to_string()),
+        specta::datatype::Fields::Unnamed(fields) if fields.fields().is_empty() => {
+            return Ok("Void".to_string())
+        }
+        // Tuple structs are converted to a proper struct with a `value` or numbered fields
         specta::datatype::Fields::Unnamed(fields) => {
-            if fields.fields().is_empty() {
-                Ok("Void".to_string())
-            } else if fields.fields().len() == 1 {
-                // Single field tuple struct - convert to a proper struct with a 'value' field
-                let field_type = datatype_to_swift(
-                    swift,
-                    types,
-                    &fields.fields()[0].ty().unwrap(),
-                    location,
-                    is_export,
-                    sid,
-                )?;
-                Ok(format!("    let value: {}\n", field_type))
-            } else {
-                // Multiple field tuple struct - convert to a proper struct with numbered fields
-                let mut result = String::new();
-                for (i, field) in fields.fields().iter().enumerate() {
-                    let field_type = datatype_to_swift(
-                        swift,
-                        types,
-                        field.ty().unwrap(),
-                        location.clone(),
-                        is_export,
-                        sid,
-                    )?;
-                    result.push_str(&format!("    public let field{}: {}\n", i, field_type));
-                }
-                Ok(result)
-            }
+            tuple_struct_members(swift, types, fields, &location, is_export)?
         }
-        specta::datatype::Fields::Named(fields) => {
-            let mut result = String::new();
-            let mut field_mappings = Vec::new();
-
-            // Check if struct will need custom Codable implementation (has nullable fields)
-            let has_nullable_fields = fields.fields().iter().any(|(_, field)| {
-                if let Some(ty) = field.ty() {
-                    matches!(ty, DataType::Nullable(_))
-                } else {
-                    false
-                }
-            });
+        specta::datatype::Fields::Named(fields) => named_struct_members(
+            swift,
+            &plan_fields(swift, types, fields, &location, is_export, None)?,
+            &CodablePlan::for_fields(swift, fields),
+            swift.generate_initializers,
+        ),
+    };
 
-            for (original_field_name, field) in fields.fields() {
-                let field_type = if let Some(ty) = field.ty() {
-                    datatype_to_swift(swift, types, ty, location.clone(), is_export, sid)?
-                } else {
-                    continue;
-                };
+    Ok(render_members(&members))
+}
 
-                let optional_marker = if field.optional() { "?" } else { "" };
-                let swift_field_name = swift.naming.convert_field(original_field_name);
+/// Everything generated for a named struct field, computed once per field.
+struct FieldPlan {
+    /// The serialized (Rust) name, used as the JSON key.
+    rust_name: String,
+    /// The Swift property name.
+    swift_name: String,
+    /// The type of the property and initializer parameter, optional if the field is.
+    swift_type: String,
+    /// The Swift type without the optional.
+    base_type: String,
+    /// Whether the field is nullable or may be missing.
+    optionality: Optionality,
+    /// The empty value decoded when a collection field is missing, if configured.
+    empty_default: Option<&'static str>,
+    /// The Swift literal of fields with a literal type, which decoding validates.
+    literal: Option<String>,
+    /// Whether the field is a `Character`, which is decoded from a single character string.
+    character: bool,
+    /// The property wrapper decoding the field with resilient decoding, e.g. `LossyArray<Item>`.
+    wrapper: Option<String>,
+    /// The length decoding checks for fixed-size arrays, if configured.
+    length: Option<usize>,
+    /// The type hoisted for an inlined field.
+    inline: Option<specta::datatype::NamedDataType>,
+}
+
+impl FieldPlan {
+    /// The name of the static constant holding the field's literal value.
+    fn literal_constant(&self) -> String {
+        format!("{}Literal", self.swift_name.trim_matches('`'))
+    }
 
-                result.push_str(&format!(
-                    "    public let {}: {}{}\n",
-                    swift_field_name, field_type, optional_marker
-                ));
+    /// The name of the local holding the string a `Character` field is decoded from.
+    fn character_string(&self) -> String {
+        format!("{}String", self.swift_name.trim_matches('`'))
+    }
+}
 
-                field_mappings.push((swift_field_name, original_field_name.to_string()));
+/// Convert the fields of a struct, skipping fields without a type.
+fn plan_fields(
+    swift: &Swift,
+    types: &TypeCollection,
+    fields: &specta::datatype::NamedFields,
+    location: &[Cow<'static, str>],
+    is_export: bool,
+    parent: Option<(&specta::datatype::NamedDataType, &str, &VariantStructNames)>,
+) -> Result<Vec<FieldPlan>> {
+    let mut plans = Vec::new();
+    let names = field_names(swift, fields);
+    for ((original_field_name, field), swift_name) in fields.fields().iter().zip(names) {
+        let Some(ty) = field.ty() else {
+            continue;
+        };
+        let Some((inner, mut optionality)) = field_optionality(field) else {
+            continue;
+        };
+        // Optional collections may be declared as collections which default to empty, unless
+        // their length is checked
+        let length = validated_length(swift, inner);
+        let empty_optional =
+            empty_collection(swift, inner, optionality).filter(|_| length.is_none());
+        if empty_optional.is_some() {
+            optionality = Optionality::default();
+        }
+
+        // Inlined fields refer to a type hoisted next to the parent, under the name chosen by
+        // the name resolver
+        let inline = parent.and_then(|(ndt, name, generated)| {
+            let mut hoisted = inline_type(swift, types, ndt, name, original_field_name, field)?;
+            if let Some(renamed) = generated.hoisted(name, original_field_name) {
+                hoisted.set_name(renamed.to_string().into());
             }
+            Some(hoisted)
+        });
+        let convert = |ty: &DataType, location: Vec<Cow<'static, str>>| match &inline {
+            Some(hoisted) => inline_field_type(swift, ty, hoisted.name(), &|key: &DataType| {
+                datatype_to_swift(swift, types, key, location.clone(), is_export, None)
+            }),
+            None => datatype_to_swift(swift, types, ty, location, is_export, None),
+        };
 
-            // Only generate CodingKeys inside the struct if we're NOT generating custom Codable extension
-            // (If we are generating extension, CodingKeys will be in the extension to avoid duplication)
-            let needs_custom_coding_keys = field_mappings
+        let mut location = location.to_vec();
+        location.push(original_field_name.clone());
+        // The base type is converted from the inner type rather than by stripping the `?`, so
+        // it is also correct for `Optional<T>` and keeps optional elements like `[String?]`
+        let base_type = convert(inner, location)?;
+        let swift_type = if optionality.is_optional() {
+            optional_type(swift, &base_type)
+        } else {
+            base_type.clone()
+        };
+
+        let wrapper = if swift.resilient_decoding
+            && swift.emit_codable
+            && !optionality.omittable
+            && length.is_none()
+        {
+            property_wrapper(ty, &swift_type)
+        } else {
+            None
+        };
+
+        let empty_default = match ty {
+            _ if empty_optional.is_some() => empty_optional,
+            _ if wrapper.is_some() || length.is_some() => None,
+            _ if swift.missing_collections != MissingCollectionStrategy::Empty => None,
+            _ if optionality.omittable => None,
+            DataType::List(_) => Some("[]"),
+            DataType::Map(_) => Some("[:]"),
+            _ => None,
+        };
+
+        plans.push(FieldPlan {
+            rust_name: original_field_name.to_string(),
+            swift_name,
+            swift_type,
+            base_type,
+            optionality,
+            empty_default,
+            literal: match ty {
+                DataType::Literal(l) => Some(literal_to_swift(l)?),
+                _ => None,
+            },
+            character: is_character_field(swift, ty),
+            wrapper,
+            length,
+            inline,
+        });
+    }
+    Ok(plans)
+}
+
+/// Whether a field is a `char` or `Option<char>` represented by `Character`.
+pub(crate) fn is_character_field(swift: &Swift, ty: &DataType) -> bool {
+    let ty = match ty {
+        DataType::Nullable(inner) => inner,
+        ty => ty,
+    };
+    swift.char_type == CharType::Character && matches!(ty, DataType::Primitive(Primitive::char))
+}
+
+/// Generate the properties, CodingKeys and initializer of a struct with named fields.
+///
+/// A public memberwise initializer is added when `initializer` is set.
+fn named_struct_members(
+    swift: &Swift,
+    plans: &[FieldPlan],
+    codable: &CodablePlan,
+    initializer: bool,
+) -> Vec<Member> {
+    let mut members = Vec::new();
+
+    for plan in plans {
+        // Wrapped properties can't be constants
+        members.push(Member::Property(match &plan.wrapper {
+            Some(wrapper) => Property::variable(&plan.swift_name, &plan.swift_type)
+                .attribute(format!("@{}", wrapper.split('<').next().unwrap_or(wrapper))),
+            None => Property::constant(&plan.swift_name, &plan.swift_type),
+        }));
+    }
+
+    // Swift has no literal types, so their values are exposed as constants
+    for plan in plans {
+        if let Some(literal) = &plan.literal {
+            members.push(Member::Property(
+                Property::constant(plan.literal_constant(), &plan.swift_type).static_value(literal),
+            ));
+        }
+    }
+
+    // CodingKeys of structs with a custom Codable extension are generated in the extension
+    if codable.coding_keys == CodingKeysPlacement::Declaration {
+        members.push(Member::Decl(coding_keys(
+            swift,
+            plans
                 .iter()
-                .any(|(swift_name, rust_name)| swift_name != rust_name);
-            if needs_custom_coding_keys && !has_nullable_fields {
-                result.push_str("\n    private enum CodingKeys: String, CodingKey {\n");
-                for (swift_name, rust_name) in &field_mappings {
-                    result.push_str(&format!(
-                        "        case {} = \"{}\"\n",
-                        swift_name, rust_name
-                    ));
-                }
-                result.push_str("    }\n");
-            }
+                .map(|plan| coding_key_case(swift, &plan.swift_name, &plan.rust_name))
+                .collect(),
+        )));
+    }
 
-            // Generate public initializer if enabled
-            if swift.generate_initializers && !field_mappings.is_empty() {
-                result.push_str("\n    public init(");
-                let init_params: Vec<String> = field_mappings
-                    .iter()
-                    .map(|(swift_name, _)| {
-                        // Get the field type from the original field
-                        let field_type = fields
-                            .fields()
-                            .iter()
-                            .find(|(name, _)| swift.naming.convert_field(name) == *swift_name)
-                            .and_then(|(_, field)| field.ty())
-                            .and_then(|ty| {
-                                datatype_to_swift(
-                                    swift,
-                                    types,
-                                    ty,
-                                    location.clone(),
-                                    is_export,
-                                    sid,
-                                )
-                                .ok()
-                            })
-                            .unwrap_or_else(|| "Any".to_string());
+    // Generate public initializer if enabled
+    if initializer && !plans.is_empty() {
+        let init_params = plans
+            .iter()
+            .map(|plan| format!("{}: {}", plan.swift_name, plan.swift_type))
+            .collect::<Vec<_>>();
+
+        // Assign parameters to properties
+        let assignments = plans
+            .iter()
+            .map(|plan| format!("self.{} = {}\n", plan.swift_name, plan.swift_name))
+            .collect::<String>();
+        members.push(Member::Function(Function::new(
+            format!("public init({})", init_params.join(", ")),
+            &assignments,
+        )));
+    }
 
-                        let optional_marker = fields
-                            .fields()
-                            .iter()
-                            .find(|(name, _)| swift.naming.convert_field(name) == *swift_name)
-                            .map(|(_, field)| if field.optional() { "?" } else { "" })
-                            .unwrap_or("");
-
-                        format!("{}: {}{}", swift_name, field_type, optional_marker)
-                    })
-                    .collect();
-                result.push_str(&init_params.join(", "));
-                result.push_str(") {\n");
-
-                // Assign parameters to properties
-                for (swift_name, _) in &field_mappings {
-                    result.push_str(&format!("        self.{} = {}\n", swift_name, swift_name));
-                }
-                result.push_str("    }\n");
-            }
+    members
+}
+
+/// Generate a `with(field:)` copy-modifier for each field of a struct.
+///
+/// The copy is built with the memberwise initializer, as the properties are constants.
+fn generate_copy_modifiers(struct_name: &str, plans: &[FieldPlan]) -> Extension {
+    let mut extension = Extension::new(struct_name).mark(format!("{} Copy Modifiers", struct_name));
+    for plan in plans {
+        let arguments = plans
+            .iter()
+            .map(|other| format!("{}: {}", other.swift_name, other.swift_name))
+            .collect::<Vec<_>>();
+        extension.members.push(Member::Function(
+            Function::new(
+                format!(
+                    "public func with({}: {}) -> Self",
+                    plan.swift_name, plan.swift_type
+                ),
+                &format!("Self({})", arguments.join(", ")),
+            )
+            .doc(&format!(
+                "A copy with `{}` replaced.",
+                plan.swift_name.trim_matches('`')
+            )),
+        ));
+    }
+    extension
+}
+
+/// Generate `allKeyPaths` and `changedFields(from:)` for a struct.
+fn generate_change_tracking(struct_name: &str, plans: &[FieldPlan]) -> Result<Extension> {
+    let mut extension =
+        Extension::new(struct_name).mark(format!("{} Change Tracking", struct_name));
+
+    let key_paths = plans
+        .iter()
+        .map(|plan| format!("\\.{}", plan.swift_name))
+        .collect::<Vec<_>>();
+    // Computed, as key paths aren't `Sendable` and can't be stored in a static constant
+    extension.members.push(Member::Function(
+        Function::new(
+            "public static var allKeyPaths: [PartialKeyPath<Self>]",
+            &format!("[{}]", key_paths.join(", ")),
+        )
+        .doc("The key paths of the stored properties, in declaration order."),
+    ));
+
+    let mut body = String::from("var changed: [String] = []\n");
+    for plan in plans {
+        writeln!(
+            body,
+            "if !Self.isSame({}, other.{}) {{ changed.append(\"{}\") }}",
+            plan.swift_name,
+            plan.swift_name,
+            plan.swift_name.trim_matches('`')
+        )?;
+    }
+    body.push_str("return changed\n");
+    extension.members.push(Member::Function(
+        Function::new(
+            "public func changedFields(from other: Self) -> [String]",
+            &body,
+        )
+        .doc("The names of the properties which differ from `other`, in declaration order."),
+    ));
+
+    extension.members.push(Member::Function(Function::new(
+        "private static func isSame<T: Encodable>(_ a: T, _ b: T) -> Bool",
+        "if let a = a as? AnyHashable, let b = b as? AnyHashable {\n    return a == b\n}\nlet encoder = JSONEncoder()\nencoder.outputFormatting = .sortedKeys\nreturn (try? encoder.encode(a)) == (try? encoder.encode(b))\n",
+    )));
+    Ok(extension)
+}
+
+/// Generate the properties of a tuple struct: `value` for a single field and `field0`,
+/// `field1`, ... otherwise.
+fn tuple_struct_members(
+    swift: &Swift,
+    types: &TypeCollection,
+    fields: &specta::datatype::UnnamedFields,
+    location: &[Cow<'static, str>],
+    is_export: bool,
+) -> Result<Vec<Member>> {
+    let field_type = |i: usize, ty: &DataType| {
+        let mut location = location.to_vec();
+        location.push(i.to_string().into());
+        datatype_to_swift(swift, types, ty, location, is_export, None)
+    };
 
-            Ok(result)
+    // Skipped fields have no type and aren't serialized
+    let tys = fields
+        .fields()
+        .iter()
+        .filter_map(|field| field.ty())
+        .collect::<Vec<_>>();
+
+    match tys.as_slice() {
+        [] => Ok(vec![]),
+        [ty] => {
+            let mut property = Property::constant("value", field_type(0, ty)?);
+            property.modifiers.clear();
+            Ok(vec![Member::Property(property)])
         }
+        tys => tys
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                Ok(Member::Property(Property::constant(
+                    format!("field{}", i),
+                    field_type(i, ty)?,
+                )))
+            })
+            .collect(),
     }
 }
 
@@ -524,11 +786,8 @@ fn enum_to_swift(
 ) -> Result<String> {
     // If we have a sid and this is being used as a type reference (not a full definition),
     // just return the enum name
-    if let Some(sid) = sid {
-        if let Some(ndt) = types.get(sid) {
-            let name = swift.naming.convert(ndt.name());
-            return Ok(name);
-        }
+    if let Some(name) = sid.and_then(|sid| reference_name(swift, types, sid)) {
+        return Ok(name);
     }
 
     // Special handling for serde_json::Number enum which should map to Double
@@ -547,30 +806,35 @@ fn enum_to_swift(
             continue;
         }
 
-        let variant_name = swift.naming.convert_enum_case(original_variant_name);
+        let variant_name = swift.case_name(original_variant_name);
 
         match variant.fields() {
             specta::datatype::Fields::Unit => {
                 if is_string_enum {
-                    // For string enums, generate raw value assignments
-                    let raw_value = generate_raw_value(
-                        original_variant_name,
-                        e.repr().and_then(|r| r.rename_all()),
-                    );
-                    result.push_str(&format!("    case {} = \"{}\"\n", variant_name, raw_value));
+                    // String enums use the serialized name as the raw value
+                    writeln!(
+                        result,
+                        "    case {} = \"{}\"",
+                        variant_name,
+                        escape_string(original_variant_name)
+                    )?;
                 } else {
-                    result.push_str(&format!("    case {}\n", variant_name));
+                    writeln!(result, "    case {}", variant_name)?;
                 }
             }
             specta::datatype::Fields::Unnamed(fields) => {
                 if fields.fields().is_empty() {
-                    result.push_str(&format!("    case {}\n", variant_name));
+                    writeln!(result, "    case {}", variant_name)?;
                 } else {
                     let types_str = fields
                         .fields()
                         .iter()
-                        .map(|f| {
+                        .enumerate()
+                        .map(|(i, f)| {
                             let field_ty = f.ty().unwrap();
+                            let mut location = location.clone();
+                            location.push(original_variant_name.clone());
+                            location.push(i.to_string().into());
                             // For references, use the referenced type's sid, not the parent enum's sid
                             let field_sid = if let DataType::Reference(r) = field_ty {
                                 Some(r.sid())
@@ -578,22 +842,17 @@ fn enum_to_swift(
                                 None
                             };
                             datatype_to_swift(
-                                swift,
-                                types,
-                                field_ty,
-                                location.clone(),
-                                is_export,
-                                field_sid,
+                                swift, types, field_ty, location, is_export, field_sid,
                             )
                         })
                         .collect::<std::result::Result<Vec<_>, _>>()?
                         .join(", ");
-                    result.push_str(&format!("    case {}({})\n", variant_name, types_str));
+                    writeln!(result, "    case {}({})", variant_name, types_str)?;
                 }
             }
             specta::datatype::Fields::Named(fields) => {
                 if fields.fields().is_empty() {
-                    result.push_str(&format!("    case {}\n", variant_name));
+                    writeln!(result, "    case {}", variant_name)?;
                 } else {
                     // Generate struct for named fields
                     // This is the old enum_to_swift function that's called from datatype_to_swift
@@ -606,7 +865,7 @@ fn enum_to_swift(
                     };
 
                     // Generate enum case that references the struct
-                    result.push_str(&format!("    case {}({})\n", variant_name, struct_name));
+                    writeln!(result, "    case {}({})", variant_name, struct_name)?;
                 }
             }
         }
@@ -624,9 +883,10 @@ fn tuple_to_swift(
     swift: &Swift,
     types: &TypeCollection,
     t: &specta::datatype::Tuple,
+    location: &[Cow<'static, str>],
 ) -> Result<String> {
     crate::datatype::collections::tuple_to_swift(t, |ty| {
-        datatype_to_swift(swift, types, ty, vec![], false, None)
+        datatype_to_swift(swift, types, ty, location.to_vec(), false, None)
     })
 }
 
@@ -635,9 +895,10 @@ fn reference_to_swift(
     swift: &Swift,
     types: &TypeCollection,
     r: &specta::datatype::Reference,
+    location: &[Cow<'static, str>],
 ) -> Result<String> {
     crate::datatype::reference::reference_to_swift(swift, types, r, |ty| {
-        datatype_to_swift(swift, types, ty, vec![], false, None)
+        datatype_to_swift(swift, types, ty, location.to_vec(), false, None)
     })
 }
 
@@ -646,132 +907,215 @@ fn generic_to_swift(_swift: &Swift, g: &specta::datatype::Generic) -> Result<Str
     crate::datatype::generic::generic_to_swift(g)
 }
 
+/// Generate the raw value helpers of a string enum, for parsing user input.
+fn generate_string_enum_helpers(raw_values: &[String]) -> Vec<Member> {
+    let raw_values = raw_values
+        .iter()
+        .map(|raw_value| format!("\"{}\"", raw_value))
+        .collect();
+
+    vec![
+        Member::Property(
+            Property::constant("allRawValues", "[String]")
+                .static_array(raw_values)
+                .doc("The raw values of all cases."),
+        ),
+        Member::Function(
+            Function::new(
+                "public init?(caseInsensitive rawValue: String)",
+                "guard let match = Self.allRawValues.first(where: { $0.caseInsensitiveCompare(rawValue) == .orderedSame }) else {\n    return nil\n}\nself.init(rawValue: match)",
+            )
+            .doc("Create a case from its raw value, ignoring case."),
+        ),
+    ]
+}
+
 /// Generate custom Codable implementation for structs with optional fields.
 ///
-/// This generates `init(from:)` and `encode(to:)` methods that preserve `nil` values
+/// Also used for every struct with [`Swift::always_emit_codable`]. This generates `init(from:)` and `encode(to:)` methods that preserve `nil` values
 /// as `null` in JSON, matching Rust's serde behavior.
 fn generate_struct_codable_impl(
     swift: &Swift,
-    types: &TypeCollection,
-    s: &specta::datatype::Struct,
     struct_name: &str,
-) -> Result<String> {
-    let mut result = String::new();
+    plans: &[FieldPlan],
+    codable: &CodablePlan,
+) -> Result<Extension> {
+    let mut extension =
+        Extension::new(struct_name).mark(format!("{} Custom Codable Implementation", struct_name));
+
+    // Generate CodingKeys enum, otherwise the implementation uses the synthesized ones
+    if codable.coding_keys == CodingKeysPlacement::Extension {
+        extension.members.push(Member::Decl(coding_keys(
+            swift,
+            plans
+                .iter()
+                .map(|plan| coding_key_case(swift, &plan.swift_name, &plan.rust_name))
+                .collect(),
+        )));
+    }
 
-    if let specta::datatype::Fields::Named(fields) = s.fields() {
-        let mut field_info = Vec::new();
-
-        // Collect field information
-        for (original_field_name, field) in fields.fields() {
-            let (field_type, base_type, is_optional) = if let Some(ty) = field.ty() {
-                let is_nullable = matches!(ty, DataType::Nullable(_));
-                let swift_type = datatype_to_swift(swift, types, ty, vec![], false, None)?;
-                // For nullable types, extract the base type (without ?)
-                let base_type = if is_nullable && swift_type.ends_with('?') {
-                    swift_type[..swift_type.len() - 1].to_string()
-                } else {
-                    swift_type.clone()
-                };
-                (swift_type, base_type, is_nullable)
+    // Generate init(from decoder:)
+    let mut result = String::new();
+    result.push_str("        let container = try decoder.container(keyedBy: CodingKeys.self)\n");
+
+    for plan in plans {
+        if plan.character {
+            let string = plan.character_string();
+            let validate = format!(
+                "guard {string}.count == 1 else {{\n{indent}    throw DecodingError.dataCorruptedError(forKey: .{name}, in: container, debugDescription: \"Expected a single character\")\n{indent}}}\n{indent}{name} = Character({string})",
+                string = string,
+                name = plan.swift_name,
+                indent = if plan.optionality.is_optional() { "            " } else { "        " },
+            );
+            if plan.optionality.is_optional() {
+                writeln!(
+                    result,
+                    "        if let {} = try container.decodeIfPresent(String.self, forKey: .{}) {{\n            {}\n        }} else {{\n            {} = nil\n        }}",
+                    string, plan.swift_name, validate, plan.swift_name
+                )?;
             } else {
-                continue;
-            };
+                writeln!(
+                    result,
+                    "        let {} = try container.decode(String.self, forKey: .{})\n        {}",
+                    string, plan.swift_name, validate
+                )?;
+            }
+            continue;
+        }
 
-            let swift_field_name = swift.naming.convert_field(original_field_name);
-            let rust_field_name = original_field_name.to_string();
+        if let Some(wrapper) = &plan.wrapper {
+            writeln!(
+                result,
+                "        _{} = try container.decode({}.self, forKey: .{})",
+                plan.swift_name.trim_matches('`'),
+                wrapper,
+                plan.swift_name
+            )?;
+            continue;
+        }
 
-            field_info.push((
-                swift_field_name,
-                rust_field_name,
-                field_type,
-                base_type,
-                is_optional,
-            ));
+        if let Some(empty) = plan.empty_default {
+            writeln!(
+                result,
+                "        {} = try container.decodeIfPresent({}.self, forKey: .{}) ?? {}",
+                plan.swift_name, plan.base_type, plan.swift_name, empty
+            )?;
+            continue;
         }
 
-        // Generate extension
-        result.push_str(&format!(
-            "\n// MARK: - {} Custom Codable Implementation\n",
-            struct_name
-        ));
-        result.push_str(&format!("extension {} {{\n", struct_name));
-
-        // Generate CodingKeys enum
-        result.push_str("    private enum CodingKeys: String, CodingKey {\n");
-        for (swift_name, rust_name, _, _, _) in &field_info {
-            result.push_str(&format!(
-                "        case {} = \"{}\"\n",
-                swift_name, rust_name
-            ));
+        let decode = if plan.optionality.is_optional() {
+            "decodeIfPresent"
+        } else {
+            "decode"
+        };
+        writeln!(
+            result,
+            "        {} = try container.{}({}.self, forKey: .{})",
+            plan.swift_name, decode, plan.base_type, plan.swift_name
+        )?;
+
+        if let Some(literal) = &plan.literal {
+            writeln!(
+                result,
+                "        guard {} == Self.{} else {{\n            throw DecodingError.dataCorruptedError(forKey: .{}, in: container, debugDescription: \"Expected {}\")\n        }}",
+                plan.swift_name,
+                plan.literal_constant(),
+                plan.swift_name,
+                escape_string(literal)
+            )?;
         }
-        result.push_str("    }\n\n");
-
-        // Generate init(from decoder:)
-        result.push_str("    public init(from decoder: Decoder) throws {\n");
-        result
-            .push_str("        let container = try decoder.container(keyedBy: CodingKeys.self)\n");
-
-        for (swift_name, _, _field_type, base_type, is_optional) in &field_info {
-            if *is_optional {
-                result.push_str(&format!(
-                    "        {} = try container.decodeIfPresent({}.self, forKey: .{})\n",
-                    swift_name, base_type, swift_name
-                ));
+
+        if let Some(length) = plan.length {
+            let throw = format!(
+                "throw DecodingError.dataCorruptedError(forKey: .{}, in: container, debugDescription: \"Expected {} elements\")",
+                plan.swift_name, length
+            );
+            if plan.optionality.is_optional() {
+                writeln!(
+                    result,
+                    "        if let {name} = {name}, {name}.count != {length} {{\n            {throw}\n        }}",
+                    name = plan.swift_name,
+                    length = length,
+                    throw = throw
+                )?;
             } else {
-                result.push_str(&format!(
-                    "        {} = try container.decode({}.self, forKey: .{})\n",
-                    swift_name, base_type, swift_name
-                ));
+                writeln!(
+                    result,
+                    "        guard {}.count == {} else {{\n            {}\n        }}",
+                    plan.swift_name, length, throw
+                )?;
             }
         }
+    }
 
-        result.push_str("    }\n\n");
-
-        // Generate encode(to encoder:)
-        result.push_str("    public func encode(to encoder: Encoder) throws {\n");
-        result.push_str("        var container = encoder.container(keyedBy: CodingKeys.self)\n");
+    extension.members.push(Member::Function(Function::new(
+        "public init(from decoder: Decoder) throws",
+        &result,
+    )));
 
-        for (swift_name, _, _, _, _) in &field_info {
-            // Use encode() for all fields - this preserves nil as null in JSON
-            result.push_str(&format!(
-                "        try container.encode({}, forKey: .{})\n",
-                swift_name, swift_name
-            ));
-        }
-
-        result.push_str("    }\n");
-        result.push_str("}\n");
+    // Generate encode(to encoder:)
+    let mut result = String::new();
+    result.push_str("        var container = encoder.container(keyedBy: CodingKeys.self)\n");
+
+    // Use encode() for all fields - this preserves nil as null in JSON. Property lists can't
+    // store nil, so optional fields are omitted instead. Fields which may be missing but aren't
+    // nullable are always omitted, since Rust can't decode `null` into them.
+    let omit_nil = swift.codable_format == CodableFormat::PropertyList;
+    for plan in plans {
+        let value = match (plan.character, plan.optionality.is_optional()) {
+            (true, true) => format!("{}.map(String.init)", plan.swift_name),
+            (true, false) => format!("String({})", plan.swift_name),
+            _ => plan.swift_name.clone(),
+        };
+        let encode = if plan.optionality.omits_nil() || (omit_nil && plan.optionality.is_optional())
+        {
+            "encodeIfPresent"
+        } else {
+            "encode"
+        };
+        writeln!(
+            result,
+            "        try container.{}({}, forKey: .{})",
+            encode, value, plan.swift_name
+        )?;
     }
 
-    Ok(result)
+    extension.members.push(Member::Function(Function::new(
+        "public func encode(to encoder: Encoder) throws",
+        &result,
+    )));
+
+    Ok(extension)
 }
 
-/// Generate custom Codable implementation for enums with struct-like variants
+/// Generate the planned custom Codable implementation of an enum, if any.
 fn generate_enum_codable_impl(
     swift: &Swift,
     types: &TypeCollection,
     e: &specta::datatype::Enum,
+    codable: &CodablePlan,
     enum_name: &str,
-) -> Result<String> {
-    // Check if this is an adjacently tagged enum first
-    let is_adjacently_tagged = if let Some(repr) = e.repr() {
-        matches!(repr, specta::datatype::EnumRepr::Adjacent { .. })
-    } else {
-        false
-    };
-
-    if is_adjacently_tagged {
-        return generate_adjacently_tagged_codable(swift, e, enum_name);
+    variant_struct_name: &dyn Fn(&str) -> String,
+    location: &[Cow<'static, str>],
+) -> Result<Option<Extension>> {
+    match codable.implementation {
+        CodableImpl::AdjacentlyTagged { .. } => {
+            generate_adjacently_tagged_codable(swift, e, enum_name, variant_struct_name, |ty| {
+                datatype_to_swift(swift, types, ty, location.to_vec(), false, None)
+            })
+            .map(Some)
+        }
+        CodableImpl::ExternallyTagged => crate::codable::enum_codable::generate_enum_codable_impl(
+            swift,
+            e,
+            enum_name,
+            variant_struct_name,
+            |ty| datatype_to_swift(swift, types, ty, location.to_vec(), false, None),
+        )
+        .map(Some),
+        _ => Ok(None),
     }
-
-    // Use the extracted enum_codable module
-    crate::codable::enum_codable::generate_enum_codable_impl(
-        swift,
-        e,
-        enum_name,
-        |variant_name| generate_variant_struct_name(swift, enum_name, variant_name),
-        |ty| datatype_to_swift(swift, types, ty, vec![], false, None),
-    )
+    .map_err(|err| err.with_location(location))
 }
 
 /// Generate custom Codable implementation for adjacently tagged enums
@@ -779,12 +1123,15 @@ fn generate_adjacently_tagged_codable(
     swift: &Swift,
     e: &specta::datatype::Enum,
     enum_name: &str,
-) -> Result<String> {
+    variant_struct_name: &dyn Fn(&str) -> String,
+    get_field_type: impl Fn(&DataType) -> Result<String>,
+) -> Result<Extension> {
     crate::codable::adjacently_tagged::generate_adjacently_tagged_codable(
         swift,
         e,
         enum_name,
-        |variant_name| generate_variant_struct_name(swift, enum_name, variant_name),
+        variant_struct_name,
+        get_field_type,
     )
 }
 
@@ -796,13 +1143,19 @@ fn generate_enum_variant_structs(
     types: &TypeCollection,
     e: &specta::datatype::Enum,
     enum_name: &str,
-) -> Result<String> {
+    variant_struct_name: &dyn Fn(&str) -> String,
+    location: &[Cow<'static, str>],
+) -> Result<Vec<Decl>> {
     crate::codable::struct_codable::generate_enum_variant_structs(
         swift,
         types,
         e,
         enum_name,
-        |variant_name| generate_variant_struct_name(swift, enum_name, variant_name),
-        |ty| datatype_to_swift(swift, types, ty, vec![], false, None),
+        variant_struct_name,
+        |ty, field_location| {
+            let mut location = location.to_vec();
+            location.extend(field_location);
+            datatype_to_swift(swift, types, ty, location, false, None)
+        },
     )
 }
@@ -0,0 +1,164 @@
+//! Inlined and anonymous types
+//!
+//! Fields marked `#[specta(inline)]`, references to types marked `#[specta(inline)]` and
+//! fields holding an anonymous struct or enum, such as the object values of a
+//! `HashMap<String, { ... }>`, have no type of their own to refer to. Swift has no anonymous
+//! types, so their definition is hoisted into a named type next to the parent. It is named
+//! after its owner path: the parent, the field and `Value` for the values of maps.
+//!
+//! ```swift
+//! public struct User: Codable {
+//!     public let id: UInt32
+//!     public let address: UserAddress
+//! }
+//!
+//! public struct UserAddress: Codable {
+//!     public let street: String
+//! }
+//! ```
+
+use specta::datatype::{DataType, Field, Fields, NamedDataType};
+use specta::TypeCollection;
+
+use crate::datatype::newtype::{phantom_id_type, raw_value_type};
+use crate::datatype::optional::optional_type;
+use crate::error::Result;
+use crate::special_types::{is_duration_struct, is_special_std_type};
+use crate::swift::Swift;
+
+/// Whether a field is inlined into a hoisted type.
+pub fn is_inlined(swift: &Swift, types: &TypeCollection, field: &Field) -> bool {
+    inlined(swift, types, field).is_some()
+}
+
+/// The named type the `field_name` field of `parent_name` is hoisted into when it is inlined.
+///
+/// The elements of `Option<T>` and `Vec<T>` fields and the values of maps are inlined.
+/// Generic references, special types and types which aren't structs or enums keep referring
+/// to their own type.
+pub fn inline_type(
+    swift: &Swift,
+    types: &TypeCollection,
+    parent: &NamedDataType,
+    parent_name: &str,
+    field_name: &str,
+    field: &Field,
+) -> Option<NamedDataType> {
+    let (target, ty, suffix) = inlined(swift, types, field)?;
+    let mut hoisted = match target {
+        Some(target) => target.clone(),
+        // Anonymous types are described as owned by their parent
+        None => {
+            let mut hoisted = parent.clone();
+            hoisted.set_docs("".into());
+            hoisted.set_deprecated(None);
+            hoisted
+        }
+    };
+
+    let name = format!("{}{}{}", parent_name, swift.type_name(field_name), suffix);
+    hoisted.set_name(name.into());
+    *hoisted.ty_mut() = ty.clone();
+    Some(hoisted)
+}
+
+/// The types hoisted next to the type declared as `name`, paired with the name of their field.
+///
+/// Only structs with named fields which aren't exported as option sets, newtypes or special
+/// types declare their fields and hoist them.
+pub fn hoisted_types(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &NamedDataType,
+    name: &str,
+) -> Vec<(String, NamedDataType)> {
+    let DataType::Struct(s) = ndt.ty() else {
+        return Vec::new();
+    };
+    let Fields::Named(fields) = s.fields() else {
+        return Vec::new();
+    };
+    if swift.option_set_flags(ndt).is_some()
+        || raw_value_type(swift, ndt).is_some()
+        || phantom_id_type(ndt).is_some()
+        || is_duration_struct(s)
+        || is_special_std_type(types, Some(ndt.sid())).is_some()
+    {
+        return Vec::new();
+    }
+
+    fields
+        .fields()
+        .iter()
+        .filter_map(|(field_name, field)| {
+            let hoisted = inline_type(swift, types, ndt, name, field_name, field)?;
+            Some((field_name.to_string(), hoisted))
+        })
+        .collect()
+}
+
+/// The Swift type of an inlined field, referring to the hoisted type `name`.
+///
+/// Map keys are converted with `convert`.
+pub fn inline_field_type<F>(swift: &Swift, ty: &DataType, name: &str, convert: &F) -> Result<String>
+where
+    F: Fn(&DataType) -> Result<String>,
+{
+    Ok(match ty {
+        DataType::Nullable(inner) => {
+            optional_type(swift, inline_field_type(swift, inner, name, convert)?)
+        }
+        DataType::List(list) => {
+            format!("[{}]", inline_field_type(swift, list.ty(), name, convert)?)
+        }
+        DataType::Map(map) => format!(
+            "[{}: {}]",
+            convert(map.key_ty())?,
+            inline_field_type(swift, map.value_ty(), name, convert)?
+        ),
+        _ => name.to_string(),
+    })
+}
+
+/// The type inlined for a field and the suffix of its name, looking through `Option<T>`,
+/// `Vec<T>` and map values.
+fn element(ty: &DataType) -> (&DataType, String) {
+    match ty {
+        DataType::Nullable(inner) => element(inner),
+        DataType::List(list) => element(list.ty()),
+        DataType::Map(map) => {
+            let (ty, suffix) = element(map.value_ty());
+            (ty, format!("Value{}", suffix))
+        }
+        ty => (ty, String::new()),
+    }
+}
+
+/// The referenced type, or `None` for anonymous types, the definition and the name suffix of
+/// an inlined field.
+fn inlined<'a>(
+    swift: &Swift,
+    types: &'a TypeCollection,
+    field: &'a Field,
+) -> Option<(Option<&'a NamedDataType>, &'a DataType, String)> {
+    let (ty, suffix) = element(field.ty()?);
+    let (target, ty) = match ty {
+        DataType::Reference(r) if field.inline() || r.inline() => {
+            if !r.generics().is_empty() || is_special_std_type(types, Some(r.sid())).is_some() {
+                return None;
+            }
+            let target = types.get(r.sid())?;
+            if swift.is_external(target) {
+                return None;
+            }
+            (Some(target), target.ty())
+        }
+        ty => (None, ty),
+    };
+
+    match ty {
+        DataType::Struct(s) if !is_duration_struct(s) => Some((target, ty, suffix)),
+        DataType::Enum(_) => Some((target, ty, suffix)),
+        _ => None,
+    }
+}
@@ -0,0 +1,94 @@
+//! Memory layout estimates
+//!
+//! A Swift enum is as large as its largest case, so a single variant holding a big struct
+//! makes every value of the enum big. The estimates here approximate the inline size of a
+//! variant payload in bytes, for [`Swift::box_variants_larger_than`](crate::Swift::box_variants_larger_than)
+//! to decide which cases are declared `indirect`.
+//!
+//! The estimate follows Swift's layout loosely: collections and strings are their fixed-size
+//! headers, optionals add a byte and padding is ignored.
+
+use specta::datatype::{DataType, Fields, Primitive};
+use specta::TypeCollection;
+
+use crate::special_types::is_special_std_type;
+
+/// How many referenced types are followed before a reference counts as a pointer.
+const MAX_DEPTH: usize = 8;
+
+/// The size of a pointer, used for references too deep to follow.
+const POINTER: usize = 8;
+
+/// Estimate the inline size of the payload of an enum variant in bytes.
+pub fn estimated_payload_size(types: &TypeCollection, fields: &Fields) -> usize {
+    fields_size(types, fields, 0)
+}
+
+/// Estimate the inline size of a Swift value of a type in bytes.
+pub fn estimated_size(types: &TypeCollection, ty: &DataType) -> usize {
+    size(types, ty, 0)
+}
+
+fn fields_size(types: &TypeCollection, fields: &Fields, depth: usize) -> usize {
+    match fields {
+        Fields::Unit => 0,
+        Fields::Unnamed(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|field| field.ty())
+            .map(|ty| size(types, ty, depth))
+            .sum(),
+        Fields::Named(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|(_, field)| field.ty())
+            .map(|ty| size(types, ty, depth))
+            .sum(),
+    }
+}
+
+fn size(types: &TypeCollection, ty: &DataType, depth: usize) -> usize {
+    match ty {
+        DataType::Primitive(primitive) => match primitive {
+            Primitive::i8 | Primitive::u8 | Primitive::bool => 1,
+            Primitive::i16 | Primitive::u16 | Primitive::f16 => 2,
+            Primitive::i32 | Primitive::u32 | Primitive::f32 => 4,
+            Primitive::i64
+            | Primitive::u64
+            | Primitive::isize
+            | Primitive::usize
+            | Primitive::f64 => 8,
+            Primitive::i128 | Primitive::u128 | Primitive::char | Primitive::String => 16,
+        },
+        DataType::Literal(_) => 16,
+        DataType::List(_) | DataType::Map(_) | DataType::Generic(_) => POINTER,
+        DataType::Nullable(inner) => size(types, inner, depth) + 1,
+        DataType::Tuple(tuple) => tuple
+            .elements()
+            .iter()
+            .map(|element| size(types, element, depth))
+            .sum(),
+        DataType::Struct(s) => fields_size(types, s.fields(), depth),
+        DataType::Enum(e) => {
+            let largest = e
+                .variants()
+                .iter()
+                .filter(|(_, variant)| !variant.skip())
+                .map(|(_, variant)| fields_size(types, variant.fields(), depth))
+                .max()
+                .unwrap_or(0);
+            largest + 1
+        }
+        DataType::Reference(reference) => {
+            if depth >= MAX_DEPTH {
+                return POINTER;
+            }
+            if is_special_std_type(types, Some(reference.sid())).is_some() {
+                return 16;
+            }
+            types
+                .get(reference.sid())
+                .map_or(POINTER, |ndt| size(types, ndt.ty(), depth + 1))
+        }
+    }
+}
@@ -7,6 +7,12 @@
 //! - `enum_gen`: Enum type generation  
 //! - `tuple_gen`: Tuple variant generation
 //! - `primitives`: Primitive type mapping and validation
+//! - `layout`: Size estimates used to box large enum payloads
+//! - `inline`: Named types hoisted for inlined and anonymous field types
+//! - `monomorphize`: Concrete declarations for instantiations of generic types
+//! - `optional`: Whether fields are optional, resolved once for every generator
+//! - `newtype`: `RawRepresentable` structs for newtypes
+//! - `option_set`: `OptionSet`s for bitflag types
 //!
 //! # Architecture
 //!
@@ -18,14 +24,22 @@
 pub mod collections;
 pub mod export;
 pub mod generic;
+pub mod inline;
+pub mod layout;
+pub mod monomorphize;
+pub mod newtype;
+pub mod option_set;
+pub mod optional;
 pub mod primitives;
 pub mod reference;
 
 // Re-export commonly used functions
-pub use collections::{list_to_swift, map_to_swift, tuple_to_swift};
+pub use collections::{list_to_swift, map_to_swift, tuple_to_swift, validated_length};
 pub use export::{datatype_to_swift, export_type_with_name};
 pub use generic::generic_to_swift;
-pub use primitives::{literal_to_swift, primitive_to_swift};
+pub use layout::{estimated_payload_size, estimated_size};
+pub use optional::{empty_collection, field_optionality, optional_type, Optionality};
+pub use primitives::{literal_to_swift, literal_type_to_swift, primitive_to_swift};
 pub use reference::reference_to_swift;
 
 // Re-export public types from submodules once they're created
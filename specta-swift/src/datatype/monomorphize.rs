@@ -0,0 +1,261 @@
+//! Generic monomorphization
+//!
+//! With [`Swift::monomorphize`](crate::Swift::monomorphize) generic types are not declared.
+//! Every concrete instantiation referenced by an exported type gets its own declaration
+//! instead, named after the generic type and its arguments:
+//!
+//! ```swift
+//! // ApiResponse<User>
+//! public struct ApiResponseUser: Codable {
+//!     public let data: User
+//! }
+//!
+//! // ApiResponse<Vec<String>>
+//! public struct ApiResponseArrayString: Codable {
+//!     public let data: [String]
+//! }
+//! ```
+//!
+//! Phantom-generic IDs such as `Id<User>` stay generic, see [`newtype`](super::newtype).
+
+use std::collections::{BTreeMap, HashSet};
+
+use specta::datatype::{DataType, Fields, Generic, NamedDataType, Reference};
+use specta::TypeCollection;
+
+use crate::datatype::newtype::phantom_id_type;
+use crate::error::{Error, Result};
+use crate::swift::Swift;
+
+/// Replace the generic types of `types` with their concrete instantiations.
+///
+/// Instantiations are collected from the non-generic types, and from the instantiations
+/// themselves until no new one is found.
+pub fn monomorphize(
+    swift: &Swift,
+    collection: &TypeCollection,
+    types: Vec<(String, NamedDataType)>,
+) -> Result<Vec<(String, NamedDataType)>> {
+    let mut result = types
+        .into_iter()
+        .filter(|(_, ndt)| ndt.generics().is_empty() || phantom_id_type(ndt).is_some())
+        .collect::<Vec<_>>();
+    let mut names = result
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<HashSet<_>>();
+    let mut instantiated = HashSet::new();
+
+    let mut index = 0;
+    while index < result.len() {
+        let mut references = Vec::new();
+        collect_instantiations(result[index].1.ty(), &mut references);
+
+        for reference in references {
+            let Some(generic) = collection.get(reference.sid()) else {
+                continue;
+            };
+            if generic.generics().is_empty()
+                || swift.is_external(generic)
+                || phantom_id_type(generic).is_some()
+            {
+                continue;
+            }
+            let Some(name) = instantiation_name(swift, collection, &reference)? else {
+                continue;
+            };
+            if !instantiated.insert(name.clone()) {
+                continue;
+            }
+            if !names.insert(name.clone()) {
+                return Err(Error::DuplicateNames(format!(
+                    "Monomorphized type '{}' ({}) has the same name as an exported type",
+                    name,
+                    generic.name()
+                )));
+            }
+
+            let mut instance = generic.clone();
+            instance.set_name(name.clone().into());
+            instance.generics_mut().clear();
+            substitute(instance.ty_mut(), reference.generics());
+            result.push((name, instance));
+        }
+        index += 1;
+    }
+
+    Ok(result)
+}
+
+/// The name of the type declared for a concrete instantiation of a generic type.
+///
+/// Returns `None` when an argument is itself generic, which only happens inside generic
+/// declarations.
+pub fn instantiation_name(
+    swift: &Swift,
+    types: &TypeCollection,
+    reference: &Reference,
+) -> Result<Option<String>> {
+    let Some(generic) = types.get(reference.sid()) else {
+        return Ok(None);
+    };
+
+    let mut name = swift.type_name(generic.name());
+    // Arguments are named in the order of the declaration
+    for parameter in generic.generics() {
+        let Some(argument) = reference.generics().get(parameter) else {
+            return Ok(None);
+        };
+        let Some(fragment) = name_fragment(swift, types, argument)? else {
+            return Ok(None);
+        };
+        name.push_str(&fragment);
+    }
+    Ok(Some(name))
+}
+
+/// The part of an instantiation's name describing one type argument.
+fn name_fragment(swift: &Swift, types: &TypeCollection, ty: &DataType) -> Result<Option<String>> {
+    let fragments = |arguments: &[&DataType]| -> Result<Option<String>> {
+        let mut result = String::new();
+        for ty in arguments {
+            match name_fragment(swift, types, ty)? {
+                Some(fragment) => result.push_str(&fragment),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(result))
+    };
+
+    Ok(match ty {
+        DataType::Generic(_) => None,
+        DataType::Nullable(inner) => {
+            fragments(&[inner.as_ref()])?.map(|inner| format!("Optional{}", inner))
+        }
+        DataType::List(list) => fragments(&[list.ty()])?.map(|element| format!("Array{}", element)),
+        DataType::Map(map) => fragments(&[map.key_ty(), map.value_ty()])?
+            .map(|entries| format!("Dictionary{}", entries)),
+        DataType::Tuple(tuple) if tuple.elements().is_empty() => Some("Void".to_string()),
+        DataType::Tuple(tuple) => fragments(&tuple.elements().iter().collect::<Vec<_>>())?,
+        DataType::Reference(reference) if !reference.generics().is_empty() => {
+            instantiation_name(swift, types, reference)?
+        }
+        // Other types are named after their Swift type, e.g. `UUID` or `Int32`
+        ty => {
+            let swift_type =
+                crate::datatype::export::datatype_to_swift(swift, types, ty, vec![], false, None)?;
+            Some(
+                swift_type
+                    .chars()
+                    .filter(|c| c.is_alphanumeric() || *c == '_')
+                    .collect(),
+            )
+        }
+    })
+}
+
+/// Collect the references with type arguments in `ty`.
+fn collect_instantiations(ty: &DataType, references: &mut Vec<Reference>) {
+    match ty {
+        DataType::Nullable(inner) => collect_instantiations(inner, references),
+        DataType::List(list) => collect_instantiations(list.ty(), references),
+        DataType::Map(map) => {
+            collect_instantiations(map.key_ty(), references);
+            collect_instantiations(map.value_ty(), references);
+        }
+        DataType::Tuple(tuple) => {
+            for element in tuple.elements() {
+                collect_instantiations(element, references);
+            }
+        }
+        DataType::Struct(s) => collect_fields(s.fields(), references),
+        DataType::Enum(e) => {
+            for (_, variant) in e.variants() {
+                collect_fields(variant.fields(), references);
+            }
+        }
+        DataType::Reference(reference) => {
+            if !reference.generics().is_empty() {
+                references.push(reference.clone());
+            }
+            for argument in reference.generics().values() {
+                collect_instantiations(argument, references);
+            }
+        }
+        DataType::Primitive(_) | DataType::Literal(_) | DataType::Generic(_) => {}
+    }
+}
+
+fn collect_fields(fields: &Fields, references: &mut Vec<Reference>) {
+    match fields {
+        Fields::Unit => {}
+        Fields::Unnamed(fields) => {
+            for ty in fields.fields().iter().filter_map(|field| field.ty()) {
+                collect_instantiations(ty, references);
+            }
+        }
+        Fields::Named(fields) => {
+            for ty in fields.fields().iter().filter_map(|(_, field)| field.ty()) {
+                collect_instantiations(ty, references);
+            }
+        }
+    }
+}
+
+/// Replace the generic parameters in `ty` with their arguments.
+fn substitute(ty: &mut DataType, arguments: &BTreeMap<Generic, DataType>) {
+    match ty {
+        DataType::Generic(generic) => {
+            if let Some(argument) = arguments.get(generic) {
+                *ty = argument.clone();
+            }
+        }
+        DataType::Nullable(inner) => substitute(inner, arguments),
+        DataType::List(list) => substitute(list.ty_mut(), arguments),
+        DataType::Map(map) => {
+            substitute(map.key_ty_mut(), arguments);
+            substitute(map.value_ty_mut(), arguments);
+        }
+        DataType::Tuple(tuple) => {
+            for element in tuple.elements_mut() {
+                substitute(element, arguments);
+            }
+        }
+        DataType::Struct(s) => substitute_fields(s.fields_mut(), arguments),
+        DataType::Enum(e) => {
+            for (_, variant) in e.variants_mut() {
+                substitute_fields(variant.fields_mut(), arguments);
+            }
+        }
+        DataType::Reference(reference) => {
+            for argument in reference.generics_mut().values_mut() {
+                substitute(argument, arguments);
+            }
+        }
+        DataType::Primitive(_) | DataType::Literal(_) => {}
+    }
+}
+
+fn substitute_fields(fields: &mut Fields, arguments: &BTreeMap<Generic, DataType>) {
+    match fields {
+        Fields::Unit => {}
+        Fields::Unnamed(fields) => {
+            for ty in fields
+                .fields_mut()
+                .iter_mut()
+                .filter_map(|field| field.ty_mut())
+            {
+                substitute(ty, arguments);
+            }
+        }
+        Fields::Named(fields) => {
+            for ty in fields
+                .fields_mut()
+                .iter_mut()
+                .filter_map(|(_, field)| field.ty_mut())
+            {
+                substitute(ty, arguments);
+            }
+        }
+    }
+}
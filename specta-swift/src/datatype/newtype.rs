@@ -0,0 +1,131 @@
+//! `RawRepresentable` newtypes
+//!
+//! With [`NewtypeStyle::RawRepresentable`], single-field tuple structs such as `UserId(Uuid)`
+//! become `RawRepresentable` structs instead of structs with a `value` property. serde codes
+//! newtypes as their field, so they get a single value Codable implementation. The standard
+//! library only provides one for integer, floating point, `Bool` and `String` raw values.
+//!
+//! Generic IDs such as `Id<User>` are `#[serde(transparent)]` wrappers whose type parameters
+//! only appear in skipped `PhantomData` fields. Whatever the style, they are exported as a
+//! phantom-generic `public struct Id<T>: RawRepresentable` and referenced as `Id<User>`, so
+//! an `Id<User>` can't be passed where an `Id<Post>` is expected in Swift either.
+
+use specta::datatype::{DataType, Fields, NamedDataType};
+use specta::TypeCollection;
+
+use crate::ast::{Decl, Function, Member, Property};
+use crate::datatype::export::datatype_to_swift;
+use crate::error::Result;
+use crate::swift::{NewtypeStyle, Swift};
+
+/// Swift types which are `Hashable`, so newtypes around them are too.
+#[rustfmt::skip]
+const HASHABLE_TYPES: &[&str] = &[
+    "Int8", "Int16", "Int32", "Int64", "Int", "UInt8", "UInt16", "UInt32", "UInt64", "UInt",
+    "Float", "Double", "Bool", "Character", "String", "UUID", "URL", "Date", "Data", "Decimal",
+];
+
+/// The wrapped type of a newtype exported as a `RawRepresentable` struct, if it is one.
+pub fn raw_value_type<'a>(swift: &Swift, ndt: &'a NamedDataType) -> Option<&'a DataType> {
+    if swift.newtypes != NewtypeStyle::RawRepresentable || !ndt.generics().is_empty() {
+        return None;
+    }
+    match ndt.ty() {
+        DataType::Struct(s) => match s.fields() {
+            Fields::Unnamed(fields) => match fields.fields() {
+                [field] => field.ty(),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The raw value type of a phantom-generic ID, if `ndt` is one: a generic type whose
+/// transparent body doesn't use its type parameters.
+pub fn phantom_id_type(ndt: &NamedDataType) -> Option<&DataType> {
+    if ndt.generics().is_empty() {
+        return None;
+    }
+    match ndt.ty() {
+        DataType::Struct(_) | DataType::Enum(_) => None,
+        ty if uses_generics(ty) => None,
+        ty => Some(ty),
+    }
+}
+
+/// Check whether `ty` refers to a type parameter.
+fn uses_generics(ty: &DataType) -> bool {
+    let fields_use_generics = |fields: &Fields| match fields {
+        Fields::Unit => false,
+        Fields::Unnamed(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|field| field.ty())
+            .any(uses_generics),
+        Fields::Named(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|(_, field)| field.ty())
+            .any(uses_generics),
+    };
+
+    match ty {
+        DataType::Generic(_) => true,
+        DataType::Nullable(inner) => uses_generics(inner),
+        DataType::List(list) => uses_generics(list.ty()),
+        DataType::Map(map) => uses_generics(map.key_ty()) || uses_generics(map.value_ty()),
+        DataType::Tuple(tuple) => tuple.elements().iter().any(uses_generics),
+        DataType::Struct(s) => fields_use_generics(s.fields()),
+        DataType::Enum(e) => e
+            .variants()
+            .iter()
+            .any(|(_, variant)| fields_use_generics(variant.fields())),
+        DataType::Reference(reference) => reference.generics().values().any(uses_generics),
+        DataType::Primitive(_) | DataType::Literal(_) => false,
+    }
+}
+
+/// Generate the `RawRepresentable` declaration of a newtype wrapping `ty`.
+pub fn generate_raw_value_newtype(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &NamedDataType,
+    name: &str,
+    ty: &DataType,
+) -> Result<Decl> {
+    let raw_type = datatype_to_swift(swift, types, ty, vec![ndt.name().clone()], false, None)?;
+
+    let mut decl = Decl::public_struct(name);
+    decl.conformances.push("RawRepresentable".into());
+    if swift.emit_codable {
+        decl.conformances.push("Codable".into());
+    }
+    if HASHABLE_TYPES.contains(&raw_type.as_str()) {
+        decl.conformances.push("Hashable".into());
+    }
+
+    decl.members
+        .push(Member::Property(Property::constant("rawValue", &raw_type)));
+    decl.members.push(Member::Function(Function::new(
+        format!("public init(rawValue: {})", raw_type),
+        "self.rawValue = rawValue\n",
+    )));
+
+    if swift.emit_codable {
+        decl.members.push(Member::Function(Function::new(
+            "public init(from decoder: Decoder) throws",
+            &format!(
+                "rawValue = try decoder.singleValueContainer().decode({}.self)\n",
+                raw_type
+            ),
+        )));
+        decl.members.push(Member::Function(Function::new(
+            "public func encode(to encoder: Encoder) throws",
+            "var container = encoder.singleValueContainer()\ntry container.encode(rawValue)\n",
+        )));
+    }
+
+    Ok(decl)
+}
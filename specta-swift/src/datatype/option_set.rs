@@ -0,0 +1,104 @@
+//! `OptionSet`s for bitflag types
+//!
+//! Bitflag types registered with [`Swift::option_set`] are integers, or newtypes around one,
+//! whose flags specta knows nothing about. They are exported as an `OptionSet` with a static
+//! member per configured flag. Its `rawValue` is the integer, which the standard library's
+//! `RawRepresentable` conformance codes as a single value, like serde does.
+
+use std::borrow::Cow;
+
+use specta::datatype::{DataType, Fields, NamedDataType, Primitive};
+
+use crate::ast::{Decl, Function, Member, Property};
+use crate::error::{Error, Result};
+use crate::swift::Swift;
+
+/// The integer a bitflag type is coded as: the type itself or the field of a newtype.
+pub fn option_set_primitive(ty: &DataType) -> Option<&Primitive> {
+    let primitive = match ty {
+        DataType::Primitive(p) => p,
+        DataType::Struct(s) => match s.fields() {
+            Fields::Unnamed(fields) => match fields.fields() {
+                [field] => match field.ty()? {
+                    DataType::Primitive(p) => p,
+                    _ => return None,
+                },
+                _ => return None,
+            },
+            _ => return None,
+        },
+        _ => return None,
+    };
+    integer_bounds(primitive).map(|_| primitive)
+}
+
+/// The Swift integer type and largest value of an integer primitive.
+fn integer_bounds(primitive: &Primitive) -> Option<(&'static str, u64)> {
+    Some(match primitive {
+        Primitive::i8 => ("Int8", i8::MAX as u64),
+        Primitive::i16 => ("Int16", i16::MAX as u64),
+        Primitive::i32 => ("Int32", i32::MAX as u64),
+        Primitive::i64 => ("Int64", i64::MAX as u64),
+        Primitive::isize => ("Int", i64::MAX as u64),
+        Primitive::u8 => ("UInt8", u8::MAX as u64),
+        Primitive::u16 => ("UInt16", u16::MAX as u64),
+        Primitive::u32 => ("UInt32", u32::MAX as u64),
+        Primitive::u64 => ("UInt64", u64::MAX),
+        Primitive::usize => ("UInt", u64::MAX),
+        _ => return None,
+    })
+}
+
+/// Generate the `OptionSet` declaration of a bitflag type.
+pub fn generate_option_set(
+    swift: &Swift,
+    ndt: &NamedDataType,
+    name: &str,
+    flags: &[(Cow<'static, str>, u64)],
+) -> Result<Decl> {
+    let (raw_type, max) = option_set_primitive(ndt.ty())
+        .and_then(integer_bounds)
+        .ok_or_else(|| {
+            Error::unsupported_type(format!(
+                "option set `{}` must be an integer or a newtype around one",
+                ndt.name()
+            ))
+        })?;
+
+    let mut decl = Decl::public_struct(name);
+    decl.conformances.push("OptionSet".into());
+    decl.conformances.push("Hashable".into());
+    if swift.emit_codable {
+        decl.conformances.push("Codable".into());
+    }
+
+    decl.members
+        .push(Member::Property(Property::constant("rawValue", raw_type)));
+    decl.members.push(Member::Function(Function::new(
+        format!("public init(rawValue: {})", raw_type),
+        "self.rawValue = rawValue\n",
+    )));
+
+    for (flag, bits) in flags {
+        if *bits > max {
+            return Err(Error::unsupported_type(format!(
+                "flag `{}` of option set `{}` doesn't fit in `{}`",
+                flag,
+                ndt.name(),
+                raw_type
+            )));
+        }
+        // Single bits are written as shifts, like flags are usually declared
+        let value = if bits.is_power_of_two() {
+            format!("1 << {}", bits.trailing_zeros())
+        } else {
+            bits.to_string()
+        };
+        decl.members.push(Member::Property(
+            Property::constant(swift.field_name(flag), name)
+                .static_value(format!("Self(rawValue: {})", value)),
+        ));
+    }
+
+    Ok(decl)
+}
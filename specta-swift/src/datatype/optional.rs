@@ -0,0 +1,79 @@
+//! Optionality of fields
+//!
+//! A field becomes an optional Swift property when its type is nullable (`Option<T>`), when it
+//! may be missing (`#[specta(optional)]`, `#[serde(default)]` or `skip_serializing_if`), or
+//! both. Declarations, initializers and Codable implementations all resolve this with
+//! [`field_optionality`], so a field is wrapped in a single optional: `#[serde(default)]` on an
+//! `Option<String>` is a `String?`, not a `String??`.
+
+use std::fmt::Display;
+
+use specta::datatype::{DataType, Field};
+
+use crate::swift::{OptionalCollectionStrategy, OptionalStyle, Swift};
+
+/// How a field is optional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Optionality {
+    /// The field's type is nullable, so it accepts `null`.
+    pub nullable: bool,
+    /// The field may be missing from the serialized value.
+    pub omittable: bool,
+}
+
+impl Optionality {
+    /// Whether the Swift property is optional.
+    pub fn is_optional(self) -> bool {
+        self.nullable || self.omittable
+    }
+
+    /// Whether `nil` is encoded by omitting the key, since the Rust type doesn't accept `null`.
+    pub fn omits_nil(self) -> bool {
+        self.omittable && !self.nullable
+    }
+}
+
+/// The type of a field without its `Option`, and how the field is optional.
+///
+/// Returns `None` for fields without a type, which are skipped.
+pub fn field_optionality(field: &Field) -> Option<(&DataType, Optionality)> {
+    let (ty, nullable) = match field.ty()? {
+        DataType::Nullable(inner) => (&**inner, true),
+        ty => (ty, false),
+    };
+    Some((
+        ty,
+        Optionality {
+            nullable,
+            omittable: field.optional(),
+        },
+    ))
+}
+
+/// Make a Swift type optional with the configured [`OptionalStyle`].
+pub fn optional_type(swift: &Swift, ty: impl Display) -> String {
+    match swift.optionals {
+        OptionalStyle::QuestionMark => format!("{}?", ty),
+        OptionalStyle::Optional => format!("Optional<{}>", ty),
+    }
+}
+
+/// The empty value of an optional collection field declared as a non-optional collection with
+/// [`OptionalCollectionStrategy::Empty`], e.g. `[]` for an `Option<Vec<T>>`.
+///
+/// `ty` is the field type without its `Option`, as returned by [`field_optionality`].
+pub fn empty_collection(
+    swift: &Swift,
+    ty: &DataType,
+    optionality: Optionality,
+) -> Option<&'static str> {
+    if swift.optional_collections != OptionalCollectionStrategy::Empty || !optionality.is_optional()
+    {
+        return None;
+    }
+    match ty {
+        DataType::List(_) => Some("[]"),
+        DataType::Map(_) => Some("[:]"),
+        _ => None,
+    }
+}
@@ -91,13 +91,13 @@ pub fn primitive_to_swift(primitive: &Primitive) -> Result<String> {
 
         // Unsupported types
         Primitive::i128 | Primitive::u128 => {
-            return Err(Error::UnsupportedType(
-                "Swift does not support 128-bit integers".to_string(),
+            return Err(Error::unsupported_type(
+                "Swift does not support 128-bit integers",
             ));
         }
         Primitive::f16 => {
-            return Err(Error::UnsupportedType(
-                "Swift does not support f16 (16-bit float)".to_string(),
+            return Err(Error::unsupported_type(
+                "Swift does not support f16 (16-bit float)",
             ));
         }
     })
@@ -153,14 +153,45 @@ pub fn literal_to_swift(literal: &Literal) -> Result<String> {
         Literal::None => "nil".to_string(),
 
         // Unsupported literals
-        _ => {
-            return Err(Error::UnsupportedType(
-                "Unsupported literal type".to_string(),
-            ))
-        }
+        _ => return Err(Error::unsupported_type("Unsupported literal type")),
     })
 }
 
+/// Convert the type of a literal to Swift.
+///
+/// Swift has no literal types, so fields with a literal type (e.g. a `kind: "image"` tag) are
+/// represented by the literal's underlying type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use specta::datatype::Literal;
+/// # use specta_swift::datatype::primitives::literal_type_to_swift;
+/// assert_eq!(literal_type_to_swift(&Literal::String("image".into())).unwrap(), "String");
+/// assert_eq!(literal_type_to_swift(&Literal::u8(1)).unwrap(), "UInt8");
+/// ```
+///
+/// # Errors
+///
+/// Returns an error for `null` and other unsupported literal types.
+pub fn literal_type_to_swift(literal: &Literal) -> Result<String> {
+    Ok(match literal {
+        Literal::i8(_) => "Int8",
+        Literal::i16(_) => "Int16",
+        Literal::i32(_) => "Int32",
+        Literal::u8(_) => "UInt8",
+        Literal::u16(_) => "UInt16",
+        Literal::u32(_) => "UInt32",
+        Literal::f32(_) => "Float",
+        Literal::f64(_) => "Double",
+        Literal::bool(_) => "Bool",
+        Literal::String(_) => "String",
+        Literal::char(_) => "Character",
+        _ => return Err(Error::unsupported_type("Unsupported literal type")),
+    }
+    .to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +287,16 @@ mod tests {
     fn test_none_literal() {
         assert_eq!(literal_to_swift(&Literal::None).unwrap(), "nil");
     }
+
+    #[test]
+    fn test_literal_types() {
+        assert_eq!(
+            literal_type_to_swift(&Literal::String("image".into())).unwrap(),
+            "String"
+        );
+        assert_eq!(literal_type_to_swift(&Literal::i32(42)).unwrap(), "Int32");
+        assert_eq!(literal_type_to_swift(&Literal::f64(1.5)).unwrap(), "Double");
+        assert_eq!(literal_type_to_swift(&Literal::bool(true)).unwrap(), "Bool");
+        assert!(literal_type_to_swift(&Literal::None).is_err());
+    }
 }
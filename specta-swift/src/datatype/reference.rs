@@ -2,11 +2,19 @@
 //!
 //! This module handles conversion of Specta reference types (type references)
 //! to Swift type names with generic parameters.
+//!
+//! Shared types are referenced from many places, so the Swift names of referenced types are
+//! memoized for the duration of an export (see [`with_reference_cache`]).
+
+use std::cell::RefCell;
+use std::collections::HashMap;
 
-use specta::TypeCollection;
+use specta::{SpectaID, TypeCollection};
 
+use crate::datatype::monomorphize::instantiation_name;
+use crate::datatype::newtype::phantom_id_type;
 use crate::error::{Error, Result};
-use crate::swift::Swift;
+use crate::swift::{MissingReferenceStrategy, Swift};
 
 /// Convert a Specta reference type to Swift.
 ///
@@ -35,7 +43,7 @@ use crate::swift::Swift;
 ///
 /// # Errors
 ///
-/// Returns `Error::InvalidIdentifier` if the referenced type is not found
+/// Returns `Error::UnknownReference` if the referenced type is not found
 pub fn reference_to_swift<F>(
     swift: &Swift,
     types: &TypeCollection,
@@ -45,17 +53,37 @@ pub fn reference_to_swift<F>(
 where
     F: Fn(&specta::datatype::DataType) -> Result<String>,
 {
+    // User-defined special types are replaced by their Swift type
+    if let Some(special) = types
+        .get(reference.sid())
+        .and_then(|ndt| swift.special_type_of(ndt))
+    {
+        return Ok(special.swift_type.into_owned());
+    }
+
     // Get the name from the TypeCollection using the SID
-    let name = if let Some(ndt) = types.get(reference.sid()) {
-        swift.naming.convert(ndt.name())
-    } else {
-        return Err(Error::InvalidIdentifier(
-            "Reference to unknown type".to_string(),
-        ));
+    let Some(name) = reference_name(swift, types, reference.sid()) else {
+        return match swift.missing_reference {
+            MissingReferenceStrategy::Error => Err(Error::unknown_reference(reference.sid())),
+            MissingReferenceStrategy::Stub => Ok(stub_name(reference.sid())),
+        };
     };
 
+    // External types are declared elsewhere and phantom-generic IDs keep their type parameter,
+    // so only their generic form exists
+    let monomorphize = swift.monomorphize
+        && !types
+            .get(reference.sid())
+            .is_some_and(|ndt| swift.is_external(ndt) || phantom_id_type(ndt).is_some());
+
     if reference.generics().is_empty() {
         Ok(name)
+    } else if let Some(name) = monomorphize
+        .then(|| instantiation_name(swift, types, reference))
+        .transpose()?
+        .flatten()
+    {
+        Ok(name)
     } else {
         let generics = reference
             .generics()
@@ -67,7 +95,173 @@ where
     }
 }
 
+/// The name of the placeholder declared for a type missing from the collection.
+///
+/// Only the `SpectaID` of a missing type is known, so the name is derived from it, e.g.
+/// `MissingType1A2B3C4D5E6F7A8B`.
+pub fn stub_name(sid: SpectaID) -> String {
+    let id = format!("{:?}", sid)
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    match id.parse::<u64>() {
+        Ok(id) => format!("MissingType{:X}", id),
+        Err(_) => format!("MissingType{}", id),
+    }
+}
+
+thread_local! {
+    /// Swift names of referenced types, memoized while an export runs on this thread.
+    static REFERENCE_NAMES: RefCell<Option<ReferenceNames>> = const { RefCell::new(None) };
+}
+
+/// Memoized names, only valid for the configuration and collection they were computed with.
+struct ReferenceNames {
+    swift: usize,
+    types: usize,
+    names: HashMap<SpectaID, String>,
+}
+
+/// Removes the cache installed by [`with_reference_cache`], even when unwinding.
+struct ReferenceCacheGuard {
+    installed: bool,
+}
+
+impl Drop for ReferenceCacheGuard {
+    fn drop(&mut self) {
+        if self.installed {
+            REFERENCE_NAMES.with(|cache| cache.borrow_mut().take());
+        }
+    }
+}
+
+/// Run `f` with the names of referenced types memoized.
+///
+/// Nested calls reuse the outer cache. Lookups for a different configuration or collection
+/// than the one the cache was installed for bypass it.
+pub(crate) fn with_reference_cache<T>(
+    swift: &Swift,
+    types: &TypeCollection,
+    f: impl FnOnce() -> T,
+) -> T {
+    let installed = REFERENCE_NAMES.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.is_some() {
+            return false;
+        }
+        *cache = Some(ReferenceNames {
+            swift: swift as *const Swift as usize,
+            types: types as *const TypeCollection as usize,
+            names: HashMap::new(),
+        });
+        true
+    });
+
+    let _guard = ReferenceCacheGuard { installed };
+    f()
+}
+
+/// Use the names the resolver chose for references to the types in `names`.
+///
+/// Duplicate types are declared under a name which can't be derived from their Rust name, so
+/// they are seeded into the cache installed by [`with_reference_cache`]. Without a cache for
+/// `swift` and `types`, references fall back to the names of the types themselves.
+pub(crate) fn use_resolved_names(
+    swift: &Swift,
+    types: &TypeCollection,
+    names: &HashMap<SpectaID, String>,
+) {
+    REFERENCE_NAMES.with(|cache| {
+        if let Some(cache) = cache.borrow_mut().as_mut() {
+            if cache.swift == swift as *const Swift as usize
+                && cache.types == types as *const TypeCollection as usize
+            {
+                cache
+                    .names
+                    .extend(names.iter().map(|(sid, name)| (*sid, name.clone())));
+            }
+        }
+    });
+}
+
+/// The Swift name of the type `sid`, memoized when a cache is installed.
+pub(crate) fn reference_name(
+    swift: &Swift,
+    types: &TypeCollection,
+    sid: SpectaID,
+) -> Option<String> {
+    let lookup = || {
+        types
+            .get(sid)
+            .map(|ndt| swift.declared_type_name(ndt.name()))
+    };
+
+    REFERENCE_NAMES.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        match cache.as_mut() {
+            Some(cache)
+                if cache.swift == swift as *const Swift as usize
+                    && cache.types == types as *const TypeCollection as usize =>
+            {
+                if let Some(name) = cache.names.get(&sid) {
+                    return Some(name.clone());
+                }
+                let name = lookup()?;
+                cache.names.insert(sid, name.clone());
+                Some(name)
+            }
+            _ => lookup(),
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     // Integration tests verify reference resolution works correctly
+
+    use specta::{NamedType, Type};
+
+    use super::*;
+    use crate::NamingConvention;
+
+    #[derive(Type)]
+    #[allow(dead_code)]
+    struct UserProfile {
+        id: u32,
+    }
+
+    fn cached_names() -> Option<usize> {
+        REFERENCE_NAMES.with(|cache| cache.borrow().as_ref().map(|cache| cache.names.len()))
+    }
+
+    #[test]
+    fn test_reference_names_are_memoized() {
+        let types = TypeCollection::default().register::<UserProfile>();
+        let sid = UserProfile::ID;
+        let swift = Swift::default();
+
+        assert_eq!(cached_names(), None);
+        with_reference_cache(&swift, &types, || {
+            assert_eq!(
+                reference_name(&swift, &types, sid).as_deref(),
+                Some("UserProfile")
+            );
+            assert_eq!(cached_names(), Some(1));
+
+            // Nested scopes share the cache, other configurations bypass it
+            with_reference_cache(&swift, &types, || {
+                assert_eq!(
+                    reference_name(&swift, &types, sid).as_deref(),
+                    Some("UserProfile")
+                );
+                let other = Swift::default().naming(NamingConvention::SnakeCase);
+                assert_eq!(
+                    reference_name(&other, &types, sid).as_deref(),
+                    Some("user_profile")
+                );
+            });
+            assert_eq!(cached_names(), Some(1));
+        });
+        assert_eq!(cached_names(), None);
+    }
 }
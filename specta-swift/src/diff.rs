@@ -0,0 +1,131 @@
+//! Comparing generated Swift against an existing file.
+//!
+//! A [`Diff`] lists the top-level declarations which would be added, removed or changed
+//! if the bindings were regenerated. This is useful for checking in CI that committed
+//! bindings are up to date.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::export::{DeclarationKind, Export};
+
+/// Declarations which differ between an existing Swift file and freshly generated code.
+///
+/// Declarations are identified by name. Extensions are identified as `extension Name`,
+/// with all extensions of the same type compared together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diff {
+    /// Declarations which would be added.
+    pub added: Vec<String>,
+    /// Declarations which would be removed.
+    pub removed: Vec<String>,
+    /// Declarations whose generated code would change.
+    pub changed: Vec<String>,
+}
+
+impl Diff {
+    /// Compare existing Swift source against newly generated source.
+    pub fn between(existing: &str, generated: &str) -> Self {
+        Self::between_exports(&Export::parse(existing), &Export::parse(generated))
+    }
+
+    /// Compare the declarations of existing Swift source against those of a new export, e.g.
+    /// from [`Swift::export_declarations`](crate::Swift::export_declarations).
+    pub fn between_exports(existing: &Export, generated: &Export) -> Self {
+        let existing = declarations_by_name(existing);
+        let generated = declarations_by_name(generated);
+
+        let mut diff = Self::default();
+        for (name, code) in &generated {
+            match existing.get(name) {
+                None => diff.added.push(name.clone()),
+                Some(existing_code) if existing_code != code => diff.changed.push(name.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = existing
+            .keys()
+            .filter(|name| !generated.contains_key(*name))
+            .cloned()
+            .collect();
+
+        diff
+    }
+
+    /// Check whether the existing file is up to date.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Swift bindings are up to date");
+        }
+
+        let sections = [
+            ('+', &self.added),
+            ('-', &self.removed),
+            ('~', &self.changed),
+        ];
+        let mut lines = sections
+            .iter()
+            .flat_map(|(marker, names)| names.iter().map(move |name| (marker, name)));
+        if let Some((marker, name)) = lines.next() {
+            write!(f, "{} {}", marker, name)?;
+        }
+        for (marker, name) in lines {
+            write!(f, "\n{} {}", marker, name)?;
+        }
+        Ok(())
+    }
+}
+
+/// The code of every top-level declaration, keyed by name.
+///
+/// Extensions are keyed as `extension Name`, with all extensions of a type joined. Blank lines
+/// are dropped, so only changes to the code itself count.
+pub(crate) fn declarations_by_name(export: &Export) -> BTreeMap<String, String> {
+    let mut declarations = BTreeMap::<String, String>::new();
+    for (name, kind, code) in export.iter() {
+        let name = if kind == DeclarationKind::Extension {
+            format!("extension {}", name)
+        } else {
+            name.to_string()
+        };
+        let entry = declarations.entry(name).or_default();
+        for line in code.lines().filter(|line| !line.trim().is_empty()) {
+            entry.push_str(line.trim_end());
+            entry.push('\n');
+        }
+    }
+    declarations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declarations_are_keyed_by_name() {
+        let declarations = declarations_by_name(&Export::parse(
+            "public struct User: Codable {\n}\n\npublic indirect enum Tree<T> {\n}\n\nextension Tree: Codable {\n}\n",
+        ));
+        assert_eq!(
+            declarations.keys().collect::<Vec<_>>(),
+            ["Tree", "User", "extension Tree"]
+        );
+    }
+
+    #[test]
+    fn test_comments_belong_to_next_declaration() {
+        let declarations = declarations_by_name(&Export::parse(
+            "import Foundation\n\n/// A user\npublic struct User: Codable {\n    public let id: UInt32\n}\n",
+        ));
+        assert_eq!(
+            declarations.get("User").map(String::as_str),
+            Some("/// A user\npublic struct User: Codable {\n    public let id: UInt32\n}\n")
+        );
+    }
+}
@@ -1,30 +1,79 @@
 //! Error types for the Swift language exporter.
 
+use std::borrow::Cow;
+
+use specta::SpectaID;
 use thiserror::Error;
 
 /// Errors that can occur during Swift code generation.
 #[derive(Debug, Error)]
 pub enum Error {
     /// Swift does not support this type.
-    #[error("Unsupported type: {0}")]
-    UnsupportedType(String),
+    #[error("Unsupported type{}: {reason}", at(.path))]
+    UnsupportedType {
+        /// Where the type was found (`Type.variant.field`).
+        path: String,
+        /// Why the type is unsupported.
+        reason: String,
+    },
 
     /// Invalid identifier for Swift.
-    #[error("Invalid identifier: {0}")]
-    InvalidIdentifier(String),
+    #[error("Invalid identifier{}: {reason}", at(.path))]
+    InvalidIdentifier {
+        /// Where the identifier was found (`Type.variant.field`).
+        path: String,
+        /// Why the identifier is invalid.
+        reason: String,
+    },
+
+    /// A type refers to a type which isn't in the type collection.
+    #[error(
+        "Reference to unknown type {sid:?}{}: {}",
+        at(.path),
+        unknown_reference_hint(.path)
+    )]
+    UnknownReference {
+        /// Where the reference was found (`Type.variant.field`).
+        path: String,
+        /// The id of the missing type.
+        sid: SpectaID,
+    },
 
     /// Circular reference detected in type definitions.
-    #[error("Circular reference detected")]
-    CircularReference,
+    #[error("Circular reference detected{}", at(.path))]
+    CircularReference {
+        /// Where the reference was found (`Type.variant.field`).
+        path: String,
+    },
 
     /// Generic constraint error.
-    #[error("Generic constraint error: {0}")]
-    GenericConstraint(String),
+    #[error("Generic constraint error{}: {reason}", at(.path))]
+    GenericConstraint {
+        /// Where the generic was found (`Type.variant.field`).
+        path: String,
+        /// What went wrong.
+        reason: String,
+    },
+
+    /// The Swift compiler rejected the generated code.
+    #[error("Invalid Swift output at line {line}: {message}\n{context}")]
+    InvalidOutput {
+        /// Line of the generated code the compiler reported.
+        line: usize,
+        /// The compiler's error message.
+        message: String,
+        /// The reported line and the lines around it.
+        context: String,
+    },
 
     /// IO error during file operations.
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Writing the generated code into its buffer failed.
+    #[error("Formatting error: {0}")]
+    Fmt(#[from] std::fmt::Error),
+
     /// Serde validation error.
     #[error("Serde validation error: {0}")]
     SerdeValidation(#[from] specta_serde::Error),
@@ -42,5 +91,83 @@ pub enum Error {
     CustomNamingFailed(String),
 }
 
+impl Error {
+    /// Create an [`Error::UnsupportedType`] whose path is filled in by the caller.
+    pub(crate) fn unsupported_type(reason: impl Into<String>) -> Self {
+        Self::UnsupportedType {
+            path: String::new(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Create an [`Error::InvalidIdentifier`] whose path is filled in by the caller.
+    pub(crate) fn invalid_identifier(reason: impl Into<String>) -> Self {
+        Self::InvalidIdentifier {
+            path: String::new(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Create an [`Error::UnknownReference`] whose path is filled in by the caller.
+    pub(crate) fn unknown_reference(sid: SpectaID) -> Self {
+        Self::UnknownReference {
+            path: String::new(),
+            sid,
+        }
+    }
+
+    /// The path (`Type.variant.field`) of the type which caused the error, if known.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::UnsupportedType { path, .. }
+            | Self::InvalidIdentifier { path, .. }
+            | Self::UnknownReference { path, .. }
+            | Self::CircularReference { path }
+            | Self::GenericConstraint { path, .. }
+                if !path.is_empty() =>
+            {
+                Some(path)
+            }
+            _ => None,
+        }
+    }
+
+    /// Attach the location of the type being converted, unless a more specific one is already set.
+    pub(crate) fn with_location(mut self, location: &[Cow<'static, str>]) -> Self {
+        if let Self::UnsupportedType { path, .. }
+        | Self::InvalidIdentifier { path, .. }
+        | Self::UnknownReference { path, .. }
+        | Self::CircularReference { path }
+        | Self::GenericConstraint { path, .. } = &mut self
+        {
+            if path.is_empty() {
+                *path = location.join(".");
+            }
+        }
+        self
+    }
+}
+
+/// Format a path for an error message.
+fn at(path: &str) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!(" at `{}`", path)
+    }
+}
+
+/// How to fix a reference to a type missing from the collection found at `path`.
+fn unknown_reference_hint(path: &str) -> String {
+    let referenced_by = match path.split('.').next() {
+        Some(name) if !name.is_empty() => format!("`{}`", name),
+        _ => "an exported type".to_string(),
+    };
+    format!(
+        "{} refers to a type which isn't in the `TypeCollection`, register it with `TypeCollection::register`",
+        referenced_by
+    )
+}
+
 /// Result type alias for Swift export operations.
 pub type Result<T> = std::result::Result<T, Error>;
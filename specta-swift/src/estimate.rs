@@ -0,0 +1,70 @@
+//! Output size estimates.
+//!
+//! Generated code is written into buffers which are allocated up front from a
+//! [`SizeEstimate`], so large exports don't repeatedly grow and copy their output.
+
+use specta::datatype::{DataType, Fields, NamedDataType};
+use specta::TypeCollection;
+
+/// Heuristic for the size of the generated code, used to pre-size output buffers.
+///
+/// The defaults fit the code generated with the default configuration. Raise them when types
+/// carry long doc comments or options like [`Swift::with_mocks`](crate::Swift::with_mocks) add
+/// code, or lower them to trade re-allocations for memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeEstimate {
+    /// Bytes for the header, imports and helper types.
+    pub prelude: usize,
+    /// Bytes per type, for the declaration itself.
+    pub per_type: usize,
+    /// Bytes per struct or variant field.
+    pub per_field: usize,
+    /// Bytes per enum variant, most of which goes into the `Codable` implementation.
+    pub per_variant: usize,
+}
+
+impl Default for SizeEstimate {
+    fn default() -> Self {
+        Self {
+            prelude: 512,
+            per_type: 96,
+            per_field: 48,
+            per_variant: 512,
+        }
+    }
+}
+
+impl SizeEstimate {
+    /// Estimate the size of the code generated for a single type.
+    pub fn for_type(&self, ndt: &NamedDataType) -> usize {
+        let (fields, variants) = match ndt.ty() {
+            DataType::Struct(s) => (field_count(s.fields()), 0),
+            DataType::Enum(e) => (
+                e.variants()
+                    .iter()
+                    .map(|(_, variant)| field_count(variant.fields()))
+                    .sum(),
+                e.variants().len(),
+            ),
+            _ => (1, 0),
+        };
+        self.per_type + fields * self.per_field + variants * self.per_variant
+    }
+
+    /// Estimate the size of the code generated for a whole collection.
+    pub fn for_types(&self, types: &TypeCollection) -> usize {
+        self.prelude
+            + types
+                .into_unsorted_iter()
+                .map(|ndt| self.for_type(ndt))
+                .sum::<usize>()
+    }
+}
+
+fn field_count(fields: &Fields) -> usize {
+    match fields {
+        Fields::Unit => 0,
+        Fields::Unnamed(fields) => fields.fields().len(),
+        Fields::Named(fields) => fields.fields().len(),
+    }
+}
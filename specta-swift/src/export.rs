@@ -0,0 +1,308 @@
+//! Looking up individual declarations in generated Swift.
+//!
+//! An [`Export`] keeps the generated source along with the position of each top-level
+//! declaration, so tools embedding the exporter (documentation generators, editor plugins)
+//! can consume single types without searching the combined file:
+//!
+//! ```rust
+//! use specta::{Type, TypeCollection};
+//! use specta_swift::Swift;
+//!
+//! #[derive(Type)]
+//! enum TaskStatus {
+//!     Pending,
+//!     Done,
+//! }
+//!
+//! let export = Swift::default()
+//!     .export_declarations(&TypeCollection::default().register::<TaskStatus>())
+//!     .unwrap();
+//!
+//! assert!(export
+//!     .declaration("TaskStatus")
+//!     .unwrap()
+//!     .starts_with("public enum TaskStatus"));
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+/// The kind of a top-level Swift declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeclarationKind {
+    /// A `struct`.
+    Struct,
+    /// An `enum`.
+    Enum,
+    /// A `class`.
+    Class,
+    /// A `protocol`.
+    Protocol,
+    /// A `typealias`.
+    Typealias,
+    /// An `extension` of another declaration.
+    Extension,
+}
+
+impl DeclarationKind {
+    /// The Swift keyword introducing the declaration.
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            Self::Struct => "struct",
+            Self::Enum => "enum",
+            Self::Class => "class",
+            Self::Protocol => "protocol",
+            Self::Typealias => "typealias",
+            Self::Extension => "extension",
+        }
+    }
+
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "struct" => Self::Struct,
+            "enum" => Self::Enum,
+            "class" => Self::Class,
+            "protocol" => Self::Protocol,
+            "typealias" => Self::Typealias,
+            "extension" => Self::Extension,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for DeclarationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.keyword())
+    }
+}
+
+/// Generated Swift source, split into its top-level declarations.
+///
+/// Declarations are kept in output order. Doc comments and attributes belong to the
+/// declaration below them, and extensions are separate declarations named after the type
+/// they extend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    source: String,
+    declarations: Vec<(String, DeclarationKind, Range<usize>)>,
+}
+
+impl Export {
+    /// Split existing Swift source into its declarations.
+    pub fn parse(source: impl Into<String>) -> Self {
+        let source = source.into();
+        let declarations = parse_declarations(&source);
+        Self {
+            source,
+            declarations,
+        }
+    }
+
+    /// Source whose declarations were already located, e.g. while rendering it.
+    pub(crate) fn with_declarations(
+        source: String,
+        declarations: Vec<(String, DeclarationKind, Range<usize>)>,
+    ) -> Self {
+        Self {
+            source,
+            declarations,
+        }
+    }
+
+    /// The full generated source.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Convert into the full generated source.
+    pub fn into_source(self) -> String {
+        self.source
+    }
+
+    /// The code of the type named `name`, without its extensions.
+    pub fn declaration(&self, name: &str) -> Option<&str> {
+        self.iter()
+            .find(|(n, kind, _)| *n == name && *kind != DeclarationKind::Extension)
+            .map(|(_, _, code)| code)
+    }
+
+    /// The code of every extension of the type named `name`.
+    pub fn extensions<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.iter()
+            .filter(move |(n, kind, _)| *n == name && *kind == DeclarationKind::Extension)
+            .map(|(_, _, code)| code)
+    }
+
+    /// Iterate over the `(name, kind, code)` of every declaration in output order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, DeclarationKind, &str)> + '_ {
+        self.declarations
+            .iter()
+            .map(|(name, kind, range)| (name.as_str(), *kind, &self.source[range.clone()]))
+    }
+
+    /// Number of top-level declarations.
+    pub fn len(&self) -> usize {
+        self.declarations.len()
+    }
+
+    /// Check whether the source has no declarations.
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+}
+
+impl fmt::Display for Export {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Find the top-level declarations of Swift source, in source order.
+///
+/// Each range covers the doc comments and attributes above the declaration and ends after
+/// its last non-empty line. Anything before the first declaration (header, imports) and
+/// top-level code which is not a declaration are not part of any range.
+///
+/// Declarations end where their braces are balanced again, so the opening brace may be on
+/// the header line or on a line of its own (see [`BraceStyle`](crate::BraceStyle)). Braces in
+/// comments and string literals are ignored.
+pub(crate) fn parse_declarations(source: &str) -> Vec<(String, DeclarationKind, Range<usize>)> {
+    let mut declarations = Vec::<(String, DeclarationKind, Range<usize>)>::new();
+    let mut braces = Braces::default();
+    // Whether the last declaration continues, either with its body or until it is opened
+    let mut in_declaration = false;
+    let mut awaiting_body = false;
+    let mut pending = None::<usize>;
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let end = start + line.trim_end().len();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // Headers wrapped over several lines are indented, `{` on its own line opens the body
+        let continues_header =
+            awaiting_body && (line.starts_with(char::is_whitespace) || line.starts_with('{'));
+        let is_top_level = braces.depth == 0 && !braces.in_string && !continues_header;
+        if is_top_level {
+            if let Some((kind, name)) = declaration_header(line) {
+                declarations.push((name, kind, pending.take().unwrap_or(start)..end));
+                braces.scan(line);
+                in_declaration = braces.depth > 0;
+                awaiting_body = !in_declaration
+                    && kind != DeclarationKind::Typealias
+                    && !line.trim_end().ends_with('}');
+                continue;
+            }
+
+            // Comments and attributes are attached to the declaration which follows them
+            if line.starts_with("//") || line.starts_with('@') {
+                pending.get_or_insert(start);
+                continue;
+            }
+
+            in_declaration = false;
+            awaiting_body = false;
+        }
+
+        pending = None;
+        braces.scan(line);
+        if in_declaration || continues_header {
+            if let Some((_, _, range)) = declarations.last_mut() {
+                range.end = end;
+            }
+            in_declaration = braces.depth > 0 || braces.in_string;
+            awaiting_body = awaiting_body && braces.depth == 0 && !line.contains('{');
+        }
+    }
+
+    declarations
+}
+
+/// Move the ranges of declarations found in a part of the source by the part's `offset`.
+pub(crate) fn offset_declarations(
+    declarations: Vec<(String, DeclarationKind, Range<usize>)>,
+    offset: usize,
+) -> impl Iterator<Item = (String, DeclarationKind, Range<usize>)> {
+    declarations
+        .into_iter()
+        .map(move |(name, kind, range)| (name, kind, offset + range.start..offset + range.end))
+}
+
+/// The nesting of braces while scanning Swift source line by line.
+#[derive(Debug, Default)]
+struct Braces {
+    depth: usize,
+    /// Whether a multi-line string literal is open.
+    in_string: bool,
+}
+
+impl Braces {
+    /// Count the braces of a line which aren't part of a comment or string literal.
+    fn scan(&mut self, line: &str) {
+        let mut chars = line.chars().peekable();
+        let mut in_string = false;
+        while let Some(c) = chars.next() {
+            if self.in_string {
+                if c == '"' && line_rest_starts_with(&mut chars, "\"\"") {
+                    self.in_string = false;
+                }
+                continue;
+            }
+            match c {
+                '\\' if in_string => {
+                    chars.next();
+                }
+                '"' if !in_string && line_rest_starts_with(&mut chars, "\"\"") => {
+                    self.in_string = true;
+                }
+                '"' => in_string = !in_string,
+                '/' if !in_string && chars.peek() == Some(&'/') => break,
+                '{' if !in_string => self.depth += 1,
+                '}' if !in_string => self.depth = self.depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Consume `expected` if the remaining characters start with it.
+fn line_rest_starts_with(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+    expected: &str,
+) -> bool {
+    let mut lookahead = chars.clone();
+    if expected.chars().all(|c| lookahead.next() == Some(c)) {
+        *chars = lookahead;
+        true
+    } else {
+        false
+    }
+}
+
+/// Get the kind and name of a top-level declaration, if the line starts one.
+fn declaration_header(line: &str) -> Option<(DeclarationKind, String)> {
+    let mut words = line.split_whitespace().peekable();
+    while let Some(word) = words.peek() {
+        if matches!(
+            *word,
+            "public" | "internal" | "private" | "fileprivate" | "final" | "indirect"
+        ) {
+            words.next();
+        } else {
+            break;
+        }
+    }
+
+    let kind = DeclarationKind::from_keyword(words.next()?)?;
+    let name = words
+        .next()?
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .next()
+        .filter(|name| !name.is_empty())?;
+
+    Some((kind, name.to_string()))
+}
@@ -0,0 +1,304 @@
+//! Canonical JSON samples of the exported types.
+//!
+//! [`Swift::export_fixtures`] writes one JSON file per struct and per enum variant, following
+//! the serde representation of the type (enum tagging, newtypes, flattening). The values match
+//! the generated `mock`s, so the same golden files can be decoded by Rust serde tests and Swift
+//! decode tests.
+
+use std::fmt::Write;
+
+use specta::datatype::{
+    DataType, EnumRepr, EnumVariant, Fields, Literal, NamedDataType, Primitive,
+};
+use specta::{SpectaID, TypeCollection};
+
+use crate::datatype::optional::field_optionality;
+use crate::error::Result;
+use crate::naming::resolver::resolve_type_names;
+use crate::report::ExportReport;
+use crate::swift::Swift;
+use crate::utils::formatting::escape_string;
+
+/// Generate the fixtures of every exported type as `(file name, JSON)` pairs.
+///
+/// Structs are written to `{Type}.json` and enum variants to `{Type}.{Variant}.json`. Generic
+/// types and types which can only be built from themselves are skipped.
+pub(crate) fn generate_fixtures(
+    swift: &Swift,
+    types: &TypeCollection,
+) -> Result<Vec<(String, String)>> {
+    let resolved = resolve_type_names(swift, types, &mut ExportReport::default())?;
+
+    let mut fixtures = Vec::new();
+    for (swift_name, ndt) in &resolved.types {
+        if !ndt.generics().is_empty() {
+            continue;
+        }
+
+        let samples = Samples {
+            swift,
+            types,
+            stack: vec![ndt.sid()],
+        };
+        match ndt.ty() {
+            DataType::Enum(e) => {
+                for (variant_name, variant) in e.variants() {
+                    if variant.skip() {
+                        continue;
+                    }
+                    if let Some(json) = samples.variant(e.repr(), variant_name, variant) {
+                        fixtures.push((
+                            format!("{}.{}.json", swift_name, variant_name),
+                            json.to_pretty_string(),
+                        ));
+                    }
+                }
+            }
+            ty => {
+                if let Some(json) = samples.value(ty, ndt.name()) {
+                    fixtures.push((format!("{}.json", swift_name), json.to_pretty_string()));
+                }
+            }
+        }
+    }
+
+    Ok(fixtures)
+}
+
+/// A JSON value.
+#[derive(Debug, Clone)]
+pub(crate) enum Json {
+    Null,
+    /// A number or boolean, written verbatim.
+    Raw(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Render the value with two space indentation and a trailing newline.
+    pub(crate) fn to_pretty_string(&self) -> String {
+        let mut result = String::new();
+        self.write(&mut result, 0);
+        result.push('\n');
+        result
+    }
+
+    fn write(&self, result: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth + 1);
+        match self {
+            Self::Null => result.push_str("null"),
+            Self::Raw(raw) => result.push_str(raw),
+            Self::String(s) => {
+                let _ = write!(result, "\"{}\"", escape_string(s));
+            }
+            Self::Array(items) if items.is_empty() => result.push_str("[]"),
+            Self::Array(items) => {
+                result.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    result.push_str(&indent);
+                    item.write(result, depth + 1);
+                    result.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                result.push_str(&"  ".repeat(depth));
+                result.push(']');
+            }
+            Self::Object(entries) if entries.is_empty() => result.push_str("{}"),
+            Self::Object(entries) => {
+                result.push_str("{\n");
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    let _ = write!(result, "{}\"{}\": ", indent, escape_string(key));
+                    value.write(result, depth + 1);
+                    result.push_str(if i + 1 < entries.len() { ",\n" } else { "\n" });
+                }
+                result.push_str(&"  ".repeat(depth));
+                result.push('}');
+            }
+        }
+    }
+}
+
+/// Builds sample values, tracking the named types being sampled to avoid cycles.
+struct Samples<'a> {
+    swift: &'a Swift,
+    types: &'a TypeCollection,
+    stack: Vec<SpectaID>,
+}
+
+impl Samples<'_> {
+    /// A sample of type `ty`, named `name` for strings.
+    fn value(&self, ty: &DataType, name: &str) -> Option<Json> {
+        Some(match ty {
+            DataType::Primitive(p) => match p {
+                Primitive::f16 | Primitive::f32 | Primitive::f64 => Json::Raw("1.0".into()),
+                Primitive::bool => Json::Raw("false".into()),
+                Primitive::char => Json::String("a".into()),
+                Primitive::String => Json::String(name.into()),
+                _ => Json::Raw("1".into()),
+            },
+            DataType::Literal(l) => match l {
+                Literal::bool(v) => Json::Raw(v.to_string()),
+                Literal::String(s) => Json::String(s.to_string()),
+                Literal::char(c) => Json::String(c.to_string()),
+                Literal::None => Json::Null,
+                Literal::i8(v) => Json::Raw(v.to_string()),
+                Literal::i16(v) => Json::Raw(v.to_string()),
+                Literal::i32(v) => Json::Raw(v.to_string()),
+                Literal::u8(v) => Json::Raw(v.to_string()),
+                Literal::u16(v) => Json::Raw(v.to_string()),
+                Literal::u32(v) => Json::Raw(v.to_string()),
+                Literal::f32(v) => Json::Raw(v.to_string()),
+                Literal::f64(v) => Json::Raw(v.to_string()),
+                _ => return None,
+            },
+            // Fixed-size arrays always have their length
+            DataType::List(l) => match l.length() {
+                Some(length) => Json::Array(vec![self.value(l.ty(), name)?; length]),
+                None => Json::Array(vec![]),
+            },
+            DataType::Map(_) => Json::Object(vec![]),
+            DataType::Nullable(_) => Json::Null,
+            DataType::Struct(s) => self.fields(s.fields(), name)?,
+            DataType::Enum(e) => {
+                // Like the mocks, prefer variants without fields
+                let mut variants = e
+                    .variants()
+                    .iter()
+                    .filter(|(_, variant)| !variant.skip())
+                    .collect::<Vec<_>>();
+                variants.sort_by_key(|(_, variant)| !matches!(variant.fields(), Fields::Unit));
+                variants.into_iter().find_map(|(variant_name, variant)| {
+                    self.variant(e.repr(), variant_name, variant)
+                })?
+            }
+            DataType::Tuple(t) if t.elements().is_empty() => Json::Null,
+            DataType::Tuple(t) => Json::Array(
+                t.elements()
+                    .iter()
+                    .map(|ty| self.value(ty, name))
+                    .collect::<Option<_>>()?,
+            ),
+            DataType::Reference(r) => {
+                let referenced = self.types.get(r.sid())?;
+                match referenced.name().as_ref() {
+                    "JsonValue" => return Some(Json::Null),
+                    "Number" => return Some(Json::Raw("1.0".into())),
+                    _ => {}
+                }
+                // The JSON of user-defined special and external types depends on their Swift type
+                if self.swift.is_external(referenced) {
+                    return None;
+                }
+                if !r.generics().is_empty()
+                    || !referenced.generics().is_empty()
+                    || self.stack.contains(&referenced.sid())
+                {
+                    return None;
+                }
+
+                self.nested(referenced)
+                    .value(referenced.ty(), referenced.name())?
+            }
+            DataType::Generic(_) => return None,
+        })
+    }
+
+    /// The sample of a struct or variant body, as serde writes it.
+    fn fields(&self, fields: &Fields, name: &str) -> Option<Json> {
+        Some(match fields {
+            Fields::Unit => Json::Null,
+            Fields::Unnamed(fields) => {
+                let mut values = fields
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field.ty())
+                    .map(|ty| self.value(ty, name))
+                    .collect::<Option<Vec<_>>>()?;
+                match values.len() {
+                    0 => Json::Array(vec![]),
+                    1 => values.remove(0),
+                    _ => Json::Array(values),
+                }
+            }
+            Fields::Named(fields) => {
+                let mut entries = Vec::new();
+                for (field_name, field) in fields.fields() {
+                    let Some((ty, optionality)) = field_optionality(field) else {
+                        continue;
+                    };
+                    // Rust can't decode `null` into fields which may be missing but aren't
+                    // nullable, so they are left out
+                    let value = match optionality {
+                        optionality if optionality.omits_nil() => continue,
+                        optionality if optionality.is_optional() => Json::Null,
+                        _ => self.value(ty, field_name)?,
+                    };
+                    match value {
+                        Json::Object(flattened) if field.flatten() => entries.extend(flattened),
+                        value => entries.push((field_name.to_string(), value)),
+                    }
+                }
+                Json::Object(entries)
+            }
+        })
+    }
+
+    /// The sample of an enum variant, following the enum's tagging.
+    fn variant(
+        &self,
+        repr: Option<&EnumRepr>,
+        variant_name: &str,
+        variant: &EnumVariant,
+    ) -> Option<Json> {
+        let is_unit = match variant.fields() {
+            Fields::Unit => true,
+            Fields::Unnamed(fields) => fields.fields().is_empty(),
+            Fields::Named(_) => false,
+        };
+        let tag = |tag: &str| (tag.to_string(), Json::String(variant_name.into()));
+
+        Some(match repr.unwrap_or(&EnumRepr::External) {
+            EnumRepr::String { .. } => Json::String(variant_name.into()),
+            EnumRepr::External if is_unit => Json::String(variant_name.into()),
+            EnumRepr::External => Json::Object(vec![(
+                variant_name.into(),
+                self.fields(variant.fields(), variant_name)?,
+            )]),
+            EnumRepr::Internal { tag: tag_name } => {
+                let mut entries = vec![tag(tag_name)];
+                match self.fields(variant.fields(), variant_name)? {
+                    Json::Object(fields) => entries.extend(fields),
+                    Json::Null => {}
+                    _ => return None,
+                }
+                Json::Object(entries)
+            }
+            EnumRepr::Adjacent {
+                tag: tag_name,
+                content,
+            } => {
+                let mut entries = vec![tag(tag_name)];
+                if !is_unit {
+                    entries.push((
+                        content.to_string(),
+                        self.fields(variant.fields(), variant_name)?,
+                    ));
+                }
+                Json::Object(entries)
+            }
+            EnumRepr::Untagged => self.fields(variant.fields(), variant_name)?,
+        })
+    }
+
+    /// Samples for the fields of `ndt`, which must not refer back to types being sampled.
+    fn nested(&self, ndt: &NamedDataType) -> Samples<'_> {
+        let mut stack = self.stack.clone();
+        stack.push(ndt.sid());
+        Samples {
+            swift: self.swift,
+            types: self.types,
+            stack,
+        }
+    }
+}
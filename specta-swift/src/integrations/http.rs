@@ -0,0 +1,306 @@
+//! URLSession HTTP clients
+//!
+//! For types exposed over REST, [`HttpClient`] takes route metadata and generates an
+//! `async` client built on `URLSession`, with one typed method per route:
+//!
+//! ```swift
+//! let api = ApiClient(baseURL: URL(string: "https://example.com/api")!)
+//! let user = try await api.getUser(id: "42")
+//! ```
+//!
+//! The `JSONEncoder` and `JSONDecoder` are passed to the client's initializer, so date and
+//! key strategies are configured once for every route. Responses outside the `2xx` range
+//! are thrown as `HTTPClientError`.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use specta::datatype::DataType;
+use specta::TypeCollection;
+
+use crate::datatype::export::datatype_to_swift;
+use crate::error::{Error, Result};
+use crate::swift::Swift;
+use crate::utils::formatting::format_doc_comment;
+
+/// An HTTP method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    /// `GET`
+    Get,
+    /// `POST`
+    Post,
+    /// `PUT`
+    Put,
+    /// `PATCH`
+    Patch,
+    /// `DELETE`
+    Delete,
+}
+
+impl HttpMethod {
+    /// The method as it is sent over the wire.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+        }
+    }
+}
+
+/// Metadata describing a single route.
+#[derive(Debug, Clone)]
+pub struct Route {
+    /// Name of the generated method (converted to camelCase).
+    pub name: Cow<'static, str>,
+    /// The HTTP method.
+    pub method: HttpMethod,
+    /// The path relative to the base URL, with parameters in braces (e.g. `/users/{id}`).
+    pub path: Cow<'static, str>,
+    /// Types of the path parameters. Parameters which are not listed are `String`s.
+    pub path_params: Vec<(Cow<'static, str>, DataType)>,
+    /// Type of the JSON request body.
+    pub request: Option<DataType>,
+    /// Type of the JSON response body.
+    pub response: Option<DataType>,
+    /// Documentation for the generated method.
+    pub docs: Cow<'static, str>,
+}
+
+impl Route {
+    /// Create a route without a request or response body.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        method: HttpMethod,
+        path: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            method,
+            path: path.into(),
+            path_params: vec![],
+            request: None,
+            response: None,
+            docs: "".into(),
+        }
+    }
+
+    /// Set the type of a path parameter.
+    pub fn path_param(mut self, name: impl Into<Cow<'static, str>>, ty: DataType) -> Self {
+        self.path_params.push((name.into(), ty));
+        self
+    }
+
+    /// Set the type of the JSON request body.
+    pub fn request(mut self, ty: DataType) -> Self {
+        self.request = Some(ty);
+        self
+    }
+
+    /// Set the type of the JSON response body.
+    pub fn response(mut self, ty: DataType) -> Self {
+        self.response = Some(ty);
+        self
+    }
+
+    /// Set the documentation of the generated method.
+    pub fn docs(mut self, docs: impl Into<Cow<'static, str>>) -> Self {
+        self.docs = docs.into();
+        self
+    }
+
+    /// Names of the parameters in the path, in order.
+    fn path_param_names(&self) -> Vec<&str> {
+        self.path
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+}
+
+/// Generates an async URLSession client for a set of routes.
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    /// The routes to generate methods for.
+    pub routes: Vec<Route>,
+    /// Name of the generated client class.
+    pub client_name: Cow<'static, str>,
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        Self {
+            routes: vec![],
+            client_name: "ApiClient".into(),
+        }
+    }
+}
+
+impl HttpClient {
+    /// Create a client without routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a route.
+    pub fn route(mut self, route: Route) -> Self {
+        self.routes.push(route);
+        self
+    }
+
+    /// Set the name of the generated client class.
+    pub fn client_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.client_name = name.into();
+        self
+    }
+
+    /// Generate the Swift client.
+    pub fn export(&self, swift: &Swift, types: &TypeCollection) -> Result<String> {
+        let mut result = String::new();
+
+        result.push_str("// MARK: - HTTP Client\n\n");
+        result.push_str("/// A response with a status code outside the 2xx range.\n");
+        result.push_str("public struct HTTPClientError: Error {\n");
+        result.push_str("    public let statusCode: Int\n");
+        result.push_str("    public let body: Data\n");
+        result.push_str("}\n\n");
+
+        writeln!(result, "public final class {} {{", self.client_name)?;
+        result.push_str("    public let baseURL: URL\n");
+        result.push_str("    public let session: URLSession\n");
+        result.push_str("    public let encoder: JSONEncoder\n");
+        result.push_str("    public let decoder: JSONDecoder\n\n");
+        result.push_str("    public init(\n");
+        result.push_str("        baseURL: URL,\n");
+        result.push_str("        session: URLSession = .shared,\n");
+        result.push_str("        encoder: JSONEncoder = JSONEncoder(),\n");
+        result.push_str("        decoder: JSONDecoder = JSONDecoder()\n");
+        result.push_str("    ) {\n");
+        result.push_str("        self.baseURL = baseURL\n");
+        result.push_str("        self.session = session\n");
+        result.push_str("        self.encoder = encoder\n");
+        result.push_str("        self.decoder = decoder\n");
+        result.push_str("    }\n\n");
+
+        result.push_str(
+            "    private func send(_ method: String, _ path: String, body: Data?) async throws -> Data {\n",
+        );
+        result.push_str(
+            "        var request = URLRequest(url: baseURL.appendingPathComponent(path))\n",
+        );
+        result.push_str("        request.httpMethod = method\n");
+        result.push_str(
+            "        request.setValue(\"application/json\", forHTTPHeaderField: \"Accept\")\n",
+        );
+        result.push_str("        if let body = body {\n");
+        result.push_str("            request.httpBody = body\n");
+        result.push_str(
+            "            request.setValue(\"application/json\", forHTTPHeaderField: \"Content-Type\")\n",
+        );
+        result.push_str("        }\n");
+        result.push_str("        let (data, response) = try await session.data(for: request)\n");
+        result.push_str("        if let response = response as? HTTPURLResponse, !(200..<300).contains(response.statusCode) {\n");
+        result.push_str(
+            "            throw HTTPClientError(statusCode: response.statusCode, body: data)\n",
+        );
+        result.push_str("        }\n");
+        result.push_str("        return data\n");
+        result.push_str("    }\n");
+
+        for route in &self.routes {
+            result.push('\n');
+            result.push_str(&self.export_route(swift, types, route)?);
+        }
+
+        result.push_str("}\n");
+
+        Ok(result)
+    }
+
+    /// Generate the client method for a route.
+    fn export_route(&self, swift: &Swift, types: &TypeCollection, route: &Route) -> Result<String> {
+        let convert = |ty: &DataType, segment: &str| {
+            let location = vec![route.name.clone(), segment.to_string().into()];
+            datatype_to_swift(swift, types, ty, location, false, None)
+        };
+
+        if route.method == HttpMethod::Get && route.request.is_some() {
+            return Err(Error::Configuration(format!(
+                "route '{}' is a GET request and can't have a request body",
+                route.name
+            )));
+        }
+
+        let mut params = Vec::new();
+        let mut path = route.path.to_string();
+        for name in route.path_param_names() {
+            let swift_name = swift.field_name(name);
+            let swift_type = match route.path_params.iter().find(|(param, _)| param == name) {
+                Some((_, ty)) => convert(ty, name)?,
+                None => "String".to_string(),
+            };
+            path = path.replace(&format!("{{{}}}", name), &format!("\\({})", swift_name));
+            params.push(format!("{}: {}", swift_name, swift_type));
+        }
+
+        let body = match &route.request {
+            Some(ty) => {
+                params.push(format!("_ body: {}", convert(ty, "request")?));
+                "try encoder.encode(body)"
+            }
+            None => "nil",
+        };
+
+        let response = route
+            .response
+            .as_ref()
+            .map(|ty| convert(ty, "response"))
+            .transpose()?
+            .filter(|ty| ty != "Void");
+
+        let mut result = String::new();
+        if !route.docs.is_empty() {
+            result.push_str(
+                &format_doc_comment(&route.docs)
+                    .lines()
+                    .map(|line| format!("    {}\n", line))
+                    .collect::<String>(),
+            );
+        }
+        writeln!(
+            result,
+            "    public func {}({}) async throws{} {{",
+            swift.field_name(&route.name),
+            params.join(", "),
+            response
+                .as_ref()
+                .map(|ty| format!(" -> {}", ty))
+                .unwrap_or_default()
+        )?;
+
+        let send = format!(
+            "try await send(\"{}\", \"{}\", body: {})",
+            route.method.as_str(),
+            path,
+            body
+        );
+        match &response {
+            Some(ty) => {
+                writeln!(result, "        let data = {}", send)?;
+                writeln!(
+                    result,
+                    "        return try decoder.decode({}.self, from: data)",
+                    ty
+                )?;
+            }
+            None => writeln!(result, "        _ = {}", send)?,
+        }
+        result.push_str("    }\n");
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,20 @@
+//! Generators for Swift code which talks to a Rust backend
+//!
+//! While the rest of the crate turns Rust types into Swift types, this module uses
+//! metadata about how those types are exchanged (commands, events, routes, …) to generate
+//! typed Swift clients:
+//!
+//! - `http`: An async URLSession client for REST routes
+//! - `subscriptions`: Async sequences for streaming subscriptions
+//! - `tauri`: Client functions for tauri-specta commands and typed event listeners
+//!
+//! Every generator produces Swift source which is meant to be appended to the output of
+//! [`Swift::export`](crate::Swift::export), as it references the exported types.
+
+pub mod http;
+pub mod subscriptions;
+pub mod tauri;
+
+pub use http::{HttpClient, HttpMethod, Route};
+pub use subscriptions::{Subscription, SubscriptionStyle, Subscriptions};
+pub use tauri::{TauriCommands, TauriEvents};
@@ -0,0 +1,261 @@
+//! Streaming subscriptions
+//!
+//! rspc-style subscriptions push a stream of values from Rust to the client. [`Subscriptions`]
+//! generates one method per subscription returning an `AsyncThrowingStream`, which decodes
+//! each message as it arrives:
+//!
+//! ```swift
+//! let subscriptions = Subscriptions(transport: myTransport)
+//! for try await progress in subscriptions.jobProgress(jobId) {
+//!     print(progress.percent)
+//! }
+//! ```
+//!
+//! The transport is provided by the app through the generated `SubscriptionTransport`
+//! protocol. It receives the subscription name and its JSON encoded input, and yields the
+//! JSON encoded messages. Cancelling the consuming task cancels the underlying stream.
+//!
+//! With [`SubscriptionStyle::Combine`] the methods and the transport use `AnyPublisher`
+//! instead, for codebases which still support iOS 13 and 14.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use specta::datatype::DataType;
+use specta::TypeCollection;
+
+use crate::datatype::export::datatype_to_swift;
+use crate::error::Result;
+use crate::swift::Swift;
+use crate::utils::formatting::format_doc_comment;
+
+/// Metadata describing a single subscription.
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    /// Name of the subscription, as sent to the transport.
+    pub name: Cow<'static, str>,
+    /// Type of the input sent when subscribing.
+    pub input: Option<DataType>,
+    /// Type of every value yielded by the subscription.
+    pub item: DataType,
+    /// Documentation for the generated method.
+    pub docs: Cow<'static, str>,
+}
+
+impl Subscription {
+    /// Create a subscription without input.
+    pub fn new(name: impl Into<Cow<'static, str>>, item: DataType) -> Self {
+        Self {
+            name: name.into(),
+            input: None,
+            item,
+            docs: "".into(),
+        }
+    }
+
+    /// Set the type of the input sent when subscribing.
+    pub fn input(mut self, ty: DataType) -> Self {
+        self.input = Some(ty);
+        self
+    }
+
+    /// Set the documentation of the generated method.
+    pub fn docs(mut self, docs: impl Into<Cow<'static, str>>) -> Self {
+        self.docs = docs.into();
+        self
+    }
+}
+
+/// The kind of sequence returned by generated subscription methods.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SubscriptionStyle {
+    /// `AsyncThrowingStream<T, Error>` (iOS 15+)
+    #[default]
+    AsyncStream,
+    /// Combine's `AnyPublisher<T, Error>`, for apps which still support iOS 13 and 14
+    Combine,
+}
+
+impl SubscriptionStyle {
+    /// The Swift type of a sequence of `item`s.
+    fn sequence_type(&self, item: &str) -> String {
+        match self {
+            Self::AsyncStream => format!("AsyncThrowingStream<{}, Error>", item),
+            Self::Combine => format!("AnyPublisher<{}, Error>", item),
+        }
+    }
+}
+
+/// Generates typed async sequences for a set of subscriptions.
+#[derive(Debug, Clone)]
+pub struct Subscriptions {
+    /// The subscriptions to generate methods for.
+    pub subscriptions: Vec<Subscription>,
+    /// Name of the generated client struct.
+    pub client_name: Cow<'static, str>,
+    /// The kind of sequence returned by the generated methods.
+    pub style: SubscriptionStyle,
+}
+
+impl Default for Subscriptions {
+    fn default() -> Self {
+        Self {
+            subscriptions: vec![],
+            client_name: "Subscriptions".into(),
+            style: SubscriptionStyle::default(),
+        }
+    }
+}
+
+impl Subscriptions {
+    /// Create a client without subscriptions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a subscription.
+    pub fn subscription(mut self, subscription: Subscription) -> Self {
+        self.subscriptions.push(subscription);
+        self
+    }
+
+    /// Set the name of the generated client struct.
+    pub fn client_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.client_name = name.into();
+        self
+    }
+
+    /// Set the kind of sequence returned by the generated methods.
+    pub fn style(mut self, style: SubscriptionStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Generate the Swift client.
+    pub fn export(&self, swift: &Swift, types: &TypeCollection) -> Result<String> {
+        let mut result = String::new();
+
+        result.push_str("// MARK: - Subscriptions\n\n");
+        if self.style == SubscriptionStyle::Combine {
+            result.push_str("import Combine\n\n");
+        }
+        result.push_str(
+            "/// Opens a subscription on the Rust side and yields its JSON encoded messages.\n",
+        );
+        result.push_str("public protocol SubscriptionTransport {\n");
+        writeln!(
+            result,
+            "    func subscribe(_ name: String, input: Data) -> {}",
+            self.style.sequence_type("Data")
+        )?;
+        result.push_str("}\n\n");
+
+        writeln!(result, "public struct {} {{", self.client_name)?;
+        result.push_str("    public let transport: SubscriptionTransport\n");
+        result.push_str("    public var encoder = JSONEncoder()\n");
+        result.push_str("    public var decoder = JSONDecoder()\n\n");
+        result.push_str("    public init(transport: SubscriptionTransport) {\n");
+        result.push_str("        self.transport = transport\n");
+        result.push_str("    }\n\n");
+
+        match self.style {
+            SubscriptionStyle::AsyncStream => {
+                result.push_str("    private func stream<T: Decodable>(_ name: String, input: @escaping () throws -> Data) -> AsyncThrowingStream<T, Error> {\n");
+                result.push_str("        let transport = self.transport\n");
+                result.push_str("        let decoder = self.decoder\n");
+                result.push_str("        return AsyncThrowingStream { continuation in\n");
+                result.push_str("            let task = Task {\n");
+                result.push_str("                do {\n");
+                result.push_str(
+                    "                    for try await message in transport.subscribe(name, input: try input()) {\n",
+                );
+                result.push_str(
+                    "                        continuation.yield(try decoder.decode(T.self, from: message))\n",
+                );
+                result.push_str("                    }\n");
+                result.push_str("                    continuation.finish()\n");
+                result.push_str("                } catch {\n");
+                result.push_str("                    continuation.finish(throwing: error)\n");
+                result.push_str("                }\n");
+                result.push_str("            }\n");
+                result
+                    .push_str("            continuation.onTermination = { _ in task.cancel() }\n");
+                result.push_str("        }\n");
+                result.push_str("    }\n");
+            }
+            SubscriptionStyle::Combine => {
+                result.push_str("    private func stream<T: Decodable>(_ name: String, input: () throws -> Data) -> AnyPublisher<T, Error> {\n");
+                result.push_str("        let decoder = self.decoder\n");
+                result.push_str("        let data: Data\n");
+                result.push_str("        do {\n");
+                result.push_str("            data = try input()\n");
+                result.push_str("        } catch {\n");
+                result.push_str("            return Fail(error: error).eraseToAnyPublisher()\n");
+                result.push_str("        }\n");
+                result.push_str("        return transport.subscribe(name, input: data)\n");
+                result.push_str("            .tryMap { try decoder.decode(T.self, from: $0) }\n");
+                result.push_str("            .eraseToAnyPublisher()\n");
+                result.push_str("    }\n");
+            }
+        }
+
+        for subscription in &self.subscriptions {
+            result.push('\n');
+            result.push_str(&self.export_subscription(swift, types, subscription)?);
+        }
+
+        result.push_str("}\n");
+
+        Ok(result)
+    }
+
+    /// Generate the client method for a subscription.
+    fn export_subscription(
+        &self,
+        swift: &Swift,
+        types: &TypeCollection,
+        subscription: &Subscription,
+    ) -> Result<String> {
+        let convert = |ty: &DataType, segment: &'static str| {
+            let location = vec![subscription.name.clone(), segment.into()];
+            datatype_to_swift(swift, types, ty, location, false, None)
+        };
+
+        let item = convert(&subscription.item, "item")?;
+        let (param, input) = match &subscription.input {
+            Some(ty) => (
+                format!("_ input: {}", convert(ty, "input")?),
+                "{ try encoder.encode(input) }",
+            ),
+            None => (String::new(), "{ Data(\"null\".utf8) }"),
+        };
+
+        let mut result = String::new();
+        if !subscription.docs.is_empty() {
+            result.push_str(
+                &format_doc_comment(&subscription.docs)
+                    .lines()
+                    .map(|line| format!("    {}\n", line))
+                    .collect::<String>(),
+            );
+        }
+        writeln!(
+            result,
+            "    public func {}({}) -> {} {{",
+            swift.field_name(&subscription.name),
+            param,
+            self.style.sequence_type(&item)
+        )?;
+        if subscription.input.is_some() {
+            result.push_str("        let encoder = self.encoder\n");
+        }
+        writeln!(
+            result,
+            "        return stream(\"{}\", input: {})",
+            subscription.name, input
+        )?;
+        result.push_str("    }\n");
+
+        Ok(result)
+    }
+}
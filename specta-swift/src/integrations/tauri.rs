@@ -0,0 +1,329 @@
+//! tauri-specta command and event clients
+//!
+//! tauri-specta describes every command as a [`Function`]. [`TauriCommands`] turns those
+//! into a Swift client with one `async throws` method per command, so iOS code never has
+//! to spell out command names or argument keys by hand:
+//!
+//! ```swift
+//! let commands = TauriCommands(invoker: myBridge)
+//! let user = try await commands.getUser(id: 42)
+//! ```
+//!
+//! The transport is provided by the app through the generated `TauriInvoker` protocol. It
+//! receives the command name and its JSON encoded arguments, and returns the JSON encoded
+//! response. Errors returned by a command (`Result::Err`) should be thrown by the invoker.
+//!
+//! [`TauriEvents`] does the same for events, generating one typed listener per event:
+//!
+//! ```swift
+//! let events = TauriEvents(source: myBridge)
+//! let unlisten = events.onJobProgress { progress in print(progress.percent) }
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use specta::datatype::{DataType, Function, FunctionReturnType};
+use specta::TypeCollection;
+
+use crate::datatype::export::datatype_to_swift;
+use crate::error::Result;
+use crate::naming::case_conversion::{to_pascal_case, upper_char};
+use crate::swift::Swift;
+use crate::utils::formatting::{format_deprecated_type, format_doc_comment, indent};
+
+/// Generates a typed Swift client for tauri-specta commands.
+#[derive(Debug, Clone)]
+pub struct TauriCommands {
+    /// The commands to generate methods for.
+    pub functions: Vec<Function>,
+    /// Name of the generated client struct.
+    pub client_name: Cow<'static, str>,
+}
+
+impl TauriCommands {
+    /// Create a generator for the given commands.
+    pub fn new(functions: impl IntoIterator<Item = Function>) -> Self {
+        Self {
+            functions: functions.into_iter().collect(),
+            client_name: "TauriCommands".into(),
+        }
+    }
+
+    /// Set the name of the generated client struct.
+    pub fn client_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.client_name = name.into();
+        self
+    }
+
+    /// Generate the Swift client.
+    pub fn export(&self, swift: &Swift, types: &TypeCollection) -> Result<String> {
+        let mut result = String::new();
+
+        result.push_str("// MARK: - Tauri Commands\n\n");
+        result.push_str(
+            "/// Sends a command to the Rust side and returns its JSON encoded response.\n",
+        );
+        result.push_str("public protocol TauriInvoker {\n");
+        result.push_str("    func invoke(_ command: String, args: Data) async throws -> Data\n");
+        result.push_str("}\n\n");
+
+        let mut methods = Vec::new();
+        for function in &self.functions {
+            let (args_struct, method) = self.export_command(swift, types, function)?;
+            if let Some(args_struct) = args_struct {
+                result.push_str(&args_struct);
+                result.push('\n');
+            }
+            methods.push(method);
+        }
+
+        writeln!(result, "public struct {} {{", self.client_name)?;
+        result.push_str("    public let invoker: TauriInvoker\n");
+        result.push_str("    public var encoder = JSONEncoder()\n");
+        result.push_str("    public var decoder = JSONDecoder()\n\n");
+        result.push_str("    public init(invoker: TauriInvoker) {\n");
+        result.push_str("        self.invoker = invoker\n");
+        result.push_str("    }\n");
+        for method in methods {
+            result.push('\n');
+            result.push_str(&indent(&method, 1));
+            result.push('\n');
+        }
+        result.push_str("}\n");
+
+        Ok(result)
+    }
+
+    /// Generate the arguments struct (if the command takes arguments) and the client method.
+    fn export_command(
+        &self,
+        swift: &Swift,
+        types: &TypeCollection,
+        function: &Function,
+    ) -> Result<(Option<String>, String)> {
+        let command_name = function.name();
+        let args_struct_name = format!("{}Args", to_pascal_case(command_name));
+
+        // Tauri expects argument keys in camelCase, which matches the Swift property names
+        let mut args = Vec::new();
+        for (arg_name, ty) in function.args() {
+            let location = vec![command_name.clone(), arg_name.clone()];
+            let swift_type = datatype_to_swift(swift, types, ty, location, false, None)?;
+            args.push((swift.field_name(arg_name), swift_type));
+        }
+
+        let return_type = match function.result() {
+            Some(FunctionReturnType::Value(ty)) | Some(FunctionReturnType::Result(ty, _)) => {
+                let location = vec![command_name.clone()];
+                datatype_to_swift(swift, types, ty, location, false, None)?
+            }
+            None => "Void".to_string(),
+        };
+
+        let args_struct = if args.is_empty() {
+            None
+        } else {
+            let mut result = format!(
+                "/// Arguments of the `{}` command.\npublic struct {}: Codable {{\n",
+                command_name, args_struct_name
+            );
+            for (name, ty) in &args {
+                writeln!(result, "    public let {}: {}", name, ty)?;
+            }
+            result.push_str("}\n");
+            Some(result)
+        };
+
+        let mut method = String::new();
+        if !function.docs().is_empty() {
+            method.push_str(&format_doc_comment(function.docs()));
+        }
+        if let Some(deprecated) = function.deprecated() {
+            method.push_str(&format_deprecated_type(
+                deprecated,
+                "This command is deprecated",
+                |name| swift.field_name(name),
+            ));
+        }
+
+        let params = args
+            .iter()
+            .map(|(name, ty)| format!("{}: {}", name, ty))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let returns = if return_type == "Void" {
+            String::new()
+        } else {
+            format!(" -> {}", return_type)
+        };
+        writeln!(
+            method,
+            "public func {}({}) async throws{} {{",
+            swift.field_name(command_name),
+            params,
+            returns
+        )?;
+
+        if args.is_empty() {
+            method.push_str("    let args = Data(\"{}\".utf8)\n");
+        } else {
+            let values = args
+                .iter()
+                .map(|(name, _)| format!("{}: {}", name, name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                method,
+                "    let args = try encoder.encode({}({}))",
+                args_struct_name, values
+            )?;
+        }
+
+        if returns.is_empty() {
+            writeln!(
+                method,
+                "    _ = try await invoker.invoke(\"{}\", args: args)",
+                command_name
+            )?;
+        } else {
+            writeln!(
+                method,
+                "    let response = try await invoker.invoke(\"{}\", args: args)",
+                command_name
+            )?;
+            writeln!(
+                method,
+                "    return try decoder.decode({}.self, from: response)",
+                return_type
+            )?;
+        }
+        method.push('}');
+
+        Ok((args_struct, method))
+    }
+}
+
+/// Generates typed Swift listeners for events emitted by the Rust side.
+///
+/// This works with tauri-specta events as well as any other registry mapping event names
+/// to payload types.
+#[derive(Debug, Clone)]
+pub struct TauriEvents {
+    /// Event names paired with the type of their payload.
+    pub events: Vec<(Cow<'static, str>, DataType)>,
+    /// Name of the generated listener struct.
+    pub client_name: Cow<'static, str>,
+}
+
+impl Default for TauriEvents {
+    fn default() -> Self {
+        Self {
+            events: vec![],
+            client_name: "TauriEvents".into(),
+        }
+    }
+}
+
+impl TauriEvents {
+    /// Create an empty event registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an event and the type of its payload.
+    pub fn event(mut self, name: impl Into<Cow<'static, str>>, payload: DataType) -> Self {
+        self.events.push((name.into(), payload));
+        self
+    }
+
+    /// Set the name of the generated listener struct.
+    pub fn client_name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.client_name = name.into();
+        self
+    }
+
+    /// Generate the Swift listeners.
+    pub fn export(&self, swift: &Swift, types: &TypeCollection) -> Result<String> {
+        let mut result = String::new();
+
+        result.push_str("// MARK: - Tauri Events\n\n");
+        result.push_str("/// Stops listening to an event.\n");
+        result.push_str("public typealias TauriUnlisten = () -> Void\n\n");
+        result.push_str(
+            "/// Delivers the JSON encoded payloads of events emitted by the Rust side.\n",
+        );
+        result.push_str("public protocol TauriEventSource {\n");
+        result.push_str(
+            "    func listen(_ event: String, handler: @escaping (Data) -> Void) -> TauriUnlisten\n",
+        );
+        result.push_str("}\n\n");
+
+        writeln!(result, "public struct {} {{", self.client_name)?;
+        result.push_str("    public let source: TauriEventSource\n");
+        result.push_str("    public var decoder = JSONDecoder()\n\n");
+        result.push_str("    public init(source: TauriEventSource) {\n");
+        result.push_str("        self.source = source\n");
+        result.push_str("    }\n");
+
+        for (name, payload) in &self.events {
+            let location = vec![name.clone()];
+            let payload_type = datatype_to_swift(swift, types, payload, location, false, None)?;
+            let method_name = format!("on{}", event_method_suffix(name));
+
+            writeln!(
+                result,
+                "\n    /// Listen to the `{}` event. Payloads which fail to decode are ignored.",
+                name
+            )?;
+            result.push_str("    @discardableResult\n");
+
+            // Events without a payload don't decode anything
+            if payload_type == "Void" {
+                writeln!(
+                    result,
+                    "    public func {}(_ handler: @escaping () -> Void) -> TauriUnlisten {{",
+                    method_name
+                )?;
+                writeln!(result, "        return source.listen(\"{}\") {{ _ in", name)?;
+                result.push_str("            handler()\n");
+            } else {
+                writeln!(
+                    result,
+                    "    public func {}(_ handler: @escaping ({}) -> Void) -> TauriUnlisten {{",
+                    method_name, payload_type
+                )?;
+                result.push_str("        let decoder = self.decoder\n");
+                writeln!(
+                    result,
+                    "        return source.listen(\"{}\") {{ data in",
+                    name
+                )?;
+                writeln!(result,
+                    "            guard let payload = try? decoder.decode({}.self, from: data) else {{ return }}",
+                    payload_type
+                )?;
+                result.push_str("            handler(payload)\n");
+            }
+            result.push_str("        }\n");
+            result.push_str("    }\n");
+        }
+
+        result.push_str("}\n");
+
+        Ok(result)
+    }
+}
+
+/// Turn an event name such as `job-progress` or `jobProgress` into `JobProgress`.
+fn event_method_suffix(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => std::iter::once(upper_char(first)).chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
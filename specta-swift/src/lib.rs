@@ -50,18 +50,60 @@
 )]
 
 // Core modules
+mod cache;
+mod cloudkit;
+mod coders;
+mod constant;
+mod core_data;
+mod diff;
 mod error;
+mod estimate;
+mod export;
+mod fixtures;
+mod manifest;
+mod mock;
+mod report;
+mod roots;
+mod schema;
 mod swift;
+#[cfg(feature = "swift-check")]
+mod verify;
+mod xctest;
 
 // New modular architecture (public for testing and external use)
+pub mod ast;
+#[cfg(feature = "cli")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cli")))]
+pub mod cli;
 pub mod codable;
+pub mod compat;
+#[cfg(feature = "toml")]
+#[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+pub mod config;
 pub mod datatype;
+pub mod integrations;
 pub mod naming;
 pub mod special_types;
+pub mod stress;
 pub mod utils;
 
+pub use constant::{Constant, SwiftConstant};
+pub use diff::Diff;
 pub use error::Error;
+pub use estimate::SizeEstimate;
+pub use export::{DeclarationKind, Export};
+pub use naming::CaseConverter;
+pub use report::{
+    CodeFeature, DuplicateResolution, ExportReport, ExportWarning, ExportedType,
+    UnresolvedReference,
+};
+pub use roots::{Root, RootTypes};
+pub use schema::schema_hash;
+pub use special_types::{HelperRegistry, HelperType, SpecialType, SpecialTypeHandler};
 pub use swift::{
-    DuplicateNameStrategy, GenericStyle, IndentStyle, NamingConvention, OptionalStyle,
-    StructNamingStrategy, Swift,
+    AstPostProcessor, BraceStyle, CharType, CodableFormat, DataStrategy, DateStrategy,
+    DuplicateContext, DuplicateNameStrategy, GenericStyle, IndentStyle, KeyStrategy,
+    MissingCollectionStrategy, MissingReferenceStrategy, NamingConvention, NewtypeStyle,
+    OptionSetFlags, OptionalCollectionStrategy, OptionalStyle, PostProcessor, QualifyOptions,
+    StructNamingStrategy, Swift, TypeFilter, UnicodeIdentifiers, VariantOrdering,
 };
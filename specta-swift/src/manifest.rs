@@ -0,0 +1,80 @@
+//! A JSON manifest of the generated names.
+//!
+//! [`Swift::export_manifest`] maps every exported Rust type (`module::Type`) to its Swift
+//! name, so other tooling generated from the same types (documentation, analytics schemas)
+//! can refer to the names the bindings actually use, including types renamed by the
+//! [`DuplicateNameStrategy`] and the helper structs created by the exporter.
+
+use std::io;
+
+use specta::TypeCollection;
+
+use crate::error::Result;
+use crate::fixtures::Json;
+use crate::swift::{DuplicateNameStrategy, Swift};
+
+/// Generate the manifest of the names used by an export.
+///
+/// Types are sorted by their Rust path so the manifest is stable between runs.
+pub(crate) fn generate_manifest(swift: &Swift, types: &TypeCollection) -> Result<String> {
+    let report = swift.export_into_with_report(&mut io::sink(), types)?;
+
+    let mut exported = report.exported_types.iter().collect::<Vec<_>>();
+    exported.sort_by_key(|ty| rust_path(&ty.module_path, &ty.name));
+
+    let types = exported
+        .into_iter()
+        .map(|ty| {
+            let renamed = report.duplicate_resolutions.iter().any(|resolution| {
+                resolution.module_path == ty.module_path
+                    && resolution.original_name == ty.name
+                    && resolution.swift_name == ty.swift_name
+            });
+            Json::Object(vec![
+                (
+                    "rust".into(),
+                    Json::String(rust_path(&ty.module_path, &ty.name)),
+                ),
+                ("swift".into(), Json::String(ty.swift_name.clone())),
+                ("renamed".into(), Json::Raw(renamed.to_string())),
+            ])
+        })
+        .collect();
+
+    let mut helper_types = report.helper_types.clone();
+    helper_types.sort();
+
+    Ok(Json::Object(vec![
+        (
+            "duplicate_name_strategy".into(),
+            Json::String(strategy_name(&swift.duplicate_name_strategy).into()),
+        ),
+        ("types".into(), Json::Array(types)),
+        (
+            "helper_types".into(),
+            Json::Array(helper_types.into_iter().map(Json::String).collect()),
+        ),
+    ])
+    .to_pretty_string())
+}
+
+/// The fully qualified path of a Rust type.
+fn rust_path(module_path: &str, name: &str) -> String {
+    if module_path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}::{}", module_path, name)
+    }
+}
+
+/// The name of a strategy, as accepted by its `FromStr` implementation.
+fn strategy_name(strategy: &DuplicateNameStrategy) -> &'static str {
+    match strategy {
+        DuplicateNameStrategy::Warn => "warn",
+        DuplicateNameStrategy::Error => "error",
+        DuplicateNameStrategy::Qualify => "qualify",
+        DuplicateNameStrategy::Suffix => "suffix",
+        DuplicateNameStrategy::Custom(_) => "custom",
+        DuplicateNameStrategy::CustomWithContext(_) => "custom-with-context",
+    }
+}
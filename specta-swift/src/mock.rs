@@ -0,0 +1,458 @@
+//! Placeholder values for SwiftUI previews and tests.
+//!
+//! With [`Swift::with_mocks`] every exported struct and enum gets a `static var mock: Self`
+//! (and structs a `mock(...)` factory whose parameters default to the placeholder values),
+//! wrapped in `#if DEBUG` so they never ship in release builds.
+//!
+//! [`Swift::with_preview_data`] additionally emits a `PreviewData` namespace with arrays of
+//! varied samples (every enum case, optional fields both `nil` and set).
+
+use std::fmt::Write;
+
+use specta::datatype::{DataType, EnumVariant, Fields, NamedDataType, Primitive};
+use specta::{SpectaID, TypeCollection};
+
+use crate::datatype::export::datatype_to_swift;
+use crate::datatype::inline::is_inlined;
+use crate::datatype::newtype::raw_value_type;
+use crate::datatype::optional::{empty_collection, field_optionality, optional_type, Optionality};
+use crate::datatype::primitives::literal_to_swift;
+use crate::error::Result;
+use crate::naming::fields::field_names;
+use crate::naming::resolver::VariantStructNames;
+use crate::special_types::detection::is_special_std_type;
+use crate::special_types::duration::is_duration_struct;
+use crate::special_types::helpers::tuple_helper_name;
+use crate::swift::Swift;
+use crate::utils::formatting::escape_string;
+
+/// Generate the mock extension for an exported type.
+///
+/// Returns `None` for types which can't be mocked: generic types, and types which can only be
+/// constructed from themselves or from types without a placeholder value.
+pub(crate) fn generate_mock_extension(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &NamedDataType,
+    swift_name: &str,
+    variant_structs: &VariantStructNames,
+) -> Result<Option<String>> {
+    if !ndt.generics().is_empty() {
+        return Ok(None);
+    }
+
+    let mocks = Mocks {
+        swift,
+        types,
+        variant_structs,
+        stack: vec![ndt.sid()],
+    };
+    let location = vec![ndt.name().clone()];
+
+    let members = match ndt.ty() {
+        // Option sets are empty by default, whatever their Rust type
+        _ if swift.option_set_flags(ndt).is_some() => {
+            "    public static var mock: Self { [] }\n".to_string()
+        }
+        DataType::Struct(s) => match s.fields() {
+            Fields::Named(fields) => {
+                let mut params = Vec::new();
+                let mut args = Vec::new();
+                let names = field_names(swift, fields);
+                for ((name, field), swift_name) in fields.fields().iter().zip(names) {
+                    let Some((ty, mut optionality)) = field_optionality(field) else {
+                        continue;
+                    };
+                    // Inlined fields refer to hoisted types, which have no mock
+                    if is_inlined(swift, types, field) {
+                        return Ok(None);
+                    }
+                    if empty_collection(swift, ty, optionality).is_some() {
+                        optionality = Optionality::default();
+                    }
+                    let value = if optionality.is_optional() {
+                        Some("nil".to_string())
+                    } else {
+                        mocks.value(ty, name)
+                    };
+                    let Some(value) = value else {
+                        return Ok(None);
+                    };
+
+                    let mut location = location.clone();
+                    location.push(name.clone());
+                    let mut field_type =
+                        datatype_to_swift(swift, types, ty, location, false, None)?;
+                    if optionality.is_optional() {
+                        field_type = optional_type(swift, field_type);
+                    }
+
+                    params.push(format!("{}: {} = {}", swift_name, field_type, value));
+                    args.push(format!("{}: {}", swift_name, swift_name));
+                }
+
+                if params.is_empty() {
+                    "    public static var mock: Self { Self() }\n".to_string()
+                } else {
+                    format!(
+                        "    public static var mock: Self {{ .mock() }}\n\n    public static func mock({}) -> Self {{\n        Self({})\n    }}\n",
+                        params.join(", "),
+                        args.join(", ")
+                    )
+                }
+            }
+            Fields::Unnamed(fields) => {
+                let values = fields
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field.ty())
+                    .map(|ty| mocks.value(ty, ndt.name()))
+                    .collect::<Option<Vec<_>>>();
+                let Some(values) = values else {
+                    return Ok(None);
+                };
+
+                let args = match values.as_slice() {
+                    [] => String::new(),
+                    [value] if raw_value_type(swift, ndt).is_some() => {
+                        format!("rawValue: {}", value)
+                    }
+                    [value] => format!("value: {}", value),
+                    values => values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, value)| format!("field{}: {}", i, value))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+                format!("    public static var mock: Self {{ Self({}) }}\n", args)
+            }
+            Fields::Unit => "    public static var mock: Self { Self() }\n".to_string(),
+        },
+        DataType::Enum(_) => match mocks.enum_value(ndt, swift_name) {
+            Some(value) => format!("    public static var mock: Self {{ {} }}\n", value),
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    Ok(Some(format!(
+        "#if DEBUG\nextension {} {{\n{}}}\n#endif",
+        swift_name, members
+    )))
+}
+
+/// Generate the `PreviewData` namespace with varied samples of every mockable type.
+///
+/// Enums get one sample per case. Structs get their `mock`, plus a sample with every optional
+/// field set and a `nil` in collections of optional elements, when they have any.
+pub(crate) fn generate_preview_data(
+    swift: &Swift,
+    types: &TypeCollection,
+    exported: &[(String, NamedDataType)],
+    variant_structs: &VariantStructNames,
+) -> Result<String> {
+    let mut result = String::new();
+    result.push_str("#if DEBUG\n");
+    result.push_str("/// Sample values of the generated types for SwiftUI previews.\n");
+    result.push_str("public enum PreviewData {\n");
+
+    for (swift_name, ndt) in exported {
+        let Some(samples) = generate_samples(swift, types, ndt, swift_name, variant_structs)?
+        else {
+            continue;
+        };
+
+        writeln!(
+            result,
+            "    public static let {}: [{}] = [",
+            swift.field_name(swift_name),
+            swift_name
+        )?;
+        for sample in samples {
+            writeln!(result, "        {},", sample)?;
+        }
+        result.push_str("    ]\n");
+    }
+
+    result.push_str("}\n");
+    result.push_str("#endif");
+
+    Ok(result)
+}
+
+/// Varied sample expressions of a type, as implicit member expressions (e.g. `.mock`).
+///
+/// Returns `None` for types without a mock.
+pub(crate) fn generate_samples(
+    swift: &Swift,
+    types: &TypeCollection,
+    ndt: &NamedDataType,
+    swift_name: &str,
+    variant_structs: &VariantStructNames,
+) -> Result<Option<Vec<String>>> {
+    if generate_mock_extension(swift, types, ndt, swift_name, variant_structs)?.is_none() {
+        return Ok(None);
+    }
+
+    let mocks = Mocks {
+        swift,
+        types,
+        variant_structs,
+        stack: vec![ndt.sid()],
+    };
+    Ok(Some(match ndt.ty() {
+        DataType::Enum(_) => mocks.enum_values(ndt, swift_name),
+        DataType::Struct(s) => {
+            let mut samples = vec![".mock".to_string()];
+            if let Fields::Named(fields) = s.fields() {
+                let overrides = fields
+                    .fields()
+                    .iter()
+                    .zip(field_names(swift, fields))
+                    .filter_map(|((name, field), swift_name)| {
+                        let (ty, optionality) = field_optionality(field)?;
+                        let value = match ty {
+                            ty if optionality.is_optional() => mocks.value(ty, name)?,
+                            // Collections with nullable elements also round-trip a `nil`
+                            DataType::List(l)
+                                if l.length().is_none()
+                                    && matches!(l.ty(), DataType::Nullable(_)) =>
+                            {
+                                "[nil]".to_string()
+                            }
+                            DataType::Map(m) if matches!(m.value_ty(), DataType::Nullable(_)) => {
+                                format!("[{}: nil]", mocks.value(m.key_ty(), name)?)
+                            }
+                            _ => return None,
+                        };
+                        Some(format!("{}: {}", swift_name, value))
+                    })
+                    .collect::<Vec<_>>();
+                if !overrides.is_empty() {
+                    samples.push(format!(".mock({})", overrides.join(", ")));
+                }
+            }
+            samples
+        }
+        _ => return Ok(None),
+    }))
+}
+
+/// Builds placeholder expressions, tracking the named types being mocked to avoid cycles.
+struct Mocks<'a> {
+    swift: &'a Swift,
+    types: &'a TypeCollection,
+    variant_structs: &'a VariantStructNames,
+    stack: Vec<SpectaID>,
+}
+
+impl Mocks<'_> {
+    /// A placeholder expression for a value of type `ty`, named `name` for strings.
+    fn value(&self, ty: &DataType, name: &str) -> Option<String> {
+        Some(match ty {
+            DataType::Primitive(p) => match p {
+                Primitive::i8
+                | Primitive::i16
+                | Primitive::i32
+                | Primitive::i64
+                | Primitive::isize
+                | Primitive::u8
+                | Primitive::u16
+                | Primitive::u32
+                | Primitive::u64
+                | Primitive::usize => "1".to_string(),
+                Primitive::f32 | Primitive::f64 => "1.0".to_string(),
+                Primitive::bool => "false".to_string(),
+                Primitive::char => "\"a\"".to_string(),
+                Primitive::String => format!("\"{}\"", escape_string(name)),
+                Primitive::i128 | Primitive::u128 | Primitive::f16 => return None,
+            },
+            DataType::Literal(l) => literal_to_swift(l).ok()?,
+            // Fixed-size arrays always have their length
+            DataType::List(l) => match l.length() {
+                Some(length) if length > 0 => format!(
+                    "Array(repeating: {}, count: {})",
+                    self.value(l.ty(), name)?,
+                    length
+                ),
+                _ => "[]".to_string(),
+            },
+            DataType::Map(_) => "[:]".to_string(),
+            DataType::Nullable(_) => "nil".to_string(),
+            DataType::Struct(s) if is_duration_struct(s) => {
+                "RustDuration(secs: 1, nanos: 0)".to_string()
+            }
+            // Tuples of several elements are represented by helper structs
+            DataType::Tuple(t) => format!(
+                "{}({})",
+                match t.elements().len() {
+                    0 | 1 => String::new(),
+                    arity => tuple_helper_name(arity),
+                },
+                t.elements()
+                    .iter()
+                    .map(|ty| self.value(ty, name))
+                    .collect::<Option<Vec<_>>>()?
+                    .join(", ")
+            ),
+            DataType::Reference(r) => {
+                if let Some(special) = is_special_std_type(self.types, Some(r.sid())) {
+                    return Some(
+                        match special.as_str() {
+                            "RustDuration" => "RustDuration(secs: 1, nanos: 0)",
+                            "Date" => "Date(timeIntervalSince1970: 0)",
+                            "Double" => "1.0",
+                            "JsonValue" => "JsonValue.null",
+                            _ => return None,
+                        }
+                        .to_string(),
+                    );
+                }
+
+                let referenced = self.types.get(r.sid())?;
+                if let Some(special) = self.swift.special_type_of(referenced) {
+                    return special.mock.map(|mock| mock.into_owned());
+                }
+                if self.swift.is_external(referenced) {
+                    return None;
+                }
+                if !r.generics().is_empty() || !referenced.generics().is_empty() {
+                    return None;
+                }
+                if !self.is_mockable(referenced) {
+                    return None;
+                }
+
+                let swift_type =
+                    datatype_to_swift(self.swift, self.types, ty, vec![], false, None).ok()?;
+                format!("{}.mock", swift_type)
+            }
+            _ => return None,
+        })
+    }
+
+    /// The placeholder case of an enum, preferring cases without associated values.
+    fn enum_value(&self, ndt: &NamedDataType, swift_name: &str) -> Option<String> {
+        let DataType::Enum(e) = ndt.ty() else {
+            return None;
+        };
+
+        let mut variants = e
+            .variants()
+            .iter()
+            .filter(|(_, variant)| !variant.skip())
+            .collect::<Vec<_>>();
+        variants.sort_by_key(|(_, variant)| match variant.fields() {
+            Fields::Unit => 0,
+            Fields::Unnamed(fields) if fields.fields().is_empty() => 0,
+            Fields::Named(fields) if fields.fields().is_empty() => 0,
+            _ => 1,
+        });
+
+        variants.into_iter().find_map(|(variant_name, variant)| {
+            self.variant_value(ndt, swift_name, variant_name, variant)
+        })
+    }
+
+    /// A placeholder for every case of an enum which can be constructed, in declaration order.
+    fn enum_values(&self, ndt: &NamedDataType, swift_name: &str) -> Vec<String> {
+        let DataType::Enum(e) = ndt.ty() else {
+            return vec![];
+        };
+
+        e.variants()
+            .iter()
+            .filter(|(_, variant)| !variant.skip())
+            .filter_map(|(variant_name, variant)| {
+                self.variant_value(ndt, swift_name, variant_name, variant)
+            })
+            .collect()
+    }
+
+    /// A placeholder for a single enum case.
+    fn variant_value(
+        &self,
+        ndt: &NamedDataType,
+        swift_name: &str,
+        variant_name: &str,
+        variant: &EnumVariant,
+    ) -> Option<String> {
+        let case = format!(".{}", self.swift.case_name(variant_name));
+        match variant.fields() {
+            Fields::Unit => Some(case),
+            Fields::Unnamed(fields) => {
+                let values = fields
+                    .fields()
+                    .iter()
+                    .filter_map(|field| field.ty())
+                    .map(|ty| self.value(ty, variant_name))
+                    .collect::<Option<Vec<_>>>()?;
+                if values.is_empty() {
+                    Some(case)
+                } else {
+                    Some(format!("{}({})", case, values.join(", ")))
+                }
+            }
+            Fields::Named(fields) => {
+                if fields.fields().is_empty() {
+                    return Some(case);
+                }
+                let args = fields
+                    .fields()
+                    .iter()
+                    .zip(field_names(self.swift, fields))
+                    .filter_map(|((name, field), swift_name)| {
+                        field.ty().map(|ty| (name, swift_name, field, ty))
+                    })
+                    .map(|(name, swift_name, field, ty)| {
+                        let value = if field.optional() {
+                            "nil".to_string()
+                        } else {
+                            self.value(ty, name)?
+                        };
+                        Some(format!("{}: {}", swift_name, value))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                let struct_name =
+                    self.variant_structs
+                        .get(self.swift, ndt.sid(), swift_name, variant_name);
+                Some(format!("{}({}({}))", case, struct_name, args.join(", ")))
+            }
+        }
+    }
+
+    /// Whether a referenced type gets a `mock`, without recursing into types being mocked.
+    fn is_mockable(&self, ndt: &NamedDataType) -> bool {
+        if self.stack.contains(&ndt.sid()) || !ndt.generics().is_empty() {
+            return false;
+        }
+
+        let mut mocks = Mocks {
+            swift: self.swift,
+            types: self.types,
+            variant_structs: self.variant_structs,
+            stack: self.stack.clone(),
+        };
+        mocks.stack.push(ndt.sid());
+
+        match ndt.ty() {
+            _ if self.swift.option_set_flags(ndt).is_some() => true,
+            DataType::Struct(s) => match s.fields() {
+                Fields::Named(fields) => fields.fields().iter().all(|(name, field)| {
+                    !is_inlined(self.swift, self.types, field)
+                        && (field.optional()
+                            || field.ty().is_none_or(|ty| mocks.value(ty, name).is_some()))
+                }),
+                Fields::Unnamed(fields) => fields.fields().iter().all(|field| {
+                    field
+                        .ty()
+                        .is_none_or(|ty| mocks.value(ty, ndt.name()).is_some())
+                }),
+                Fields::Unit => true,
+            },
+            DataType::Enum(_) => mocks.enum_value(ndt, "").is_some(),
+            _ => false,
+        }
+    }
+}
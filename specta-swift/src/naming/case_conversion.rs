@@ -10,6 +10,212 @@
 //! - And more...
 //!
 //! These utilities ensure consistent naming across generated Swift code.
+//!
+//! [`NamingConvention`](crate::NamingConvention) converts identifiers with a
+//! [`CaseConverter`]. The built-in conventions are implemented by [`PascalCaseConverter`],
+//! [`CamelCaseConverter`] and [`SnakeCaseConverter`], and
+//! [`NamingConvention::Custom`](crate::NamingConvention::Custom) accepts any other converter.
+
+use std::fmt;
+
+/// Converts Rust identifiers to the Swift identifiers of a naming convention.
+///
+/// Field names are normalized to `snake_case` or `camelCase` words before conversion, and
+/// keywords in field and case names are escaped afterwards, so converters only decide on the
+/// casing. The `Debug` output is part of the [export cache](crate::Swift::cache_file)
+/// fingerprint and should identify the converter's rules.
+///
+/// # Examples
+///
+/// Objective-C style prefixed type names:
+///
+/// ```rust
+/// use specta_swift::naming::PascalCaseConverter;
+/// use specta_swift::{CaseConverter, NamingConvention, Swift};
+///
+/// #[derive(Debug)]
+/// struct Prefixed(&'static str);
+///
+/// impl CaseConverter for Prefixed {
+///     fn convert(&self, name: &str) -> String {
+///         format!("{}{}", self.0, PascalCaseConverter.convert(name))
+///     }
+/// }
+///
+/// let swift = Swift::new().naming(NamingConvention::custom(Prefixed("SD")));
+/// assert_eq!(swift.type_name("user_profile"), "SDUserProfile");
+/// assert_eq!(swift.field_name("user_id"), "userId");
+/// ```
+pub trait CaseConverter: fmt::Debug + Send + Sync {
+    /// Convert a type name.
+    fn convert(&self, name: &str) -> String;
+
+    /// Convert a field name, in camelCase by default.
+    fn convert_field(&self, name: &str) -> String {
+        CamelCaseConverter.convert(name)
+    }
+
+    /// Convert an enum case name, in camelCase by default.
+    fn convert_enum_case(&self, name: &str) -> String {
+        CamelCaseConverter.convert(name)
+    }
+}
+
+/// PascalCase type names with camelCase fields and cases, as is idiomatic in Swift.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PascalCaseConverter;
+
+impl CaseConverter for PascalCaseConverter {
+    fn convert(&self, name: &str) -> String {
+        // Convert snake_case to PascalCase
+        name.split('_')
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    None => String::new(),
+                    Some(first) => std::iter::once(upper_char(first)).chain(chars).collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// camelCase type, field and case names.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CamelCaseConverter;
+
+impl CaseConverter for CamelCaseConverter {
+    fn convert(&self, name: &str) -> String {
+        // Convert snake_case or PascalCase to camelCase
+        if name.contains('_') {
+            // Handle snake_case
+            let parts: Vec<&str> = name.split('_').collect();
+            if parts.is_empty() {
+                return name.to_string();
+            }
+
+            let mut result = String::new();
+            for (i, part) in parts.iter().enumerate() {
+                if i == 0 {
+                    result.push_str(&to_lower(part));
+                } else {
+                    let mut chars = part.chars();
+                    match chars.next() {
+                        None => continue,
+                        Some(first) => {
+                            result.push(upper_char(first));
+                            for c in chars {
+                                result.push(lower_char(c));
+                            }
+                        }
+                    }
+                }
+            }
+            result
+        } else {
+            // Handle PascalCase - convert to camelCase
+            let mut chars = name.chars();
+            match chars.next() {
+                None => name.to_string(),
+                Some(first) => {
+                    let mut result = String::new();
+                    result.push(lower_char(first));
+                    for c in chars {
+                        result.push(c); // Keep the rest as-is for PascalCase
+                    }
+                    result
+                }
+            }
+        }
+    }
+}
+
+/// snake_case type, field and case names.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SnakeCaseConverter;
+
+impl CaseConverter for SnakeCaseConverter {
+    fn convert(&self, name: &str) -> String {
+        // Convert camelCase/PascalCase to snake_case
+        let mut result = String::new();
+
+        for c in name.chars() {
+            if c.is_uppercase() && !result.is_empty() {
+                result.push('_');
+            }
+            result.push(lower_char(c));
+        }
+
+        result
+    }
+
+    fn convert_field(&self, name: &str) -> String {
+        self.convert(name)
+    }
+
+    fn convert_enum_case(&self, name: &str) -> String {
+        self.convert(name)
+    }
+}
+
+/// The uppercase form of a character for use in an identifier.
+///
+/// ASCII letters are converted directly. Other characters use their Unicode mapping only when
+/// it is a single character, so `ß` or `ŉ` are kept instead of growing into several letters
+/// and identifiers never depend on how a platform expands such mappings.
+///
+/// # Examples
+///
+/// ```rust
+/// # use specta_swift::naming::case_conversion::upper_char;
+/// assert_eq!(upper_char('i'), 'I');
+/// assert_eq!(upper_char('é'), 'É');
+/// assert_eq!(upper_char('ß'), 'ß');
+/// ```
+pub fn upper_char(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_uppercase();
+    }
+    single_char(c.to_uppercase()).unwrap_or(c)
+}
+
+/// The lowercase form of a character for use in an identifier.
+///
+/// Like [`upper_char`], mappings to several characters are skipped: the Turkish dotted `İ`
+/// is kept rather than becoming `i` followed by a combining dot.
+///
+/// # Examples
+///
+/// ```rust
+/// # use specta_swift::naming::case_conversion::lower_char;
+/// assert_eq!(lower_char('I'), 'i');
+/// assert_eq!(lower_char('É'), 'é');
+/// assert_eq!(lower_char('İ'), 'İ');
+/// ```
+pub fn lower_char(c: char) -> char {
+    if c.is_ascii() {
+        return c.to_ascii_lowercase();
+    }
+    single_char(c.to_lowercase()).unwrap_or(c)
+}
+
+/// Uppercase a string with [`upper_char`].
+pub fn to_upper(s: &str) -> String {
+    s.chars().map(upper_char).collect()
+}
+
+/// Lowercase a string with [`lower_char`].
+pub fn to_lower(s: &str) -> String {
+    s.chars().map(lower_char).collect()
+}
+
+/// The only character of a case mapping.
+fn single_char(mut mapping: impl Iterator<Item = char>) -> Option<char> {
+    match (mapping.next(), mapping.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
 
 /// Convert a snake_case string to camelCase.
 ///
@@ -110,10 +316,10 @@ pub fn to_pascal_case(s: &str) -> String {
         if c == '_' || c == '-' {
             capitalize_next = true;
         } else if capitalize_next {
-            result.push(c.to_uppercase().next().unwrap_or(c));
+            result.push(upper_char(c));
             capitalize_next = false;
         } else {
-            result.push(c.to_lowercase().next().unwrap_or(c));
+            result.push(lower_char(c));
         }
     }
 
@@ -405,6 +611,30 @@ mod tests {
         assert!(!is_pascal_case(""));
     }
 
+    #[test]
+    fn test_builtin_converters() {
+        assert_eq!(PascalCaseConverter.convert("user_profile"), "UserProfile");
+        assert_eq!(PascalCaseConverter.convert_field("user_id"), "userId");
+        assert_eq!(CamelCaseConverter.convert("UserProfile"), "userProfile");
+        assert_eq!(SnakeCaseConverter.convert("UserProfile"), "user_profile");
+        assert_eq!(
+            SnakeCaseConverter.convert_enum_case("InProgress"),
+            "in_progress"
+        );
+    }
+
+    #[test]
+    fn test_multi_char_case_mappings_are_kept() {
+        assert_eq!(to_upper("straße"), "STRAßE");
+        assert_eq!(to_lower("İSTANBUL"), "İstanbul");
+        assert_eq!(
+            CamelCaseConverter.convert("İstanbul_şehir"),
+            "İstanbulŞehir"
+        );
+        assert_eq!(PascalCaseConverter.convert("ßtraße"), "ßtraße");
+        assert_eq!(SnakeCaseConverter.convert("İlçeAdı"), "İlçe_adı");
+    }
+
     #[test]
     fn test_roundtrip_conversions() {
         let original = "hello_world_foo_bar";
@@ -0,0 +1,50 @@
+//! Field name disambiguation
+//!
+//! Distinct Rust field names can convert to the same Swift property name, e.g. `user_id` and
+//! `userId` both become `userId`. The first field keeps the converted name and later ones get
+//! the lowest free numeric suffix (`userId2`), so the generated properties stay unique and the
+//! names don't change between exports. The JSON keys are unaffected.
+
+use std::collections::HashSet;
+
+use specta::datatype::NamedFields;
+
+use crate::swift::Swift;
+
+/// The Swift property names of `fields`, in the order of [`NamedFields::fields`].
+///
+/// Skipped fields, which have no property, keep their converted name and never take a name
+/// from another field.
+pub fn field_names(swift: &Swift, fields: &NamedFields) -> Vec<String> {
+    let converted = fields
+        .fields()
+        .iter()
+        .map(|(name, _)| swift.field_name(name))
+        .collect::<Vec<_>>();
+    let mut taken = fields
+        .fields()
+        .iter()
+        .zip(&converted)
+        .filter(|((_, field), _)| field.ty().is_some())
+        .map(|(_, name)| name.clone())
+        .collect::<HashSet<_>>();
+
+    let mut used = HashSet::new();
+    fields
+        .fields()
+        .iter()
+        .zip(converted)
+        .map(|((_, field), name)| {
+            if field.ty().is_none() || used.insert(name.clone()) {
+                return name;
+            }
+            let unique = (2..)
+                .map(|i| format!("{}{}", name, i))
+                .find(|candidate| !taken.contains(candidate))
+                .expect("a free suffix");
+            taken.insert(unique.clone());
+            used.insert(unique.clone());
+            unique
+        })
+        .collect()
+}
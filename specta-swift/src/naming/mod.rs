@@ -3,8 +3,12 @@
 //! This module handles all naming-related functionality including:
 //!
 //! - `strategies`: Duplicate name resolution strategies
-//! - `case_conversion`: Converting between naming conventions (snake_case ↔ camelCase)
+//! - `case_conversion`: Converting between naming conventions (snake_case ↔ camelCase) and
+//!   the `CaseConverter` trait behind `NamingConvention`
+//! - `fields`: Unique property names for fields converting to the same name
 //! - `resolver`: Name conflict detection and resolution
+//! - `shadowing`: Type names shadowing Swift standard library and Foundation types
+//! - `unicode`: NFC normalization and ASCII transliteration of identifiers
 //!
 //! # Architecture
 //!
@@ -17,21 +21,32 @@
 //!
 //! # Naming Strategies
 //!
-//! Four strategies are available for handling duplicate names:
+//! Six strategies are available for handling duplicate names:
 //!
-//! - `Warn`: Log warnings but allow duplicates (default)
+//! - `Warn`: Log warnings and qualify the conflicting names (default)
 //! - `Error`: Fail generation on duplicates
 //! - `Qualify`: Auto-generate qualified names from module paths
+//! - `Suffix`: Append a numeric suffix to later duplicates
 //! - `Custom`: User-provided naming function
+//! - `CustomWithContext`: User-provided naming function which sees the names already in use
 
 // Submodules
 pub mod case_conversion;
+pub mod fields;
 pub mod rename_rules;
+pub mod resolver;
+pub mod shadowing;
+pub mod unicode;
 pub mod variant_naming;
 
 // Re-export commonly used functions
-pub use case_conversion::{snake_to_camel, snake_to_pascal, to_pascal_case, to_snake_case};
+pub use case_conversion::{
+    snake_to_camel, snake_to_pascal, to_pascal_case, to_snake_case, CamelCaseConverter,
+    CaseConverter, PascalCaseConverter, SnakeCaseConverter,
+};
+pub use fields::field_names;
 pub use rename_rules::{generate_raw_value, generate_string_enum_raw_value};
+pub use resolver::{resolve_type_names, ResolvedNames, VariantStructNames};
 pub use variant_naming::generate_variant_struct_name;
 
 // Re-export public types from submodules once they're created
@@ -2,6 +2,9 @@
 //!
 //! This module handles Serde's `rename_all` attribute conversion rules.
 //! These rules control how variant/field names are serialized.
+//!
+//! Like serde, the rules only change the case of ASCII letters, so the raw values match the
+//! names serde writes for non-ASCII variants too.
 
 /// Generate raw value for string enum variants based on serde rename rules.
 ///
@@ -35,20 +38,24 @@
 /// ```
 pub fn generate_raw_value(variant_name: &str, rename_all: Option<&str>) -> String {
     match rename_all {
-        Some("lowercase") => variant_name.to_lowercase(),
-        Some("UPPERCASE") => variant_name.to_uppercase(),
+        Some("lowercase") => variant_name.to_ascii_lowercase(),
+        Some("UPPERCASE") => variant_name.to_ascii_uppercase(),
         Some("camelCase") => {
             let mut chars = variant_name.chars();
             match chars.next() {
                 None => String::new(),
-                Some(first) => first.to_lowercase().chain(chars).collect(),
+                Some(first) => std::iter::once(first.to_ascii_lowercase())
+                    .chain(chars)
+                    .collect(),
             }
         }
         Some("PascalCase") => {
             let mut chars = variant_name.chars();
             match chars.next() {
                 None => String::new(),
-                Some(first) => first.to_uppercase().chain(chars).collect(),
+                Some(first) => std::iter::once(first.to_ascii_uppercase())
+                    .chain(chars)
+                    .collect(),
             }
         }
         Some("snake_case") => {
@@ -60,9 +67,9 @@ pub fn generate_raw_value(variant_name: &str, rename_all: Option<&str>) -> Strin
             .enumerate()
             .flat_map(|(i, c)| {
                 if c.is_uppercase() && i > 0 {
-                    vec!['_', c.to_uppercase().next().unwrap()]
+                    vec!['_', c.to_ascii_uppercase()]
                 } else {
-                    vec![c.to_uppercase().next().unwrap()]
+                    vec![c.to_ascii_uppercase()]
                 }
             })
             .collect(),
@@ -71,9 +78,9 @@ pub fn generate_raw_value(variant_name: &str, rename_all: Option<&str>) -> Strin
             .enumerate()
             .flat_map(|(i, c)| {
                 if c.is_uppercase() && i > 0 {
-                    vec!['-', c.to_lowercase().next().unwrap()]
+                    vec!['-', c.to_ascii_lowercase()]
                 } else {
-                    vec![c.to_lowercase().next().unwrap()]
+                    vec![c.to_ascii_lowercase()]
                 }
             })
             .collect(),
@@ -82,13 +89,13 @@ pub fn generate_raw_value(variant_name: &str, rename_all: Option<&str>) -> Strin
             .enumerate()
             .flat_map(|(i, c)| {
                 if c.is_uppercase() && i > 0 {
-                    vec!['-', c.to_uppercase().next().unwrap()]
+                    vec!['-', c.to_ascii_uppercase()]
                 } else {
-                    vec![c.to_uppercase().next().unwrap()]
+                    vec![c.to_ascii_uppercase()]
                 }
             })
             .collect(),
-        _ => variant_name.to_lowercase(), // Default to lowercase
+        _ => variant_name.to_ascii_lowercase(), // Default to lowercase
     }
 }
 
@@ -107,7 +114,7 @@ pub fn generate_raw_value(variant_name: &str, rename_all: Option<&str>) -> Strin
 /// The renamed string
 pub fn generate_string_enum_raw_value(
     variant_name: &str,
-    naming: crate::swift::NamingConvention,
+    naming: &crate::swift::NamingConvention,
 ) -> String {
     match naming {
         crate::swift::NamingConvention::SnakeCase => variant_name
@@ -121,8 +128,8 @@ pub fn generate_string_enum_raw_value(
                 }
             })
             .collect::<String>()
-            .to_lowercase(),
-        _ => variant_name.to_lowercase(), // Default to lowercase
+            .to_ascii_lowercase(),
+        _ => variant_name.to_ascii_lowercase(), // Default to lowercase
     }
 }
 
@@ -0,0 +1,551 @@
+//! Name conflict detection and resolution
+//!
+//! This module decides the final Swift name of every exported type. Duplicate Rust type
+//! names, and collisions between user types and the structs generated for enum variants
+//! (e.g. `TaskStatusCompletedData`) or the types hoisted for inlined fields (e.g.
+//! `UserAddress`), are resolved according to the configured [`DuplicateNameStrategy`].
+
+use std::collections::{HashMap, HashSet};
+
+use specta::datatype::{DataType, Fields, NamedDataType};
+use specta::{SpectaID, TypeCollection};
+
+use crate::codable::CodablePlan;
+use crate::datatype::inline::hoisted_types;
+use crate::datatype::monomorphize::monomorphize;
+use crate::datatype::reference::use_resolved_names;
+use crate::error::{Error, Result};
+use crate::naming::case_conversion::upper_char;
+use crate::naming::shadowing::shadows_swift_symbol;
+use crate::naming::variant_naming::generate_variant_struct_name;
+use crate::report::{DuplicateResolution, ExportReport, ExportWarning};
+use crate::roots::root_closure;
+use crate::special_types::helpers::is_builtin_json_value;
+use crate::swift::{DuplicateContext, DuplicateNameStrategy, Swift, VariantOrdering};
+
+/// The final Swift names chosen for an export.
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedNames {
+    /// Exported types paired with their Swift name, sorted by name.
+    pub types: Vec<(String, NamedDataType)>,
+    /// Names of the structs generated for enum variants with named fields and of the types
+    /// hoisted for inlined fields.
+    pub variant_structs: VariantStructNames,
+    /// Swift names of the declared types by their id, which references to them use.
+    pub type_names: HashMap<SpectaID, String>,
+}
+
+/// Names of the structs generated for enum variants with named fields, and of the types
+/// hoisted for inlined fields.
+///
+/// Only names which differ from [`generate_variant_struct_name`] or the name chosen by
+/// [`inline_type`](crate::datatype::inline::inline_type) are stored.
+#[derive(Debug, Clone, Default)]
+pub struct VariantStructNames {
+    variants: HashMap<(SpectaID, String), String>,
+    hoisted: HashMap<(String, String), String>,
+}
+
+impl VariantStructNames {
+    /// Get the struct name for a variant of the enum identified by `enum_sid`.
+    pub fn get(
+        &self,
+        swift: &Swift,
+        enum_sid: SpectaID,
+        enum_name: &str,
+        variant_name: &str,
+    ) -> String {
+        self.variants
+            .get(&(enum_sid, variant_name.to_string()))
+            .cloned()
+            .unwrap_or_else(|| generate_variant_struct_name(swift, enum_name, variant_name))
+    }
+
+    /// Get the names of all structs generated for the variants of an enum.
+    pub fn all_for(&self, swift: &Swift, ndt: &NamedDataType, enum_name: &str) -> Vec<String> {
+        struct_variants(ndt)
+            .map(|variant_name| self.get(swift, ndt.sid(), enum_name, variant_name))
+            .collect()
+    }
+
+    /// Get the name of the type hoisted for the `field_name` field of the type declared as
+    /// `parent_name`, if it was renamed.
+    pub fn hoisted(&self, parent_name: &str, field_name: &str) -> Option<&str> {
+        self.hoisted
+            .get(&(parent_name.to_string(), field_name.to_string()))
+            .map(String::as_str)
+    }
+
+    /// The names used by a hoisted type, whose variant structs are named after it rather than
+    /// after the type it was inlined from.
+    pub(crate) fn for_hoisted(&self) -> Self {
+        Self {
+            variants: HashMap::new(),
+            hoisted: self.hoisted.clone(),
+        }
+    }
+}
+
+/// Resolve the Swift name of every type in the collection according to the duplicate name strategy.
+pub fn resolve_type_names(
+    swift: &Swift,
+    types: &TypeCollection,
+    report: &mut ExportReport,
+) -> Result<ResolvedNames> {
+    let mut result = handle_duplicate_names(swift, types, report)?;
+    // Instantiations share the id of their generic type, so names are collected before
+    let type_names = result
+        .iter()
+        .map(|(name, ndt)| (ndt.sid(), name.clone()))
+        .collect::<HashMap<_, _>>();
+    use_resolved_names(swift, types, &type_names);
+    if swift.monomorphize {
+        result = monomorphize(swift, types, result)?;
+    }
+    let variant_structs = resolve_variant_struct_names(swift, types, &result, report)?;
+
+    for (name, ndt) in &result {
+        let original = swift.type_name(ndt.name());
+        if !shadows_swift_symbol(&original) {
+            continue;
+        }
+        if shadows_swift_symbol(name) {
+            report.warnings.push(ExportWarning::ShadowedName {
+                name: name.clone(),
+                renamed_to: None,
+            });
+        } else if *name != original && *name == swift.declared_type_name(ndt.name()) {
+            report.warnings.push(ExportWarning::ShadowedName {
+                name: original,
+                renamed_to: Some(name.clone()),
+            });
+        }
+    }
+
+    // Print warnings to stderr if any
+    if !report.warnings.is_empty() {
+        eprintln!(
+            "{}",
+            report
+                .warnings
+                .iter()
+                .map(|warning| warning.to_string())
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        );
+    }
+
+    if swift.variant_ordering == VariantOrdering::Alphabetical {
+        for (_, ndt) in &mut result {
+            if let DataType::Enum(e) = ndt.ty_mut() {
+                // Sorted by the emitted case name, so serialized names like `SCREAMING_CASE` or
+                // `lowercase` don't change the order
+                e.variants_mut()
+                    .sort_by_cached_key(|(name, _)| swift.case_name(name));
+            }
+        }
+    }
+
+    // Sort by name for deterministic output
+    result.sort_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b));
+
+    Ok(ResolvedNames {
+        types: result,
+        variant_structs,
+        type_names,
+    })
+}
+
+/// Generate a qualified name based on module path to avoid conflicts.
+pub fn generate_qualified_name(swift: &Swift, ndt: &NamedDataType) -> String {
+    let options = &swift.qualify;
+    let mut module_path: &str = ndt.module_path();
+    for prefix in &options.strip_prefixes {
+        if module_path == prefix.as_ref() {
+            module_path = "";
+        } else if let Some(rest) = module_path
+            .strip_prefix(prefix.as_ref())
+            .and_then(|rest| rest.strip_prefix("::"))
+        {
+            module_path = rest;
+        }
+    }
+
+    let meaningful_parts: Vec<&str> = module_path
+        .split("::")
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    if meaningful_parts.is_empty() {
+        return swift.type_name(ndt.name());
+    }
+
+    // Take the last `depth` meaningful parts of the module path
+    let parts_to_use = match options.depth {
+        Some(depth) if meaningful_parts.len() > depth => {
+            &meaningful_parts[meaningful_parts.len() - depth..]
+        }
+        _ => &meaningful_parts[..],
+    };
+
+    // Convert to PascalCase and combine with the type name
+    let mut segments = parts_to_use
+        .iter()
+        .map(|part| {
+            // Convert snake_case to PascalCase
+            part.split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        None => String::new(),
+                        Some(first) => std::iter::once(upper_char(first)).chain(chars).collect(),
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    segments.push(swift.type_name(ndt.name()));
+
+    segments.join(&options.separator)
+}
+
+/// Check for duplicate names and handle them according to the strategy.
+fn handle_duplicate_names(
+    swift: &Swift,
+    types: &TypeCollection,
+    report: &mut ExportReport,
+) -> Result<Vec<(String, NamedDataType)>> {
+    let mut name_to_types: HashMap<String, Vec<NamedDataType>> = HashMap::new();
+    let reachable = root_closure(&swift.roots, types)?;
+
+    // Group types by name
+    for ndt in types.into_sorted_iter() {
+        if !swift.is_included(&ndt) {
+            continue;
+        }
+        if reachable
+            .as_ref()
+            .is_some_and(|reachable| !reachable.contains(&ndt.sid()))
+        {
+            continue;
+        }
+
+        // User-defined special types and assumed external types map to existing Swift types
+        if swift.is_external(&ndt) {
+            continue;
+        }
+
+        if ndt.name() == "JsonValue"
+            && is_builtin_json_value(&ndt)
+            && swift
+                .helpers
+                .get("JsonValue")
+                .is_some_and(|helper| helper.is_needed(types))
+        {
+            continue;
+        }
+
+        name_to_types
+            .entry(ndt.name().to_string())
+            .or_default()
+            .push(ndt);
+    }
+
+    let mut result = Vec::new();
+
+    // Process groups in a stable order so suffixes are assigned deterministically
+    let mut name_to_types = name_to_types.into_iter().collect::<Vec<_>>();
+    name_to_types.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut taken_names = name_to_types
+        .iter()
+        .map(|(name, _)| swift.declared_type_name(name))
+        .collect::<HashSet<_>>();
+
+    for (name, type_list) in name_to_types {
+        if let [ndt] = type_list.as_slice() {
+            // No duplicates, use original name
+            result.push((swift.declared_type_name(&name), ndt.clone()));
+        } else {
+            // Handle duplicates based on strategy
+            match &swift.duplicate_name_strategy {
+                DuplicateNameStrategy::Warn => {
+                    // Qualify every conflicting type so nothing is silently dropped
+                    let origins = type_list.iter().map(describe_origin).collect();
+                    let mut renamed_to = Vec::new();
+                    for ndt in type_list {
+                        let qualified_name =
+                            unique_name(generate_qualified_name(swift, &ndt), &mut taken_names);
+                        record_resolution(report, &ndt, &qualified_name);
+                        renamed_to.push(qualified_name.clone());
+                        result.push((qualified_name, ndt));
+                    }
+
+                    report.warnings.push(ExportWarning::DuplicateName {
+                        name: swift.type_name(&name),
+                        origins,
+                        renamed_to,
+                    });
+                }
+                DuplicateNameStrategy::Error => {
+                    let error_msg = format!(
+                        "Duplicate type names found for '{}':\n{}",
+                        name,
+                        type_list
+                            .iter()
+                            .map(|ndt| format!("   - {}", describe_origin(ndt)))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    );
+                    return Err(Error::DuplicateNames(error_msg));
+                }
+                DuplicateNameStrategy::Qualify => {
+                    // Generate qualified names for all duplicates, which may still collide once
+                    // the module path is shortened
+                    for ndt in type_list {
+                        let qualified_name =
+                            unique_name(generate_qualified_name(swift, &ndt), &mut taken_names);
+                        record_resolution(report, &ndt, &qualified_name);
+                        result.push((qualified_name, ndt));
+                    }
+                }
+                DuplicateNameStrategy::Custom(naming_fn) => {
+                    // Use custom naming function for all duplicates
+                    for ndt in type_list {
+                        let custom_name = swift.type_name(&(naming_fn)(&ndt));
+                        record_resolution(report, &ndt, &custom_name);
+                        result.push((custom_name, ndt));
+                    }
+                }
+                DuplicateNameStrategy::CustomWithContext(naming_fn) => {
+                    for ndt in &type_list {
+                        let context = DuplicateContext {
+                            name: &name,
+                            colliding: &type_list,
+                            taken_names: &taken_names,
+                        };
+                        let custom_name = swift.type_name(&(naming_fn)(ndt, &context));
+                        taken_names.insert(custom_name.clone());
+                        record_resolution(report, ndt, &custom_name);
+                        result.push((custom_name, ndt.clone()));
+                    }
+                }
+                DuplicateNameStrategy::Suffix => {
+                    let base_name = swift.declared_type_name(&name);
+                    for (i, ndt) in type_list.into_iter().enumerate() {
+                        if i == 0 {
+                            result.push((base_name.clone(), ndt));
+                            continue;
+                        }
+
+                        let suffixed_name = next_suffixed_name(&base_name, &mut taken_names);
+                        record_resolution(report, &ndt, &suffixed_name);
+                        result.push((suffixed_name, ndt));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Resolve collisions between the structs generated for enum variants, the types hoisted for
+/// inlined fields and all other names.
+///
+/// User-defined types always keep their name, so a colliding generated type is the one renamed.
+fn resolve_variant_struct_names(
+    swift: &Swift,
+    types: &TypeCollection,
+    resolved: &[(String, NamedDataType)],
+    report: &mut ExportReport,
+) -> Result<VariantStructNames> {
+    let mut origins = resolved
+        .iter()
+        .map(|(name, ndt)| (name.clone(), describe_origin(ndt)))
+        .collect::<HashMap<_, _>>();
+    let mut taken_names = origins.keys().cloned().collect::<HashSet<_>>();
+
+    let mut overrides = VariantStructNames::default();
+
+    for (enum_name, ndt) in resolved {
+        for variant_name in struct_variants(ndt) {
+            let struct_name = generate_variant_struct_name(swift, enum_name, variant_name);
+            let origin = format!(
+                "struct generated for variant '{}::{}' ({}:{})",
+                enum_name,
+                variant_name,
+                ndt.module_path(),
+                ndt.location().line()
+            );
+
+            let Some(existing_origin) = origins.get(&struct_name).cloned() else {
+                taken_names.insert(struct_name.clone());
+                origins.insert(struct_name, origin);
+                continue;
+            };
+
+            // Describe the generated struct as a type owned by the enum's module
+            let mut helper = ndt.clone();
+            helper.set_name(struct_name.clone().into());
+
+            let renamed = rename_generated_type(
+                swift,
+                resolved,
+                &helper,
+                [existing_origin, origin.clone()],
+                &mut taken_names,
+                report,
+            )?;
+            origins.insert(renamed.clone(), origin);
+            overrides
+                .variants
+                .insert((ndt.sid(), variant_name.to_string()), renamed);
+        }
+    }
+
+    // Hoisted types are declared next to their parent and may hoist inlined fields of their own
+    let mut parents = resolved.to_vec();
+    let mut next = 0;
+    while let Some((parent_name, parent)) = parents.get(next).cloned() {
+        next += 1;
+        for (field_name, mut hoisted) in hoisted_types(swift, types, &parent, &parent_name) {
+            let hoisted_name = hoisted.name().to_string();
+            let origin = format!(
+                "type hoisted for field '{}.{}' ({}:{})",
+                parent_name,
+                field_name,
+                parent.module_path(),
+                parent.location().line()
+            );
+
+            let name = match origins.get(&hoisted_name).cloned() {
+                None => {
+                    taken_names.insert(hoisted_name.clone());
+                    origins.insert(hoisted_name.clone(), origin);
+                    hoisted_name
+                }
+                Some(existing_origin) => {
+                    let renamed = rename_generated_type(
+                        swift,
+                        resolved,
+                        &hoisted,
+                        [existing_origin, origin.clone()],
+                        &mut taken_names,
+                        report,
+                    )?;
+                    origins.insert(renamed.clone(), origin);
+                    overrides
+                        .hoisted
+                        .insert((parent_name.clone(), field_name), renamed.clone());
+                    renamed
+                }
+            };
+
+            hoisted.set_name(name.clone().into());
+            parents.push((name, hoisted));
+        }
+    }
+
+    Ok(overrides)
+}
+
+/// Rename a generated type whose name, the name of `helper`, is already taken.
+fn rename_generated_type(
+    swift: &Swift,
+    resolved: &[(String, NamedDataType)],
+    helper: &NamedDataType,
+    origins: [String; 2],
+    taken_names: &mut HashSet<String>,
+    report: &mut ExportReport,
+) -> Result<String> {
+    let name = helper.name().to_string();
+    let renamed = match &swift.duplicate_name_strategy {
+        DuplicateNameStrategy::Error => {
+            return Err(Error::DuplicateNames(format!(
+                "Duplicate type names found for '{}':\n   - {}\n   - {}",
+                name, origins[0], origins[1]
+            )));
+        }
+        DuplicateNameStrategy::Warn | DuplicateNameStrategy::Qualify => {
+            unique_name(generate_qualified_name(swift, helper), taken_names)
+        }
+        DuplicateNameStrategy::Custom(naming_fn) => swift.type_name(&(naming_fn)(helper)),
+        DuplicateNameStrategy::CustomWithContext(naming_fn) => {
+            let mut colliding = resolved
+                .iter()
+                .filter(|(resolved_name, _)| *resolved_name == name)
+                .map(|(_, ndt)| ndt.clone())
+                .collect::<Vec<_>>();
+            colliding.push(helper.clone());
+            let context = DuplicateContext {
+                name: &name,
+                colliding: &colliding,
+                taken_names,
+            };
+            swift.type_name(&(naming_fn)(helper, &context))
+        }
+        DuplicateNameStrategy::Suffix => next_suffixed_name(&name, taken_names),
+    };
+
+    if matches!(swift.duplicate_name_strategy, DuplicateNameStrategy::Warn) {
+        report.warnings.push(ExportWarning::DuplicateName {
+            name: name.clone(),
+            origins: origins.to_vec(),
+            renamed_to: vec![name, renamed.clone()],
+        });
+    }
+
+    taken_names.insert(renamed.clone());
+    record_resolution(report, helper, &renamed);
+    Ok(renamed)
+}
+
+/// Names of the enum variants which are generated as separate structs.
+fn struct_variants(ndt: &NamedDataType) -> impl Iterator<Item = &str> {
+    let variants = match ndt.ty() {
+        DataType::Enum(e) if CodablePlan::for_enum(e).variant_structs => e.variants(),
+        _ => &[],
+    };
+
+    variants
+        .iter()
+        .filter(|(_, variant)| {
+            matches!(variant.fields(), Fields::Named(fields) if !fields.fields().is_empty())
+        })
+        .map(|(variant_name, _)| variant_name.as_ref())
+}
+
+/// Use `name` if it is still free, otherwise fall back to a numeric suffix.
+fn unique_name(name: String, taken_names: &mut HashSet<String>) -> String {
+    if taken_names.insert(name.clone()) {
+        name
+    } else {
+        next_suffixed_name(&name, taken_names)
+    }
+}
+
+/// Describe where a type is defined (`module::path:line`).
+fn describe_origin(ndt: &NamedDataType) -> String {
+    format!("{}:{}", ndt.module_path(), ndt.location().line())
+}
+
+/// Find the next free `{base}{n}` name, starting at `2`.
+fn next_suffixed_name(base_name: &str, taken_names: &mut HashSet<String>) -> String {
+    let mut counter = 2;
+    let mut suffixed_name = format!("{}{}", base_name, counter);
+    while taken_names.contains(&suffixed_name) {
+        counter += 1;
+        suffixed_name = format!("{}{}", base_name, counter);
+    }
+    taken_names.insert(suffixed_name.clone());
+    suffixed_name
+}
+
+/// Record that a duplicate type was exported under a different Swift name.
+fn record_resolution(report: &mut ExportReport, ndt: &NamedDataType, swift_name: &str) {
+    report.duplicate_resolutions.push(DuplicateResolution {
+        original_name: ndt.name().to_string(),
+        module_path: ndt.module_path().to_string(),
+        swift_name: swift_name.to_string(),
+    });
+}
@@ -0,0 +1,31 @@
+//! Names shadowing Swift symbols
+//!
+//! A generated type named like a standard library, Foundation or SwiftUI type (e.g. `Data`,
+//! `Error`, `Result` or `State`) shadows it in the whole module. The generated code still
+//! compiles, but every other use of the original type in the app then refers to the generated
+//! one, which fails with confusing errors far from the bindings. Such names are reported as
+//! [`ExportWarning::ShadowedName`](crate::ExportWarning::ShadowedName) and can be prefixed
+//! with [`Swift::prefix_shadowed_names`](crate::Swift::prefix_shadowed_names).
+
+/// Commonly used types of the Swift standard library, Foundation, SwiftUI and Combine.
+#[rustfmt::skip]
+const SWIFT_SYMBOLS: &[&str] = &[
+    // Standard library
+    "Any", "AnyHashable", "AnyObject", "Array", "Bool", "Character", "ClosedRange", "Codable",
+    "Collection", "Decodable", "Dictionary", "Double", "Duration", "Encodable", "Equatable",
+    "Error", "Float", "Hashable", "Identifiable", "Int", "Int8", "Int16", "Int32", "Int64",
+    "Mirror", "Never", "Optional", "Range", "Result", "Sendable", "Sequence", "Set", "String",
+    "Substring", "Task", "UInt", "UInt8", "UInt16", "UInt32", "UInt64", "Unicode", "Void",
+    // Foundation
+    "Bundle", "Calendar", "Data", "Date", "DateComponents", "DateInterval", "Decimal",
+    "FileManager", "IndexPath", "IndexSet", "JSONDecoder", "JSONEncoder", "Locale",
+    "Measurement", "Notification", "Operation", "Process", "Progress", "Thread", "TimeInterval",
+    "TimeZone", "Timer", "URL", "URLRequest", "URLSession", "UUID",
+    // SwiftUI and Combine
+    "Binding", "Color", "Environment", "Font", "Image", "Published", "State", "Text", "View",
+];
+
+/// Whether a Swift type name shadows a commonly used Swift symbol.
+pub fn shadows_swift_symbol(name: &str) -> bool {
+    SWIFT_SYMBOLS.contains(&name)
+}
@@ -44,10 +44,10 @@ use crate::swift::{NamingConvention, StructNamingStrategy, Swift};
 pub fn generate_variant_struct_name(swift: &Swift, enum_name: &str, variant_name: &str) -> String {
     match swift.struct_naming {
         StructNamingStrategy::AutoRename => {
-            format!("{}{}Data", enum_name, swift.naming.convert(variant_name))
+            format!("{}{}Data", enum_name, swift.variant_type_name(variant_name))
         }
         StructNamingStrategy::KeepOriginal => {
-            format!("{}Data", swift.naming.convert(variant_name))
+            format!("{}Data", swift.variant_type_name(variant_name))
         }
     }
 }
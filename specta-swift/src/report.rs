@@ -0,0 +1,306 @@
+//! Export reporting.
+//!
+//! An [`ExportReport`] describes what happened during an export beyond the generated
+//! source itself, such as which types were emitted, which duplicate type names were
+//! renamed and which references point at types that were not exported.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+/// Summary of a Swift export.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportReport {
+    /// Types which were exported, in output order.
+    pub exported_types: Vec<ExportedType>,
+    /// Helper types generated by the exporter (e.g. `RustDuration` or enum variant structs).
+    pub helper_types: Vec<String>,
+    /// Types which were renamed to resolve a duplicate name.
+    pub duplicate_resolutions: Vec<DuplicateResolution>,
+    /// References to types which are not part of the export (e.g. removed by a filter).
+    pub unresolved_references: Vec<UnresolvedReference>,
+    /// Non-fatal problems found during the export.
+    pub warnings: Vec<ExportWarning>,
+}
+
+/// A type which was exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedType {
+    /// The name of the Rust type, after any `#[serde(rename)]` on the type.
+    pub name: String,
+    /// The module path of the type.
+    pub module_path: String,
+    /// The Swift name of the type.
+    pub swift_name: String,
+    /// Number of lines generated for the type, including its helpers and extensions.
+    pub lines: usize,
+    /// How many of the lines each part of the generated code takes, in output order.
+    pub features: Vec<(CodeFeature, usize)>,
+    /// Whether the code was reused from the cache file instead of being generated.
+    pub cached: bool,
+}
+
+/// A part of the code generated for a type, used to attribute its size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum CodeFeature {
+    /// The type declaration with its properties, cases and `CodingKeys`.
+    Declaration,
+    /// The public memberwise initializer.
+    Initializer,
+    /// A custom `Codable` implementation.
+    Codable,
+    /// Structs generated for struct-like enum variants.
+    VariantStructs,
+    /// The `mock` extension.
+    Mocks,
+    /// `init?(jsonData:)` and `jsonData()`.
+    JsonHelpers,
+    /// `init(dictionary:)` and `dictionary`.
+    DictionaryHelpers,
+    /// The `with(field:)` copy-modifiers.
+    CopyModifiers,
+    /// `allKeyPaths` and `changedFields(from:)`.
+    ChangeTracking,
+    /// `CKRecord` conversions.
+    CloudKit,
+    /// The `NSManagedObject` subclass and its converters.
+    CoreData,
+    /// Code registered with [`Swift::extend_type`](crate::Swift::extend_type).
+    Extensions,
+}
+
+impl fmt::Display for CodeFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Declaration => "declaration",
+            Self::Initializer => "initializer",
+            Self::Codable => "custom Codable",
+            Self::VariantStructs => "variant structs",
+            Self::Mocks => "mocks",
+            Self::JsonHelpers => "JSON helpers",
+            Self::DictionaryHelpers => "dictionary helpers",
+            Self::CopyModifiers => "copy modifiers",
+            Self::ChangeTracking => "change tracking",
+            Self::CloudKit => "CloudKit",
+            Self::CoreData => "Core Data",
+            Self::Extensions => "extensions",
+        })
+    }
+}
+
+/// A reference to a type which is not part of the export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedReference {
+    /// The Swift name of the type containing the reference.
+    pub referenced_by: String,
+    /// The name of the referenced Rust type, after any `#[serde(rename)]` on the type.
+    pub name: String,
+    /// The module path of the referenced type.
+    pub module_path: String,
+}
+
+/// A type which was renamed because another type shares its name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateResolution {
+    /// The name of the Rust type, after any `#[serde(rename)]` on the type.
+    pub original_name: String,
+    /// The module path of the renamed type.
+    pub module_path: String,
+    /// The Swift name which was chosen for the type.
+    pub swift_name: String,
+}
+
+/// A non-fatal problem found during export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExportWarning {
+    /// Several types share a name and were renamed so all of them could be exported.
+    DuplicateName {
+        /// The conflicting Swift name.
+        name: String,
+        /// Where each conflicting type comes from (`module::path:line`).
+        origins: Vec<String>,
+        /// The Swift names the conflicting types were exported as.
+        renamed_to: Vec<String>,
+    },
+    /// A type contains constructs `PropertyListEncoder` can't encode, found when targeting
+    /// [`CodableFormat::PropertyList`](crate::CodableFormat::PropertyList).
+    PropertyListIncompatible {
+        /// The Swift name of the type.
+        name: String,
+        /// Why the type can't be stored in a property list.
+        reason: String,
+    },
+    /// A type refers to a type missing from the `TypeCollection`, which was replaced by a
+    /// placeholder with [`MissingReferenceStrategy::Stub`](crate::MissingReferenceStrategy::Stub).
+    MissingReference {
+        /// The Swift name of the type containing the reference.
+        referenced_by: String,
+        /// The Swift name of the placeholder.
+        stub: String,
+    },
+    /// A type is named like a Swift standard library or Foundation type and shadows it.
+    ShadowedName {
+        /// The Swift name shadowing the Swift type.
+        name: String,
+        /// The name the type was exported as with
+        /// [`Swift::prefix_shadowed_names`](crate::Swift::prefix_shadowed_names).
+        renamed_to: Option<String>,
+    },
+    /// A key doesn't survive the round trip through the coders of
+    /// [`KeyStrategy::ConvertFromSnakeCase`](crate::KeyStrategy::ConvertFromSnakeCase), so the
+    /// `CodingKeys` of its type spell it out for the encoder.
+    UnconvertibleKey {
+        /// The Rust name of the type with the key.
+        name: String,
+        /// The JSON key, e.g. a camelCase field or an enum tag.
+        key: String,
+        /// The Swift property or case name the key belongs to.
+        swift_name: String,
+    },
+}
+
+impl fmt::Display for ExportWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateName {
+                name,
+                origins,
+                renamed_to,
+            } => {
+                writeln!(f, "⚠️  WARNING: Duplicate struct name '{}' found:", name)?;
+                for origin in origins {
+                    writeln!(f, "   - {}", origin)?;
+                }
+                write!(f, "   Exported as: {}", renamed_to.join(", "))
+            }
+            Self::PropertyListIncompatible { name, reason } => {
+                write!(f, "⚠️  WARNING: '{}' {}", name, reason)
+            }
+            Self::MissingReference {
+                referenced_by,
+                stub,
+            } => write!(
+                f,
+                "⚠️  WARNING: '{}' refers to a type which isn't in the TypeCollection, exported as the placeholder '{}'",
+                referenced_by, stub
+            ),
+            Self::ShadowedName {
+                name,
+                renamed_to: Some(renamed_to),
+            } => write!(
+                f,
+                "⚠️  WARNING: '{}' shadows a Swift type, exported as '{}'",
+                name, renamed_to
+            ),
+            Self::ShadowedName {
+                name,
+                renamed_to: None,
+            } => write!(
+                f,
+                "⚠️  WARNING: '{}' shadows a Swift type of the same name, rename it or set a prefix with `Swift::prefix_shadowed_names`",
+                name
+            ),
+            Self::UnconvertibleKey {
+                name,
+                key,
+                swift_name,
+            } => write!(
+                f,
+                "⚠️  WARNING: the key '{}' of '{}' doesn't convert to and from the Swift name '{}' with the snake_case key strategy, its CodingKeys spell the key out",
+                key, name, swift_name
+            ),
+        }
+    }
+}
+
+impl ExportReport {
+    /// Total number of lines generated for all exported types.
+    pub fn total_lines(&self) -> usize {
+        self.exported_types.iter().map(|ty| ty.lines).sum()
+    }
+
+    /// Total number of lines each feature contributes to the exported types, largest first.
+    ///
+    /// Helps deciding which options to disable when the generated code grows too large, see
+    /// [`Swift::minimal`](crate::Swift::minimal).
+    pub fn lines_by_feature(&self) -> Vec<(CodeFeature, usize)> {
+        let mut totals = BTreeMap::new();
+        for (feature, lines) in self.exported_types.iter().flat_map(|ty| &ty.features) {
+            *totals.entry(*feature).or_insert(0) += lines;
+        }
+        let mut totals = totals.into_iter().collect::<Vec<_>>();
+        totals.sort_by_key(|(_, lines)| std::cmp::Reverse(*lines));
+        totals
+    }
+
+    /// A human readable table of the lines generated per type and feature.
+    pub fn size_summary(&self) -> String {
+        let mut summary = String::new();
+        for ty in &self.exported_types {
+            let features = ty
+                .features
+                .iter()
+                .map(|(feature, lines)| format!("{} {}", feature, lines))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let _ = writeln!(
+                summary,
+                "{}: {} lines ({})",
+                ty.swift_name, ty.lines, features
+            );
+        }
+        let totals = self
+            .lines_by_feature()
+            .iter()
+            .map(|(feature, lines)| format!("{} {}", feature, lines))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = write!(summary, "Total: {} lines ({})", self.total_lines(), totals);
+        summary
+    }
+
+    /// Look up the Swift name chosen for a duplicate type by its module path and original name.
+    pub fn resolved_name(&self, module_path: &str, original_name: &str) -> Option<&str> {
+        self.duplicate_resolutions
+            .iter()
+            .find(|r| r.module_path == module_path && r.original_name == original_name)
+            .map(|r| r.swift_name.as_str())
+    }
+}
+
+/// Attribute the lines of a type declaration to the features they implement.
+///
+/// The declaration is split at the markers the generators emit: the memberwise initializer
+/// of structs, the structs following an enum and the `// MARK: - ... Codable Implementation`
+/// extension.
+pub(crate) fn declaration_features(declaration: &str) -> Vec<(CodeFeature, usize)> {
+    let mut counts = BTreeMap::new();
+    let mut feature = CodeFeature::Declaration;
+    let mut closed = false;
+    for line in declaration.trim_end().lines() {
+        if line.starts_with("// MARK: - ") && line.ends_with("Codable Implementation") {
+            feature = CodeFeature::Codable;
+        } else if line.starts_with("// MARK: - ") && line.ends_with("Copy Modifiers") {
+            feature = CodeFeature::CopyModifiers;
+        } else if line.starts_with("// MARK: - ") && line.ends_with("Change Tracking") {
+            feature = CodeFeature::ChangeTracking;
+        } else if feature == CodeFeature::Declaration {
+            if line.starts_with("    public init(") {
+                feature = CodeFeature::Initializer;
+            } else if closed && line.starts_with("public struct ") {
+                feature = CodeFeature::VariantStructs;
+            }
+        }
+
+        *counts.entry(feature).or_insert(0) += 1;
+
+        if line == "}" {
+            closed = true;
+        }
+        if feature == CodeFeature::Initializer && line == "    }" {
+            feature = CodeFeature::Declaration;
+        }
+    }
+    counts.into_iter().collect()
+}
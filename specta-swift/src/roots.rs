@@ -0,0 +1,103 @@
+//! Exporting only the types reachable from a set of root types.
+//!
+//! Apps often register every type in one large [`TypeCollection`] shared by several
+//! frontends. Restricting an export to its roots generates only the roots and the types
+//! they transitively reference, so each frontend gets a minimal Swift surface.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use specta::{NamedType, SpectaID, TypeCollection};
+
+use crate::error::{Error, Result};
+use crate::utils::validation::collect_references;
+
+/// A type whose dependencies are exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Root {
+    /// Every type with this Rust name.
+    Name(Cow<'static, str>),
+    /// The type with this ID, e.g. `<User as NamedType>::ID`.
+    Id(SpectaID),
+}
+
+/// Types which can be passed to [`Swift::export_roots`](crate::Swift::export_roots).
+///
+/// Implemented for tuples of up to eight named types, a single root is written `(User,)`.
+pub trait RootTypes {
+    /// The IDs of the root types.
+    fn ids() -> Vec<SpectaID>;
+}
+
+macro_rules! impl_root_types {
+    ($($ty:ident),+) => {
+        impl<$($ty: NamedType),+> RootTypes for ($($ty,)+) {
+            fn ids() -> Vec<SpectaID> {
+                vec![$($ty::ID),+]
+            }
+        }
+    };
+}
+
+impl_root_types!(A);
+impl_root_types!(A, B);
+impl_root_types!(A, B, C);
+impl_root_types!(A, B, C, D);
+impl_root_types!(A, B, C, D, E);
+impl_root_types!(A, B, C, D, E, F);
+impl_root_types!(A, B, C, D, E, F, G);
+impl_root_types!(A, B, C, D, E, F, G, H);
+
+/// Collect the roots and every type they transitively reference.
+///
+/// Returns `None` when there are no roots, meaning every type is exported.
+pub(crate) fn root_closure(
+    roots: &[Root],
+    types: &TypeCollection,
+) -> Result<Option<HashSet<SpectaID>>> {
+    if roots.is_empty() {
+        return Ok(None);
+    }
+
+    let mut pending = Vec::new();
+    for root in roots {
+        match root {
+            Root::Name(name) => {
+                let start = pending.len();
+                pending.extend(
+                    types
+                        .into_unsorted_iter()
+                        .filter(|ndt| ndt.name() == name)
+                        .map(|ndt| ndt.sid()),
+                );
+                if pending.len() == start {
+                    return Err(Error::Configuration(format!(
+                        "root type '{}' is not part of the type collection",
+                        name
+                    )));
+                }
+            }
+            Root::Id(sid) => {
+                if types.get(*sid).is_none() {
+                    return Err(Error::Configuration(format!(
+                        "root type {:?} is not part of the type collection",
+                        sid
+                    )));
+                }
+                pending.push(*sid);
+            }
+        }
+    }
+
+    let mut closure = HashSet::new();
+    while let Some(sid) = pending.pop() {
+        if !closure.insert(sid) {
+            continue;
+        }
+        if let Some(ndt) = types.get(sid) {
+            collect_references(ndt.ty(), &mut pending);
+        }
+    }
+
+    Ok(Some(closure))
+}
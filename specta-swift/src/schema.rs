@@ -0,0 +1,37 @@
+//! A hash identifying the schema the bindings were generated from.
+
+use specta::TypeCollection;
+
+/// Compute a hash of every type in the collection.
+///
+/// The hash is stable between runs and platforms (unlike [`std::hash::DefaultHasher`]), so a
+/// Rust server can compare it with the `SpectaSchema.hash` constant emitted by
+/// [`Swift::with_schema_hash`](crate::Swift::with_schema_hash) during a handshake.
+pub fn schema_hash(types: &TypeCollection) -> String {
+    // 64-bit FNV-1a
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+
+    for ndt in types.into_sorted_iter() {
+        write(ndt.module_path().as_bytes());
+        write(b"::");
+        write(ndt.name().as_bytes());
+        write(format!("{:?}{:?}", ndt.generics(), ndt.ty()).as_bytes());
+        write(b"\n");
+    }
+
+    format!("{:016x}", hash)
+}
+
+/// Generate the `SpectaSchema` namespace holding the schema hash.
+pub(crate) fn generate_schema_constant(types: &TypeCollection) -> String {
+    format!(
+        "/// Identifies the Rust types these bindings were generated from.\npublic enum SpectaSchema {{\n    public static let hash = \"{}\"\n}}",
+        schema_hash(types)
+    )
+}
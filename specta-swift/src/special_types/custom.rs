@@ -0,0 +1,62 @@
+//! User-defined special types
+//!
+//! Like `Duration` and `SystemTime`, an application's own types can map to an existing
+//! Swift type instead of being exported, e.g. an internal `Timestamp` struct to `Date`.
+//! Handlers are registered with [`Swift::special_type`](crate::Swift::special_type).
+
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use specta::datatype::NamedDataType;
+
+/// The Swift representation of a special type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecialType {
+    /// The Swift type used wherever the type is referenced.
+    pub swift_type: Cow<'static, str>,
+    /// A Swift expression used as the type's mock value.
+    pub mock: Option<Cow<'static, str>>,
+}
+
+impl SpecialType {
+    /// Map a type to `swift_type`.
+    pub fn new(swift_type: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            swift_type: swift_type.into(),
+            mock: None,
+        }
+    }
+
+    /// Set the mock value, e.g. `Date(timeIntervalSince1970: 0)`.
+    pub fn mock(mut self, value: impl Into<Cow<'static, str>>) -> Self {
+        self.mock = Some(value.into());
+        self
+    }
+}
+
+type SpecialTypeFn = dyn Fn(&NamedDataType) -> Option<SpecialType> + Send + Sync;
+
+/// A detection for user-defined special types.
+#[derive(Clone)]
+pub struct SpecialTypeHandler(Arc<SpecialTypeFn>);
+
+impl SpecialTypeHandler {
+    /// Create a handler from a detection function.
+    pub fn new(
+        detect: impl Fn(&NamedDataType) -> Option<SpecialType> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(detect))
+    }
+
+    /// Detect whether `ndt` is a special type.
+    pub fn detect(&self, ndt: &NamedDataType) -> Option<SpecialType> {
+        (self.0)(ndt)
+    }
+}
+
+impl fmt::Debug for SpecialTypeHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SpecialTypeHandler(..)")
+    }
+}
@@ -0,0 +1,419 @@
+//! Helper types emitted alongside the generated types
+//!
+//! Some Rust types are represented by Swift types which are not generated from the
+//! collection, like `RustDuration` for `std::time::Duration`, `JsonValue` for
+//! `serde_json::Value` and `Tuple2` to `Tuple12` for tuples, which aren't `Codable` in Swift. Each [`HelperType`] knows its Swift source and when it is needed,
+//! and the [`HelperRegistry`] of a [`Swift`](crate::Swift) configuration emits the needed
+//! helpers before the generated types.
+//!
+//! Integrations can register their own helpers:
+//!
+//! ```rust
+//! use specta_swift::{HelperType, Swift};
+//!
+//! let swift = Swift::new().helper(HelperType::new(
+//!     "AnyCodable",
+//!     "public struct AnyCodable: Codable {}\n",
+//!     |types| types.into_unsorted_iter().any(|ndt| ndt.name() == "Dynamic"),
+//! ));
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+use specta::datatype::{DataType, EnumRepr, Fields};
+use specta::TypeCollection;
+
+use super::is_duration_struct;
+
+type ConditionFn = dyn Fn(&TypeCollection) -> bool + Send + Sync;
+
+/// A Swift type emitted when the exported types need it.
+#[derive(Clone)]
+pub struct HelperType {
+    name: Cow<'static, str>,
+    source: Cow<'static, str>,
+    needed: Arc<ConditionFn>,
+}
+
+impl HelperType {
+    /// Create a helper which is emitted when `needed` returns `true` for the exported types.
+    ///
+    /// `source` is emitted verbatim and should end with a newline.
+    pub fn new(
+        name: impl Into<Cow<'static, str>>,
+        source: impl Into<Cow<'static, str>>,
+        needed: impl Fn(&TypeCollection) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            source: source.into(),
+            needed: Arc::new(needed),
+        }
+    }
+
+    /// Create a helper which is emitted in every export.
+    pub fn always(
+        name: impl Into<Cow<'static, str>>,
+        source: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self::new(name, source, |_| true)
+    }
+
+    /// The Swift name of the helper.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The Swift source of the helper.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Check whether the types need the helper.
+    pub fn is_needed(&self, types: &TypeCollection) -> bool {
+        (self.needed)(types)
+    }
+}
+
+impl fmt::Debug for HelperType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HelperType")
+            .field("name", &self.name)
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The helper types available to an export, emitted in registration order.
+#[derive(Debug, Clone)]
+pub struct HelperRegistry {
+    helpers: Vec<HelperType>,
+}
+
+impl Default for HelperRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl HelperRegistry {
+    /// A registry without any helpers.
+    pub fn empty() -> Self {
+        Self { helpers: vec![] }
+    }
+
+    /// A registry with the built-in `RustDuration`, `JsonValue` and tuple helpers.
+    pub fn builtin() -> Self {
+        let registry = Self::empty()
+            .register(HelperType::new(
+                "RustDuration",
+                generate_duration_helper(),
+                needs_duration_helper,
+            ))
+            .register(HelperType::new(
+                "JsonValue",
+                generate_json_value_helper(),
+                needs_json_value_helper,
+            ));
+        (2..=MAX_TUPLE_ARITY).fold(registry, |registry, arity| {
+            registry.register(HelperType::new(
+                tuple_helper_name(arity),
+                generate_tuple_helper(arity),
+                move |types| needs_tuple_helper(types, arity),
+            ))
+        })
+    }
+
+    /// Register a helper, replacing any helper with the same name.
+    pub fn register(mut self, helper: HelperType) -> Self {
+        match self.helpers.iter_mut().find(|h| h.name == helper.name) {
+            Some(existing) => *existing = helper,
+            None => self.helpers.push(helper),
+        }
+        self
+    }
+
+    /// Get the helper with the given name.
+    pub fn get(&self, name: &str) -> Option<&HelperType> {
+        self.helpers.iter().find(|helper| helper.name == name)
+    }
+
+    /// Every registered helper.
+    pub fn helpers(&self) -> &[HelperType] {
+        &self.helpers
+    }
+
+    /// The helpers needed by the types, in registration order.
+    pub fn needed<'a>(&'a self, types: &'a TypeCollection) -> impl Iterator<Item = &'a HelperType> {
+        self.helpers.iter().filter(|helper| helper.is_needed(types))
+    }
+}
+
+/// Check if the type collection contains any Duration types that need the helper
+fn needs_duration_helper(types: &TypeCollection) -> bool {
+    for ndt in types.into_sorted_iter() {
+        if ndt.name() == "Duration" {
+            return true;
+        }
+        // Also check if any struct fields contain Duration
+        if let specta::datatype::DataType::Struct(s) = ndt.ty() {
+            if let specta::datatype::Fields::Named(fields) = s.fields() {
+                for (_, field) in fields.fields() {
+                    if let Some(ty) = field.ty() {
+                        if let specta::datatype::DataType::Reference(r) = ty {
+                            if let Some(referenced_ndt) = types.get(r.sid()) {
+                                if referenced_ndt.name() == "Duration" {
+                                    return true;
+                                }
+                            }
+                        }
+                        // Also check if the field type is a Duration struct directly
+                        if let specta::datatype::DataType::Struct(struct_ty) = ty {
+                            if is_duration_struct(struct_ty) {
+                                return true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Generate the Duration helper struct
+fn generate_duration_helper() -> String {
+    "// MARK: - Duration Helper\n".to_string()
+        + "/// Helper struct to decode Rust Duration format {\"secs\": u64, \"nanos\": u32}\n"
+        + "public struct RustDuration: Codable {\n"
+        + "    public let secs: UInt64\n"
+        + "    public let nanos: UInt32\n"
+        + "    \n"
+        + "    public var timeInterval: TimeInterval {\n"
+        + "        return Double(secs) + Double(nanos) / 1_000_000_000.0\n"
+        + "    }\n"
+        + "}\n\n"
+}
+
+/// Check if a JsonValue type is the built-in serde_json::Value type
+pub(crate) fn is_builtin_json_value(ndt: &specta::datatype::NamedDataType) -> bool {
+    // Consider it built-in if it's from serde_json crate OR from specta's legacy_impls
+    ndt.module_path().contains("serde_json") || ndt.module_path().contains("legacy_impls")
+}
+
+/// Check if we need to generate the JsonValue helper
+fn needs_json_value_helper(types: &TypeCollection) -> bool {
+    for ndt in types.into_sorted_iter() {
+        if ndt.name() == "JsonValue" && is_builtin_json_value(&ndt) {
+            return true;
+        }
+        // Also check if any struct fields contain built-in JsonValue
+        if let specta::datatype::DataType::Struct(s) = ndt.ty() {
+            if let specta::datatype::Fields::Named(fields) = s.fields() {
+                for (_, field) in fields.fields() {
+                    if let Some(ty) = field.ty() {
+                        if let specta::datatype::DataType::Reference(r) = ty {
+                            if let Some(referenced_ndt) = types.get(r.sid()) {
+                                if referenced_ndt.name() == "JsonValue"
+                                    && is_builtin_json_value(referenced_ndt)
+                                {
+                                    return true;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Generate the JsonValue helper enum
+fn generate_json_value_helper() -> String {
+    "// MARK: - JSON Value Helper\n".to_string()
+        + "/// Helper enum to represent arbitrary JSON values\n"
+        + "public indirect enum JsonValue: Codable {\n"
+        + "    case null\n"
+        + "    case bool(Bool)\n"
+        + "    case number(Double)\n"
+        + "    case string(String)\n"
+        + "    case array([JsonValue])\n"
+        + "    case object([String: JsonValue])\n"
+        + "    \n"
+        + "    public init(from decoder: Decoder) throws {\n"
+        + "        let container = try decoder.singleValueContainer()\n"
+        + "        \n"
+        + "        if container.decodeNil() {\n"
+        + "            self = .null\n"
+        + "        } else if let bool = try? container.decode(Bool.self) {\n"
+        + "            self = .bool(bool)\n"
+        + "        } else if let int = try? container.decode(Int.self) {\n"
+        + "            self = .number(Double(int))\n"
+        + "        } else if let double = try? container.decode(Double.self) {\n"
+        + "            self = .number(double)\n"
+        + "        } else if let string = try? container.decode(String.self) {\n"
+        + "            self = .string(string)\n"
+        + "        } else if let array = try? container.decode([JsonValue].self) {\n"
+        + "            self = .array(array)\n"
+        + "        } else if let object = try? container.decode([String: JsonValue].self) {\n"
+        + "            self = .object(object)\n"
+        + "        } else {\n"
+        + "            throw DecodingError.typeMismatch(JsonValue.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: \"Invalid JsonValue\"))\n"
+        + "        }\n"
+        + "    }\n"
+        + "    \n"
+        + "    public func encode(to encoder: Encoder) throws {\n"
+        + "        var container = encoder.singleValueContainer()\n"
+        + "        \n"
+        + "        switch self {\n"
+        + "        case .null:\n"
+        + "            try container.encodeNil()\n"
+        + "        case .bool(let bool):\n"
+        + "            try container.encode(bool)\n"
+        + "        case .number(let double):\n"
+        + "            try container.encode(double)\n"
+        + "        case .string(let string):\n"
+        + "            try container.encode(string)\n"
+        + "        case .array(let array):\n"
+        + "            try container.encode(array)\n"
+        + "        case .object(let object):\n"
+        + "            try container.encode(object)\n"
+        + "        }\n"
+        + "    }\n"
+        + "    \n"
+        + "    // MARK: - Convenience Constructors\n"
+        + "    public static func nullValue() -> JsonValue { .null }\n"
+        + "    public static func boolValue(_ value: Bool) -> JsonValue { .bool(value) }\n"
+        + "    public static func numberValue(_ value: Double) -> JsonValue { .number(value) }\n"
+        + "    public static func numberValue(_ value: Int) -> JsonValue { .number(Double(value)) }\n"
+        + "    public static func stringValue(_ value: String) -> JsonValue { .string(value) }\n"
+        + "    public static func arrayValue(_ value: [JsonValue]) -> JsonValue { .array(value) }\n"
+        + "    public static func objectValue(_ value: [String: JsonValue]) -> JsonValue { .object(value) }\n"
+        + "}\n\n"
+}
+
+/// The largest tuple specta implements `Type` for, and so the largest tuple helper.
+pub const MAX_TUPLE_ARITY: usize = 12;
+
+/// The name of the helper struct representing tuples with `arity` elements, e.g. `Tuple2`.
+pub fn tuple_helper_name(arity: usize) -> String {
+    format!("Tuple{}", arity)
+}
+
+/// Check if any exported type contains a tuple with `arity` elements
+fn needs_tuple_helper(types: &TypeCollection, arity: usize) -> bool {
+    types
+        .into_unsorted_iter()
+        .any(|ndt| contains_tuple(ndt.ty(), arity))
+}
+
+/// Check if a type contains a tuple with `arity` elements, at any depth.
+fn contains_tuple(ty: &DataType, arity: usize) -> bool {
+    let in_fields = |fields: &Fields| match fields {
+        Fields::Named(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|(_, field)| field.ty())
+            .any(|ty| contains_tuple(ty, arity)),
+        Fields::Unnamed(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|field| field.ty())
+            .any(|ty| contains_tuple(ty, arity)),
+        Fields::Unit => false,
+    };
+
+    match ty {
+        DataType::Tuple(tuple) => {
+            tuple.elements().len() == arity
+                || tuple.elements().iter().any(|ty| contains_tuple(ty, arity))
+        }
+        DataType::Nullable(inner) => contains_tuple(inner, arity),
+        DataType::List(list) => contains_tuple(list.ty(), arity),
+        DataType::Map(map) => {
+            contains_tuple(map.key_ty(), arity) || contains_tuple(map.value_ty(), arity)
+        }
+        DataType::Reference(reference) => reference
+            .generics()
+            .values()
+            .any(|ty| contains_tuple(ty, arity)),
+        DataType::Struct(s) => in_fields(s.fields()),
+        DataType::Enum(e) => e.variants().iter().any(|(_, variant)| {
+            in_fields(variant.fields())
+                || (matches!(e.repr(), Some(EnumRepr::Adjacent { .. }))
+                    && !variant.skip()
+                    && tuple_variant_arity(variant.fields()) == arity)
+        }),
+        _ => false,
+    }
+}
+
+/// The number of values of a tuple variant. Adjacently tagged tuple variants are coded
+/// through the tuple helpers.
+fn tuple_variant_arity(fields: &Fields) -> usize {
+    match fields {
+        Fields::Unnamed(fields) => fields.fields().iter().filter(|f| f.ty().is_some()).count(),
+        _ => 0,
+    }
+}
+
+/// Generate the helper struct for tuples with `arity` elements, coded as an array like serde
+fn generate_tuple_helper(arity: usize) -> String {
+    let name = tuple_helper_name(arity);
+    let elements = (0..arity).collect::<Vec<_>>();
+    let join = |f: &dyn Fn(usize) -> String, separator: &str| {
+        elements
+            .iter()
+            .map(|&i| f(i))
+            .collect::<Vec<_>>()
+            .join(separator)
+    };
+    let generics = join(&|i| format!("T{}", i), ", ");
+    let conformance = |protocol: &str| {
+        format!(
+            "extension {}: {} where {} {{}}\n",
+            name,
+            protocol,
+            join(&|i| format!("T{}: {}", i, protocol), ", ")
+        )
+    };
+
+    let mut source = format!("// MARK: - {} Helper\n", name);
+    source += &format!(
+        "/// Helper struct for Rust tuples of {} elements, coded as an array like serde does\n",
+        arity
+    );
+    source += &format!("public struct {}<{}> {{\n", name, generics);
+    source += &join(&|i| format!("    public let _{}: T{}\n", i, i), "");
+    source += "\n";
+    source += &format!(
+        "    public init({}) {{\n",
+        join(&|i| format!("_ _{}: T{}", i, i), ", ")
+    );
+    source += &join(&|i| format!("        self._{} = _{}\n", i, i), "");
+    source += "    }\n}\n\n";
+    source += &format!(
+        "extension {}: Codable where {} {{\n",
+        name,
+        join(&|i| format!("T{}: Codable", i), ", ")
+    );
+    source += "    public init(from decoder: Decoder) throws {\n";
+    source += "        var container = try decoder.unkeyedContainer()\n";
+    source += &join(
+        &|i| format!("        _{} = try container.decode(T{}.self)\n", i, i),
+        "",
+    );
+    source += "    }\n\n";
+    source += "    public func encode(to encoder: Encoder) throws {\n";
+    source += "        var container = encoder.unkeyedContainer()\n";
+    source += &join(&|i| format!("        try container.encode(_{})\n", i), "");
+    source += "    }\n}\n\n";
+    source += &conformance("Equatable");
+    source += &conformance("Hashable");
+    source += &conformance("Sendable");
+    source += "\n";
+    source
+}
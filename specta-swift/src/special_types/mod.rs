@@ -6,6 +6,8 @@
 //! - `json_value`: JsonValue type for arbitrary JSON
 //! - `duration`: Rust Duration → Swift TimeInterval conversion
 //! - `serde_json`: serde_json::Value type handling
+//! - `helpers`: the registry of helper types emitted before the generated types
+//! - `custom`: user-defined special types registered with `Swift::special_type`
 //!
 //! # Architecture
 //!
@@ -40,13 +42,17 @@
 //! ```
 
 // Submodules
+pub mod custom;
 pub mod detection;
 pub mod duration;
+pub mod helpers;
 pub mod serde_json;
 
 // Re-export commonly used functions
+pub use custom::{SpecialType, SpecialTypeHandler};
 pub use detection::is_special_std_type;
 pub use duration::is_duration_struct;
+pub use helpers::{HelperRegistry, HelperType};
 pub use serde_json::is_serde_json_number_enum;
 
 // Re-export public types from submodules once they're created
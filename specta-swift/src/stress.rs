@@ -0,0 +1,205 @@
+//! Synthetic type collections and timing helpers for profiling exports.
+//!
+//! The crate's benchmarks are built on these helpers, and they work the same for your own
+//! schemas:
+//!
+//! ```rust
+//! use specta_swift::stress::{measure, Synthetic};
+//! use specta_swift::Swift;
+//!
+//! let types = Synthetic::new(100).fields(8).variants(4).depth(3).build();
+//! let measurement = measure(&Swift::default(), &types, 3).unwrap();
+//! println!("{}", measurement);
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use specta::builder::NamedDataTypeBuilder;
+use specta::datatype::{DataType, Enum, EnumVariant, Field, List, Primitive, Struct};
+use specta::TypeCollection;
+
+use crate::error::{Error, Result};
+use crate::swift::Swift;
+
+/// Builder for a synthetic [`TypeCollection`] of a given shape.
+///
+/// Every fourth type is an enum mixing unit, tuple and struct-like variants, the others are
+/// structs mixing primitive, optional, list and reference fields. Fields reference the
+/// previously generated types, forming chains `depth` types deep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Synthetic {
+    /// Number of types to generate.
+    pub types: usize,
+    /// Number of fields of each struct.
+    pub fields: usize,
+    /// Number of variants of each enum.
+    pub variants: usize,
+    /// Length of the reference chains between types.
+    pub depth: usize,
+}
+
+impl Synthetic {
+    /// A collection of `types` types with the default shape.
+    pub fn new(types: usize) -> Self {
+        Self {
+            types,
+            fields: 6,
+            variants: 4,
+            depth: 2,
+        }
+    }
+
+    /// Set the number of fields of each struct.
+    pub fn fields(mut self, fields: usize) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Set the number of variants of each enum.
+    pub fn variants(mut self, variants: usize) -> Self {
+        self.variants = variants;
+        self
+    }
+
+    /// Set the length of the reference chains between types.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Generate the collection.
+    pub fn build(&self) -> TypeCollection {
+        let mut types = TypeCollection::default();
+        let mut references = Vec::<DataType>::with_capacity(self.types);
+
+        for i in 0..self.types {
+            // Reference a type from the same chain, so chains are `depth` types long
+            let referenced = (self.depth > 1 && i % self.depth != 0)
+                .then(|| references.get(i - 1).cloned())
+                .flatten();
+
+            let ty = if i % 4 == 3 {
+                self.enum_type(i, referenced)
+            } else {
+                self.struct_type(referenced)
+            };
+            let name = format!("{}{}", if i % 4 == 3 { "Enum" } else { "Struct" }, i);
+            let ndt = NamedDataTypeBuilder::new(name, vec![], ty).module_path("synthetic");
+            match types.create(ndt) {
+                Ok(reference) => references.push(DataType::Reference(reference)),
+                // The collection is full, which needs `u64::MAX` types
+                Err(()) => break,
+            }
+        }
+
+        types
+    }
+
+    fn struct_type(&self, referenced: Option<DataType>) -> DataType {
+        let mut builder = Struct::named();
+        for field in 0..self.fields {
+            builder = builder.field(
+                format!("field_{}", field),
+                Field::new(field_type(field, referenced.as_ref())),
+            );
+        }
+        builder.build()
+    }
+
+    fn enum_type(&self, i: usize, referenced: Option<DataType>) -> DataType {
+        let mut e = Enum::new();
+        for variant in 0..self.variants {
+            // Variant fields are taken from a struct with the same fields
+            let fields = match (i + variant) % 3 {
+                0 => None,
+                1 => Some(
+                    Struct::unnamed()
+                        .field(Field::new(field_type(variant, referenced.as_ref())))
+                        .build(),
+                ),
+                _ => Some(self.struct_type(referenced.clone())),
+            };
+            let mut v = EnumVariant::unit();
+            if let Some(DataType::Struct(s)) = fields {
+                v.set_fields(s.fields().clone());
+            }
+            e.variants_mut()
+                .push((Cow::Owned(format!("Variant{}", variant)), v));
+        }
+        DataType::Enum(e)
+    }
+}
+
+/// The type of the `index`th field, cycling through the common kinds of fields.
+fn field_type(index: usize, referenced: Option<&DataType>) -> DataType {
+    match (index % 5, referenced) {
+        (0, _) => DataType::Primitive(Primitive::u32),
+        (1, _) => DataType::Primitive(Primitive::String),
+        (2, _) => DataType::Nullable(Box::new(DataType::Primitive(Primitive::String))),
+        (3, _) => DataType::List(List::new(DataType::Primitive(Primitive::f64))),
+        (_, Some(referenced)) => referenced.clone(),
+        (_, None) => DataType::Primitive(Primitive::bool),
+    }
+}
+
+/// Timing of repeated exports of the same collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    /// Number of types in the collection.
+    pub types: usize,
+    /// Number of exports which were timed.
+    pub iterations: u32,
+    /// Size of the generated code in bytes.
+    pub bytes: usize,
+    /// Total time of all exports.
+    pub total: Duration,
+}
+
+impl Measurement {
+    /// Average time of a single export.
+    pub fn per_export(&self) -> Duration {
+        self.total / self.iterations.max(1)
+    }
+
+    /// Types exported per second.
+    pub fn types_per_second(&self) -> f64 {
+        self.types as f64 / self.per_export().as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} types ({} bytes) in {:?} per export, {:.0} types/s",
+            self.types,
+            self.bytes,
+            self.per_export(),
+            self.types_per_second()
+        )
+    }
+}
+
+/// Export `types` `iterations` times and measure how long it takes.
+pub fn measure(swift: &Swift, types: &TypeCollection, iterations: u32) -> Result<Measurement> {
+    if iterations == 0 {
+        return Err(Error::Configuration(
+            "at least one iteration is required".to_string(),
+        ));
+    }
+
+    let mut bytes = 0;
+    let start = Instant::now();
+    for _ in 0..iterations {
+        bytes = swift.export(types)?.len();
+    }
+
+    Ok(Measurement {
+        types: types.len(),
+        iterations,
+        bytes,
+        total: start.elapsed(),
+    })
+}
@@ -1,12 +1,58 @@
 //! Swift language exporter configuration and main export functionality.
 
-use std::{borrow::Cow, path::Path};
-
-use specta::TypeCollection;
-
-use crate::datatype::export::export_type_with_name;
-use crate::error::Result;
-use crate::special_types::is_duration_struct;
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    fmt::{self, Write as _},
+    io::{self, Write},
+    ops::Range,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
+
+use specta::{
+    datatype::{DataType, EnumVariant, Fields, NamedDataType},
+    NamedType, TypeCollection,
+};
+
+use crate::ast::{reindent_line, Decl, Item, Printer, TypeDefinition};
+use crate::cache::{fingerprint, is_cacheable, ExportCache};
+use crate::cloudkit::{generate_record_conversions, RECORD_ERROR};
+use crate::codable::PROPERTY_WRAPPERS;
+use crate::coders::{
+    generate_coders, generate_dictionary_helpers, generate_json_helpers, needs_coders,
+    unconvertible_keys,
+};
+use crate::constant::{Constant, SwiftConstant};
+use crate::core_data::{generate_managed_object, ENTITY_ERROR};
+use crate::datatype::export::{build_type_definition, datatype_to_swift};
+use crate::datatype::layout::estimated_payload_size;
+use crate::datatype::reference::{stub_name, use_resolved_names, with_reference_cache};
+use crate::diff::Diff;
+use crate::error::{Error, Result};
+use crate::estimate::SizeEstimate;
+use crate::export::{offset_declarations, parse_declarations, DeclarationKind, Export};
+use crate::fixtures::generate_fixtures;
+use crate::manifest::generate_manifest;
+use crate::mock::{generate_mock_extension, generate_preview_data};
+use crate::naming::case_conversion::{
+    to_lower, CamelCaseConverter, CaseConverter, PascalCaseConverter, SnakeCaseConverter,
+};
+use crate::naming::resolver::{resolve_type_names, VariantStructNames};
+use crate::naming::shadowing::shadows_swift_symbol;
+use crate::naming::unicode;
+use crate::report::{
+    declaration_features, CodeFeature, ExportReport, ExportWarning, ExportedType,
+    UnresolvedReference,
+};
+use crate::roots::{Root, RootTypes};
+use crate::schema::generate_schema_constant;
+use crate::special_types::{
+    is_special_std_type, HelperRegistry, HelperType, SpecialType, SpecialTypeHandler,
+};
+use crate::utils::validation::{collect_references, property_list_issues};
+use crate::xctest::generate_codable_tests;
 
 /// Swift language exporter.
 #[derive(Debug, Clone)]
@@ -15,10 +61,24 @@ pub struct Swift {
     pub header: Cow<'static, str>,
     /// Indentation style for generated code.
     pub indent: IndentStyle,
+    /// Where opening braces of declarations and functions go.
+    pub brace_style: BraceStyle,
+    /// Write `name : Type` instead of `name: Type` in declarations.
+    pub space_before_colon: bool,
+    /// Add a comma after the last element of wrapped array literals.
+    pub trailing_commas: bool,
+    /// Wrap array literals which would make a line longer than this.
+    pub line_width: Option<usize>,
     /// Naming convention for identifiers.
     pub naming: NamingConvention,
+    /// How non-ASCII characters in identifiers are handled.
+    pub unicode_identifiers: UnicodeIdentifiers,
+    /// Prefix added to type names shadowing Swift symbols such as `Data` or `Result`.
+    pub shadowed_name_prefix: Option<Cow<'static, str>>,
     /// Generic type style.
     pub generics: GenericStyle,
+    /// Declare every concrete instantiation of a generic type instead of the generic type.
+    pub monomorphize: bool,
     /// Optional type style.
     pub optionals: OptionalStyle,
     /// Additional protocols to conform to.
@@ -29,8 +89,173 @@ pub struct Swift {
     pub struct_naming: StructNamingStrategy,
     /// Generate public initializers for structs.
     pub generate_initializers: bool,
+    /// Types overriding [`generate_initializers`](Self::generate_initializers), by Rust name.
+    pub initializer_overrides: Vec<(Cow<'static, str>, bool)>,
+    /// Emit explicit `CodingKeys`, `init(from:)` and `encode(to:)` for every struct.
+    pub always_emit_codable: bool,
+    /// Make the generated types conform to `Codable`.
+    pub emit_codable: bool,
+    /// The serialization format the `Codable` implementations target.
+    pub codable_format: CodableFormat,
+    /// How JSON keys are mapped to Swift property names.
+    pub key_strategy: KeyStrategy,
+    /// How missing array and dictionary fields are decoded.
+    pub missing_collections: MissingCollectionStrategy,
+    /// How optional array and dictionary fields are generated.
+    pub optional_collections: OptionalCollectionStrategy,
+    /// How references to types missing from the collection are exported.
+    pub missing_reference: MissingReferenceStrategy,
+    /// Decode arrays, strings and booleans through lenient property wrappers.
+    pub resilient_decoding: bool,
+    /// Reject fixed-size array fields (`[T; N]`) whose decoded length isn't `N`.
+    pub validate_array_lengths: bool,
+    /// The Swift type of `char` values.
+    pub char_type: CharType,
+    /// How single-field tuple structs, such as `UserId(Uuid)`, are generated.
+    pub newtypes: NewtypeStyle,
+    /// The order of enum cases and their `CodingKeys`.
+    pub variant_ordering: VariantOrdering,
+    /// Enum cases whose payload is estimated larger than this many bytes are `indirect`.
+    pub variant_boxing_threshold: Option<usize>,
+    /// Enums overriding [`variant_boxing_threshold`](Self::variant_boxing_threshold), by Rust
+    /// name. `true` boxes every data-carrying case, `false` none.
+    pub variant_boxing_overrides: Vec<(Cow<'static, str>, bool)>,
+    /// Bitflag types exported as `OptionSet`s, by Rust name, with the bits of their flags.
+    pub option_sets: Vec<(Cow<'static, str>, OptionSetFlags)>,
+    /// How `Date` values are encoded by the generated coders.
+    pub date_strategy: DateStrategy,
+    /// How `Data` values are encoded by the generated coders.
+    pub data_strategy: DataStrategy,
+    /// Emit the `JSONEncoder.specta` and `JSONDecoder.specta` factories.
+    pub generate_coders: bool,
+    /// Make the output compile and behave the same with Foundation on Linux.
+    pub linux_support: bool,
+    /// Emit `allRawValues` and `init?(caseInsensitive:)` for string enums.
+    pub string_enum_helpers: bool,
+    /// Emit `init?(jsonData:)` and `jsonData()` for structs and enums.
+    pub json_helpers: bool,
+    /// Emit `init(dictionary:)` and `dictionary` for structs.
+    pub dictionary_helpers: bool,
+    /// Emit `with(field:)` copy-modifiers for structs.
+    pub copy_modifiers: bool,
+    /// Emit `allKeyPaths` and `changedFields(from:)` for structs.
+    pub change_tracking: bool,
+    /// Structs converted to and from `CKRecord`, by Rust name.
+    pub cloudkit_records: Vec<Cow<'static, str>>,
+    /// Structs mirrored by an `NSManagedObject` subclass, by Rust name.
+    pub core_data_entities: Vec<Cow<'static, str>>,
+    /// Generate `mock` placeholder values for structs and enums.
+    pub generate_mocks: bool,
+    /// Generate a `PreviewData` namespace with sample values (requires `generate_mocks`).
+    pub generate_preview_data: bool,
+    /// Constants emitted in the `Constants` namespace.
+    pub constants: Vec<Constant>,
+    /// Emit a `SpectaSchema.hash` constant identifying the exported types.
+    pub schema_hash: bool,
+    /// Module imported with `@testable` by the tests written by [`Swift::export_tests`].
+    pub test_module: Option<Cow<'static, str>>,
+    /// Also compare the decoded samples with the originals in the tests of
+    /// [`Swift::export_tests`], for types conforming to `Equatable`.
+    pub equatable_tests: bool,
     /// Strategy for handling duplicate type names.
     pub duplicate_name_strategy: DuplicateNameStrategy,
+    /// Options used when qualifying duplicate type names.
+    pub qualify: QualifyOptions,
+    /// Predicates a type must satisfy to be exported.
+    pub filters: Vec<TypeFilter>,
+    /// Types whose dependencies are exported. Empty exports every type.
+    pub roots: Vec<Root>,
+    /// Helper types emitted before the generated types when needed.
+    pub helpers: HelperRegistry,
+    /// User-defined special types, checked in registration order.
+    pub special_types: Vec<SpecialTypeHandler>,
+    /// Swift types assumed to be declared, and `Codable`, outside the generated code.
+    pub assumed_external: Vec<Cow<'static, str>>,
+    /// Hooks applied in order to each generated declaration.
+    pub post_processors: Vec<PostProcessor>,
+    /// Hooks applied in order to the syntax tree of each generated declaration.
+    pub ast_post_processors: Vec<AstPostProcessor>,
+    /// Custom Swift code appended after a type, keyed by the type's Swift name.
+    pub type_extensions: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    /// File used to cache generated code between exports.
+    pub cache_path: Option<PathBuf>,
+    /// Heuristic used to pre-size the buffers generated code is written into.
+    pub size_estimate: SizeEstimate,
+    /// Render declarations on the thread pool when the `rayon` feature is enabled.
+    pub parallel: bool,
+}
+
+/// A predicate deciding whether a type is exported.
+#[derive(Clone)]
+pub struct TypeFilter(Arc<dyn Fn(&NamedDataType) -> bool + Send + Sync>);
+
+impl TypeFilter {
+    /// Create a filter from a predicate.
+    pub fn new(predicate: impl Fn(&NamedDataType) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(predicate))
+    }
+
+    /// Check whether a type passes the filter.
+    pub fn matches(&self, ndt: &NamedDataType) -> bool {
+        (self.0)(ndt)
+    }
+}
+
+impl fmt::Debug for TypeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TypeFilter(..)")
+    }
+}
+
+/// The flags of an option set with their bits, see [`Swift::option_set`].
+pub type OptionSetFlags = Vec<(Cow<'static, str>, u64)>;
+
+type PostProcessorFn = dyn Fn(&NamedDataType, String) -> String + Send + Sync;
+
+/// A hook which rewrites the generated Swift declaration of a type.
+#[derive(Clone)]
+pub struct PostProcessor(Arc<PostProcessorFn>);
+
+impl PostProcessor {
+    /// Create a post-processor from a function.
+    pub fn new(f: impl Fn(&NamedDataType, String) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Apply the post-processor to a declaration.
+    pub fn apply(&self, ndt: &NamedDataType, declaration: String) -> String {
+        (self.0)(ndt, declaration)
+    }
+}
+
+impl fmt::Debug for PostProcessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PostProcessor(..)")
+    }
+}
+
+type AstPostProcessorFn = dyn Fn(&NamedDataType, &mut TypeDefinition) + Send + Sync;
+
+/// A hook which edits the syntax tree of a generated declaration before it is rendered.
+#[derive(Clone)]
+pub struct AstPostProcessor(Arc<AstPostProcessorFn>);
+
+impl AstPostProcessor {
+    /// Create a post-processor from a function.
+    pub fn new(f: impl Fn(&NamedDataType, &mut TypeDefinition) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Apply the post-processor to a declaration.
+    pub fn apply(&self, ndt: &NamedDataType, definition: &mut TypeDefinition) {
+        (self.0)(ndt, definition)
+    }
+}
+
+impl fmt::Debug for AstPostProcessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AstPostProcessor(..)")
+    }
 }
 
 /// Indentation style for generated Swift code.
@@ -48,8 +273,44 @@ impl Default for IndentStyle {
     }
 }
 
-/// Naming convention for Swift identifiers.
+impl IndentStyle {
+    /// The text of one level of indentation.
+    pub fn unit(self) -> String {
+        match self {
+            Self::Spaces(spaces) => " ".repeat(spaces),
+            Self::Tabs => "\t".into(),
+        }
+    }
+
+    /// Convert code indented with four spaces per level to this style.
+    pub fn reindent(self, code: &str) -> Cow<'_, str> {
+        if self == Self::Spaces(4) {
+            return Cow::Borrowed(code);
+        }
+        let unit = self.unit();
+        let mut result = String::with_capacity(code.len());
+        for (i, line) in code.split('\n').enumerate() {
+            if i > 0 {
+                result.push('\n');
+            }
+            result.push_str(&reindent_line(line, &unit));
+        }
+        Cow::Owned(result)
+    }
+}
+
+/// Placement of the opening brace of declarations and functions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BraceStyle {
+    /// `struct User {`, the Swift convention.
+    #[default]
+    SameLine,
+    /// The brace on its own line below the declaration.
+    NextLine,
+}
+
+/// Naming convention for Swift identifiers.
+#[derive(Debug, Clone, Default)]
 pub enum NamingConvention {
     /// PascalCase naming (default for Swift types).
     #[default]
@@ -58,6 +319,34 @@ pub enum NamingConvention {
     CamelCase,
     /// snake_case naming.
     SnakeCase,
+    /// Naming with a user-provided [`CaseConverter`], see [`NamingConvention::custom`].
+    Custom(Arc<dyn CaseConverter>),
+}
+
+impl PartialEq for NamingConvention {
+    /// Custom conventions are equal when they share the same converter.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+        }
+    }
+}
+
+impl Eq for NamingConvention {}
+
+/// How non-ASCII characters in type, field and case names are handled.
+///
+/// The serialized names are never changed, so renamed fields keep their original spelling in
+/// the `CodingKeys`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnicodeIdentifiers {
+    /// Normalize names to their precomposed form (NFC), so `e` followed by a combining accent
+    /// and `é` produce the same identifier (default).
+    #[default]
+    Normalize,
+    /// Normalize names and transliterate Latin letters to ASCII (`Café` becomes `Cafe`).
+    Transliterate,
 }
 
 /// Generic type style for Swift.
@@ -80,6 +369,127 @@ pub enum OptionalStyle {
     Optional,
 }
 
+/// How JSON keys are mapped to Swift property names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyStrategy {
+    /// Generate `CodingKeys` for every type whose property names differ from the JSON keys.
+    #[default]
+    CodingKeys,
+    /// Skip the `CodingKeys` of properties and rely on the decoder converting `snake_case`
+    /// keys, configured by the generated `JSONDecoder.specta`.
+    ConvertFromSnakeCase,
+}
+
+/// The serialization format the generated `Codable` implementations target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CodableFormat {
+    /// `JSONEncoder` and `JSONDecoder` (default).
+    #[default]
+    Json,
+    /// MessagePack coders such as `MessagePackEncoder`, for data written by
+    /// `rmp_serde::to_vec_named` (structs must be maps, not arrays).
+    ///
+    /// Enums follow serde's representation exactly instead of relying on JSON specifics, and
+    /// [`Swift::export_tests`] round-trips through MessagePack.
+    MessagePack,
+    /// `PropertyListEncoder` and `PropertyListDecoder`, e.g. for types stored in
+    /// `UserDefaults`.
+    ///
+    /// Property lists can't store `nil`, so optional fields are omitted instead of encoded as
+    /// `null` and unit variants are encoded as strings. Constructs which still can't be
+    /// stored are reported as [`ExportWarning::PropertyListIncompatible`].
+    PropertyList,
+}
+
+/// The order enum cases are generated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VariantOrdering {
+    /// Keep the order the variants are declared in Rust (default).
+    #[default]
+    Declaration,
+    /// Sort the variants by their Swift case name, so reordering them in Rust doesn't change
+    /// the bindings.
+    Alphabetical,
+}
+
+/// The Swift type `char` values are represented by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CharType {
+    /// `Character`, which isn't `Codable`, so structs with `char` fields decode a single
+    /// character string in a custom implementation (default).
+    #[default]
+    Character,
+    /// `String`, which is `Codable` but doesn't enforce a single character.
+    String,
+}
+
+/// How single-field tuple structs (newtypes), such as `UserId(Uuid)`, are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewtypeStyle {
+    /// A struct with a `value` property (default).
+    #[default]
+    Struct,
+    /// A `RawRepresentable` struct with a `rawValue`, coded as the wrapped value like serde
+    /// does. IDs stay distinct types, e.g. a `UserId` can't be passed where a `PostId` is
+    /// expected.
+    RawRepresentable,
+}
+
+/// How array and dictionary fields missing from the JSON are decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingCollectionStrategy {
+    /// Decoding fails, like serde without `#[serde(default)]` (default).
+    #[default]
+    Error,
+    /// Missing fields decode as `[]` or `[:]`, like serde with `#[serde(default)]`.
+    Empty,
+}
+
+/// How optional array and dictionary fields, such as `Option<Vec<T>>`, are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OptionalCollectionStrategy {
+    /// An optional collection, e.g. `[String]?` (default).
+    #[default]
+    Optional,
+    /// A non-optional collection which decodes as `[]` or `[:]` when the field is missing or
+    /// `null`, e.g. `[String]`.
+    Empty,
+}
+
+/// How references to types which aren't in the `TypeCollection` are exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingReferenceStrategy {
+    /// The export fails with [`Error::UnknownReference`] (default).
+    #[default]
+    Error,
+    /// The reference refers to an empty placeholder struct and a warning is reported.
+    Stub,
+}
+
+/// How `Date` values are encoded, configured on the generated coders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateStrategy {
+    /// Foundation's default, seconds since 2001-01-01 (default).
+    #[default]
+    DeferredToDate,
+    /// ISO 8601 strings, e.g. `chrono::DateTime` with serde.
+    Iso8601,
+    /// Seconds since the Unix epoch.
+    SecondsSince1970,
+    /// Milliseconds since the Unix epoch.
+    MillisecondsSince1970,
+}
+
+/// How `Data` values are encoded, configured on the generated coders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DataStrategy {
+    /// Base64 strings, Foundation's default (default).
+    #[default]
+    Base64,
+    /// Arrays of bytes, like serde encodes `Vec<u8>`.
+    DeferredToData,
+}
+
 /// Struct naming strategy for enum variants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum StructNamingStrategy {
@@ -95,8 +505,8 @@ pub enum StructNamingStrategy {
 /// Strategy for handling duplicate type names during export.
 #[derive(Debug, Clone, Default)]
 pub enum DuplicateNameStrategy {
-    /// Emit warnings but continue with last definition (default).
-    /// This maintains backward compatibility while alerting users to potential issues.
+    /// Emit warnings and qualify the conflicting names so every type is still exported (default).
+    /// Warnings are printed to stderr and listed in the [`ExportReport`](crate::ExportReport).
     #[default]
     Warn,
     /// Fail the build with an error when duplicates are found.
@@ -105,9 +515,370 @@ pub enum DuplicateNameStrategy {
     /// Use qualified names automatically based on module paths.
     /// Example: `LibraryInfo` from `core::ops::libraries` becomes `CoreOpsLibrariesLibraryInfo`.
     Qualify,
+    /// Keep the first definition's name and append `2`, `3`, … to later duplicates.
+    /// Example: the second `LibraryInfo` becomes `LibraryInfo2`.
+    Suffix,
     /// Use a custom naming function to resolve duplicates.
     /// The function receives the NamedDataType and should return a unique name.
     Custom(fn(&specta::datatype::NamedDataType) -> String),
+    /// Like [`Custom`](Self::Custom), but the function also receives a [`DuplicateContext`]
+    /// with every colliding type and the names already chosen, so it can guarantee the
+    /// returned name is globally unique.
+    CustomWithContext(fn(&NamedDataType, &DuplicateContext<'_>) -> String),
+}
+
+/// The state of name resolution passed to [`DuplicateNameStrategy::CustomWithContext`].
+#[derive(Debug, Clone, Copy)]
+pub struct DuplicateContext<'a> {
+    /// The conflicting name, before the naming strategy is applied.
+    pub name: &'a str,
+    /// Every type sharing [`name`](Self::name), including the one being renamed.
+    pub colliding: &'a [NamedDataType],
+    /// Swift names which are already in use, including the names returned by earlier calls.
+    pub taken_names: &'a HashSet<String>,
+}
+
+impl DuplicateContext<'_> {
+    /// Whether `name` is already used by another type.
+    pub fn is_taken(&self, name: &str) -> bool {
+        self.taken_names.contains(name)
+    }
+}
+
+/// Parse an option value, accepting `kebab-case`, `snake_case` and the variant name itself.
+fn parse_option<T: Clone>(kind: &str, value: &str, options: &[(&str, T)]) -> Result<T> {
+    let normalized = value.trim().to_ascii_lowercase().replace(['_', '-'], "");
+    options
+        .iter()
+        .find(|(name, _)| name.replace('-', "") == normalized)
+        .map(|(_, option)| option.clone())
+        .ok_or_else(|| {
+            Error::Configuration(format!(
+                "invalid {} '{}', expected one of: {}",
+                kind,
+                value,
+                options
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        })
+}
+
+impl FromStr for IndentStyle {
+    type Err = Error;
+
+    /// Parse `tabs` or a number of spaces.
+    fn from_str(s: &str) -> Result<Self> {
+        if s.trim().eq_ignore_ascii_case("tabs") {
+            return Ok(Self::Tabs);
+        }
+        s.trim().parse().map(Self::Spaces).map_err(|_| {
+            Error::Configuration(format!(
+                "invalid indent '{}', expected 'tabs' or a number of spaces",
+                s
+            ))
+        })
+    }
+}
+
+impl FromStr for BraceStyle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "brace style",
+            s,
+            &[("same-line", Self::SameLine), ("next-line", Self::NextLine)],
+        )
+    }
+}
+
+impl FromStr for NamingConvention {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "naming convention",
+            s,
+            &[
+                ("pascal-case", Self::PascalCase),
+                ("camel-case", Self::CamelCase),
+                ("snake-case", Self::SnakeCase),
+            ],
+        )
+    }
+}
+
+impl FromStr for GenericStyle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "generic style",
+            s,
+            &[("protocol", Self::Protocol), ("typealias", Self::Typealias)],
+        )
+    }
+}
+
+impl FromStr for OptionalStyle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "optional style",
+            s,
+            &[
+                ("question-mark", Self::QuestionMark),
+                ("optional", Self::Optional),
+            ],
+        )
+    }
+}
+
+impl FromStr for KeyStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "key strategy",
+            s,
+            &[
+                ("coding-keys", Self::CodingKeys),
+                ("convert-from-snake-case", Self::ConvertFromSnakeCase),
+            ],
+        )
+    }
+}
+
+impl FromStr for UnicodeIdentifiers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "unicode identifiers mode",
+            s,
+            &[
+                ("normalize", Self::Normalize),
+                ("transliterate", Self::Transliterate),
+            ],
+        )
+    }
+}
+
+impl FromStr for CodableFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "codable format",
+            s,
+            &[
+                ("json", Self::Json),
+                ("message-pack", Self::MessagePack),
+                ("property-list", Self::PropertyList),
+            ],
+        )
+    }
+}
+
+impl FromStr for VariantOrdering {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "variant ordering",
+            s,
+            &[
+                ("declaration", Self::Declaration),
+                ("alphabetical", Self::Alphabetical),
+            ],
+        )
+    }
+}
+
+impl FromStr for CharType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "char type",
+            s,
+            &[("character", Self::Character), ("string", Self::String)],
+        )
+    }
+}
+
+impl FromStr for NewtypeStyle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "newtype style",
+            s,
+            &[
+                ("struct", Self::Struct),
+                ("raw-representable", Self::RawRepresentable),
+            ],
+        )
+    }
+}
+
+impl FromStr for MissingCollectionStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "missing collection strategy",
+            s,
+            &[("error", Self::Error), ("empty", Self::Empty)],
+        )
+    }
+}
+
+impl FromStr for OptionalCollectionStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "optional collection strategy",
+            s,
+            &[("optional", Self::Optional), ("empty", Self::Empty)],
+        )
+    }
+}
+
+impl FromStr for MissingReferenceStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "missing reference strategy",
+            s,
+            &[("error", Self::Error), ("stub", Self::Stub)],
+        )
+    }
+}
+
+impl FromStr for DateStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "date strategy",
+            s,
+            &[
+                ("deferred-to-date", Self::DeferredToDate),
+                ("iso8601", Self::Iso8601),
+                ("seconds-since-1970", Self::SecondsSince1970),
+                ("milliseconds-since-1970", Self::MillisecondsSince1970),
+            ],
+        )
+    }
+}
+
+impl FromStr for DataStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "data strategy",
+            s,
+            &[
+                ("base64", Self::Base64),
+                ("deferred-to-data", Self::DeferredToData),
+            ],
+        )
+    }
+}
+
+impl FromStr for StructNamingStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "struct naming strategy",
+            s,
+            &[
+                ("auto-rename", Self::AutoRename),
+                ("keep-original", Self::KeepOriginal),
+            ],
+        )
+    }
+}
+
+impl FromStr for DuplicateNameStrategy {
+    type Err = Error;
+
+    /// Parse any strategy except the custom ones.
+    fn from_str(s: &str) -> Result<Self> {
+        parse_option(
+            "duplicate name strategy",
+            s,
+            &[
+                ("warn", Self::Warn),
+                ("error", Self::Error),
+                ("qualify", Self::Qualify),
+                ("suffix", Self::Suffix),
+            ],
+        )
+    }
+}
+
+/// Options controlling how qualified names are built from module paths.
+///
+/// Used by [`DuplicateNameStrategy::Qualify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QualifyOptions {
+    /// Separator inserted between the module segments and the type name.
+    pub separator: Cow<'static, str>,
+    /// Maximum number of trailing module segments to keep (`None` keeps all).
+    pub depth: Option<usize>,
+    /// Module path prefixes removed before qualifying (e.g. the crate name).
+    pub strip_prefixes: Vec<Cow<'static, str>>,
+}
+
+impl Default for QualifyOptions {
+    fn default() -> Self {
+        Self {
+            separator: "".into(),
+            depth: Some(3),
+            strip_prefixes: vec![],
+        }
+    }
+}
+
+impl QualifyOptions {
+    /// Create qualify options with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the separator inserted between module segments and the type name.
+    pub fn separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Keep only the last `depth` module segments.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Keep every module segment.
+    pub fn full_path(mut self) -> Self {
+        self.depth = None;
+        self
+    }
+
+    /// Remove a module path prefix (e.g. `my_crate` or `my_crate::api`) before qualifying.
+    pub fn strip_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.strip_prefixes.push(prefix.into());
+        self
+    }
 }
 
 impl Default for Swift {
@@ -115,14 +886,66 @@ impl Default for Swift {
         Self {
             header: "// This file has been generated by Specta. DO NOT EDIT.".into(),
             indent: IndentStyle::default(),
+            brace_style: BraceStyle::default(),
+            space_before_colon: false,
+            trailing_commas: false,
+            line_width: None,
             naming: NamingConvention::default(),
+            unicode_identifiers: UnicodeIdentifiers::default(),
+            shadowed_name_prefix: None,
             generics: GenericStyle::default(),
+            monomorphize: false,
             optionals: OptionalStyle::default(),
             protocols: vec![],
             serde: false,
             struct_naming: StructNamingStrategy::default(),
             generate_initializers: false,
+            initializer_overrides: vec![],
+            always_emit_codable: false,
+            emit_codable: true,
+            codable_format: CodableFormat::default(),
+            key_strategy: KeyStrategy::default(),
+            missing_collections: MissingCollectionStrategy::default(),
+            optional_collections: OptionalCollectionStrategy::default(),
+            missing_reference: MissingReferenceStrategy::default(),
+            resilient_decoding: false,
+            validate_array_lengths: false,
+            char_type: CharType::default(),
+            newtypes: NewtypeStyle::default(),
+            variant_ordering: VariantOrdering::default(),
+            variant_boxing_threshold: None,
+            variant_boxing_overrides: vec![],
+            option_sets: vec![],
+            date_strategy: DateStrategy::default(),
+            data_strategy: DataStrategy::default(),
+            generate_coders: false,
+            linux_support: false,
+            string_enum_helpers: false,
+            json_helpers: false,
+            dictionary_helpers: false,
+            copy_modifiers: false,
+            change_tracking: false,
+            cloudkit_records: vec![],
+            core_data_entities: vec![],
+            generate_mocks: false,
+            generate_preview_data: false,
+            constants: vec![],
+            schema_hash: false,
+            test_module: None,
+            equatable_tests: false,
             duplicate_name_strategy: DuplicateNameStrategy::default(),
+            qualify: QualifyOptions::default(),
+            filters: vec![],
+            roots: vec![],
+            helpers: HelperRegistry::default(),
+            special_types: vec![],
+            assumed_external: vec![],
+            post_processors: vec![],
+            ast_post_processors: vec![],
+            type_extensions: vec![],
+            cache_path: None,
+            size_estimate: SizeEstimate::default(),
+            parallel: true,
         }
     }
 }
@@ -133,6 +956,38 @@ impl Swift {
         Self::default()
     }
 
+    /// Create an exporter which only generates the types and the `Codable` code they need.
+    ///
+    /// Initializers, helpers, mocks and every other option which only adds code are turned
+    /// off explicitly, so the preset stays minimal if defaults change. Options which change the
+    /// shape of the generated types, like [`Swift::option_set`], keep their defaults, so
+    /// switching presets never changes what the types decode. Use
+    /// [`ExportReport::lines_by_feature`] to see what the remaining code is made of.
+    pub fn minimal() -> Self {
+        Self {
+            generate_initializers: false,
+            initializer_overrides: vec![],
+            always_emit_codable: false,
+            resilient_decoding: false,
+            validate_array_lengths: false,
+            variant_boxing_threshold: None,
+            variant_boxing_overrides: vec![],
+            generate_coders: false,
+            linux_support: false,
+            string_enum_helpers: false,
+            json_helpers: false,
+            dictionary_helpers: false,
+            copy_modifiers: false,
+            change_tracking: false,
+            cloudkit_records: vec![],
+            core_data_entities: vec![],
+            generate_mocks: false,
+            generate_preview_data: false,
+            schema_hash: false,
+            ..Self::default()
+        }
+    }
+
     /// Set the header comment for generated files.
     pub fn header(mut self, header: impl Into<Cow<'static, str>>) -> Self {
         self.header = header.into();
@@ -145,18 +1000,85 @@ impl Swift {
         self
     }
 
+    /// Set where the opening braces of declarations, extensions and functions go.
+    ///
+    /// Braces inside generated function bodies keep the Swift convention. Declaration
+    /// lookups ([`Swift::export_declarations`]), [`Swift::diff`] and [`compat`](crate::compat)
+    /// checks work with either style.
+    pub fn brace_style(mut self, style: BraceStyle) -> Self {
+        self.brace_style = style;
+        self
+    }
+
+    /// Write a space before the colons of property types and conformance clauses, e.g.
+    /// `public let id : UInt32`.
+    pub fn space_before_colon(mut self, enabled: bool) -> Self {
+        self.space_before_colon = enabled;
+        self
+    }
+
+    /// Add a comma after the last element of array literals wrapped by [`Swift::line_width`].
+    pub fn trailing_commas(mut self, enabled: bool) -> Self {
+        self.trailing_commas = enabled;
+        self
+    }
+
+    /// Wrap array literals (e.g. `allRawValues`) with one element per line when they would
+    /// make a line longer than `width` columns.
+    pub fn line_width(mut self, width: usize) -> Self {
+        self.line_width = Some(width);
+        self
+    }
+
+    /// The printer rendering declarations in the configured style.
+    pub fn printer(&self) -> Printer {
+        Printer {
+            indent: self.indent,
+            braces: self.brace_style,
+            space_before_colon: self.space_before_colon,
+            trailing_commas: self.trailing_commas,
+            line_width: self.line_width,
+        }
+    }
+
     /// Set the naming convention.
     pub fn naming(mut self, convention: NamingConvention) -> Self {
         self.naming = convention;
         self
     }
 
+    /// Set how non-ASCII characters in identifiers are handled.
+    pub fn unicode_identifiers(mut self, mode: UnicodeIdentifiers) -> Self {
+        self.unicode_identifiers = mode;
+        self
+    }
+
+    /// Prefix type names which shadow Swift symbols, e.g. `Data` becomes `AppData` with the
+    /// prefix `App`.
+    ///
+    /// Without a prefix such types keep their name and an [`ExportWarning::ShadowedName`] is
+    /// reported, since they hide the standard library or Foundation type in the whole module.
+    pub fn prefix_shadowed_names(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.shadowed_name_prefix = Some(prefix.into());
+        self
+    }
+
     /// Set the generic type style.
     pub fn generics(mut self, style: GenericStyle) -> Self {
         self.generics = style;
         self
     }
 
+    /// Declare concrete types instead of generic ones.
+    ///
+    /// Every instantiation found in the exported types gets its own declaration, named after
+    /// the generic type and its arguments (`ApiResponse<User>` becomes `ApiResponseUser`).
+    /// Generic types which are never instantiated are not exported.
+    pub fn monomorphize(mut self, enabled: bool) -> Self {
+        self.monomorphize = enabled;
+        self
+    }
+
     /// Set the optional type style.
     pub fn optionals(mut self, style: OptionalStyle) -> Self {
         self.optionals = style;
@@ -169,329 +1091,1392 @@ impl Swift {
         self
     }
 
-    /// Add a protocol that all types should conform to.
-    pub fn add_protocol(mut self, protocol: impl Into<Cow<'static, str>>) -> Self {
-        self.protocols.push(protocol.into());
+    /// Generate public initializers for the given structs, even when
+    /// [`generate_initializers`](Self::generate_initializers) is disabled.
+    ///
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type.
+    pub fn initializers_for<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.initializer_overrides
+            .extend(names.into_iter().map(|name| (name.into(), true)));
         self
     }
 
-    /// Configure struct naming strategy for enum variants.
-    pub fn struct_naming(mut self, strategy: StructNamingStrategy) -> Self {
-        self.struct_naming = strategy;
+    /// Never generate public initializers for the given structs, e.g. types with so many
+    /// fields that the initializer is unwieldy.
+    ///
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type.
+    pub fn skip_initializers_for<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.initializer_overrides
+            .extend(names.into_iter().map(|name| (name.into(), false)));
         self
     }
 
-    /// Configure how to handle duplicate type names during export.
-    pub fn duplicate_name_strategy(mut self, strategy: DuplicateNameStrategy) -> Self {
-        self.duplicate_name_strategy = strategy;
+    /// Emit explicit `CodingKeys`, `init(from:)` and `encode(to:)` for every struct with fields.
+    ///
+    /// By default Swift synthesizes the implementation unless a struct has optional fields,
+    /// which need an explicit one to encode `nil` as `null`.
+    pub fn always_emit_codable(mut self, enabled: bool) -> Self {
+        self.always_emit_codable = enabled;
         self
     }
 
-    /// Export types to a Swift string.
-    pub fn export(&self, types: &TypeCollection) -> Result<String> {
-        if self.serde {
-            specta_serde::validate(types)?;
-        }
+    /// Make the generated types conform to `Codable` (default).
+    ///
+    /// When disabled, structs and enums are plain type shapes without `Codable`, `CodingKeys`
+    /// or custom implementations, e.g. for types serialized by a hand-written binary encoder.
+    /// The JSON and dictionary helpers, which need `Codable`, are not emitted either.
+    pub fn emit_codable(mut self, enabled: bool) -> Self {
+        self.emit_codable = enabled;
+        self
+    }
 
-        let mut result = String::new();
+    /// Set the serialization format the `Codable` implementations target.
+    ///
+    /// Formats other than [`CodableFormat::Json`] can't be combined with
+    /// [`KeyStrategy::ConvertFromSnakeCase`], which relies on `JSONDecoder`.
+    pub fn codable_format(mut self, format: CodableFormat) -> Self {
+        self.codable_format = format;
+        self
+    }
 
-        // Add header
-        if !self.header.is_empty() {
-            result.push_str(&self.header);
-            result.push('\n');
-        }
+    /// Set how JSON keys are mapped to Swift property names.
+    ///
+    /// [`KeyStrategy::ConvertFromSnakeCase`] drops the `CodingKeys` of every type and emits
+    /// coders configured to convert the keys instead (see [`Swift::with_coders`]), which must
+    /// be used to decode the types. This requires camelCase property names. The coders also
+    /// convert enum tags and renamed keys, so the `CodingKeys` of a type spell out the keys
+    /// which don't survive the round trip and the export reports an
+    /// [`ExportWarning::UnconvertibleKey`] for each.
+    pub fn key_strategy(mut self, strategy: KeyStrategy) -> Self {
+        self.key_strategy = strategy;
+        self
+    }
 
-        // Add imports
-        result.push_str("import Foundation\n");
-        if self.serde {
-            result.push_str("import Codable\n");
-        }
-        for protocol in &self.protocols {
-            result.push_str(&format!("import {}\n", protocol));
+    /// Set how array and dictionary fields missing from the JSON are decoded.
+    ///
+    /// [`MissingCollectionStrategy::Empty`] emits a custom Codable implementation for structs
+    /// with collection fields, decoding them with `decodeIfPresent` and an empty default.
+    pub fn missing_collections(mut self, strategy: MissingCollectionStrategy) -> Self {
+        self.missing_collections = strategy;
+        self
+    }
+
+    /// Set how optional array and dictionary fields of structs, such as `Option<Vec<T>>` or
+    /// `#[serde(default)] Vec<T>`, are generated.
+    ///
+    /// [`OptionalCollectionStrategy::Empty`] declares them as non-optional collections decoded
+    /// with `decodeIfPresent` and an empty default. Fields of enum variants stay optional.
+    pub fn optional_collections(mut self, strategy: OptionalCollectionStrategy) -> Self {
+        self.optional_collections = strategy;
+        self
+    }
+
+    /// Set how references to types which aren't in the `TypeCollection` are exported.
+    ///
+    /// [`MissingReferenceStrategy::Stub`] declares an empty `MissingType…` struct for every
+    /// missing type instead of failing the export, and reports an
+    /// [`ExportWarning::MissingReference`] naming the type referring to it.
+    pub fn missing_reference(mut self, strategy: MissingReferenceStrategy) -> Self {
+        self.missing_reference = strategy;
+        self
+    }
+
+    /// Decode required array, `String` and `Bool` fields of structs through the `@LossyArray`,
+    /// `@DefaultEmptyString` and `@DefaultFalse` property wrappers.
+    ///
+    /// Missing and `null` values decode as empty or `false` and array elements which fail to
+    /// decode are skipped. The wrappers replace the custom `init(from:)` otherwise emitted for
+    /// [`MissingCollectionStrategy::Empty`], keeping the output of large schemas small. They
+    /// are emitted once before the generated types.
+    pub fn resilient_decoding(mut self, enabled: bool) -> Self {
+        self.resilient_decoding = enabled;
+        self
+    }
+
+    /// Check the length of fixed-size array fields when decoding.
+    ///
+    /// Rust arrays (`[T; N]`) are exported as `[T]`, which accepts any number of elements. With
+    /// this enabled, structs with fixed-size array or `Option<[T; N]>` fields get a custom
+    /// `init(from:)` which throws a `DecodingError` unless the array has exactly `N` elements.
+    /// These fields aren't wrapped for [resilient decoding](Self::resilient_decoding) or
+    /// defaulted to empty, as that would break their length.
+    pub fn validate_array_lengths(mut self, enabled: bool) -> Self {
+        self.validate_array_lengths = enabled;
+        self
+    }
+
+    /// Set the Swift type of `char` values.
+    ///
+    /// Structs with `char` or `Option<char>` fields get a custom Codable implementation with
+    /// [`CharType::Character`], which rejects strings that aren't a single character.
+    pub fn char_type(mut self, char_type: CharType) -> Self {
+        self.char_type = char_type;
+        self
+    }
+
+    /// Set how single-field tuple structs (newtypes) are generated.
+    ///
+    /// With [`NewtypeStyle::RawRepresentable`], `struct UserId(Uuid)` becomes a
+    /// `RawRepresentable` struct with a `rawValue: UUID`, coded as the bare UUID. It is
+    /// `Hashable` when the wrapped type is a primitive or a standard type like `UUID`, so IDs
+    /// can be used as dictionary keys and in sets. Generic newtypes keep the `value` property.
+    pub fn newtypes(mut self, style: NewtypeStyle) -> Self {
+        self.newtypes = style;
+        self
+    }
+
+    /// Set the order of enum cases and their `CodingKeys`.
+    ///
+    /// [`VariantOrdering::Alphabetical`] keeps the bindings stable when variants are reordered
+    /// in Rust. Cases are always coded by name, so the order never changes the JSON.
+    pub fn variant_ordering(mut self, ordering: VariantOrdering) -> Self {
+        self.variant_ordering = ordering;
+        self
+    }
+
+    /// Declare enum cases `indirect` when their payload is estimated larger than `bytes`.
+    ///
+    /// A Swift enum is as large as its largest case, so one variant holding a big struct makes
+    /// every value big. Indirect cases store their payload in a heap box instead, which is
+    /// transparent to code matching on the enum.
+    pub fn box_variants_larger_than(mut self, bytes: usize) -> Self {
+        self.variant_boxing_threshold = Some(bytes);
+        self
+    }
+
+    /// Declare every data-carrying case of the given enums `indirect`, whatever its size.
+    ///
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type.
+    pub fn box_variants_of<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.variant_boxing_overrides
+            .extend(names.into_iter().map(|name| (name.into(), true)));
+        self
+    }
+
+    /// Never declare cases of the given enums `indirect`, e.g. enums matched in hot loops.
+    ///
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type.
+    pub fn never_box_variants_of<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.variant_boxing_overrides
+            .extend(names.into_iter().map(|name| (name.into(), false)));
+        self
+    }
+
+    /// Export a bitflag type as an `OptionSet` with a static member per flag.
+    ///
+    /// The type must be an integer or a newtype around one, e.g. a `#[specta(transparent)]`
+    /// wrapper of the `bits()` of a `bitflags` type, and is coded as its integer value. Flags
+    /// are named in Rust (`READ`, `WRITE_ALL`) and converted like fields (`read`, `writeAll`).
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type.
+    ///
+    /// ```rust
+    /// # use specta_swift::Swift;
+    /// let swift = Swift::new().option_set("Permissions", [("READ", 1), ("WRITE", 2)]);
+    /// ```
+    pub fn option_set<S, I, F>(mut self, name: S, flags: I) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+        I: IntoIterator<Item = (F, u64)>,
+        F: Into<Cow<'static, str>>,
+    {
+        self.option_sets.push((
+            name.into(),
+            flags
+                .into_iter()
+                .map(|(flag, bits)| (flag.into(), bits))
+                .collect(),
+        ));
+        self
+    }
+
+    /// Set how `Date` values are encoded, emitting the coders configured with it.
+    pub fn date_strategy(mut self, strategy: DateStrategy) -> Self {
+        self.date_strategy = strategy;
+        self
+    }
+
+    /// Set how `Data` values are encoded, emitting the coders configured with it.
+    pub fn data_strategy(mut self, strategy: DataStrategy) -> Self {
+        self.data_strategy = strategy;
+        self
+    }
+
+    /// Emit `JSONEncoder.specta` and `JSONDecoder.specta`, configured with the key, date and
+    /// data strategies so every consumer encodes and decodes like the Rust side.
+    ///
+    /// The coders are also emitted whenever one of the strategies isn't the default.
+    pub fn with_coders(mut self) -> Self {
+        self.generate_coders = true;
+        self
+    }
+
+    /// Make the generated code compile and behave the same in server-side Swift and
+    /// Swift-on-Linux test environments.
+    ///
+    /// The coders use explicit ISO 8601 and base64 implementations under `#if os(Linux)`,
+    /// where swift-corelibs-foundation's built-in strategies differ from Apple's, and the
+    /// CloudKit and Core Data code is wrapped in `#if canImport(...)`.
+    pub fn with_linux_support(mut self) -> Self {
+        self.linux_support = true;
+        self
+    }
+
+    /// Emit `public static let allRawValues: [String]` and `init?(caseInsensitive:)` for string
+    /// enums, for parsing user input and query parameters.
+    pub fn with_string_enum_helpers(mut self) -> Self {
+        self.string_enum_helpers = true;
+        self
+    }
+
+    /// Emit `init?(jsonData: Data)` and `func jsonData() throws -> Data` for every struct and
+    /// enum, decoding and encoding with the shared coders (see [`Swift::with_coders`]).
+    pub fn with_json_helpers(mut self) -> Self {
+        self.json_helpers = true;
+        self
+    }
+
+    /// Emit `init(dictionary: [String: Any]) throws` and `var dictionary: [String: Any]` for
+    /// every struct with named fields, for Objective-C and analytics APIs which take
+    /// dictionaries. The values are converted through JSON with the shared coders.
+    pub fn with_dictionary_helpers(mut self) -> Self {
+        self.dictionary_helpers = true;
+        self
+    }
+
+    /// Emit a `func with(field: Type) -> Self` copy-modifier for every field of structs with
+    /// named fields, returning a copy with the field replaced.
+    ///
+    /// The properties are constants, so modified values are otherwise built by calling the
+    /// memberwise initializer with every field, e.g. when updating SwiftUI state.
+    pub fn with_copy_modifiers(mut self) -> Self {
+        self.copy_modifiers = true;
+        self
+    }
+
+    /// Emit `static var allKeyPaths: [PartialKeyPath<Self>]` and
+    /// `func changedFields(from: Self) -> [String]` for every non-generic struct with named
+    /// fields, for change tracking and analytics without reflection.
+    ///
+    /// Fields are compared as `AnyHashable` when their type is `Hashable`, and by their
+    /// encoded JSON otherwise, so this requires [`Swift::emit_codable`].
+    pub fn with_change_tracking(mut self) -> Self {
+        self.change_tracking = true;
+        self
+    }
+
+    /// Emit `init(record: CKRecord) throws` and `toRecord()` for the given structs, for apps
+    /// which sync them through iCloud.
+    ///
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type. Only
+    /// string, number, boolean, `Date` and `Data` fields are supported, the export fails for
+    /// any other field.
+    pub fn cloudkit_records_for<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.cloudkit_records
+            .extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Emit an `NSManagedObject` subclass named `<Type>Entity` for the given structs, with
+    /// converters to and from the struct, so offline caches don't hand-write the mapping.
+    ///
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type. The
+    /// entities still have to be added to the Core Data model.
+    pub fn core_data_entities_for<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.core_data_entities
+            .extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Generate a `static var mock: Self` for every struct and enum, for SwiftUI previews and
+    /// tests. Structs also get a `mock(...)` factory to override individual fields.
+    pub fn with_mocks(mut self) -> Self {
+        self.generate_mocks = true;
+        self
+    }
+
+    /// Generate a `PreviewData` namespace with arrays of varied samples of every type, such as
+    /// each enum case or structs with their optional fields set. Implies [`Swift::with_mocks`].
+    pub fn with_preview_data(mut self) -> Self {
+        self.generate_mocks = true;
+        self.generate_preview_data = true;
+        self
+    }
+
+    /// Add a constant to the `Constants` namespace, so values shared between Rust and Swift
+    /// (limits, versions, …) come from a single source.
+    ///
+    /// The name is converted with the field naming convention, e.g. `MAX_UPLOAD_SIZE` becomes
+    /// `Constants.maxUploadSize`.
+    pub fn constant<T: SwiftConstant>(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: T,
+    ) -> Self {
+        self.constants.push(Constant {
+            name: name.into(),
+            swift_type: T::swift_type().into(),
+            value: value.swift_literal(),
+        });
+        self
+    }
+
+    /// Emit `public enum SpectaSchema { public static let hash = "…" }`, computed from the
+    /// type collection with [`schema_hash`](crate::schema_hash). Comparing it with the hash
+    /// computed on the Rust side detects bindings generated from another schema revision.
+    pub fn with_schema_hash(mut self) -> Self {
+        self.schema_hash = true;
+        self
+    }
+
+    /// Set the module containing the generated types, imported with `@testable` by
+    /// [`Swift::export_tests`].
+    pub fn test_module(mut self, module: impl Into<Cow<'static, str>>) -> Self {
+        self.test_module = Some(module.into());
+        self
+    }
+
+    /// Make the tests written by [`Swift::export_tests`] also assert that every sample, one
+    /// per enum variant shape, decodes to a value equal to the encoded one.
+    ///
+    /// Comparing the values catches fields which the hand-written `Codable` implementations
+    /// drop or swap symmetrically, which comparing the encoded data can't. The generated types
+    /// must conform to `Equatable`, e.g. through [`Swift::with_ast_post_processor`].
+    pub fn equatable_tests(mut self, enabled: bool) -> Self {
+        self.equatable_tests = enabled;
+        self
+    }
+
+    /// Add a protocol that all types should conform to.
+    pub fn add_protocol(mut self, protocol: impl Into<Cow<'static, str>>) -> Self {
+        self.protocols.push(protocol.into());
+        self
+    }
+
+    /// Configure struct naming strategy for enum variants.
+    pub fn struct_naming(mut self, strategy: StructNamingStrategy) -> Self {
+        self.struct_naming = strategy;
+        self
+    }
+
+    /// Configure how to handle duplicate type names during export.
+    pub fn duplicate_name_strategy(mut self, strategy: DuplicateNameStrategy) -> Self {
+        self.duplicate_name_strategy = strategy;
+        self
+    }
+
+    /// Configure how [`DuplicateNameStrategy::Qualify`] builds qualified names.
+    pub fn qualify_options(mut self, options: QualifyOptions) -> Self {
+        self.qualify = options;
+        self
+    }
+
+    /// Only export types matching the predicate.
+    ///
+    /// Can be called multiple times, in which case a type must match every filter.
+    /// Types which are filtered out but referenced by exported types must be defined elsewhere.
+    pub fn filter(
+        mut self,
+        predicate: impl Fn(&NamedDataType) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.filters.push(TypeFilter::new(predicate));
+        self
+    }
+
+    /// Only export types defined in one of the given modules or their submodules.
+    ///
+    /// Modules are matched against the full module path, e.g. `my_crate::api`.
+    pub fn include_modules<I, S>(self, modules: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        let modules = modules.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.filter(move |ndt| {
+            modules
+                .iter()
+                .any(|module| is_in_module(ndt.module_path(), module))
+        })
+    }
+
+    /// Skip types defined in any of the given modules or their submodules.
+    ///
+    /// Modules are matched against the full module path, e.g. `my_crate::internal`.
+    pub fn exclude_modules<I, S>(self, modules: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        let modules = modules.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.filter(move |ndt| {
+            !modules
+                .iter()
+                .any(|module| is_in_module(ndt.module_path(), module))
+        })
+    }
+
+    /// Only export the given types and the types they transitively reference.
+    ///
+    /// Types are matched by their Rust name, after any `#[serde(rename)]` on the type. Can be
+    /// called multiple times, and is combined with [`Swift::filter`] so filtered out
+    /// dependencies must be defined elsewhere.
+    pub fn roots<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.roots
+            .extend(names.into_iter().map(|name| Root::Name(name.into())));
+        self
+    }
+
+    /// Only export `T` and the types it transitively references, along with any other roots.
+    pub fn root<T: NamedType>(mut self) -> Self {
+        self.roots.push(Root::Id(T::ID));
+        self
+    }
+
+    /// Export only the given root types and their transitive dependencies.
+    ///
+    /// `R` is a tuple of named types, e.g. `swift.export_roots::<(User, Post)>(&types)`.
+    pub fn export_roots<R: RootTypes>(&self, types: &TypeCollection) -> Result<String> {
+        let mut swift = self.clone();
+        swift.roots.extend(R::ids().into_iter().map(Root::Id));
+        swift.export(types)
+    }
+
+    /// Register a helper type, replacing any helper with the same name.
+    ///
+    /// The helper is emitted before the generated types whenever the exported types need it.
+    pub fn helper(mut self, helper: HelperType) -> Self {
+        self.helpers = self.helpers.register(helper);
+        self
+    }
+
+    /// Replace the registry of helper types, e.g. with [`HelperRegistry::empty`] to emit none.
+    pub fn helpers(mut self, helpers: HelperRegistry) -> Self {
+        self.helpers = helpers;
+        self
+    }
+
+    /// Map types to an existing Swift type instead of exporting them.
+    ///
+    /// The handler is called for every type in the collection. Types it returns a
+    /// [`SpecialType`] for are not generated, and references to them use
+    /// [`SpecialType::swift_type`], the same way `Duration` and `SystemTime` are handled.
+    /// Handlers run in registration order and the first match wins.
+    ///
+    /// ```rust
+    /// use specta_swift::{SpecialType, Swift};
+    ///
+    /// let swift = Swift::new().special_type(|ndt| {
+    ///     (ndt.name() == "Timestamp")
+    ///         .then(|| SpecialType::new("Date").mock("Date(timeIntervalSince1970: 0)"))
+    /// });
+    /// ```
+    pub fn special_type(
+        mut self,
+        detect: impl Fn(&NamedDataType) -> Option<SpecialType> + Send + Sync + 'static,
+    ) -> Self {
+        self.special_types.push(SpecialTypeHandler::new(detect));
+        self
+    }
+
+    /// Treat Swift types as already declared and `Codable`, e.g. types from another module.
+    ///
+    /// A type whose Swift name is in the list is handled like a
+    /// [`special type`](Self::special_type) mapped to that name: it is referred to by name
+    /// (keeping its generic arguments) but isn't declared, monomorphized, inlined or reported
+    /// as an [`UnresolvedReference`], and gets no mock or fixture.
+    ///
+    /// ```rust
+    /// use specta_swift::Swift;
+    ///
+    /// let swift = Swift::new().assume_external(["Money", "GeoPoint"]);
+    /// ```
+    pub fn assume_external<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Cow<'static, str>>,
+    {
+        self.assumed_external
+            .extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add a hook which runs on each generated declaration.
+    ///
+    /// The hook receives the type and its generated Swift code (including any
+    /// variant structs and Codable extensions) and returns the code to emit.
+    /// Hooks run in the order they were added.
+    pub fn with_post_processor(
+        mut self,
+        f: impl Fn(&NamedDataType, String) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.post_processors.push(PostProcessor::new(f));
+        self
+    }
+
+    /// Add a hook which edits the syntax tree of each generated declaration.
+    ///
+    /// The hook runs before the declaration is rendered, so it can add conformances,
+    /// members or attributes without parsing Swift, and its changes are indented like the
+    /// rest of the output. Hooks run in the order they were added, before the string
+    /// post-processors of [`Swift::with_post_processor`]. Types are never reused from the
+    /// [cache file](Swift::cache_file) while a hook is registered.
+    ///
+    /// ```rust
+    /// use specta_swift::Swift;
+    ///
+    /// let swift = Swift::new().with_ast_post_processor(|_, definition| {
+    ///     if let Some(decl) = definition.declaration_mut() {
+    ///         decl.conform_to("Sendable");
+    ///     }
+    /// });
+    /// ```
+    pub fn with_ast_post_processor(
+        mut self,
+        f: impl Fn(&NamedDataType, &mut TypeDefinition) + Send + Sync + 'static,
+    ) -> Self {
+        self.ast_post_processors.push(AstPostProcessor::new(f));
+        self
+    }
+
+    /// Append custom Swift code after the generated declaration of a type.
+    ///
+    /// `type_name` is matched against the exported Swift name. The code is emitted
+    /// verbatim after any post-processors have run, so regenerated files stay
+    /// self-contained. Snippets for the same type are emitted in registration order.
+    pub fn extend_type(
+        mut self,
+        type_name: impl Into<Cow<'static, str>>,
+        code: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.type_extensions.push((type_name.into(), code.into()));
+        self
+    }
+
+    /// Cache generated code in a file so unchanged types are not rendered again on the next export.
+    ///
+    /// Each type is stored with a fingerprint of everything it was generated from, so a
+    /// type is re-rendered whenever it, the types it references, their Swift names or the
+    /// configuration change. Closures can't be fingerprinted, so types are never reused while
+    /// a custom [`NamingConvention`] or [`DuplicateNameStrategy`], a [filter](Swift::filter),
+    /// a [special type](Swift::special_type) or a syntax tree hook is set.
+    pub fn cache_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_path = Some(path.into());
+        self
+    }
+
+    /// Render declarations in parallel with the `rayon` feature (enabled by default).
+    ///
+    /// The output is identical either way, declarations are always written in the same order.
+    /// Without the feature declarations are always rendered sequentially.
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Set the heuristic used to pre-size output buffers.
+    pub fn size_estimate(mut self, estimate: SizeEstimate) -> Self {
+        self.size_estimate = estimate;
+        self
+    }
+
+    /// The Swift name of a type, following the naming convention.
+    pub fn type_name(&self, name: &str) -> String {
+        self.naming.convert(&self.normalize_identifier(name))
+    }
+
+    /// The Swift name a type is declared and referred to with.
+    ///
+    /// Like [`type_name`](Self::type_name), with the
+    /// [`shadowed name prefix`](Self::prefix_shadowed_names) added to names shadowing Swift
+    /// symbols.
+    pub fn declared_type_name(&self, name: &str) -> String {
+        let name = self.type_name(name);
+        match &self.shadowed_name_prefix {
+            Some(prefix) if shadows_swift_symbol(&name) && !self.is_assumed_external(&name) => {
+                format!("{}{}", prefix, name)
+            }
+            _ => name,
         }
-        result.push('\n');
+    }
 
-        // Check if we need to inject Duration helper
-        if needs_duration_helper(types) {
-            result.push_str(&generate_duration_helper());
+    /// The Swift type name of a serialized variant name, e.g. `RenamedVar` for `renamed-var`.
+    ///
+    /// Variants can be renamed to anything, so characters which can't appear in an identifier
+    /// separate words instead.
+    pub fn variant_type_name(&self, name: &str) -> String {
+        self.type_name(&normalize_serialized_name(name))
+    }
+
+    /// The Swift name of a field, following the naming convention.
+    pub fn field_name(&self, name: &str) -> String {
+        self.naming.convert_field(&self.normalize_identifier(name))
+    }
+
+    /// The flags of a type exported as an `OptionSet`, if it is one.
+    pub fn option_set_flags(&self, ndt: &NamedDataType) -> Option<&[(Cow<'static, str>, u64)]> {
+        self.option_sets
+            .iter()
+            .rev()
+            .find(|(name, _)| name == ndt.name())
+            .map(|(_, flags)| flags.as_slice())
+    }
+
+    /// The Swift name of an enum case, following the naming convention.
+    pub fn case_name(&self, name: &str) -> String {
+        self.naming
+            .convert_enum_case(&self.normalize_identifier(name))
+    }
+
+    fn normalize_identifier(&self, name: &str) -> String {
+        match self.unicode_identifiers {
+            UnicodeIdentifiers::Normalize => unicode::nfc(name),
+            UnicodeIdentifiers::Transliterate => unicode::transliterate(name),
         }
+    }
 
-        // Check if we need to inject JsonValue helper
-        if needs_json_value_helper(types) {
-            result.push_str(&generate_json_value_helper());
+    /// Whether a public initializer is generated for a struct.
+    ///
+    /// The last override for the type wins, falling back to
+    /// [`generate_initializers`](Self::generate_initializers).
+    pub fn generates_initializer(&self, ndt: &NamedDataType) -> bool {
+        self.initializer_overrides
+            .iter()
+            .rev()
+            .find(|(name, _)| name == ndt.name())
+            .map_or(self.generate_initializers, |(_, enabled)| *enabled)
+    }
+
+    /// Wrap code depending on an Apple-only module in `#if canImport(...)` with
+    /// [`with_linux_support`](Self::with_linux_support).
+    fn platform_specific(&self, module: &str, code: impl Into<String>) -> String {
+        let code = code.into();
+        if self.linux_support {
+            format!("#if canImport({})\n{}\n#endif", module, code)
+        } else {
+            code
         }
+    }
 
-        // Export types - handle duplicates according to strategy
-        let named_types = handle_duplicate_names(types, &self.duplicate_name_strategy)?;
+    /// Whether a case of an enum is declared `indirect`.
+    ///
+    /// The last override for the enum wins, falling back to comparing the estimated payload
+    /// size with [`variant_boxing_threshold`](Self::variant_boxing_threshold).
+    pub fn boxes_variant(
+        &self,
+        types: &TypeCollection,
+        ndt: &NamedDataType,
+        variant: &EnumVariant,
+    ) -> bool {
+        let has_data = match variant.fields() {
+            Fields::Unit => false,
+            Fields::Unnamed(fields) => !fields.fields().is_empty(),
+            Fields::Named(fields) => !fields.fields().is_empty(),
+        };
+        if !has_data {
+            return false;
+        }
 
-        for (swift_name, ndt) in named_types {
-            result.push_str(&export_type_with_name(self, types, &ndt, &swift_name)?);
-            result.push_str("\n\n");
+        match self
+            .variant_boxing_overrides
+            .iter()
+            .rev()
+            .find(|(name, _)| name == ndt.name())
+        {
+            Some((_, boxed)) => *boxed,
+            None => self.variant_boxing_threshold.is_some_and(|threshold| {
+                estimated_payload_size(types, variant.fields()) > threshold
+            }),
         }
+    }
 
-        Ok(result)
+    /// Find the user-defined special type a type is mapped to.
+    pub fn special_type_of(&self, ndt: &NamedDataType) -> Option<SpecialType> {
+        self.special_types
+            .iter()
+            .find_map(|handler| handler.detect(ndt))
     }
 
-    /// Export types to a file.
-    pub fn export_to(&self, path: impl AsRef<Path>, types: &TypeCollection) -> Result<()> {
-        let content = self.export(types)?;
-        std::fs::write(path, content)?;
-        Ok(())
+    /// Check whether a Swift type is [`assumed to be external`](Self::assume_external).
+    pub fn is_assumed_external(&self, swift_name: &str) -> bool {
+        self.assumed_external.iter().any(|name| name == swift_name)
     }
-}
 
-impl NamingConvention {
-    /// Convert a string to the appropriate naming convention.
-    pub fn convert(&self, name: &str) -> String {
-        match self {
-            Self::PascalCase => self.to_pascal_case(name),
-            Self::CamelCase => self.to_camel_case(name),
-            Self::SnakeCase => self.to_snake_case(name),
-        }
+    /// Check whether a type is declared outside the generated code, either as a
+    /// [`special type`](Self::special_type) or an [`assumed external`](Self::assume_external)
+    /// type.
+    pub fn is_external(&self, ndt: &NamedDataType) -> bool {
+        self.special_type_of(ndt).is_some()
+            || self.is_assumed_external(&self.declared_type_name(ndt.name()))
     }
 
-    /// Convert a string to camelCase (for field names).
-    pub fn convert_to_camel_case(&self, name: &str) -> String {
-        self.to_camel_case(name)
+    /// Check whether a type passes every configured filter.
+    pub fn is_included(&self, ndt: &NamedDataType) -> bool {
+        self.filters.iter().all(|filter| filter.matches(ndt))
     }
 
-    /// Convert a string to the appropriate naming convention for fields.
-    pub fn convert_field(&self, name: &str) -> String {
-        let converted = match self {
-            Self::PascalCase => self.to_camel_case(name), // Fields should be camelCase even with PascalCase
-            Self::CamelCase => self.to_camel_case(name),
-            Self::SnakeCase => self.to_snake_case(name),
-        };
-        escape_reserved_keywords(&converted)
+    /// Export types to a Swift string.
+    pub fn export(&self, types: &TypeCollection) -> Result<String> {
+        self.export_with_report(types).map(|(output, _)| output)
     }
 
-    /// Convert a string to the appropriate naming convention for enum cases.
-    pub fn convert_enum_case(&self, name: &str) -> String {
-        let converted = match self {
-            Self::PascalCase => self.to_camel_case(name), // Enum cases should be camelCase
-            Self::CamelCase => self.to_camel_case(name),
-            Self::SnakeCase => self.to_snake_case(name),
-        };
-        escape_reserved_keywords(&converted)
+    /// Export types and split the output into its top-level declarations.
+    ///
+    /// Useful for tools which consume single types, e.g. `export.declaration("User")`. The
+    /// declarations of each type are taken from its syntax tree while it is rendered, only code
+    /// generated as text, e.g. by post-processors, is split with [`Export::parse`].
+    pub fn export_declarations(&self, types: &TypeCollection) -> Result<Export> {
+        let mut output = Vec::with_capacity(self.size_estimate.for_types(types));
+        let mut declarations = Vec::new();
+        with_reference_cache(self, types, || {
+            self.write_export(&mut output, types, &mut declarations)
+        })?;
+        let output = String::from_utf8(output)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Ok(Export::with_declarations(output, declarations))
     }
 
-    fn to_camel_case(&self, name: &str) -> String {
-        // Convert snake_case or PascalCase to camelCase
-        if name.contains('_') {
-            // Handle snake_case
-            let parts: Vec<&str> = name.split('_').collect();
-            if parts.is_empty() {
-                return name.to_string();
-            }
+    /// Convert a single data type to the Swift type it is referred to as, e.g. `[String: User]`.
+    ///
+    /// References are named like in a full export of `types`, but nothing is declared.
+    pub fn datatype(&self, types: &TypeCollection, dt: &DataType) -> Result<String> {
+        datatype_to_swift(self, types, dt, vec![], false, None)
+    }
 
-            let mut result = String::new();
-            for (i, part) in parts.iter().enumerate() {
-                if i == 0 {
-                    result.push_str(&part.to_lowercase());
-                } else {
-                    let mut chars = part.chars();
-                    match chars.next() {
-                        None => continue,
-                        Some(first) => {
-                            result.push(first.to_uppercase().next().unwrap_or(first));
-                            for c in chars {
-                                result.extend(c.to_lowercase());
-                            }
-                        }
-                    }
+    /// Render the declaration of a single type, along with its Codable implementation and
+    /// the helper declarations it owns (e.g. the structs of enum variants).
+    ///
+    /// Post-processors are applied like in [`Swift::export`], but the header, runtime helpers
+    /// and per-type extensions such as mocks are left out. Names aren't deduplicated against
+    /// the other types, so a variant struct may differ from a full export when names collide.
+    pub fn declaration(&self, types: &TypeCollection, ndt: &NamedDataType) -> Result<String> {
+        let name = self.declared_type_name(ndt.name());
+        let mut definition =
+            build_type_definition(self, types, ndt, &name, &VariantStructNames::default())?;
+        for post_processor in &self.ast_post_processors {
+            post_processor.apply(ndt, &mut definition);
+        }
+        Ok(self.post_processors.iter().fold(
+            definition.print(&self.printer()),
+            |declaration, post_processor| post_processor.apply(ndt, declaration),
+        ))
+    }
+
+    /// Export types to a Swift string along with a report describing the export.
+    pub fn export_with_report(&self, types: &TypeCollection) -> Result<(String, ExportReport)> {
+        let mut output = Vec::with_capacity(self.size_estimate.for_types(types));
+        let report = self.export_into_with_report(&mut output, types)?;
+        let output = String::from_utf8(output)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Ok((output, report))
+    }
+
+    /// Export types into a writer, streaming each declaration as soon as it is generated.
+    ///
+    /// Unlike [`Swift::export`] the full output is never held in memory, which keeps peak
+    /// memory low for very large collections and allows writing directly to files or pipes.
+    pub fn export_into(&self, writer: &mut impl Write, types: &TypeCollection) -> Result<()> {
+        self.export_into_with_report(writer, types).map(|_| ())
+    }
+
+    /// Export types into a writer along with a report describing the export.
+    pub fn export_into_with_report(
+        &self,
+        writer: &mut impl Write,
+        types: &TypeCollection,
+    ) -> Result<ExportReport> {
+        with_reference_cache(self, types, || {
+            self.write_export(writer, types, &mut Vec::new())
+        })
+    }
+
+    /// Declare a placeholder for every type referenced by `resolved` which isn't in `types`.
+    fn missing_reference_stubs(
+        &self,
+        types: &TypeCollection,
+        resolved: &[(String, NamedDataType)],
+        report: &mut ExportReport,
+    ) -> String {
+        let mut stubs = String::new();
+        let mut declared = HashSet::new();
+        for (swift_name, ndt) in resolved {
+            let mut references = Vec::new();
+            collect_references(ndt.ty(), &mut references);
+            for sid in references {
+                if types.get(sid).is_some() {
+                    continue;
                 }
-            }
-            result
-        } else {
-            // Handle PascalCase - convert to camelCase
-            let mut chars = name.chars();
-            match chars.next() {
-                None => name.to_string(),
-                Some(first) => {
-                    let mut result = String::new();
-                    result.push(first.to_lowercase().next().unwrap_or(first));
-                    for c in chars {
-                        result.push(c); // Keep the rest as-is for PascalCase
+                let stub = stub_name(sid);
+                let warning = ExportWarning::MissingReference {
+                    referenced_by: swift_name.clone(),
+                    stub: stub.clone(),
+                };
+                if report.warnings.contains(&warning) {
+                    continue;
+                }
+                eprintln!("{}", warning);
+                report.warnings.push(warning);
+
+                if declared.insert(sid) {
+                    let mut decl = Decl::public_struct(&stub);
+                    decl.docs.push(
+                        "/// Placeholder for a type missing from the exported `TypeCollection`."
+                            .into(),
+                    );
+                    if self.emit_codable {
+                        decl.conform_to("Codable");
                     }
-                    result
+                    let definition = TypeDefinition {
+                        items: vec![Item::Decl(decl)],
+                    };
+                    stubs.push_str(&definition.print(&self.printer()));
+                    stubs.push_str("\n\n");
                 }
             }
         }
+        stubs
     }
 
-    fn to_pascal_case(&self, name: &str) -> String {
-        // Convert snake_case to PascalCase
-        name.split('_')
-            .map(|part| {
-                let mut chars = part.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(first) => first.to_uppercase().chain(chars).collect(),
+    /// Write the export, collecting the name, kind and range of every top-level declaration
+    /// into `declarations`.
+    fn write_export(
+        &self,
+        writer: &mut impl Write,
+        types: &TypeCollection,
+        declarations: &mut Vec<(String, DeclarationKind, Range<usize>)>,
+    ) -> Result<ExportReport> {
+        let mut report = ExportReport::default();
+
+        if self.serde {
+            specta_serde::validate(types)?;
+        }
+        if self.codable_format != CodableFormat::Json
+            && self.key_strategy == KeyStrategy::ConvertFromSnakeCase
+        {
+            return Err(Error::Configuration(format!(
+                "the convert-from-snake-case key strategy relies on JSONDecoder and can't be used with the {:?} format",
+                self.codable_format
+            )));
+        }
+
+        // Keys which the coders don't convert are spelled out by their type's CodingKeys
+        let unconvertible =
+            if self.key_strategy == KeyStrategy::ConvertFromSnakeCase && self.emit_codable {
+                unconvertible_keys(self, types)?
+            } else {
+                Vec::new()
+            };
+        let verbatim_keys = !unconvertible.is_empty();
+        report.warnings.extend(unconvertible);
+
+        let mut result = String::with_capacity(self.size_estimate.prelude);
+
+        // Add header
+        if !self.header.is_empty() {
+            result.push_str(&self.header);
+            result.push('\n');
+        }
+
+        // Add imports
+        result.push_str("import Foundation\n");
+        if self.serde {
+            result.push_str("import Codable\n");
+        }
+        for (module, used) in [
+            ("CloudKit", !self.cloudkit_records.is_empty()),
+            ("CoreData", !self.core_data_entities.is_empty()),
+        ] {
+            if used {
+                result.push_str(&self.platform_specific(module, format!("import {}", module)));
+                result.push('\n');
+            }
+        }
+        for protocol in &self.protocols {
+            writeln!(result, "import {}", protocol)?;
+        }
+        result.push('\n');
+        if self.codable_format == CodableFormat::MessagePack {
+            result.push_str("// Codable implementations target MessagePack, encode on the Rust side with `rmp_serde::to_vec_named`.\n\n");
+        } else if self.codable_format == CodableFormat::PropertyList {
+            result.push_str("// Codable implementations target PropertyListEncoder, encode on the Rust side with the `plist` crate.\n\n");
+        }
+
+        // Helper types needed by the exported types
+        let mut has_helpers = false;
+        for helper in self.helpers.needed(types) {
+            result.push_str(helper.source());
+            report.helper_types.push(helper.name().to_string());
+            has_helpers = true;
+        }
+        if has_helpers {
+            result.push_str("// MARK: - Generated Types\n\n");
+        }
+
+        // Shared coders, configured with the chosen strategies
+        if needs_coders(self) {
+            result.push_str(&generate_coders(self, verbatim_keys)?);
+        }
+        if !self.cloudkit_records.is_empty() {
+            result.push_str(&self.platform_specific("CloudKit", RECORD_ERROR.trim_end()));
+            result.push_str("\n\n");
+        }
+        if !self.core_data_entities.is_empty() {
+            result.push_str(&self.platform_specific("CoreData", ENTITY_ERROR.trim_end()));
+            result.push_str("\n\n");
+        }
+        if self.resilient_decoding && self.emit_codable {
+            result.push_str(PROPERTY_WRAPPERS);
+        }
+
+        // Export types - handle duplicates according to strategy
+        let resolved = resolve_type_names(self, types, &mut report)?;
+
+        if self.codable_format == CodableFormat::PropertyList {
+            for (swift_name, ndt) in &resolved.types {
+                for reason in property_list_issues(types, ndt) {
+                    let warning = ExportWarning::PropertyListIncompatible {
+                        name: swift_name.clone(),
+                        reason,
+                    };
+                    eprintln!("{}", warning);
+                    report.warnings.push(warning);
                 }
-            })
-            .collect()
-    }
+            }
+        }
 
-    fn to_snake_case(&self, name: &str) -> String {
-        // Convert camelCase/PascalCase to snake_case
-        let mut result = String::new();
-        let mut chars = name.chars().peekable();
+        if self.missing_reference == MissingReferenceStrategy::Stub {
+            result.push_str(&self.missing_reference_stubs(types, &resolved.types, &mut report));
+        }
+
+        // The prelude and the trailing code are generated as text, so they are split here
+        let prelude = self.indent.reindent(&result);
+        declarations.extend(parse_declarations(&prelude));
+        writer.write_all(prelude.as_bytes())?;
+        let mut written = prelude.len();
+        result.clear();
+
+        let exported = resolved
+            .types
+            .iter()
+            .map(|(_, ndt)| ndt.sid())
+            .collect::<HashSet<_>>();
+
+        let cache = self.cache_path.as_deref().map(ExportCache::load);
+        let mut new_cache = ExportCache::default();
+
+        let render = |(swift_name, ndt): &(String, NamedDataType)| {
+            self.render_type(
+                types,
+                ndt,
+                swift_name,
+                &resolved.variant_structs,
+                cache.as_ref(),
+            )
+        };
+        // With the `rayon` feature all types are rendered up front on the thread pool,
+        // otherwise each type is rendered right before it is written
+        #[cfg(feature = "rayon")]
+        let rendered = if self.parallel {
+            use rayon::prelude::*;
+            resolved
+                .types
+                .par_iter()
+                .map(|entry| {
+                    with_reference_cache(self, types, || {
+                        use_resolved_names(self, types, &resolved.type_names);
+                        render(entry)
+                    })
+                })
+                .collect::<Vec<_>>()
+        } else {
+            resolved.types.iter().map(render).collect::<Vec<_>>()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let rendered = resolved.types.iter().map(render);
 
-        while let Some(c) = chars.next() {
-            if c.is_uppercase() && !result.is_empty() {
-                result.push('_');
+        for ((swift_name, ndt), rendered) in resolved.types.iter().zip(rendered) {
+            let rendered = rendered?;
+            if let Some(fingerprint) = rendered.fingerprint {
+                new_cache.insert(swift_name.clone(), fingerprint, rendered.declaration);
             }
-            result.push(c.to_lowercase().next().unwrap_or(c));
-        }
-
-        result
-    }
-}
-
-/// Check if the type collection contains any Duration types that need the helper
-fn needs_duration_helper(types: &TypeCollection) -> bool {
-    for ndt in types.into_sorted_iter() {
-        if ndt.name() == "Duration" {
-            return true;
-        }
-        // Also check if any struct fields contain Duration
-        if let specta::datatype::DataType::Struct(s) = ndt.ty() {
-            if let specta::datatype::Fields::Named(fields) = s.fields() {
-                for (_, field) in fields.fields() {
-                    if let Some(ty) = field.ty() {
-                        if let specta::datatype::DataType::Reference(r) = ty {
-                            if let Some(referenced_ndt) = types.get(r.sid()) {
-                                if referenced_ndt.name() == "Duration" {
-                                    return true;
-                                }
-                            }
-                        }
-                        // Also check if the field type is a Duration struct directly
-                        if let specta::datatype::DataType::Struct(struct_ty) = ty {
-                            if is_duration_struct(struct_ty) {
-                                return true;
-                            }
-                        }
-                    }
+            declarations.extend(offset_declarations(rendered.declarations, written));
+            result.push_str(&rendered.code);
+
+            report.exported_types.push(ExportedType {
+                name: ndt.name().to_string(),
+                module_path: ndt.module_path().to_string(),
+                swift_name: swift_name.clone(),
+                lines: result.trim_end().lines().count(),
+                features: rendered.features,
+                cached: rendered.cached,
+            });
+            report
+                .helper_types
+                .extend(resolved.variant_structs.all_for(self, ndt, swift_name));
+
+            // Record references to types which are not part of this export
+            let mut references = Vec::new();
+            collect_references(ndt.ty(), &mut references);
+            for sid in references {
+                if exported.contains(&sid) || is_special_std_type(types, Some(sid)).is_some() {
+                    continue;
+                }
+                let Some(referenced) = types.get(sid) else {
+                    continue;
+                };
+                if self.is_external(referenced) {
+                    continue;
+                }
+                let unresolved = UnresolvedReference {
+                    referenced_by: swift_name.clone(),
+                    name: referenced.name().to_string(),
+                    module_path: referenced.module_path().to_string(),
+                };
+                if !report.unresolved_references.contains(&unresolved) {
+                    report.unresolved_references.push(unresolved);
                 }
             }
+
+            writer.write_all(result.as_bytes())?;
+            written += result.len();
+            result.clear();
+        }
+
+        if !self.constants.is_empty() {
+            result.push_str("public enum Constants {\n");
+            for constant in &self.constants {
+                // SCREAMING_CASE is lowercased first so `BETA` becomes `beta`, not `bETA`
+                let name = if constant.name.chars().any(char::is_lowercase) {
+                    constant.name.to_string()
+                } else {
+                    to_lower(&constant.name)
+                };
+                writeln!(
+                    result,
+                    "    public static let {}: {} = {}",
+                    self.field_name(&name),
+                    constant.swift_type,
+                    constant.value
+                )?;
+            }
+            result.push_str("}\n\n");
+        }
+
+        if self.schema_hash {
+            result.push_str(&generate_schema_constant(types));
+            result.push_str("\n\n");
+        }
+
+        if self.generate_mocks && self.generate_preview_data {
+            result.push_str(&generate_preview_data(
+                self,
+                types,
+                &resolved.types,
+                &resolved.variant_structs,
+            )?);
+            result.push_str("\n\n");
         }
+
+        let trailer = self.indent.reindent(&result);
+        declarations.extend(offset_declarations(parse_declarations(&trailer), written));
+        writer.write_all(trailer.as_bytes())?;
+        writer.flush()?;
+
+        if let Some(path) = &self.cache_path {
+            new_cache.save(path)?;
+        }
+
+        Ok(report)
     }
-    false
-}
-
-/// Generate the Duration helper struct
-fn generate_duration_helper() -> String {
-    "// MARK: - Duration Helper\n".to_string()
-        + "/// Helper struct to decode Rust Duration format {\"secs\": u64, \"nanos\": u32}\n"
-        + "public struct RustDuration: Codable {\n"
-        + "    public let secs: UInt64\n"
-        + "    public let nanos: UInt32\n"
-        + "    \n"
-        + "    public var timeInterval: TimeInterval {\n"
-        + "        return Double(secs) + Double(nanos) / 1_000_000_000.0\n"
-        + "    }\n"
-        + "}\n\n"
-        + "// MARK: - Generated Types\n\n"
-}
-
-/// Check if a JsonValue type is the built-in serde_json::Value type
-fn is_builtin_json_value(ndt: &specta::datatype::NamedDataType) -> bool {
-    // Consider it built-in if it's from serde_json crate OR from specta's legacy_impls
-    ndt.module_path().contains("serde_json") || ndt.module_path().contains("legacy_impls")
-}
-
-/// Check if we need to generate the JsonValue helper
-fn needs_json_value_helper(types: &TypeCollection) -> bool {
-    for ndt in types.into_sorted_iter() {
-        if ndt.name() == "JsonValue" && is_builtin_json_value(&ndt) {
-            return true;
-        }
-        // Also check if any struct fields contain built-in JsonValue
-        if let specta::datatype::DataType::Struct(s) = ndt.ty() {
-            if let specta::datatype::Fields::Named(fields) = s.fields() {
-                for (_, field) in fields.fields() {
-                    if let Some(ty) = field.ty() {
-                        if let specta::datatype::DataType::Reference(r) = ty {
-                            if let Some(referenced_ndt) = types.get(r.sid()) {
-                                if referenced_ndt.name() == "JsonValue"
-                                    && is_builtin_json_value(referenced_ndt)
-                                {
-                                    return true;
-                                }
-                            }
-                        }
-                    }
+
+    /// Render the code of a single type: its declaration (reused from the cache when
+    /// possible), mock and registered extensions.
+    fn render_type(
+        &self,
+        types: &TypeCollection,
+        ndt: &NamedDataType,
+        swift_name: &str,
+        variant_structs: &VariantStructNames,
+        cache: Option<&ExportCache>,
+    ) -> Result<RenderedType> {
+        let cache = cache.filter(|_| is_cacheable(self));
+        let fingerprint = cache.map(|_| fingerprint(self, types, ndt, swift_name, variant_structs));
+        let cached = cache
+            .zip(fingerprint)
+            .and_then(|(cache, fingerprint)| cache.get(swift_name, fingerprint))
+            .map(str::to_string);
+
+        let is_cached = cached.is_some();
+        // The declarations are known from the syntax tree, only cached code has to be split
+        let (declaration, mut declarations) = match cached {
+            Some(declaration) => {
+                let declarations = parse_declarations(&declaration);
+                (declaration, declarations)
+            }
+            None => {
+                let mut definition =
+                    build_type_definition(self, types, ndt, swift_name, variant_structs)?;
+                for post_processor in &self.ast_post_processors {
+                    post_processor.apply(ndt, &mut definition);
                 }
+                definition.print_declarations(&self.printer())
             }
+        };
+
+        let mut code = self
+            .post_processors
+            .iter()
+            .fold(declaration.clone(), |declaration, post_processor| {
+                post_processor.apply(ndt, declaration)
+            });
+        // Post-processors may rewrite the declaration in any way
+        if !self.post_processors.is_empty() {
+            declarations = parse_declarations(&code);
         }
+        let mut features = declaration_features(&code);
+        code.push_str("\n\n");
+
+        // The blank lines separating a part from the previous one are counted with the part
+        let mut push = |feature: CodeFeature, part: &str| {
+            let before = code.trim_end().lines().count();
+            declarations.extend(offset_declarations(parse_declarations(part), code.len()));
+            code.push_str(part.trim_end());
+            features.push((feature, code.trim_end().lines().count() - before));
+            code.push_str("\n\n");
+        };
+
+        if self.generate_mocks {
+            if let Some(mock) =
+                generate_mock_extension(self, types, ndt, swift_name, variant_structs)?
+            {
+                push(CodeFeature::Mocks, &self.indent.reindent(&mock));
+            }
+        }
+
+        if self.json_helpers && self.emit_codable {
+            if let Some(helpers) = generate_json_helpers(ndt, swift_name)? {
+                push(CodeFeature::JsonHelpers, &self.indent.reindent(&helpers));
+            }
+        }
+
+        if self.dictionary_helpers && self.emit_codable {
+            if let Some(helpers) = generate_dictionary_helpers(ndt, swift_name)? {
+                push(
+                    CodeFeature::DictionaryHelpers,
+                    &self.indent.reindent(&helpers),
+                );
+            }
+        }
+
+        if self.cloudkit_records.iter().any(|name| name == ndt.name()) {
+            let conversions = generate_record_conversions(self, types, ndt, swift_name)?;
+            push(
+                CodeFeature::CloudKit,
+                &self
+                    .indent
+                    .reindent(&self.platform_specific("CloudKit", conversions)),
+            );
+        }
+
+        if self
+            .core_data_entities
+            .iter()
+            .any(|name| name == ndt.name())
+        {
+            let managed_object = generate_managed_object(self, types, ndt, swift_name)?;
+            push(
+                CodeFeature::CoreData,
+                &self
+                    .indent
+                    .reindent(&self.platform_specific("CoreData", managed_object)),
+            );
+        }
+
+        // Add custom code registered for this type
+        for (_, extension) in self
+            .type_extensions
+            .iter()
+            .filter(|(type_name, _)| type_name == swift_name)
+        {
+            push(CodeFeature::Extensions, extension);
+        }
+
+        Ok(RenderedType {
+            code,
+            declarations,
+            features,
+            declaration,
+            fingerprint,
+            cached: is_cached,
+        })
+    }
+
+    /// Write an XCTest file which round-trips samples of every type through `JSONEncoder` and
+    /// `JSONDecoder`, catching `Codable` bugs at Swift test time.
+    ///
+    /// Types without a mock (e.g. generic types) are not tested. The mocks are included in the
+    /// test file unless [`Swift::with_mocks`] already adds them to the bindings.
+    pub fn export_tests(&self, path: impl AsRef<Path>, types: &TypeCollection) -> Result<()> {
+        let tests = with_reference_cache(self, types, || generate_codable_tests(self, types))?;
+        std::fs::write(path, tests)?;
+        Ok(())
+    }
+
+    /// Write a canonical JSON sample of every struct and enum variant to `dir`.
+    ///
+    /// Structs are written to `{Type}.json` and enum variants to `{Type}.{Variant}.json`. The
+    /// samples follow the serde representation of each type and match the generated mocks, so
+    /// they can be shared as golden files between Rust and Swift tests.
+    pub fn export_fixtures(&self, dir: impl AsRef<Path>, types: &TypeCollection) -> Result<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let fixtures = with_reference_cache(self, types, || generate_fixtures(self, types))?;
+        for (file_name, json) in fixtures {
+            std::fs::write(dir.join(file_name), json)?;
+        }
+        Ok(())
+    }
+
+    /// Write a JSON manifest mapping every exported Rust type to its Swift name.
+    ///
+    /// The manifest lists each type's path (`module::Type`), its Swift name and whether it was
+    /// renamed by the [`DuplicateNameStrategy`], along with the helper types the exporter
+    /// generated. Other tooling can read it to refer to the same names as the bindings.
+    pub fn export_manifest(&self, path: impl AsRef<Path>, types: &TypeCollection) -> Result<()> {
+        std::fs::write(path, generate_manifest(self, types)?)?;
+        Ok(())
+    }
+
+    /// Export types to a file.
+    ///
+    /// The file is left untouched if its content would not change, so build tools watching
+    /// it are not triggered needlessly.
+    pub fn export_to(&self, path: impl AsRef<Path>, types: &TypeCollection) -> Result<()> {
+        let content = self.export(types)?;
+        if std::fs::read_to_string(path.as_ref()).ok().as_deref() != Some(content.as_str()) {
+            std::fs::write(path, content)?;
+        }
+        Ok(())
+    }
+
+    /// Compare the code which would be generated against an existing file without writing it.
+    ///
+    /// A missing file is treated as empty, so every declaration is reported as added.
+    pub fn diff(&self, path: impl AsRef<Path>, types: &TypeCollection) -> Result<Diff> {
+        let generated = self.export_declarations(types)?;
+        let existing = match std::fs::read_to_string(path) {
+            Ok(existing) => existing,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Diff::between_exports(&Export::parse(existing), &generated))
+    }
+}
+
+/// The generated code of a single type.
+struct RenderedType {
+    /// Declaration, mock and extensions, ready to be written.
+    code: String,
+    /// The name, kind and range in `code` of every top-level declaration.
+    declarations: Vec<(String, DeclarationKind, Range<usize>)>,
+    /// The declaration before post-processing, as stored in the cache.
+    declaration: String,
+    /// Fingerprint of the type's inputs, when caching is enabled.
+    fingerprint: Option<u64>,
+    /// Whether the declaration was reused from the cache.
+    cached: bool,
+    /// Lines per feature, see [`ExportedType::features`].
+    features: Vec<(CodeFeature, usize)>,
+}
+
+impl NamingConvention {
+    /// Name identifiers with a custom [`CaseConverter`].
+    pub fn custom(converter: impl CaseConverter + 'static) -> Self {
+        Self::Custom(Arc::new(converter))
+    }
+
+    /// The converter implementing the naming convention.
+    pub fn converter(&self) -> &dyn CaseConverter {
+        match self {
+            Self::PascalCase => &PascalCaseConverter,
+            Self::CamelCase => &CamelCaseConverter,
+            Self::SnakeCase => &SnakeCaseConverter,
+            Self::Custom(converter) => converter.as_ref(),
+        }
+    }
+
+    /// Convert a string to the appropriate naming convention.
+    pub fn convert(&self, name: &str) -> String {
+        self.converter().convert(name)
+    }
+
+    /// Convert a string to camelCase (for field names).
+    pub fn convert_to_camel_case(&self, name: &str) -> String {
+        CamelCaseConverter.convert(name)
+    }
+
+    /// Convert a string to the appropriate naming convention for fields.
+    ///
+    /// `name` is the serialized name, so it may use any `rename_all` casing. Separators which
+    /// aren't valid in Swift identifiers (`user-id`) become word boundaries, and names without
+    /// lowercase letters (`USER_ID`) are lowercased first.
+    pub fn convert_field(&self, name: &str) -> String {
+        let name = normalize_serialized_name(name);
+        escape_reserved_keywords(&self.converter().convert_field(&name))
+    }
+
+    /// Convert a string to the appropriate naming convention for enum cases.
+    ///
+    /// Like [`convert_field`](Self::convert_field), `name` is the serialized name of the
+    /// variant, which `#[serde(rename = "...")]` may set to any string.
+    pub fn convert_enum_case(&self, name: &str) -> String {
+        let name = normalize_serialized_name(name);
+        escape_reserved_keywords(&self.converter().convert_enum_case(&name))
     }
-    false
-}
-
-/// Generate the JsonValue helper enum
-fn generate_json_value_helper() -> String {
-    "// MARK: - JSON Value Helper\n".to_string()
-        + "/// Helper enum to represent arbitrary JSON values\n"
-        + "public indirect enum JsonValue: Codable {\n"
-        + "    case null\n"
-        + "    case bool(Bool)\n"
-        + "    case number(Double)\n"
-        + "    case string(String)\n"
-        + "    case array([JsonValue])\n"
-        + "    case object([String: JsonValue])\n"
-        + "    \n"
-        + "    public init(from decoder: Decoder) throws {\n"
-        + "        let container = try decoder.singleValueContainer()\n"
-        + "        \n"
-        + "        if container.decodeNil() {\n"
-        + "            self = .null\n"
-        + "        } else if let bool = try? container.decode(Bool.self) {\n"
-        + "            self = .bool(bool)\n"
-        + "        } else if let int = try? container.decode(Int.self) {\n"
-        + "            self = .number(Double(int))\n"
-        + "        } else if let double = try? container.decode(Double.self) {\n"
-        + "            self = .number(double)\n"
-        + "        } else if let string = try? container.decode(String.self) {\n"
-        + "            self = .string(string)\n"
-        + "        } else if let array = try? container.decode([JsonValue].self) {\n"
-        + "            self = .array(array)\n"
-        + "        } else if let object = try? container.decode([String: JsonValue].self) {\n"
-        + "            self = .object(object)\n"
-        + "        } else {\n"
-        + "            throw DecodingError.typeMismatch(JsonValue.self, DecodingError.Context(codingPath: decoder.codingPath, debugDescription: \"Invalid JsonValue\"))\n"
-        + "        }\n"
-        + "    }\n"
-        + "    \n"
-        + "    public func encode(to encoder: Encoder) throws {\n"
-        + "        var container = encoder.singleValueContainer()\n"
-        + "        \n"
-        + "        switch self {\n"
-        + "        case .null:\n"
-        + "            try container.encodeNil()\n"
-        + "        case .bool(let bool):\n"
-        + "            try container.encode(bool)\n"
-        + "        case .number(let double):\n"
-        + "            try container.encode(double)\n"
-        + "        case .string(let string):\n"
-        + "            try container.encode(string)\n"
-        + "        case .array(let array):\n"
-        + "            try container.encode(array)\n"
-        + "        case .object(let object):\n"
-        + "            try container.encode(object)\n"
-        + "        }\n"
-        + "    }\n"
-        + "    \n"
-        + "    // MARK: - Convenience Constructors\n"
-        + "    public static func nullValue() -> JsonValue { .null }\n"
-        + "    public static func boolValue(_ value: Bool) -> JsonValue { .bool(value) }\n"
-        + "    public static func numberValue(_ value: Double) -> JsonValue { .number(value) }\n"
-        + "    public static func numberValue(_ value: Int) -> JsonValue { .number(Double(value)) }\n"
-        + "    public static func stringValue(_ value: String) -> JsonValue { .string(value) }\n"
-        + "    public static func arrayValue(_ value: [JsonValue]) -> JsonValue { .array(value) }\n"
-        + "    public static func objectValue(_ value: [String: JsonValue]) -> JsonValue { .object(value) }\n"
-        + "}\n\n"
-        + "// MARK: - Generated Types\n\n"
+}
+
+/// Turn a serialized name into `snake_case` or `camelCase` words the conventions can convert.
+fn normalize_serialized_name(name: &str) -> String {
+    let name = name.replace(|c: char| !c.is_alphanumeric() && c != '_', "_");
+    if name.chars().any(char::is_lowercase) {
+        name
+    } else {
+        to_lower(&name)
+    }
+}
+
+/// Check if `module_path` is `module` or one of its submodules.
+fn is_in_module(module_path: &str, module: &str) -> bool {
+    module_path == module
+        || module_path
+            .strip_prefix(module)
+            .is_some_and(|rest| rest.starts_with("::"))
 }
 
 /// Escape Swift reserved keywords by wrapping them in backticks
@@ -561,148 +2546,3 @@ fn escape_reserved_keywords(name: &str) -> String {
         name.to_string()
     }
 }
-
-/// Generate a qualified name based on module path to avoid conflicts.
-fn generate_qualified_name(ndt: &specta::datatype::NamedDataType) -> String {
-    let module_parts: Vec<&str> = ndt.module_path().split("::").collect();
-
-    if module_parts.len() <= 1 {
-        return ndt.name().to_string();
-    }
-
-    // Filter out common test module names and take meaningful parts
-    let meaningful_parts: Vec<&str> = module_parts
-        .iter()
-        .filter(|part| !matches!(**part, "tests" | "duplicate_names" | "lib"))
-        .cloned()
-        .collect();
-
-    if meaningful_parts.is_empty() {
-        return ndt.name().to_string();
-    }
-
-    // Take the last 2-3 meaningful parts of the module path
-    let parts_to_use = if meaningful_parts.len() >= 3 {
-        &meaningful_parts[meaningful_parts.len() - 3..]
-    } else {
-        &meaningful_parts[..]
-    };
-
-    // Convert to PascalCase and combine with the type name
-    let qualified_prefix = parts_to_use
-        .iter()
-        .map(|part| {
-            // Convert snake_case to PascalCase
-            part.split('_')
-                .map(|word| {
-                    let mut chars = word.chars();
-                    match chars.next() {
-                        None => String::new(),
-                        Some(first) => first.to_uppercase().chain(chars).collect(),
-                    }
-                })
-                .collect::<String>()
-        })
-        .collect::<String>();
-
-    format!("{}{}", qualified_prefix, ndt.name())
-}
-
-/// Check for duplicate names and handle them according to the strategy.
-fn handle_duplicate_names(
-    types: &TypeCollection,
-    strategy: &DuplicateNameStrategy,
-) -> Result<Vec<(String, specta::datatype::NamedDataType)>> {
-    let mut name_to_types: std::collections::HashMap<String, Vec<specta::datatype::NamedDataType>> =
-        std::collections::HashMap::new();
-
-    // Group types by name
-    for ndt in types.into_sorted_iter() {
-        if ndt.name() == "JsonValue"
-            && is_builtin_json_value(&ndt)
-            && needs_json_value_helper(types)
-        {
-            continue;
-        }
-
-        name_to_types
-            .entry(ndt.name().to_string())
-            .or_insert_with(Vec::new)
-            .push(ndt);
-    }
-
-    let mut result = Vec::new();
-    let mut warnings = Vec::new();
-
-    for (name, type_list) in name_to_types {
-        if type_list.len() == 1 {
-            // No duplicates, use original name
-            result.push((name, type_list.into_iter().next().unwrap()));
-        } else {
-            // Handle duplicates based on strategy
-            match strategy {
-                DuplicateNameStrategy::Warn => {
-                    // Generate warning message
-                    let warning_msg = format!(
-                        "⚠️  WARNING: Duplicate struct name '{}' found:\n{}",
-                        name,
-                        type_list
-                            .iter()
-                            .map(|ndt| format!(
-                                "   - {}:{}",
-                                ndt.module_path(),
-                                ndt.location().line()
-                            ))
-                            .collect::<Vec<_>>()
-                            .join("\n")
-                    );
-                    warnings.push(warning_msg);
-
-                    // Use the last definition (maintains backward compatibility)
-                    let last_type = type_list.into_iter().last().unwrap();
-                    result.push((name, last_type));
-                }
-                DuplicateNameStrategy::Error => {
-                    let error_msg = format!(
-                        "Duplicate type names found for '{}':\n{}",
-                        name,
-                        type_list
-                            .iter()
-                            .map(|ndt| format!(
-                                "   - {}:{}",
-                                ndt.module_path(),
-                                ndt.location().line()
-                            ))
-                            .collect::<Vec<_>>()
-                            .join("\n")
-                    );
-                    return Err(crate::error::Error::DuplicateNames(error_msg));
-                }
-                DuplicateNameStrategy::Qualify => {
-                    // Generate qualified names for all duplicates
-                    for ndt in type_list {
-                        let qualified_name = generate_qualified_name(&ndt);
-                        result.push((qualified_name, ndt));
-                    }
-                }
-                DuplicateNameStrategy::Custom(naming_fn) => {
-                    // Use custom naming function for all duplicates
-                    for ndt in type_list {
-                        let custom_name = (naming_fn)(&ndt);
-                        result.push((custom_name, ndt));
-                    }
-                }
-            }
-        }
-    }
-
-    // Print warnings to stderr if any
-    if !warnings.is_empty() {
-        eprintln!("{}", warnings.join("\n\n"));
-    }
-
-    // Sort by name for deterministic output
-    result.sort_by(|(name_a, _), (name_b, _)| name_a.cmp(name_b));
-
-    Ok(result)
-}
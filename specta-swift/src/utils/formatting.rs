@@ -9,6 +9,8 @@
 //!
 //! These utilities ensure consistent formatting across all generated Swift code.
 
+use specta::datatype::DeprecatedType;
+
 /// Add indentation to each line of a string.
 ///
 /// # Arguments
@@ -63,15 +65,241 @@ pub fn indent(s: &str, level: usize) -> String {
 /// assert_eq!(formatted, "/// This is a type.\n/// It does things.\n");
 /// ```
 pub fn format_doc_comment(docs: &str) -> String {
+    let docs = process_doc(docs);
     let mut result = String::new();
-    for line in docs.lines() {
+    let mut lines = docs.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            let mut block = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with(&trimmed[..3]) {
+                    break;
+                }
+                block.push(line);
+            }
+            push_code_block(&mut result, trimmed[3..].trim(), &block);
+        } else {
+            push_doc_line(&mut result, trimmed);
+        }
+    }
+    result
+}
+
+/// Append a line of a doc comment, without trailing whitespace on blank lines.
+fn push_doc_line(result: &mut String, line: &str) {
+    let line = line.trim_end();
+    if line.is_empty() {
+        result.push_str("///\n");
+    } else {
         result.push_str("/// ");
-        result.push_str(line.trim_start());
+        result.push_str(line);
         result.push('\n');
     }
+}
+
+/// Append a fenced code block, keeping the indentation of its lines relative to each other.
+///
+/// Rust blocks are labelled `rust`, since unlabelled blocks are shown as Swift, and their
+/// hidden lines (`# use ...;`) are dropped like rustdoc does.
+fn push_code_block(result: &mut String, info: &str, lines: &[&str]) {
+    let is_rust = info
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|attr| !attr.is_empty())
+        .all(|attr| {
+            matches!(
+                attr,
+                "rust"
+                    | "ignore"
+                    | "no_run"
+                    | "should_panic"
+                    | "compile_fail"
+                    | "test_harness"
+                    | "standalone_crate"
+            ) || attr.starts_with("edition")
+        });
+
+    let lines = lines
+        .iter()
+        .filter_map(|line| {
+            if !is_rust {
+                return Some(*line);
+            }
+            let trimmed = line.trim_start();
+            if trimmed == "#" || trimmed.starts_with("# ") {
+                return None;
+            }
+            // `##` escapes a line starting with `#`
+            match trimmed.strip_prefix("##") {
+                Some(_) => Some(&trimmed[1..]),
+                None => Some(*line),
+            }
+        })
+        .collect::<Vec<_>>();
+    let common_indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    push_doc_line(
+        result,
+        &format!("```{}", if is_rust { "rust" } else { info }),
+    );
+    for line in lines {
+        push_doc_line(result, line.get(common_indent..).unwrap_or(""));
+    }
+    push_doc_line(result, "```");
+}
+
+/// Convert Rust documentation into Markdown suitable for Swift doc comments.
+///
+/// Outside of code blocks and code spans:
+///
+/// - Intra-doc links like `[Foo]`, `` [`Foo`] `` and `[text](crate::Foo)` are rewritten to
+///   code spans or their text, as the Rust paths mean nothing in Swift. Their reference
+///   definitions (`[Foo]: crate::Foo`) are dropped.
+/// - Raw HTML is escaped so it is shown as written.
+/// - Comment delimiters (`/*` and `*/`) are escaped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use specta_swift::utils::formatting::process_doc;
+/// assert_eq!(process_doc("See [`User`] and [Role]."), "See `User` and `Role`.");
+/// assert_eq!(process_doc("Use <b>bold</b>"), "Use \\<b>bold\\</b>");
+/// ```
+pub fn process_doc(docs: &str) -> String {
+    let mut lines = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for line in docs.lines() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            lines.push(line.to_string());
+        } else if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fence = Some(&trimmed[..3]);
+            lines.push(line.to_string());
+        } else if !is_intra_doc_link_definition(trimmed) {
+            lines.push(process_doc_line(line));
+        }
+    }
+
+    let mut result = lines.join("\n");
+    if docs.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Rewrite a line of prose, leaving code spans untouched.
+fn process_doc_line(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '`' => {
+                let span = code_span(rest);
+                result.push_str(span);
+                rest = &rest[span.len()..];
+                continue;
+            }
+            '[' => {
+                if let Some((replacement, len)) = intra_doc_link(rest) {
+                    result.push_str(&replacement);
+                    rest = &rest[len..];
+                    continue;
+                }
+                result.push('[');
+            }
+            '<' if is_html_tag(rest) => result.push_str("\\<"),
+            '*' if rest[1..].starts_with('/') => result.push_str("*\\"),
+            '/' if rest[1..].starts_with('*') => result.push_str("/\\"),
+            c => result.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
     result
 }
 
+/// The code span at the start of `s`, or just its opening backticks when it isn't closed.
+fn code_span(s: &str) -> &str {
+    let ticks = s.len() - s.trim_start_matches('`').len();
+    let delimiter = &s[..ticks];
+    match s[ticks..].find(delimiter) {
+        Some(end) => &s[..ticks + end + ticks],
+        None => delimiter,
+    }
+}
+
+/// The replacement of an intra-doc link at the start of `s` and the length it replaces.
+fn intra_doc_link(s: &str) -> Option<(String, usize)> {
+    let close = s.find(']')?;
+    let text = &s[1..close];
+    let after = &s[close + 1..];
+
+    // `[text](target)` and `[text][target]`
+    for (open, end) in [('(', ')'), ('[', ']')] {
+        if let Some(target) = after.strip_prefix(open) {
+            let target_end = target.find(end)?;
+            let target = &target[..target_end];
+            let len = close + 2 + target_end + 1;
+            return match open {
+                '(' if !is_intra_doc_path(target) => None,
+                '[' if !target.is_empty() && !is_intra_doc_path(target) => None,
+                _ => Some((text.to_string(), len)),
+            };
+        }
+    }
+
+    // `[Foo]` and `[`Foo`]`
+    let path = text.trim_matches('`');
+    is_intra_doc_path(path).then(|| (format!("`{}`", strip_disambiguator(path)), close + 1))
+}
+
+/// Whether a link target is a Rust path rather than a URL or anchor.
+fn is_intra_doc_path(target: &str) -> bool {
+    let target = target.trim_matches('`');
+    let path = strip_disambiguator(target);
+    path.chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && path.chars().all(|c| {
+            c.is_alphanumeric() || matches!(c, '_' | ':' | '<' | '>' | ',' | '(' | ')' | '!')
+        })
+        && !target.contains("://")
+}
+
+/// Strip a rustdoc disambiguator like `struct@` or `fn@`.
+fn strip_disambiguator(path: &str) -> &str {
+    path.split_once('@').map_or(path, |(_, path)| path)
+}
+
+/// Whether a line is a reference definition of an intra-doc link (`[Foo]: crate::Foo`).
+fn is_intra_doc_link_definition(line: &str) -> bool {
+    line.strip_prefix('[')
+        .and_then(|line| line.split_once("]: "))
+        .is_some_and(|(_, target)| is_intra_doc_path(target.trim()))
+}
+
+/// Whether `s` starts with an HTML tag or comment, rather than an autolink or comparison.
+fn is_html_tag(s: &str) -> bool {
+    let Some(end) = s.find('>') else {
+        return false;
+    };
+    let tag = &s[1..end];
+    tag.starts_with(|c: char| c.is_ascii_alphabetic() || c == '/' || c == '!')
+        && !tag.contains("://")
+        && !tag.starts_with("mailto:")
+}
+
 /// Escape a string for use in Swift code.
 ///
 /// Handles common escape sequences like quotes, newlines, etc.
@@ -149,6 +377,59 @@ pub fn format_deprecated(message: &str) -> String {
     format!("@available(*, deprecated, message: \"{}\")\n", message)
 }
 
+/// Format a Specta deprecation as Swift's `@available` attribute.
+///
+/// The `since` version is included in the message, and replacements are emitted as
+/// `renamed:` so Xcode offers a fix-it. `rename` converts the replacement's Rust name to its
+/// Swift name, and `fallback` is the message of deprecations without a note.
+///
+/// # Examples
+///
+/// ```rust
+/// # use specta::datatype::DeprecatedType;
+/// # use specta_swift::utils::formatting::format_deprecated_type;
+/// let deprecated = DeprecatedType::DeprecatedRenamed {
+///     since: Some("2.0.0".into()),
+///     note: "Use Account instead".into(),
+///     renamed: "Account".into(),
+/// };
+/// assert_eq!(
+///     format_deprecated_type(&deprecated, "This type is deprecated", |name| name.to_string()),
+///     "@available(*, deprecated, renamed: \"Account\", message: \"Use Account instead (deprecated since 2.0.0)\")\n"
+/// );
+/// ```
+pub fn format_deprecated_type(
+    deprecated: &DeprecatedType,
+    fallback: &str,
+    rename: impl Fn(&str) -> String,
+) -> String {
+    let (since, note, renamed) = match deprecated {
+        DeprecatedType::DeprecatedWithSince { since, note } => (since.as_deref(), &**note, None),
+        DeprecatedType::DeprecatedRenamed {
+            since,
+            note,
+            renamed,
+        } => (since.as_deref(), &**note, Some(rename(renamed))),
+        _ => (None, "", None),
+    };
+
+    let message = match (note.is_empty(), since) {
+        (false, Some(since)) => format!("{} (deprecated since {})", note, since),
+        (false, None) => note.to_string(),
+        (true, Some(since)) => format!("Deprecated since {}", since),
+        (true, None) => fallback.to_string(),
+    };
+
+    match renamed {
+        Some(renamed) => format!(
+            "@available(*, deprecated, renamed: \"{}\", message: \"{}\")\n",
+            escape_string(&renamed),
+            escape_string(&message)
+        ),
+        None => format_deprecated(&escape_string(&message)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,6 +472,73 @@ mod tests {
         assert_eq!(format_doc_comment(docs), expected);
     }
 
+    #[test]
+    fn test_format_doc_comment_blank_lines() {
+        assert_eq!(
+            format_doc_comment("Title\n\nBody"),
+            "/// Title\n///\n/// Body\n"
+        );
+    }
+
+    #[test]
+    fn test_format_doc_comment_code_blocks() {
+        let docs =
+            " Example:\n\n ```\n # use my_crate::User;\n let user = User {\n     id: 1,\n };\n ```";
+        assert_eq!(
+            format_doc_comment(docs),
+            "/// Example:\n///\n/// ```rust\n/// let user = User {\n///     id: 1,\n/// };\n/// ```\n"
+        );
+
+        let docs = "```json,ignore\n{\n  \"id\": 1\n}\n```";
+        assert_eq!(
+            format_doc_comment(docs),
+            "/// ```json,ignore\n/// {\n///   \"id\": 1\n/// }\n/// ```\n"
+        );
+
+        // Unterminated blocks are closed
+        assert_eq!(
+            format_doc_comment("```no_run\nfoo();"),
+            "/// ```rust\n/// foo();\n/// ```\n"
+        );
+    }
+
+    #[test]
+    fn test_process_doc_intra_doc_links() {
+        assert_eq!(process_doc("See [`User`]."), "See `User`.");
+        assert_eq!(process_doc("See [crate::User]."), "See `crate::User`.");
+        assert_eq!(process_doc("See [struct@User]."), "See `User`.");
+        assert_eq!(process_doc("See [the user](crate::User)."), "See the user.");
+        assert_eq!(process_doc("See [the user][User]."), "See the user.");
+        assert_eq!(process_doc("A [user]\n\n[user]: crate::User"), "A `user`\n");
+    }
+
+    #[test]
+    fn test_process_doc_keeps_markdown() {
+        assert_eq!(
+            process_doc("See [the docs](https://docs.rs)."),
+            "See [the docs](https://docs.rs)."
+        );
+        assert_eq!(process_doc("- [ ] todo"), "- [ ] todo");
+        assert_eq!(process_doc("Array `[Foo]` here"), "Array `[Foo]` here");
+        assert_eq!(
+            process_doc("a < b and <https://docs.rs>"),
+            "a < b and <https://docs.rs>"
+        );
+    }
+
+    #[test]
+    fn test_process_doc_escapes() {
+        assert_eq!(process_doc("<b>bold</b>"), "\\<b>bold\\</b>");
+        assert_eq!(process_doc("ends */ here"), "ends *\\/ here");
+        assert_eq!(process_doc("starts /* here"), "starts /\\* here");
+    }
+
+    #[test]
+    fn test_process_doc_skips_code_blocks() {
+        let docs = "Example:\n```rust\nlet x: Vec<[u8; 2]> = vec![]; /* ok */\n```";
+        assert_eq!(process_doc(docs), docs);
+    }
+
     #[test]
     fn test_escape_string_quotes() {
         assert_eq!(escape_string("say \"hi\""), "say \\\"hi\\\"");
@@ -226,25 +574,25 @@ mod tests {
 
     #[test]
     fn test_join_non_empty_basic() {
-        let items = vec!["a", "b", "c"];
+        let items = ["a", "b", "c"];
         assert_eq!(join_non_empty(items.iter().copied(), ", "), "a, b, c");
     }
 
     #[test]
     fn test_join_non_empty_filters_empty() {
-        let items = vec!["a", "", "b", "", "c"];
+        let items = ["a", "", "b", "", "c"];
         assert_eq!(join_non_empty(items.iter().copied(), ", "), "a, b, c");
     }
 
     #[test]
     fn test_join_non_empty_all_empty() {
-        let items = vec!["", "", ""];
+        let items = ["", "", ""];
         assert_eq!(join_non_empty(items.iter().copied(), ", "), "");
     }
 
     #[test]
     fn test_join_non_empty_single_item() {
-        let items = vec!["a"];
+        let items = ["a"];
         assert_eq!(join_non_empty(items.iter().copied(), ", "), "a");
     }
 
@@ -257,6 +605,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_deprecated_type() {
+        let rename = |name: &str| name.to_string();
+        assert_eq!(
+            format_deprecated_type(&DeprecatedType::Deprecated, "Deprecated", rename),
+            "@available(*, deprecated, message: \"Deprecated\")\n"
+        );
+        let with_since = DeprecatedType::DeprecatedWithSince {
+            since: Some("1.2.0".into()),
+            note: "".into(),
+        };
+        assert_eq!(
+            format_deprecated_type(&with_since, "Deprecated", rename),
+            "@available(*, deprecated, message: \"Deprecated since 1.2.0\")\n"
+        );
+        let renamed = DeprecatedType::DeprecatedRenamed {
+            since: None,
+            note: "Use \"Account\"".into(),
+            renamed: "account".into(),
+        };
+        assert_eq!(
+            format_deprecated_type(&renamed, "Deprecated", |name| name.to_uppercase()),
+            "@available(*, deprecated, renamed: \"ACCOUNT\", message: \"Use \\\"Account\\\"\")\n"
+        );
+    }
+
     #[test]
     fn test_format_deprecated_empty() {
         let result = format_deprecated("");
@@ -4,7 +4,7 @@
 //!
 //! - `formatting`: Code formatting helpers (indentation, line wrapping, etc.)
 //! - `validation`: Type validation and sanity checks
-//! - `testing`: Test utilities and helpers
+//! - `testing`: Snapshot testing helpers for generated code
 //!
 //! # Architecture
 //!
@@ -27,17 +27,15 @@
 //!
 //! ## Testing
 //!
-//! - Test assertion helpers
-//! - Mock data generation
-//! - Output comparison utilities
+//! - Snapshot assertions (`assert_swift_snapshot!`)
+//! - Whitespace-insensitive output comparison
+//! - Per-type snapshot files
 
 // Submodules
 pub mod formatting;
+pub mod testing;
 pub mod validation;
 
 // Re-export commonly used functions
-pub use formatting::{escape_string, format_doc_comment, indent};
+pub use formatting::{escape_string, format_doc_comment, indent, process_doc};
 pub use validation::is_recursive_type_reference;
-
-// Re-export public types from submodules once they're created
-// pub use testing::*;
@@ -0,0 +1,187 @@
+//! Snapshot testing helpers for generated Swift.
+//!
+//! Asserting on generated code with `contains()` is brittle: it misses unexpected additions and
+//! breaks on unrelated formatting changes. These helpers compare whole outputs (or individual
+//! declarations) against snapshot files instead, ignoring insignificant whitespace.
+//!
+//! ```rust,no_run
+//! use specta::TypeCollection;
+//! use specta_swift::{assert_swift_snapshot, Swift};
+//!
+//! let output = Swift::new().export(&TypeCollection::default()).unwrap();
+//!
+//! // Compared against `tests/snapshots/empty.swift`
+//! assert_swift_snapshot!("empty", output);
+//! ```
+//!
+//! Missing snapshots are written on the first run. Set `SPECTA_SWIFT_UPDATE_SNAPSHOTS=1` to
+//! overwrite snapshots which no longer match.
+
+// Failing an assertion is the whole point of these helpers.
+#![allow(clippy::panic)]
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::diff::declarations_by_name;
+use crate::export::Export;
+
+/// Environment variable which makes snapshot assertions overwrite mismatching snapshots.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "SPECTA_SWIFT_UPDATE_SNAPSHOTS";
+
+/// Normalize whitespace so outputs differing only in formatting compare equal.
+///
+/// Line endings are unified, trailing whitespace is removed from every line and blank lines
+/// are dropped. Indentation is kept, as it is significant for reviewing the code.
+///
+/// # Examples
+///
+/// ```rust
+/// # use specta_swift::utils::testing::normalize_whitespace;
+/// assert_eq!(
+///     normalize_whitespace("struct A {  \r\n\n    let a: Int\n}\n"),
+///     "struct A {\n    let a: Int\n}"
+/// );
+/// ```
+pub fn normalize_whitespace(code: &str) -> String {
+    code.lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Assert that two pieces of Swift are equal, ignoring insignificant whitespace.
+///
+/// On failure the first differing line is reported along with both outputs.
+#[track_caller]
+pub fn assert_swift_eq(actual: &str, expected: &str) {
+    let actual = normalize_whitespace(actual);
+    let expected = normalize_whitespace(expected);
+    if actual == expected {
+        return;
+    }
+
+    panic!(
+        "Swift output does not match{}\n\n--- expected\n{}\n\n+++ actual\n{}\n",
+        first_difference(&actual, &expected),
+        expected,
+        actual
+    );
+}
+
+/// Split generated Swift into its top-level declarations, keyed by type name.
+///
+/// Extensions are grouped with the type they extend, so each entry holds everything generated
+/// for one type.
+pub fn declarations(output: &str) -> BTreeMap<String, String> {
+    let mut declarations = BTreeMap::<String, String>::new();
+    for (name, code) in declarations_by_name(&Export::parse(output)) {
+        let name = name
+            .strip_prefix("extension ")
+            .map(str::to_string)
+            .unwrap_or(name);
+        let entry = declarations.entry(name).or_default();
+        if !entry.is_empty() {
+            entry.push('\n');
+        }
+        entry.push_str(&code);
+    }
+    declarations
+}
+
+/// Compare `actual` against the snapshot file `dir/{name}.swift`.
+///
+/// Prefer the [`assert_swift_snapshot!`](crate::assert_swift_snapshot) macro, which resolves
+/// `dir` relative to the calling crate.
+#[track_caller]
+pub fn assert_snapshot(dir: impl AsRef<Path>, name: &str, actual: &str) {
+    let path = dir.as_ref().join(format!("{}.swift", name));
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some_and(|value| value != "0");
+
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if !update => {
+            if normalize_whitespace(&expected) != normalize_whitespace(actual) {
+                panic!(
+                    "Swift snapshot `{}` does not match{}\nRun with {}=1 to update it.\n\n--- {}\n{}\n\n+++ actual\n{}\n",
+                    name,
+                    first_difference(
+                        &normalize_whitespace(actual),
+                        &normalize_whitespace(&expected)
+                    ),
+                    UPDATE_SNAPSHOTS_VAR,
+                    path.display(),
+                    normalize_whitespace(&expected),
+                    normalize_whitespace(actual),
+                );
+            }
+        }
+        _ => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .unwrap_or_else(|err| panic!("failed to create {}: {}", parent.display(), err));
+            }
+            std::fs::write(&path, actual)
+                .unwrap_or_else(|err| panic!("failed to write {}: {}", path.display(), err));
+        }
+    }
+}
+
+/// Snapshot every type of a generated file separately, as `dir/{Type}.swift`.
+///
+/// Per-type snapshots keep review diffs focused on the types which actually changed.
+#[track_caller]
+pub fn assert_type_snapshots(dir: impl AsRef<Path>, output: &str) {
+    for (name, code) in declarations(output) {
+        assert_snapshot(dir.as_ref(), &name, &code);
+    }
+}
+
+/// Describe the first line which differs between two normalized outputs.
+fn first_difference(actual: &str, expected: &str) -> String {
+    let mut actual_lines = actual.lines();
+    let mut expected_lines = expected.lines();
+    for line in 1.. {
+        match (actual_lines.next(), expected_lines.next()) {
+            (Some(a), Some(e)) if a == e => continue,
+            (None, None) => break,
+            (a, e) => {
+                return format!(
+                    " (first difference at normalized line {}: expected `{}`, found `{}`)",
+                    line,
+                    e.unwrap_or("<end of output>"),
+                    a.unwrap_or("<end of output>")
+                )
+            }
+        }
+    }
+    String::new()
+}
+
+/// Compare generated Swift against a snapshot in the calling crate's `tests/snapshots` directory.
+///
+/// `assert_swift_snapshot!("name", output)` compares the whole output with
+/// `tests/snapshots/name.swift`, while `assert_swift_snapshot!(types: "dir", output)` writes
+/// one snapshot per type to `tests/snapshots/dir/{Type}.swift`. Whitespace differences are
+/// ignored and missing snapshots are created.
+#[macro_export]
+macro_rules! assert_swift_snapshot {
+    (types: $dir:expr, $output:expr $(,)?) => {
+        $crate::utils::testing::assert_type_snapshots(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots")
+                .join($dir),
+            &$output,
+        )
+    };
+    ($name:expr, $output:expr $(,)?) => {
+        $crate::utils::testing::assert_snapshot(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots"),
+            $name,
+            &$output,
+        )
+    };
+}
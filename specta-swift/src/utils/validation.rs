@@ -5,8 +5,13 @@
 //! - Recursive type detection
 //! - Circular reference detection
 //! - Type safety checks
+//! - Property list compatibility
 
-use specta::{datatype::DataType, SpectaID};
+use specta::datatype::{DataType, Fields, NamedDataType};
+use specta::{SpectaID, TypeCollection};
+
+use crate::codable::{CodableImpl, CodablePlan};
+use crate::special_types::helpers::is_builtin_json_value;
 
 /// Check if a DataType references the given SpectaID (for detecting recursive types).
 ///
@@ -90,10 +95,153 @@ pub fn is_recursive_type_reference(ty: &DataType, target_sid: SpectaID) -> bool
     }
 }
 
+/// Collect the IDs of all types referenced by a DataType, including generic arguments.
+pub fn collect_references(ty: &DataType, references: &mut Vec<SpectaID>) {
+    let mut collect_fields = |fields: &specta::datatype::Fields| match fields {
+        specta::datatype::Fields::Named(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|(_, field)| field.ty())
+            .for_each(|ty| collect_references(ty, references)),
+        specta::datatype::Fields::Unnamed(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|field| field.ty())
+            .for_each(|ty| collect_references(ty, references)),
+        specta::datatype::Fields::Unit => {}
+    };
+
+    match ty {
+        DataType::Reference(reference) => {
+            references.push(reference.sid());
+            for generic in reference.generics().values() {
+                collect_references(generic, references);
+            }
+        }
+        DataType::Nullable(inner) => collect_references(inner, references),
+        DataType::List(list) => collect_references(list.ty(), references),
+        DataType::Map(map) => {
+            collect_references(map.key_ty(), references);
+            collect_references(map.value_ty(), references);
+        }
+        DataType::Tuple(tuple) => {
+            for element in tuple.elements() {
+                collect_references(element, references);
+            }
+        }
+        DataType::Struct(s) => collect_fields(s.fields()),
+        DataType::Enum(e) => {
+            for (_, variant) in e.variants() {
+                collect_fields(variant.fields());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Find the constructs of a type which `PropertyListEncoder` can't encode.
+///
+/// Property lists have no `null` and their root must be a dictionary or an array. Optional
+/// fields are omitted by the generated code, but optional collection elements, `JsonValue`s
+/// and types which encode as a single string can't be fixed by generation.
+pub(crate) fn property_list_issues(types: &TypeCollection, ndt: &NamedDataType) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    if let DataType::Enum(e) = ndt.ty() {
+        let has_unit_variants = e.variants().iter().any(|(_, variant)| {
+            !variant.skip()
+                && match variant.fields() {
+                    Fields::Unit => true,
+                    Fields::Unnamed(fields) => fields.fields().is_empty(),
+                    Fields::Named(_) => false,
+                }
+        });
+        if e.repr().is_some_and(|repr| repr.is_string()) {
+            issues.push(
+                "encodes as a single string, so it can't be the root of a property list".into(),
+            );
+        } else if has_unit_variants
+            && CodablePlan::for_enum(e).implementation == CodableImpl::ExternallyTagged
+        {
+            issues.push(
+                "unit variants encode as a single string, so they can't be the root of a property list"
+                    .into(),
+            );
+        }
+    }
+
+    collect_property_list_issues(types, ndt.ty(), false, &mut issues);
+    issues
+}
+
+fn collect_property_list_issues(
+    types: &TypeCollection,
+    ty: &DataType,
+    in_collection: bool,
+    issues: &mut Vec<String>,
+) {
+    let mut push = |issue: &str| {
+        if !issues.iter().any(|existing| existing == issue) {
+            issues.push(issue.to_string());
+        }
+    };
+
+    match ty {
+        DataType::Nullable(inner) => {
+            if in_collection {
+                push("contains optional collection elements, but property lists can't store nil");
+            }
+            collect_property_list_issues(types, inner, in_collection, issues);
+        }
+        DataType::List(list) => collect_property_list_issues(types, list.ty(), true, issues),
+        DataType::Map(map) => collect_property_list_issues(types, map.value_ty(), true, issues),
+        DataType::Tuple(tuple) => {
+            for element in tuple.elements() {
+                collect_property_list_issues(types, element, true, issues);
+            }
+        }
+        DataType::Reference(reference)
+            if types
+                .get(reference.sid())
+                .is_some_and(is_builtin_json_value) =>
+        {
+            push("contains JsonValue, which can hold null");
+        }
+        DataType::Struct(s) => collect_fields_property_list_issues(types, s.fields(), issues),
+        DataType::Enum(e) => {
+            for (_, variant) in e.variants() {
+                collect_fields_property_list_issues(types, variant.fields(), issues);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_fields_property_list_issues(
+    types: &TypeCollection,
+    fields: &Fields,
+    issues: &mut Vec<String>,
+) {
+    let tys: Vec<&DataType> = match fields {
+        Fields::Named(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|(_, field)| field.ty())
+            .collect(),
+        Fields::Unnamed(fields) => fields
+            .fields()
+            .iter()
+            .filter_map(|field| field.ty())
+            .collect(),
+        Fields::Unit => vec![],
+    };
+    for ty in tys {
+        collect_property_list_issues(types, ty, false, issues);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     // Tests would verify recursive detection with actual type data
     #[test]
     fn test_detects_direct_reference() {
@@ -0,0 +1,119 @@
+//! Checking generated Swift with the Swift compiler.
+
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::{Error, Result};
+use crate::swift::Swift;
+
+/// Counter distinguishing the temporary directories of the checks run by this process.
+static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+impl Swift {
+    /// Check that generated Swift parses, using `swiftc -parse`.
+    ///
+    /// The compiler is taken from the `SWIFTC` environment variable, falling back to `swiftc`
+    /// on the `PATH`. The first error reported by the compiler is returned as
+    /// [`Error::InvalidOutput`] with the surrounding lines of `output`, so broken code
+    /// generation is caught in Rust CI rather than in Xcode.
+    ///
+    /// ```rust,no_run
+    /// # use specta::TypeCollection;
+    /// # use specta_swift::Swift;
+    /// let swift = Swift::new();
+    /// let output = swift.export(&TypeCollection::default()).unwrap();
+    /// swift.verify(&output).unwrap();
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "swift-check")))]
+    pub fn verify(&self, output: &str) -> Result<()> {
+        // Every call gets its own directory, so parallel checks don't remove each other's files
+        let dir = std::env::temp_dir().join(format!(
+            "specta-swift-verify-{}-{}",
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("Bindings.swift");
+        std::fs::write(&path, output)?;
+
+        let compiler = std::env::var("SWIFTC").unwrap_or_else(|_| "swiftc".to_string());
+        let result = Command::new(&compiler).arg("-parse").arg(&path).output();
+        let _ = std::fs::remove_dir_all(&dir);
+        let result = result.map_err(|err| {
+            Error::Configuration(format!("failed to run '{}': {}", compiler, err))
+        })?;
+
+        if result.status.success() {
+            return Ok(());
+        }
+
+        let stderr = String::from_utf8_lossy(&result.stderr);
+        Err(
+            parse_diagnostic(&stderr, output).unwrap_or_else(|| Error::InvalidOutput {
+                line: 0,
+                message: stderr.trim().to_string(),
+                context: String::new(),
+            }),
+        )
+    }
+}
+
+/// Convert the first `file:line:column: error: message` diagnostic into an error.
+fn parse_diagnostic(stderr: &str, source: &str) -> Option<Error> {
+    stderr.lines().find_map(|line| {
+        let (location, message) = line.split_once(": error: ")?;
+        let mut parts = location.rsplitn(3, ':');
+        let _column = parts.next()?;
+        let line_number = parts.next()?.parse::<usize>().ok()?;
+
+        let lines = source.lines().collect::<Vec<_>>();
+        let start = line_number.saturating_sub(2);
+        let end = (line_number + 1).min(lines.len());
+        let context = (start..end)
+            .map(|i| format!("{:>4} | {}", i + 1, lines[i]))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(Error::InvalidOutput {
+            line: line_number,
+            message: message.to_string(),
+            context,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_diagnostic() {
+        let source = "import Foundation\n\npublic struct User {\n    public let id: UInt32\n";
+        let err = parse_diagnostic(
+            "/tmp/Bindings.swift:4:26: error: expected '}' in struct\n/tmp/Bindings.swift:3:19: note: to match this opening '{'\n",
+            source,
+        )
+        .unwrap();
+
+        match err {
+            Error::InvalidOutput {
+                line,
+                message,
+                context,
+            } => {
+                assert_eq!(line, 4);
+                assert_eq!(message, "expected '}' in struct");
+                assert_eq!(
+                    context,
+                    "   3 | public struct User {\n   4 |     public let id: UInt32"
+                );
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn test_no_diagnostic() {
+        assert!(parse_diagnostic("<unknown>:0: error: no such file", "").is_none());
+    }
+}
@@ -0,0 +1,122 @@
+//! XCTest round-trip tests for the generated `Codable` implementations.
+
+use std::fmt::Write;
+
+use specta::TypeCollection;
+
+use crate::error::Result;
+use crate::mock::{generate_mock_extension, generate_samples};
+use crate::naming::resolver::resolve_type_names;
+use crate::report::ExportReport;
+use crate::swift::{CodableFormat, Swift};
+
+/// Generate an XCTest case which encodes, decodes and re-encodes samples of every type.
+///
+/// The encoded data is compared instead of the values, as the generated types aren't
+/// `Equatable` unless [`Swift::equatable_tests`] says so, in which case the decoded values are
+/// compared too. The samples go through the coders of the configured [`CodableFormat`].
+/// When the exporter doesn't generate mocks, they are included in the test file instead.
+pub(crate) fn generate_codable_tests(swift: &Swift, types: &TypeCollection) -> Result<String> {
+    let resolved = resolve_type_names(swift, types, &mut ExportReport::default())?;
+
+    let mut result = String::new();
+    if !swift.header.is_empty() {
+        result.push_str(&swift.header);
+        result.push('\n');
+    }
+    result.push_str("import XCTest\n");
+    if swift.codable_format == CodableFormat::MessagePack {
+        result.push_str("import MessagePack\n");
+    }
+    if let Some(module) = &swift.test_module {
+        writeln!(result, "@testable import {}", module)?;
+    }
+    result.push('\n');
+
+    let mut tests = String::new();
+    for (swift_name, ndt) in &resolved.types {
+        let Some(samples) =
+            generate_samples(swift, types, ndt, swift_name, &resolved.variant_structs)?
+        else {
+            continue;
+        };
+
+        if !swift.generate_mocks {
+            if let Some(mock) =
+                generate_mock_extension(swift, types, ndt, swift_name, &resolved.variant_structs)?
+            {
+                result.push_str(&mock);
+                result.push_str("\n\n");
+            }
+        }
+
+        writeln!(tests, "\n    func test{}RoundTrip() throws {{", swift_name)?;
+        writeln!(tests, "        let samples: [{}] = [", swift_name)?;
+        for sample in samples {
+            writeln!(tests, "            {},", sample)?;
+        }
+        tests.push_str("        ]\n");
+        tests.push_str("        for sample in samples {\n");
+        tests.push_str("            try assertRoundTrip(sample)\n");
+        if swift.equatable_tests {
+            tests.push_str("            try assertDecodesEqual(sample)\n");
+        }
+        tests.push_str("        }\n");
+        tests.push_str("    }\n");
+    }
+
+    result.push_str("final class SpectaCodableTests: XCTestCase {\n");
+    result.push_str("    private func assertRoundTrip<T: Codable>(_ value: T, file: StaticString = #filePath, line: UInt = #line) throws {\n");
+    match swift.codable_format {
+        CodableFormat::Json => {
+            result.push_str("        let encoder = JSONEncoder()\n");
+            result.push_str("        encoder.outputFormatting = .sortedKeys\n");
+            result.push_str("        let encoded = try encoder.encode(value)\n");
+            result.push_str(
+                "        let decoded = try JSONDecoder().decode(T.self, from: encoded)\n",
+            );
+            result.push_str("        let reencoded = try encoder.encode(decoded)\n");
+            result.push_str("        XCTAssertEqual(String(decoding: reencoded, as: UTF8.self), String(decoding: encoded, as: UTF8.self), file: file, line: line)\n");
+        }
+        CodableFormat::MessagePack => {
+            result.push_str("        let encoder = MessagePackEncoder()\n");
+            result.push_str("        let encoded = try encoder.encode(value)\n");
+            result.push_str(
+                "        let decoded = try MessagePackDecoder().decode(T.self, from: encoded)\n",
+            );
+            result.push_str("        let reencoded = try encoder.encode(decoded)\n");
+            result.push_str("        XCTAssertEqual(reencoded, encoded, file: file, line: line)\n");
+        }
+        CodableFormat::PropertyList => {
+            result.push_str("        let encoder = PropertyListEncoder()\n");
+            result.push_str("        encoder.outputFormat = .xml\n");
+            result.push_str("        let encoded = try encoder.encode(value)\n");
+            result.push_str(
+                "        let decoded = try PropertyListDecoder().decode(T.self, from: encoded)\n",
+            );
+            result.push_str("        let reencoded = try encoder.encode(decoded)\n");
+            result.push_str("        XCTAssertEqual(reencoded, encoded, file: file, line: line)\n");
+        }
+    }
+    result.push_str("    }\n");
+    if swift.equatable_tests {
+        result.push_str(&equality_assertion(swift.codable_format));
+    }
+    result.push_str(&tests);
+    result.push_str("}\n");
+
+    Ok(result)
+}
+
+/// The helper asserting that a value decodes to an equal value after being encoded.
+fn equality_assertion(format: CodableFormat) -> String {
+    let (encoder, decoder) = match format {
+        CodableFormat::Json => ("JSONEncoder()", "JSONDecoder()"),
+        CodableFormat::MessagePack => ("MessagePackEncoder()", "MessagePackDecoder()"),
+        CodableFormat::PropertyList => ("PropertyListEncoder()", "PropertyListDecoder()"),
+    };
+    format!(
+        "\n    private func assertDecodesEqual<T: Codable & Equatable>(_ value: T, file: StaticString = #filePath, line: UInt = #line) throws {{\n        let encoded = try {}.encode(value)\n        let decoded = try {}.decode(T.self, from: encoded)\n        XCTAssertEqual(decoded, value, file: file, line: line)\n    }}\n",
+        encoder, decoder
+    )
+}
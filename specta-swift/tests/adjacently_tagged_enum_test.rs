@@ -42,3 +42,132 @@ fn test_adjacently_tagged_enum_with_empty_tuple() {
                 .any(|line| line.contains("case .custom:") && !line.contains("fatalError"))
     );
 }
+
+#[derive(Type)]
+#[specta(tag = "type", content = "data")]
+#[allow(dead_code)]
+pub enum Connection {
+    Idle,
+    Closed(),
+    Active { port: u16 },
+}
+
+/// The lines of the `case` in `function` matching `case_line`, up to the next case.
+fn case_body<'a>(output: &'a str, function: &str, case_line: &str) -> Vec<&'a str> {
+    output
+        .lines()
+        .skip_while(|line| !line.contains(function))
+        .skip_while(|line| line.trim() != case_line)
+        .skip(1)
+        .take_while(|line| !line.trim().starts_with("case ") && line.trim() != "}")
+        .collect()
+}
+
+#[test]
+fn test_adjacently_tagged_unit_variants_ignore_content() {
+    let types = TypeCollection::default().register::<Connection>();
+    let output = Swift::new().export(&types).unwrap();
+
+    // `{"type": "Idle"}` and `{"type": "Idle", "data": null}` both decode, since the content
+    // key isn't read for unit variants
+    for case in ["case .idle:", "case .closed:"] {
+        let body = case_body(&output, "init(from decoder: Decoder)", case);
+        assert!(!body.is_empty(), "missing {}", case);
+        assert!(
+            body.iter().all(|line| !line.contains(".content")),
+            "{:?}",
+            body
+        );
+    }
+
+    // Variants with data still require it
+    let body = case_body(&output, "init(from decoder: Decoder)", "case .active:");
+    assert!(
+        body.iter()
+            .any(|line| line
+                .contains("container.decode(ConnectionActiveData.self, forKey: .content)"))
+    );
+}
+
+#[test]
+fn test_adjacently_tagged_unit_variants_encode_without_content() {
+    let types = TypeCollection::default().register::<Connection>();
+    let output = Swift::new().export(&types).unwrap();
+
+    // Like serde, unit variants are encoded as `{"type": "Idle"}`
+    let body = case_body(&output, "func encode(to encoder: Encoder)", "case .idle:");
+    assert_eq!(
+        body.iter().map(|line| line.trim()).collect::<Vec<_>>(),
+        vec!["try container.encode(VariantType.idle, forKey: .tag)"]
+    );
+}
+
+#[derive(Type, serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", content = "data")]
+pub enum Transfer {
+    Waiting,
+    Started(String),
+    Progress(String, u32),
+    Finished { bytes: u64 },
+}
+
+#[test]
+fn test_adjacently_tagged_tuple_variants_round_trip() {
+    // serde stores newtype values directly and tuple values as an array
+    for (value, json) in [
+        (
+            Transfer::Started("a.txt".into()),
+            r#"{"type":"Started","data":"a.txt"}"#,
+        ),
+        (
+            Transfer::Progress("a.txt".into(), 40),
+            r#"{"type":"Progress","data":["a.txt",40]}"#,
+        ),
+    ] {
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Transfer>(json).unwrap(), value);
+    }
+
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Transfer>())
+        .unwrap();
+    assert!(!output.contains("fatalError"));
+
+    assert_eq!(
+        case_body(&output, "init(from decoder: Decoder)", "case .started:"),
+        ["            self = .started(try container.decode(String.self, forKey: .content))"]
+    );
+    assert_eq!(
+        case_body(&output, "init(from decoder: Decoder)", "case .progress:"),
+        [
+            "            let data = try container.decode(Tuple2<String, UInt32>.self, forKey: .content)",
+            "            self = .progress(data._0, data._1)",
+        ]
+    );
+    assert_eq!(
+        case_body(
+            &output,
+            "encode(to encoder: Encoder)",
+            "case .started(let value0):"
+        ),
+        [
+            "            try container.encode(VariantType.started, forKey: .tag)",
+            "            try container.encode(value0, forKey: .content)",
+        ]
+    );
+    assert_eq!(
+        case_body(
+            &output,
+            "encode(to encoder: Encoder)",
+            "case .progress(let value0, let value1):"
+        ),
+        [
+            "            try container.encode(VariantType.progress, forKey: .tag)",
+            "            try container.encode(Tuple2(value0, value1), forKey: .content)",
+        ]
+    );
+
+    // The tuple helper codes its values as the array serde writes
+    assert!(output.contains("public struct Tuple2<T0, T1>"));
+    assert!(!output.contains("public struct Tuple3"));
+}
@@ -0,0 +1,66 @@
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type, Serialize)]
+#[serde(tag = "eventType", content = "event_data", rename_all = "snake_case")]
+#[specta(tag = "eventType", content = "event_data", rename_all = "snake_case")]
+#[allow(dead_code)]
+enum DeviceEvent {
+    PoweredOn,
+    BatteryLow { percent: u8 },
+}
+
+#[derive(Type, Serialize)]
+#[serde(tag = "kind", content = "payload", rename_all = "SCREAMING_SNAKE_CASE")]
+#[specta(tag = "kind", content = "payload", rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(dead_code)]
+enum Command {
+    StartSync { force: bool },
+}
+
+/// The keys and the tag of the JSON serde writes for `value`.
+fn serialized(value: impl Serialize) -> (Vec<String>, String, String) {
+    let json = serde_json::to_value(value).unwrap();
+    let object = json.as_object().unwrap();
+    let mut keys = object.keys().cloned().collect::<Vec<_>>();
+    keys.sort();
+    let tag_key = keys
+        .iter()
+        .find(|key| object[*key].is_string())
+        .unwrap()
+        .clone();
+    let tag = object[&tag_key].as_str().unwrap().to_string();
+    (keys, tag_key, tag)
+}
+
+#[test]
+fn test_type_keys_match_serde_with_rename_all() {
+    let types = TypeCollection::default().register::<DeviceEvent>();
+    let output = Swift::new().export(&types).unwrap();
+
+    let (keys, tag_key, tag) = serialized(DeviceEvent::BatteryLow { percent: 5 });
+    assert_eq!(keys, vec!["eventType", "event_data"]);
+    assert!(output.contains(&format!("case tag = \"{}\"", tag_key)));
+    assert!(output.contains("case content = \"event_data\""));
+    assert!(output.contains(&format!("case batteryLow = \"{}\"", tag)));
+    assert_eq!(tag, "battery_low");
+
+    // Unit variants only have the tag
+    let (keys, _, tag) = serialized(DeviceEvent::PoweredOn);
+    assert_eq!(keys, vec!["eventType"]);
+    assert!(output.contains(&format!("case poweredOn = \"{}\"", tag)));
+}
+
+#[test]
+fn test_type_keys_are_verbatim_for_every_casing() {
+    let types = TypeCollection::default().register::<Command>();
+    let output = Swift::new().export(&types).unwrap();
+
+    let (keys, _, tag) = serialized(Command::StartSync { force: true });
+    assert_eq!(keys, vec!["kind", "payload"]);
+    assert_eq!(tag, "START_SYNC");
+    assert!(output.contains("case tag = \"kind\""));
+    assert!(output.contains("case content = \"payload\""));
+    assert!(output.contains("case startSync = \"START_SYNC\""));
+}
@@ -0,0 +1,124 @@
+use specta::{Type, TypeCollection};
+use specta_swift::ast::{Function, Member, Property};
+use specta_swift::{IndentStyle, Swift};
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    nickname: Option<String>,
+}
+
+#[derive(Type)]
+enum Shape {
+    Circle { radius: f64 },
+    Square(f64),
+}
+
+#[test]
+fn test_ast_post_processor_edits_declarations() {
+    let types = TypeCollection::default().register::<User>();
+
+    let output = Swift::new()
+        .with_ast_post_processor(|_, definition| {
+            let decl = definition.declaration_mut().unwrap();
+            decl.conform_to("Identifiable");
+            decl.conform_to("Codable");
+            decl.members.push(Member::Property(
+                Property::constant("version", "Int").static_value("2"),
+            ));
+            decl.members.push(Member::Function(Function::new(
+                "public func describe() -> String",
+                "if let nickname {\n    return nickname\n}\nreturn String(id)",
+            )));
+        })
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct User: Codable, Identifiable {"));
+    assert!(output.contains(
+        "    public let nickname: String?\n\n    public static let version: Int = 2\n\n    public func describe() -> String {\n        if let nickname {\n            return nickname\n        }\n        return String(id)\n    }\n}"
+    ));
+}
+
+#[test]
+fn test_ast_post_processor_sees_extensions() {
+    let types = TypeCollection::default().register::<User>();
+
+    let output = Swift::new()
+        .with_ast_post_processor(|_, definition| {
+            for extension in definition.extensions_mut() {
+                extension.mark = Some("Decoding".into());
+            }
+        })
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("}\n\n// MARK: - Decoding\nextension User {\n"));
+}
+
+#[test]
+fn test_tabs_indent_every_level() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Shape>();
+
+    let output = Swift::new()
+        .indent(IndentStyle::Tabs)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct User: Codable {\n\tpublic let id: UInt32\n"));
+    assert!(output.contains("\tpublic init(from decoder: Decoder) throws {\n\t\tlet container"));
+    assert!(output.contains("\tcase circle(ShapeCircleData)\n"));
+    assert!(!output.lines().any(|line| line.starts_with("    ")));
+}
+
+#[test]
+fn test_two_space_indent() {
+    let types = TypeCollection::default().register::<Shape>();
+
+    let output = Swift::new()
+        .indent(IndentStyle::Spaces(2))
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("  case square(Double)\n"));
+    assert!(output.contains("  public init(from decoder: Decoder) throws {\n    // Try"));
+}
+
+#[test]
+fn test_blank_lines_between_declarations() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Shape>();
+
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(output.contains("}\n\npublic struct ShapeCircleData: Codable {"));
+    assert!(output.contains("}\n\n// MARK: - User Custom Codable Implementation"));
+    assert!(!output.contains("\n\n\n"));
+    assert!(!output
+        .lines()
+        .any(|line| !line.is_empty() && line.trim().is_empty()));
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Pair(u32, #[serde(skip)] String, bool);
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Token(#[specta(skip)] u64, String);
+
+#[test]
+fn test_tuple_structs_skip_skipped_fields() {
+    let types = TypeCollection::default()
+        .register::<Pair>()
+        .register::<Token>();
+
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(output.contains("    public let field0: UInt32\n    public let field1: Bool\n"));
+    assert!(!output.contains("field2"));
+    assert!(output.contains("    let value: String\n"));
+}
@@ -0,0 +1,162 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[derive(Type)]
+struct Comment {
+    author: User,
+    body: String,
+}
+
+#[derive(Type)]
+enum Event {
+    Created { id: u32 },
+    Deleted,
+}
+
+fn cache_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("specta_swift_cache_{}.txt", name))
+}
+
+#[test]
+fn test_unchanged_types_are_reused() {
+    let path = cache_path("reuse");
+    std::fs::remove_file(&path).ok();
+
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+    let swift = Swift::new().cache_file(&path);
+
+    let (first, report) = swift.export_with_report(&types).unwrap();
+    assert!(report.exported_types.iter().all(|ty| !ty.cached));
+
+    let (second, report) = swift.export_with_report(&types).unwrap();
+    assert!(report.exported_types.iter().all(|ty| ty.cached));
+    assert_eq!(first, second);
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_only_new_types_are_rendered() {
+    let path = cache_path("new_types");
+    std::fs::remove_file(&path).ok();
+
+    let swift = Swift::new().cache_file(&path);
+    swift
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Comment>();
+    let (output, report) = swift.export_with_report(&types).unwrap();
+
+    let cached = report
+        .exported_types
+        .iter()
+        .map(|ty| (ty.swift_name.as_str(), ty.cached))
+        .collect::<Vec<_>>();
+    assert_eq!(cached, vec![("Comment", false), ("User", true)]);
+    assert_eq!(output, Swift::new().export(&types).unwrap());
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_config_change_invalidates_cache() {
+    let path = cache_path("config");
+    std::fs::remove_file(&path).ok();
+
+    let types = TypeCollection::default().register::<User>();
+    Swift::new().cache_file(&path).export(&types).unwrap();
+
+    let (_, report) = Swift::new()
+        .cache_file(&path)
+        .naming(specta_swift::NamingConvention::SnakeCase)
+        .export_with_report(&types)
+        .unwrap();
+    assert!(report.exported_types.iter().all(|ty| !ty.cached));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_corrupt_cache_is_ignored() {
+    let path = cache_path("corrupt");
+    std::fs::write(&path, "not a cache file").unwrap();
+
+    let types = TypeCollection::default().register::<User>();
+    let (output, report) = Swift::new()
+        .cache_file(&path)
+        .export_with_report(&types)
+        .unwrap();
+    assert!(report.exported_types.iter().all(|ty| !ty.cached));
+    assert_eq!(output, Swift::new().export(&types).unwrap());
+
+    std::fs::remove_file(path).ok();
+}
+
+mod other {
+    use specta::Type;
+
+    #[derive(Type)]
+    pub struct User {
+        pub email: String,
+    }
+}
+
+#[test]
+fn test_renamed_reference_invalidates_cache() {
+    let path = cache_path("renamed_reference");
+    std::fs::remove_file(&path).ok();
+
+    let swift = Swift::new().cache_file(&path);
+    let first = swift
+        .export(
+            &TypeCollection::default()
+                .register::<User>()
+                .register::<Comment>(),
+        )
+        .unwrap();
+    assert!(first.contains("public let author: User"));
+
+    // The duplicate name renames `User`, which `Comment` refers to
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Comment>()
+        .register::<other::User>();
+    let (output, report) = swift.export_with_report(&types).unwrap();
+    assert!(!output.contains("public let author: User\n"));
+    assert_eq!(output, Swift::new().export(&types).unwrap());
+    assert!(report
+        .exported_types
+        .iter()
+        .filter(|ty| ty.name == "Comment")
+        .all(|ty| !ty.cached));
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_closure_options_bypass_cache() {
+    let path = cache_path("closures");
+    std::fs::remove_file(&path).ok();
+
+    let types = TypeCollection::default().register::<User>();
+    let swift = Swift::new()
+        .cache_file(&path)
+        .filter(|ndt| ndt.name() != "Comment");
+    swift.export(&types).unwrap();
+
+    let (_, report) = swift.export_with_report(&types).unwrap();
+    assert!(report.exported_types.iter().all(|ty| !ty.cached));
+
+    std::fs::remove_file(path).ok();
+}
@@ -0,0 +1,56 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Profile {
+    id: u32,
+    display_name: Option<String>,
+    address: Address,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+#[test]
+fn test_change_tracking() {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<Page<Address>>();
+    let output = Swift::new().with_change_tracking().export(&types).unwrap();
+
+    assert!(output.contains("// MARK: - Profile Change Tracking\nextension Profile {"));
+    assert!(output.contains(
+        "    /// The key paths of the stored properties, in declaration order.\n    public static var allKeyPaths: [PartialKeyPath<Self>] {\n        [\\.id, \\.displayName, \\.address]\n    }"
+    ));
+    assert!(output.contains(
+        "    public func changedFields(from other: Self) -> [String] {\n        var changed: [String] = []\n        if !Self.isSame(id, other.id) { changed.append(\"id\") }\n        if !Self.isSame(displayName, other.displayName) { changed.append(\"displayName\") }\n"
+    ));
+    // Generated types aren't `Hashable`, so they are compared by their JSON
+    assert!(output.contains("    private static func isSame<T: Encodable>(_ a: T, _ b: T) -> Bool {\n        if let a = a as? AnyHashable, let b = b as? AnyHashable {"));
+    assert!(output.contains("extension Address {"));
+    // Generic parameters aren't known to be `Encodable`
+    assert!(!output.contains("extension Page {"));
+}
+
+#[test]
+fn test_change_tracking_requires_codable() {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<Page<Address>>();
+    let output = Swift::new()
+        .with_change_tracking()
+        .emit_codable(false)
+        .export(&types)
+        .unwrap();
+    assert!(!output.contains("changedFields"));
+}
@@ -0,0 +1,63 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{CharType, Swift};
+
+#[derive(Type)]
+struct Contact {
+    name: String,
+    initial: char,
+    middle_initial: Option<char>,
+}
+
+#[test]
+fn test_character_fields_decode_single_character() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Contact>())
+        .unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("    public let initial: Character\n"));
+    assert!(output.contains("    public let middleInitial: Character?\n"));
+    assert!(output.contains("// MARK: - Contact Custom Codable Implementation"));
+    assert!(output.contains(
+        "        let initialString = try container.decode(String.self, forKey: .initial)\n        guard initialString.count == 1 else {\n            throw DecodingError.dataCorruptedError(forKey: .initial, in: container, debugDescription: \"Expected a single character\")\n        }\n        initial = Character(initialString)\n"
+    ));
+    assert!(output.contains(
+        "        if let middleInitialString = try container.decodeIfPresent(String.self, forKey: .middleInitial) {\n            guard middleInitialString.count == 1 else {\n"
+    ));
+    assert!(output.contains(
+        "            middleInitial = Character(middleInitialString)\n        } else {\n            middleInitial = nil\n        }\n"
+    ));
+    assert!(output.contains("try container.encode(String(initial), forKey: .initial)"));
+    assert!(output
+        .contains("try container.encode(middleInitial.map(String.init), forKey: .middleInitial)"));
+}
+
+#[test]
+fn test_string_char_type() {
+    #[derive(Type)]
+    struct Grade {
+        letter: char,
+    }
+
+    let output = Swift::new()
+        .char_type(CharType::String)
+        .export(&TypeCollection::default().register::<Grade>())
+        .unwrap();
+
+    assert!(output.contains("    public let letter: String\n"));
+    assert!(!output.contains("Character"));
+    assert!(!output.contains("init(from decoder: Decoder)"));
+
+    // Without nullable fields, `Character` alone needs the custom implementation
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Grade>())
+        .unwrap();
+    assert!(output.contains("    public let letter: Character\n"));
+    assert!(output.contains("letter = Character(letterString)"));
+}
+
+#[test]
+fn test_char_type_from_str() {
+    assert_eq!("string".parse::<CharType>().unwrap(), CharType::String);
+    assert!("char".parse::<CharType>().is_err());
+}
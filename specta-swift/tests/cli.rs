@@ -0,0 +1,180 @@
+#![cfg(feature = "cli")]
+
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::cli::{dump_types, load_types, Cli, Command};
+use specta_swift::{DuplicateNameStrategy, IndentStyle, NamingConvention, Swift};
+
+#[derive(Type, Serialize)]
+struct User {
+    id: u32,
+    display_name: String,
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("specta_swift_cli_{}.swift", name))
+}
+
+#[test]
+fn test_parse_flags() {
+    let cli = Cli::parse([
+        "export",
+        "Types.swift",
+        "--indent",
+        "tabs",
+        "--naming",
+        "camel-case",
+        "--duplicates",
+        "suffix",
+        "--protocol",
+        "Combine",
+        "--initializers",
+        "--mocks",
+        "--strip-prefix",
+        "my_crate",
+    ])
+    .unwrap();
+
+    assert_eq!(cli.command, Command::Export(Some("Types.swift".into())));
+    assert_eq!(cli.swift.indent, IndentStyle::Tabs);
+    assert_eq!(cli.swift.naming, NamingConvention::CamelCase);
+    assert!(matches!(
+        cli.swift.duplicate_name_strategy,
+        DuplicateNameStrategy::Suffix
+    ));
+    assert_eq!(cli.swift.protocols, vec!["Combine"]);
+    assert!(cli.swift.generate_initializers);
+    assert!(cli.swift.generate_mocks);
+    assert_eq!(cli.swift.qualify.strip_prefixes, vec!["my_crate"]);
+}
+
+#[test]
+fn test_parse_manifest() {
+    let cli = Cli::parse(["manifest", "names.json", "--duplicates", "qualify"]).unwrap();
+
+    assert_eq!(cli.command, Command::Manifest("names.json".into()));
+    assert!(matches!(
+        cli.swift.duplicate_name_strategy,
+        DuplicateNameStrategy::Qualify
+    ));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(Cli::parse(["export", "--naming", "kebab"]).is_err());
+    assert!(Cli::parse(["export", "--unknown"]).is_err());
+    assert!(Cli::parse(["check"]).is_err());
+    assert!(Cli::parse(["manifest"]).is_err());
+    assert!(Cli::parse(["publish"]).is_err());
+    assert_eq!(
+        Cli::parse(Vec::<String>::new()).unwrap().command,
+        Command::Help
+    );
+}
+
+#[test]
+fn test_export_then_check() {
+    let types = TypeCollection::default().register::<User>();
+    let path = temp_path("check");
+    let path_arg = path.to_str().unwrap();
+
+    let export = Cli::parse(["export", path_arg]).unwrap();
+    assert!(export.execute(&types).unwrap());
+
+    let check = Cli::parse(["check", path_arg]).unwrap();
+    assert!(check.execute(&types).unwrap());
+
+    let check = Cli::parse(["check", path_arg, "--initializers"]).unwrap();
+    assert!(!check.execute(&types).unwrap());
+
+    std::fs::remove_file(path).ok();
+}
+
+/// A recursive, generic type referencing a type declared after it.
+#[derive(Type, Serialize)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<User>,
+}
+
+/// Something that happened.
+#[derive(Type, Serialize)]
+#[serde(tag = "type", content = "data")]
+#[allow(dead_code)]
+enum Activity {
+    Created(Tree<String>),
+    Renamed { from: String, to: String },
+    Cleared,
+}
+
+#[derive(Type, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[specta(deprecated(since = "2.0.0", note = "Use Priority", renamed = "Priority"))]
+#[allow(dead_code)]
+enum Level {
+    Low,
+    VeryHigh,
+}
+
+#[derive(Type, Serialize)]
+struct Stats(u8, [u16; 3], std::collections::HashMap<String, f64>);
+
+#[test]
+fn test_dump_round_trip() {
+    let types = TypeCollection::default()
+        .register::<Activity>()
+        .register::<Level>()
+        .register::<Stats>();
+    let dump = dump_types(&types).unwrap();
+    let loaded = load_types(&dump).unwrap();
+
+    assert_eq!(loaded.len(), types.len());
+    assert_eq!(dump_types(&loaded).unwrap(), dump);
+
+    let swift = Swift::default().with_serde();
+    let exported = swift.export(&types).unwrap();
+    assert_eq!(swift.export(&loaded).unwrap(), exported);
+    assert!(exported.contains("let children: [Tree<T>]"));
+    assert!(exported.contains("@available(*, deprecated, renamed: \"Priority\""));
+}
+
+#[test]
+fn test_execute_from_dump() {
+    let types = TypeCollection::default().register::<User>();
+    let dump = temp_path("dump").with_extension("json");
+    let path = temp_path("from_dump");
+    let dump_arg = dump.to_str().unwrap();
+    let path_arg = path.to_str().unwrap();
+
+    let cli = Cli::parse(["dump", dump_arg]).unwrap();
+    assert_eq!(cli.command, Command::Dump(dump.clone()));
+    assert!(cli.execute(&types).unwrap());
+
+    let export = Cli::parse(["export", path_arg, "--types", dump_arg]).unwrap();
+    assert_eq!(export.types.as_deref(), Some(dump.as_path()));
+    assert!(export.execute(&TypeCollection::default()).unwrap());
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        Swift::default().export(&types).unwrap()
+    );
+
+    assert!(Cli::parse(["check", path_arg, "--types", dump_arg])
+        .unwrap()
+        .execute(&TypeCollection::default())
+        .unwrap());
+
+    std::fs::remove_file(dump).ok();
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_load_invalid_dump() {
+    assert!(load_types("{").is_err());
+    assert!(load_types(r#"{"version": 2, "types": []}"#).is_err());
+    assert!(load_types(
+        r#"{"version": 1, "types": [{"name": "A", "module_path": "a", "ty": {"Reference": {"index": 1}}}]}"#
+    )
+    .is_err());
+}
@@ -0,0 +1,68 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{SpecialType, Swift};
+
+#[derive(Type)]
+pub struct Timestamp {
+    pub millis: i64,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Note {
+    title: String,
+    pinned: bool,
+    word_count: u32,
+    edited_at: Option<Timestamp>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Folder {
+    name: String,
+    notes: Vec<Note>,
+}
+
+fn cloudkit() -> Swift {
+    Swift::new()
+        .special_type(|ndt| (ndt.name() == "Timestamp").then(|| SpecialType::new("Date")))
+        .cloudkit_records_for(["Note"])
+}
+
+#[test]
+fn test_cloudkit_record_conversions() {
+    let types = TypeCollection::default().register::<Note>();
+    let output = cloudkit().export(&types).unwrap();
+
+    assert!(output.contains("import Foundation\nimport CloudKit\n"));
+    assert!(output.contains("public enum SpectaRecordError: Error {"));
+    assert!(output.contains("extension Note {\n    /// The record type used for `Note` records.\n    public static let recordType: CKRecord.RecordType = \"Note\""));
+    assert!(output.contains(
+        "        guard let wordCount = record[\"word_count\"] as? UInt32 else {\n            throw SpectaRecordError.invalidField(\"word_count\")\n        }\n        self.wordCount = wordCount\n"
+    ));
+    assert!(output.contains("        self.editedAt = record[\"edited_at\"] as? Date\n"));
+    assert!(output.contains("        record[\"edited_at\"] = editedAt\n"));
+    assert!(output.contains("    public func toRecord(recordID: CKRecord.ID? = nil) -> CKRecord {"));
+}
+
+#[test]
+fn test_cloudkit_rejects_unsupported_fields() {
+    let types = TypeCollection::default().register::<Folder>();
+    let err = cloudkit()
+        .cloudkit_records_for(["Folder"])
+        .export(&types)
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Unsupported type at `Folder.notes`: '[Note]' can't be stored in a CloudKit record, only strings, numbers, booleans, Date and Data can"
+    );
+}
+
+#[test]
+fn test_cloudkit_is_opt_in() {
+    let types = TypeCollection::default().register::<Note>();
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(!output.contains("CloudKit"));
+    assert!(!output.contains("CKRecord"));
+}
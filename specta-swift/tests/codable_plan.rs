@@ -0,0 +1,109 @@
+use specta::{NamedType, Type, TypeCollection};
+use specta_swift::codable::{CodableImpl, CodablePlan, CodingKeysPlacement};
+use specta_swift::Swift;
+
+#[derive(Type)]
+pub struct Plain {
+    pub id: u32,
+}
+
+#[derive(Type)]
+pub struct Renamed {
+    pub user_id: u32,
+}
+
+#[derive(Type)]
+pub struct WithOptional {
+    pub user_id: u32,
+    pub email: Option<String>,
+}
+
+#[derive(Type)]
+pub enum Event {
+    Started,
+    Progress(u32),
+    Finished { total: u32 },
+}
+
+#[derive(Type)]
+#[specta(tag = "type", content = "data")]
+pub enum Status {
+    Idle,
+    Busy,
+}
+
+fn plan<T: NamedType>() -> CodablePlan {
+    let types = TypeCollection::default().register::<T>();
+    let ndt = types.get(T::ID).unwrap();
+    CodablePlan::for_type(&Swift::default(), &types, ndt)
+}
+
+#[test]
+fn test_struct_plans() {
+    assert_eq!(plan::<Plain>(), CodablePlan::synthesized());
+
+    let renamed = plan::<Renamed>();
+    assert_eq!(renamed.coding_keys, CodingKeysPlacement::Declaration);
+    assert_eq!(renamed.implementation, CodableImpl::Synthesized);
+
+    let optional = plan::<WithOptional>();
+    assert_eq!(optional.coding_keys, CodingKeysPlacement::Extension);
+    assert_eq!(optional.implementation, CodableImpl::Custom);
+    assert!(optional.conforms_in_declaration());
+}
+
+#[test]
+fn test_enum_plans() {
+    let event = plan::<Event>();
+    assert_eq!(event.implementation, CodableImpl::ExternallyTagged);
+    assert!(event.variant_structs);
+    assert!(!event.conforms_in_declaration());
+
+    let status = plan::<Status>();
+    assert_eq!(
+        status.implementation,
+        CodableImpl::AdjacentlyTagged {
+            tag: "type".to_string(),
+            content: "data".to_string(),
+        }
+    );
+    assert!(!status.variant_structs);
+}
+
+#[test]
+fn test_adjacently_tagged_unit_enum_conforms_once() {
+    let types = TypeCollection::default().register::<Status>();
+    let output = Swift::default().export(&types).unwrap();
+
+    assert!(output.contains("public enum Status {"));
+    assert_eq!(output.matches("Status: Codable").count(), 1);
+}
+
+#[derive(Type)]
+pub struct Empty {}
+
+#[test]
+fn test_always_emit_codable() {
+    let types = TypeCollection::default()
+        .register::<Plain>()
+        .register::<Renamed>()
+        .register::<Empty>();
+    let output = Swift::default()
+        .always_emit_codable(true)
+        .export(&types)
+        .unwrap();
+
+    // Every struct with fields gets the same explicit implementation, with the CodingKeys
+    // in the extension
+    for name in ["Plain", "Renamed"] {
+        assert!(output.contains(&format!("public struct {}: Codable {{", name)));
+        assert!(output.contains(&format!(
+            "// MARK: - {} Custom Codable Implementation",
+            name
+        )));
+    }
+    assert_eq!(output.matches("private enum CodingKeys").count(), 2);
+    assert!(output.contains("        case userId = \"user_id\""));
+    assert!(output.contains("        id = try container.decode(UInt32.self, forKey: .id)"));
+    assert!(!output.contains("Empty Custom Codable Implementation"));
+}
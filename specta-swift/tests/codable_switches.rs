@@ -0,0 +1,66 @@
+use specta::datatype::{DataType, Enum};
+use specta::{NamedType, Type, TypeCollection};
+use specta_swift::codable::exhaustive::check_switches;
+use specta_swift::{CodableFormat, Error, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Event {
+    Started,
+    Progress(u32),
+    Moved(i32, i32),
+    Finished {
+        total: u32,
+    },
+    #[specta(skip)]
+    Internal,
+}
+
+#[derive(Type)]
+#[specta(tag = "type", content = "data")]
+#[allow(dead_code)]
+enum Status {
+    Idle,
+    Busy { job: String },
+}
+
+fn event_enum(types: &TypeCollection) -> &Enum {
+    match types.get(Event::ID).unwrap().ty() {
+        DataType::Enum(e) => e,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_generated_switches_are_exhaustive() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<Status>();
+    for format in [CodableFormat::Json, CodableFormat::MessagePack] {
+        Swift::new().codable_format(format).export(&types).unwrap();
+    }
+}
+
+#[test]
+fn test_missing_encode_case_fails() {
+    let types = TypeCollection::default().register::<Event>();
+    let decode = "case .started:\ncase .progress:\ncase .moved:\ncase .finished:\n";
+    let encode = "case .started:\ncase .progress(let value):\ncase .finished(let data):\n";
+
+    let err =
+        check_switches(&Swift::new(), event_enum(&types), "Event", decode, encode).unwrap_err();
+    assert!(matches!(err, Error::UnsupportedType { .. }));
+    assert_eq!(err.path(), Some("Event.Moved"));
+    assert!(err
+        .to_string()
+        .contains("encode(to:) has no case for this variant"));
+}
+
+#[test]
+fn test_skipped_variants_are_not_required() {
+    let types = TypeCollection::default().register::<Event>();
+    let decode = "case .started:\ncase .progress:\ncase .moved:\ncase .finished:\n";
+    let encode = "case .started:\ncase .progress(let value):\ncase .moved(let value0, let value1):\ncase .finished(let data):\n";
+
+    check_switches(&Swift::new(), event_enum(&types), "Event", decode, encode).unwrap();
+}
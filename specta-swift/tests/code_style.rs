@@ -0,0 +1,170 @@
+use specta::{Type, TypeCollection};
+use specta_swift::compat::{self, BreakingChange};
+use specta_swift::{BraceStyle, Diff, IndentStyle, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Account {
+    id: u32,
+    nickname: Option<String>,
+}
+
+#[derive(Type)]
+#[specta(rename_all = "snake_case")]
+#[allow(dead_code)]
+enum Region {
+    NorthAmerica,
+    SouthAmerica,
+    Europe,
+    AsiaPacific,
+}
+
+mod previous {
+    use specta::Type;
+
+    /// `Account` before its `email` was removed.
+    #[derive(Type)]
+    #[allow(dead_code)]
+    pub struct Account {
+        id: u32,
+        nickname: Option<String>,
+        email: String,
+    }
+}
+
+#[test]
+fn test_default_style_is_unchanged() {
+    let types = TypeCollection::default()
+        .register::<Account>()
+        .register::<Region>();
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(output.contains("public struct Account: Codable {\n    public let id: UInt32\n"));
+    assert!(output.contains("    public init(from decoder: Decoder) throws {\n"));
+}
+
+#[test]
+fn test_braces_on_next_line() {
+    let types = TypeCollection::default()
+        .register::<Account>()
+        .register::<Region>();
+    let output = Swift::new()
+        .brace_style(BraceStyle::NextLine)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct Account: Codable\n{\n    public let id: UInt32\n"));
+    assert!(
+        output.contains("extension Account\n{\n    public init(from decoder: Decoder) throws\n")
+    );
+    assert!(output
+        .contains("    public init(from decoder: Decoder) throws\n    {\n        let container"));
+    assert!(output.contains("public enum Region: String, Codable\n{\n"));
+}
+
+#[test]
+fn test_declaration_consumers_with_every_brace_style() {
+    let types = TypeCollection::default()
+        .register::<Account>()
+        .register::<Region>();
+    let previous_types = TypeCollection::default()
+        .register::<previous::Account>()
+        .register::<Region>();
+
+    for style in [BraceStyle::SameLine, BraceStyle::NextLine] {
+        let swift = Swift::new().brace_style(style);
+
+        let export = swift.export_declarations(&types).unwrap();
+        let account = export.declaration("Account").unwrap();
+        assert!(account.contains("    public let nickname: String?\n"));
+        assert!(account.ends_with('}'));
+        assert_eq!(export.extensions("Account").count(), 1);
+        assert!(export
+            .declaration("Region")
+            .unwrap()
+            .contains("    case asiaPacific = \"asia_pacific\"\n"));
+
+        let previous = swift.export(&previous_types).unwrap();
+        assert!(Diff::between(&previous, &previous).is_empty());
+        let path = std::env::temp_dir().join(format!("specta_swift_brace_style_{:?}.swift", style));
+        std::fs::write(&path, &previous).unwrap();
+        let diff = swift.diff(&path, &types).unwrap();
+        std::fs::remove_file(path).ok();
+        assert_eq!(diff.to_string(), "~ Account\n~ extension Account");
+
+        assert_eq!(
+            compat::check_with(&swift, &previous, &types).unwrap(),
+            [BreakingChange::RemovedField {
+                type_name: "Account".into(),
+                field: "email".into(),
+            }]
+        );
+    }
+}
+
+#[test]
+fn test_space_before_colon() {
+    let types = TypeCollection::default()
+        .register::<Account>()
+        .register::<Region>();
+    let output = Swift::new()
+        .space_before_colon(true)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct Account : Codable {\n    public let id : UInt32\n"));
+    assert!(output.contains("    public let nickname : String?\n"));
+    assert!(output.contains("public enum Region : String, Codable {"));
+}
+
+#[test]
+fn test_wrapped_arrays_with_trailing_commas() {
+    let types = TypeCollection::default()
+        .register::<Account>()
+        .register::<Region>();
+    let swift = Swift::new().with_string_enum_helpers().line_width(60);
+
+    let output = swift.clone().export(&types).unwrap();
+    assert!(output.contains(
+        "    public static let allRawValues: [String] = [\n        \"north_america\",\n        \"south_america\",\n        \"europe\",\n        \"asia_pacific\"\n    ]\n"
+    ));
+
+    let output = swift
+        .trailing_commas(true)
+        .indent(IndentStyle::Tabs)
+        .export(&types)
+        .unwrap();
+    assert!(output.contains("\t\t\"asia_pacific\",\n\t]\n"));
+}
+
+#[test]
+fn test_short_arrays_stay_inline() {
+    let types = TypeCollection::default()
+        .register::<Account>()
+        .register::<Region>();
+    let output = Swift::new()
+        .with_string_enum_helpers()
+        .line_width(200)
+        .trailing_commas(true)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains(
+        "public static let allRawValues: [String] = [\"north_america\", \"south_america\", \"europe\", \"asia_pacific\"]\n"
+    ));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_style_from_config() {
+    let swift = Swift::from_config_str(
+        "brace_style = \"next-line\"\nspace_before_colon = true\ntrailing_commas = true\nline_width = 80",
+    )
+    .unwrap();
+
+    assert_eq!(swift.brace_style, BraceStyle::NextLine);
+    assert!(swift.space_before_colon);
+    assert!(swift.trailing_commas);
+    assert_eq!(swift.line_width, Some(80));
+    assert!(Swift::from_config_str("brace_style = \"allman\"").is_err());
+}
@@ -0,0 +1,103 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{DataStrategy, DateStrategy, Swift};
+
+#[derive(Type)]
+pub struct Upload {
+    pub name: String,
+}
+
+#[test]
+fn test_coders_are_opt_in() {
+    let output = Swift::default()
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+    assert!(!output.contains("public static var specta"));
+}
+
+#[test]
+fn test_with_coders_uses_defaults() {
+    let output = Swift::default()
+        .with_coders()
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("extension JSONEncoder {\n    /// The encoder configured for the JSON of the Rust side.\n    public static var specta: JSONEncoder {\n        let encoder = JSONEncoder()\n        encoder.dateEncodingStrategy = .deferredToDate\n        encoder.dataEncodingStrategy = .base64\n        return encoder\n    }\n}"));
+    assert!(output.contains("extension JSONDecoder {"));
+    assert!(!output.contains("keyDecodingStrategy"));
+}
+
+#[test]
+fn test_strategies_emit_coders() {
+    let output = Swift::default()
+        .date_strategy(DateStrategy::Iso8601)
+        .data_strategy(DataStrategy::DeferredToData)
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("encoder.dateEncodingStrategy = .iso8601"));
+    assert!(output.contains("decoder.dateDecodingStrategy = .iso8601"));
+    assert!(output.contains("encoder.dataEncodingStrategy = .deferredToData"));
+    assert!(output.contains("decoder.dataDecodingStrategy = .deferredToData"));
+
+    // The coders are part of the prelude, before the types
+    assert!(
+        output.find("extension JSONDecoder").unwrap()
+            < output.find("public struct Upload").unwrap()
+    );
+}
+
+#[test]
+fn test_strategy_from_str() {
+    assert_eq!(
+        "milliseconds_since_1970".parse::<DateStrategy>().unwrap(),
+        DateStrategy::MillisecondsSince1970
+    );
+    assert_eq!(
+        "deferred-to-data".parse::<DataStrategy>().unwrap(),
+        DataStrategy::DeferredToData
+    );
+}
+
+#[derive(Type)]
+pub enum Visibility {
+    Public,
+    Private,
+}
+
+#[test]
+fn test_json_helpers() {
+    let types = TypeCollection::default()
+        .register::<Upload>()
+        .register::<Visibility>();
+    let output = Swift::default().with_json_helpers().export(&types).unwrap();
+
+    // The helpers use the shared coders, which are emitted with them
+    assert!(output.contains("public static var specta: JSONDecoder"));
+    assert!(output.contains(
+        "// MARK: - Upload JSON Helpers\nextension Upload {\n    /// Decode a value with `JSONDecoder.specta`, returning `nil` if the JSON is invalid.\n    public init?(jsonData: Data) {\n        guard let value = try? JSONDecoder.specta.decode(Self.self, from: jsonData) else {\n            return nil\n        }\n        self = value\n    }\n\n    /// Encode the value with `JSONEncoder.specta`.\n    public func jsonData() throws -> Data {\n        try JSONEncoder.specta.encode(self)\n    }\n}"
+    ));
+    assert!(output.contains("extension Visibility {\n    /// Decode a value"));
+
+    let output = Swift::default().export(&types).unwrap();
+    assert!(!output.contains("init?(jsonData: Data)"));
+}
+
+#[test]
+fn test_dictionary_helpers() {
+    let types = TypeCollection::default()
+        .register::<Upload>()
+        .register::<Visibility>();
+    let output = Swift::default()
+        .with_dictionary_helpers()
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public static var specta: JSONEncoder"));
+    assert!(output.contains("// MARK: - Upload Dictionary Bridging\nextension Upload {"));
+    assert!(output.contains(
+        "    public init(dictionary: [String: Any]) throws {\n        let data = try JSONSerialization.data(withJSONObject: dictionary)\n        self = try JSONDecoder.specta.decode(Self.self, from: data)\n    }"
+    ));
+    assert!(output.contains("    public var dictionary: [String: Any] {\n        guard let data = try? JSONEncoder.specta.encode(self),"));
+    // Enums aren't encoded as dictionaries
+    assert!(!output.contains("extension Visibility {"));
+}
@@ -27,3 +27,69 @@ fn test_coding_keys_generation() {
     assert!(result.contains("case jobType = \"job_type\""));
     assert!(result.contains("private enum CodingKeys: String, CodingKey"));
 }
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Contact {
+    name: String,
+    email: Option<String>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Member {
+    id: u32,
+    display_name: String,
+    nickname: Option<String>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Label {
+    id: u32,
+    display_name: String,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Change {
+    Moved { from: u32, to: u32 },
+    Renamed { old_name: String },
+}
+
+#[test]
+fn test_no_redundant_coding_keys() {
+    let types = TypeCollection::default()
+        .register::<Contact>()
+        .register::<Change>();
+    let output = Swift::new().export(&types).unwrap();
+
+    // The custom implementation uses the synthesized keys
+    assert!(output.contains("extension Contact {\n    public init(from decoder: Decoder)"));
+    assert!(output.contains("let container = try decoder.container(keyedBy: CodingKeys.self)"));
+
+    // Only the renamed variant struct spells out its keys, besides the enum's own
+    assert!(output.contains("public struct ChangeMovedData: Codable {\n    public let from: UInt32\n    public let to: UInt32\n}"));
+    assert!(output.contains("case oldName = \"old_name\""));
+    assert_eq!(output.matches("private enum CodingKeys").count(), 2);
+}
+
+#[test]
+fn test_coding_keys_only_map_renamed_properties() {
+    let types = TypeCollection::default()
+        .register::<Member>()
+        .register::<Label>();
+    let output = Swift::new().export(&types).unwrap();
+
+    // Once per type, in the extension of the custom implementation or the declaration
+    assert_eq!(output.matches("private enum CodingKeys").count(), 2);
+    assert_eq!(
+        output
+            .matches("case displayName = \"display_name\"")
+            .count(),
+        2
+    );
+    assert!(output.contains("        case id\n"));
+    assert!(output.contains("        case nickname\n"));
+    assert!(!output.contains("case id = \"id\""));
+}
@@ -0,0 +1,147 @@
+use specta::{Type, TypeCollection};
+use specta_swift::compat::{check, BreakingChange};
+use specta_swift::Swift;
+
+mod v1 {
+    use specta::Type;
+
+    #[derive(Type)]
+    pub struct User {
+        pub id: u32,
+        pub name: String,
+        pub email: String,
+    }
+
+    #[derive(Type)]
+    pub enum Status {
+        Active,
+        Suspended,
+        Deleted,
+    }
+
+    #[derive(Type)]
+    pub struct Legacy {
+        pub value: u32,
+    }
+}
+
+mod v2 {
+    use specta::Type;
+
+    #[derive(Type)]
+    pub struct User {
+        pub id: u64,
+        pub name: String,
+        pub avatar: Option<String>,
+        pub role: String,
+    }
+
+    #[derive(Type)]
+    pub enum Status {
+        Active,
+        Banned,
+        Deleted,
+        Archived(u32),
+    }
+}
+
+#[derive(Type)]
+enum Plan {
+    Free,
+    Pro,
+}
+
+#[derive(Type)]
+enum RenamedPlan {
+    Free,
+    Premium,
+}
+
+fn old_export() -> String {
+    Swift::new()
+        .export(
+            &TypeCollection::default()
+                .register::<v1::User>()
+                .register::<v1::Status>()
+                .register::<v1::Legacy>(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_breaking_changes() {
+    let changes = check(
+        &old_export(),
+        &TypeCollection::default()
+            .register::<v2::User>()
+            .register::<v2::Status>(),
+    )
+    .unwrap();
+    for change in &changes {
+        println!("{}", change);
+    }
+
+    assert!(changes.contains(&BreakingChange::RemovedType {
+        name: "Legacy".into()
+    }));
+    assert!(changes.contains(&BreakingChange::ChangedFieldType {
+        type_name: "User".into(),
+        field: "id".into(),
+        old: "UInt32".into(),
+        new: "UInt64".into(),
+    }));
+    assert!(changes.contains(&BreakingChange::RemovedField {
+        type_name: "User".into(),
+        field: "email".into(),
+    }));
+    assert!(changes.contains(&BreakingChange::AddedRequiredField {
+        type_name: "User".into(),
+        field: "role".into(),
+    }));
+    // Optional fields can be missing from old data
+    assert!(!changes
+        .iter()
+        .any(|change| change.to_string().contains("avatar")));
+
+    assert!(changes.contains(&BreakingChange::RemovedVariant {
+        type_name: "Status".into(),
+        variant: "suspended".into(),
+    }));
+    assert!(changes.contains(&BreakingChange::AddedVariant {
+        type_name: "Status".into(),
+        variant: "archived".into(),
+    }));
+    assert_eq!(
+        BreakingChange::RemovedType {
+            name: "Legacy".into()
+        }
+        .to_string(),
+        "type `Legacy` was removed"
+    );
+}
+
+#[test]
+fn test_renamed_variant() {
+    let old = Swift::new()
+        .export(&TypeCollection::default().register::<Plan>())
+        .unwrap()
+        .replace("Plan", "RenamedPlan");
+    let changes = check(&old, &TypeCollection::default().register::<RenamedPlan>()).unwrap();
+
+    assert_eq!(
+        changes,
+        vec![BreakingChange::RenamedVariant {
+            type_name: "RenamedPlan".into(),
+            from: "pro".into(),
+            to: "premium".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_no_changes() {
+    let types = TypeCollection::default().register::<v1::User>();
+    let old = Swift::new().export(&types).unwrap();
+
+    assert!(check(&old, &types).unwrap().is_empty());
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "toml")]
+
+use specta::{Type, TypeCollection};
+use specta_swift::{DuplicateNameStrategy, IndentStyle, NamingConvention, Swift};
+
+#[derive(Type)]
+struct User {
+    id: u32,
+}
+
+#[test]
+fn test_config_str() {
+    let swift = Swift::from_config_str(
+        r#"
+header = "// Custom header"
+indent = "tabs"
+naming = "camel-case"
+protocols = ["Combine"]
+generate_initializers = true
+generate_mocks = true
+duplicate_name_strategy = "suffix"
+exclude_modules = ["my_app::internal"]
+
+[qualify]
+separator = "_"
+full_path = true
+strip_prefixes = ["my_app"]
+
+[extensions]
+User = "extension User: Identifiable {}"
+
+[option_sets]
+Permissions = { WRITE = 2, READ = 1 }
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(swift.header, "// Custom header");
+    assert_eq!(swift.indent, IndentStyle::Tabs);
+    assert_eq!(swift.naming, NamingConvention::CamelCase);
+    assert_eq!(swift.protocols, vec!["Combine"]);
+    assert!(swift.generate_initializers);
+    assert!(swift.generate_mocks);
+    assert!(matches!(
+        swift.duplicate_name_strategy,
+        DuplicateNameStrategy::Suffix
+    ));
+    assert_eq!(swift.qualify.separator, "_");
+    assert_eq!(swift.qualify.depth, None);
+    assert_eq!(swift.qualify.strip_prefixes, vec!["my_app"]);
+    assert_eq!(swift.filters.len(), 1);
+    assert_eq!(swift.type_extensions.len(), 1);
+    assert_eq!(swift.option_sets.len(), 1);
+    assert_eq!(
+        swift.option_sets[0].1,
+        vec![("READ".into(), 1), ("WRITE".into(), 2)]
+    );
+}
+
+#[test]
+fn test_config_numeric_indent_and_defaults() {
+    let swift = Swift::from_config_str("indent = 2").unwrap();
+    assert_eq!(swift.indent, IndentStyle::Spaces(2));
+    assert_eq!(swift.naming, NamingConvention::PascalCase);
+
+    let swift = Swift::from_config_str("").unwrap();
+    assert_eq!(swift.indent, IndentStyle::Spaces(4));
+}
+
+#[test]
+fn test_config_errors() {
+    assert!(Swift::from_config_str("naming = \"kebab-case\"").is_err());
+    assert!(Swift::from_config_str("unknown_option = true").is_err());
+    assert!(Swift::from_config_str("[qualify]\nseperator = \"_\"").is_err());
+}
+
+#[test]
+fn test_config_file() {
+    let dir = std::env::temp_dir().join("specta_swift_config_test");
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("specta-swift.toml");
+    std::fs::write(
+        &path,
+        "cache_file = \"swift.cache\"\n\n[extensions]\nUser = \"extension User: Identifiable {}\"\n",
+    )
+    .unwrap();
+
+    let swift = Swift::from_config_file(&path).unwrap();
+    assert_eq!(swift.cache_path, Some(dir.join("swift.cache")));
+
+    let output = swift
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+    assert!(output.contains("extension User: Identifiable {}"));
+
+    std::fs::remove_dir_all(dir).ok();
+}
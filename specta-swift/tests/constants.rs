@@ -0,0 +1,48 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct Upload {
+    size: u64,
+}
+
+const MAX_UPLOAD_SIZE: u64 = 10 * 1024 * 1024;
+
+#[test]
+fn test_constants() {
+    let output = Swift::new()
+        .constant("MAX_UPLOAD_SIZE", MAX_UPLOAD_SIZE)
+        .constant("api_version", "v2")
+        .constant("DEFAULT_RATIO", 1.5f64)
+        .constant("retryCount", -3i32)
+        .constant("BETA", true)
+        .constant("greeting", String::from("say \"hi\""))
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+    println!("{}", output);
+
+    assert!(output.contains(
+        "public enum Constants {\n    public static let maxUploadSize: UInt64 = 10485760\n    public static let apiVersion: String = \"v2\"\n    public static let defaultRatio: Double = 1.5\n    public static let retryCount: Int32 = -3\n    public static let beta: Bool = true\n    public static let greeting: String = \"say \\\"hi\\\"\"\n}"
+    ));
+}
+
+#[test]
+fn test_float_literals() {
+    let output = Swift::new()
+        .constant("WHOLE", 2.0f32)
+        .constant("LIMIT", f64::INFINITY)
+        .export(&TypeCollection::default())
+        .unwrap();
+
+    assert!(output.contains("    public static let whole: Float = 2.0\n"));
+    assert!(output.contains("    public static let limit: Double = Double.infinity\n"));
+}
+
+#[test]
+fn test_no_constants() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(!output.contains("enum Constants"));
+}
@@ -0,0 +1,74 @@
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::{DuplicateNameStrategy, Error, Swift};
+
+mod accounts {
+    use super::*;
+
+    #[derive(Type, Serialize)]
+    #[serde(rename = "Account")]
+    pub struct AccountRecord {
+        pub id: u32,
+    }
+}
+
+mod billing {
+    use super::*;
+
+    #[derive(Type, Serialize)]
+    #[specta(rename = "Account")]
+    pub struct BillingAccount {
+        pub balance: f64,
+    }
+}
+
+#[derive(Type, Serialize)]
+#[serde(rename = "Session")]
+pub struct SessionRecord {
+    pub account: accounts::AccountRecord,
+}
+
+#[derive(Type, Serialize)]
+#[serde(rename = "Visibility")]
+pub enum VisibilityKind {
+    Public,
+    Private(String),
+}
+
+#[test]
+fn test_container_rename_controls_swift_name() {
+    let types = TypeCollection::default()
+        .register::<SessionRecord>()
+        .register::<VisibilityKind>();
+    let (output, report) = Swift::default().export_with_report(&types).unwrap();
+
+    assert!(output.contains("public struct Session: Codable"));
+    assert!(output.contains("public struct Account: Codable"));
+    assert!(output.contains("public enum Visibility {"));
+    assert!(!output.contains("Record"));
+    assert!(!output.contains("VisibilityKind"));
+
+    // References use the renamed type
+    assert!(output.contains("    public let account: Account\n"));
+
+    // The report lists the wire names
+    let mut names = report
+        .exported_types
+        .iter()
+        .map(|ty| ty.name.as_str())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, ["Account", "Session", "Visibility"]);
+}
+
+#[test]
+fn test_container_renames_are_checked_for_duplicates() {
+    let types = TypeCollection::default()
+        .register::<accounts::AccountRecord>()
+        .register::<billing::BillingAccount>();
+
+    let result = Swift::default()
+        .duplicate_name_strategy(DuplicateNameStrategy::Error)
+        .export(&types);
+    assert!(matches!(result, Err(Error::DuplicateNames(_))));
+}
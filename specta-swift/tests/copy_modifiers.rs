@@ -0,0 +1,63 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{CodeFeature, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Order {
+    id: u32,
+    status: Option<String>,
+    r#default: bool,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Shape {
+    Circle { radius: f64 },
+}
+
+#[test]
+fn test_copy_modifiers() {
+    let types = TypeCollection::default()
+        .register::<Order>()
+        .register::<Shape>();
+    let output = Swift::new().with_copy_modifiers().export(&types).unwrap();
+
+    assert!(output.contains("// MARK: - Order Copy Modifiers\nextension Order {"));
+    assert!(output.contains(
+        "    /// A copy with `status` replaced.\n    public func with(status: String?) -> Self {\n        Self(id: id, status: status, `default`: `default`)\n    }"
+    ));
+    assert!(output.contains("    public func with(id: UInt32) -> Self {"));
+    assert!(output.contains("    public func with(`default`: Bool) -> Self {"));
+    // Enums and their variant structs have no copy-modifiers
+    assert!(!output.contains("extension Shape {"));
+    assert!(!output.contains("radius: radius"));
+}
+
+#[test]
+fn test_copy_modifiers_disabled_by_default() {
+    let types = TypeCollection::default()
+        .register::<Order>()
+        .register::<Shape>();
+    let output = Swift::new().export(&types).unwrap();
+    assert!(!output.contains("func with("));
+}
+
+#[test]
+fn test_copy_modifiers_size_report() {
+    let types = TypeCollection::default()
+        .register::<Order>()
+        .register::<Shape>();
+    let (_, report) = Swift::new()
+        .with_copy_modifiers()
+        .export_with_report(&types)
+        .unwrap();
+    let order = report
+        .exported_types
+        .iter()
+        .find(|ty| ty.swift_name == "Order")
+        .unwrap();
+    assert!(order
+        .features
+        .iter()
+        .any(|(feature, lines)| *feature == CodeFeature::CopyModifiers && *lines > 0));
+}
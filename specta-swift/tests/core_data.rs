@@ -0,0 +1,70 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Note {
+    title: String,
+    body: Option<String>,
+    pinned: bool,
+    word_count: u32,
+    rating: Option<u8>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Folder {
+    name: String,
+    tags: Vec<String>,
+}
+
+fn core_data() -> Swift {
+    Swift::new().core_data_entities_for(["Note"])
+}
+
+#[test]
+fn test_core_data_entity() {
+    let types = TypeCollection::default().register::<Note>();
+    let output = core_data().export(&types).unwrap();
+
+    assert!(output.contains("import Foundation\nimport CoreData\n"));
+    assert!(output.contains("public enum SpectaEntityError: Error {"));
+    assert!(output.contains(
+        "@objc(NoteEntity)\npublic final class NoteEntity: NSManagedObject {\n    @nonobjc public class func fetchRequest() -> NSFetchRequest<NoteEntity> {\n        NSFetchRequest<NoteEntity>(entityName: \"NoteEntity\")\n    }\n"
+    ));
+    assert!(output.contains("    @NSManaged public var title: String?\n"));
+    assert!(output.contains("    @NSManaged public var pinned: Bool\n"));
+    assert!(output.contains("    @NSManaged public var wordCount: Int64\n"));
+    assert!(output
+        .contains("    /// Stored as `Int16`.\n    @NSManaged public var rating: NSNumber?\n"));
+}
+
+#[test]
+fn test_core_data_converters() {
+    let types = TypeCollection::default().register::<Note>();
+    let output = core_data().export(&types).unwrap();
+
+    assert!(output
+        .contains("    public func update(from value: Note) {\n        title = value.title\n"));
+    assert!(output.contains("        wordCount = Int64(value.wordCount)\n"));
+    assert!(output.contains("        rating = value.rating.map { NSNumber(value: Int16($0)) }\n"));
+
+    assert!(output.contains("    public init(entity: NoteEntity) throws {\n        guard let title = entity.title else {\n            throw SpectaEntityError.missingAttribute(\"title\")\n        }\n        self.title = title\n        self.body = entity.body\n"));
+    assert!(output.contains("        self.pinned = entity.pinned\n"));
+    assert!(output.contains("        self.wordCount = UInt32(entity.wordCount)\n"));
+    assert!(output.contains("        self.rating = entity.rating.map { UInt8($0.int16Value) }\n"));
+}
+
+#[test]
+fn test_core_data_rejects_unsupported_fields() {
+    let types = TypeCollection::default().register::<Folder>();
+    let err = Swift::new()
+        .core_data_entities_for(["Folder"])
+        .export(&types)
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Unsupported type at `Folder.tags`: '[String]' has no Core Data attribute type, only strings, numbers, booleans, UUID, Date and Data do"
+    );
+}
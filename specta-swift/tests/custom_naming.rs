@@ -0,0 +1,75 @@
+use specta::{Type, TypeCollection};
+use specta_swift::naming::{CamelCaseConverter, PascalCaseConverter};
+use specta_swift::{CaseConverter, NamingConvention, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct UserProfile {
+    user_id: u32,
+    status: Status,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Status {
+    Active,
+    InReview,
+}
+
+/// Objective-C style prefixed type names.
+#[derive(Debug)]
+struct Prefixed(&'static str);
+
+impl CaseConverter for Prefixed {
+    fn convert(&self, name: &str) -> String {
+        format!("{}{}", self.0, PascalCaseConverter.convert(name))
+    }
+}
+
+/// Prefixed field names, keeping the default case names.
+#[derive(Debug)]
+struct PrefixedFields;
+
+impl CaseConverter for PrefixedFields {
+    fn convert(&self, name: &str) -> String {
+        PascalCaseConverter.convert(name)
+    }
+
+    fn convert_field(&self, name: &str) -> String {
+        format!("m_{}", CamelCaseConverter.convert(name))
+    }
+}
+
+#[test]
+fn test_custom_converter_names_types() {
+    let output = Swift::new()
+        .naming(NamingConvention::custom(Prefixed("SD")))
+        .export(&TypeCollection::default().register::<UserProfile>())
+        .unwrap();
+
+    assert!(output.contains("public struct SDUserProfile: Codable {"));
+    assert!(output.contains("public let userId: UInt32"));
+    assert!(output.contains("public let status: SDStatus"));
+    assert!(output.contains("case inReview"));
+}
+
+#[test]
+fn test_custom_converter_names_fields() {
+    let output = Swift::new()
+        .naming(NamingConvention::custom(PrefixedFields))
+        .export(&TypeCollection::default().register::<UserProfile>())
+        .unwrap();
+
+    assert!(output.contains("public let m_userId: UInt32"));
+    assert!(output.contains("case m_userId = \"user_id\""));
+}
+
+#[test]
+fn test_custom_conventions_compare_by_converter() {
+    let custom = NamingConvention::custom(Prefixed("SD"));
+
+    assert_eq!(custom, custom.clone());
+    assert_ne!(custom, NamingConvention::custom(Prefixed("SD")));
+    assert_ne!(custom, NamingConvention::PascalCase);
+    assert_eq!(NamingConvention::SnakeCase, NamingConvention::SnakeCase);
+}
@@ -0,0 +1,151 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{BraceStyle, DeclarationKind, Export, Swift};
+
+/// A task in the queue
+#[derive(Type)]
+struct Task {
+    id: u32,
+    status: TaskStatus,
+}
+
+#[derive(Type)]
+enum TaskStatus {
+    Pending,
+    Running { progress: f32 },
+    Done,
+}
+
+fn export() -> Export {
+    Swift::default()
+        .export_declarations(
+            &TypeCollection::default()
+                .register::<Task>()
+                .register::<TaskStatus>(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_declaration_lookup() {
+    let export = export();
+
+    let task = export.declaration("Task").unwrap();
+    assert!(task.starts_with("/// A task in the queue\npublic struct Task"));
+    assert!(task.ends_with('}'));
+    assert!(!task.contains("TaskStatus {"));
+
+    let status = export.declaration("TaskStatus").unwrap();
+    assert!(status.starts_with("public enum TaskStatus"));
+    assert!(!status.contains("extension"));
+
+    assert!(export.declaration("Missing").is_none());
+}
+
+#[test]
+fn test_extensions_are_separate() {
+    let export = export();
+
+    let extensions = export.extensions("TaskStatus").collect::<Vec<_>>();
+    assert_eq!(extensions.len(), 1);
+    assert!(extensions[0].contains("\nextension TaskStatus: Codable {"));
+    assert!(extensions[0].ends_with('}'));
+}
+
+#[test]
+fn test_iteration_in_output_order() {
+    let export = export();
+
+    let declarations = export
+        .iter()
+        .map(|(name, kind, _)| (name, kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        declarations,
+        [
+            ("Task", DeclarationKind::Struct),
+            ("TaskStatus", DeclarationKind::Enum),
+            ("TaskStatusRunningData", DeclarationKind::Struct),
+            ("TaskStatus", DeclarationKind::Extension),
+        ]
+    );
+    assert_eq!(export.len(), 4);
+}
+
+#[test]
+fn test_declaration_lookup_with_braces_on_next_line() {
+    let export = Swift::default()
+        .brace_style(BraceStyle::NextLine)
+        .export_declarations(
+            &TypeCollection::default()
+                .register::<Task>()
+                .register::<TaskStatus>(),
+        )
+        .unwrap();
+
+    let task = export.declaration("Task").unwrap();
+    assert!(task.starts_with("/// A task in the queue\npublic struct Task: Codable\n{\n"));
+    assert!(task.ends_with('}'));
+    assert!(!task.contains("TaskStatus\n{"));
+    assert_eq!(export.extensions("TaskStatus").count(), 1);
+    assert_eq!(export.len(), 4);
+}
+
+#[test]
+fn test_parsing_finds_the_rendered_declarations() {
+    let types = TypeCollection::default()
+        .register::<Task>()
+        .register::<TaskStatus>();
+    for style in [BraceStyle::SameLine, BraceStyle::NextLine] {
+        let export = Swift::default()
+            .brace_style(style)
+            .with_mocks()
+            .export_declarations(&types)
+            .unwrap();
+        assert_eq!(Export::parse(export.source()), export);
+    }
+}
+
+#[test]
+fn test_parse_existing_source() {
+    let export = Export::parse(
+        "import Foundation\n\npublic typealias Id = String\n\n@available(*, deprecated)\npublic struct Old: Codable {}\n\n#if DEBUG\nextension Old {\n    static var mock: Old { Old() }\n}\n#endif\n",
+    );
+
+    assert_eq!(
+        export.declaration("Id"),
+        Some("public typealias Id = String")
+    );
+    assert_eq!(
+        export.declaration("Old"),
+        Some("@available(*, deprecated)\npublic struct Old: Codable {}")
+    );
+    assert_eq!(
+        export.extensions("Old").collect::<Vec<_>>(),
+        ["extension Old {\n    static var mock: Old { Old() }\n}"]
+    );
+    assert!(export.source().ends_with("#endif\n"));
+}
+
+#[test]
+fn test_parse_braces_on_next_line() {
+    let export = Export::parse(
+        "public struct User: Codable\n{\n    public let name: String\n\n    private enum CodingKeys: String, CodingKey\n    {\n        case name = \"user_name\"\n    }\n}\n\npublic typealias Id = String\n\nextension User\n{\n    static let brace = \"}\" // }\n}\n",
+    );
+
+    assert_eq!(
+        export
+            .iter()
+            .map(|(name, kind, _)| (name, kind))
+            .collect::<Vec<_>>(),
+        [
+            ("User", DeclarationKind::Struct),
+            ("Id", DeclarationKind::Typealias),
+            ("User", DeclarationKind::Extension),
+        ]
+    );
+    assert!(export.declaration("User").unwrap().ends_with("    }\n}"));
+    assert_eq!(
+        export.extensions("User").collect::<Vec<_>>(),
+        ["extension User\n{\n    static let brace = \"}\" // }\n}"]
+    );
+}
@@ -0,0 +1,55 @@
+#![allow(deprecated)]
+
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[deprecated(since = "1.4.0", note = "Profiles are merged into accounts")]
+struct Profile {
+    name: String,
+}
+
+#[derive(Type)]
+#[specta(deprecated(since = "2.0.0", note = "Use Account instead", renamed = "Account"))]
+struct LegacyAccount {
+    id: u32,
+}
+
+#[derive(Type)]
+#[specta(deprecated(renamed = "account_settings"))]
+struct Settings {
+    theme: String,
+}
+
+#[test]
+fn test_deprecated_since() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Profile>())
+        .unwrap();
+
+    assert!(output.contains(
+        "@available(*, deprecated, message: \"Profiles are merged into accounts (deprecated since 1.4.0)\")\npublic struct Profile"
+    ));
+}
+
+#[test]
+fn test_deprecated_renamed() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<LegacyAccount>())
+        .unwrap();
+
+    assert!(output.contains(
+        "@available(*, deprecated, renamed: \"Account\", message: \"Use Account instead (deprecated since 2.0.0)\")\npublic struct LegacyAccount"
+    ));
+}
+
+#[test]
+fn test_deprecated_renamed_uses_swift_name() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Settings>())
+        .unwrap();
+
+    assert!(output.contains(
+        "@available(*, deprecated, renamed: \"AccountSettings\", message: \"This type is deprecated\")\npublic struct Settings"
+    ));
+}
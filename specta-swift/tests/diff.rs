@@ -0,0 +1,86 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[derive(Type)]
+struct Post {
+    title: String,
+}
+
+#[derive(Type)]
+enum Event {
+    Created { id: u32 },
+    Deleted,
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("specta_swift_diff_{}.swift", name))
+}
+
+#[test]
+fn test_diff_up_to_date() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+    let path = temp_path("up_to_date");
+
+    let swift = Swift::new();
+    swift.export_to(&path, &types).unwrap();
+
+    let diff = swift.diff(&path, &types).unwrap();
+    assert!(diff.is_empty(), "{}", diff);
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_diff_reports_changes() {
+    let path = temp_path("changes");
+
+    Swift::new()
+        .export_to(
+            &path,
+            &TypeCollection::default()
+                .register::<User>()
+                .register::<Event>(),
+        )
+        .unwrap();
+
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Post>();
+    let mut swift = Swift::new();
+    swift.generate_initializers = true;
+    let diff = swift.diff(&path, &types).unwrap();
+
+    assert_eq!(diff.added, vec!["Post".to_string()]);
+    assert_eq!(
+        diff.removed,
+        vec![
+            "Event".to_string(),
+            "EventCreatedData".to_string(),
+            "extension Event".to_string()
+        ]
+    );
+    assert_eq!(diff.changed, vec!["User".to_string()]);
+    assert_eq!(
+        diff.to_string(),
+        "+ Post\n- Event\n- EventCreatedData\n- extension Event\n~ User"
+    );
+
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn test_diff_missing_file() {
+    let types = TypeCollection::default().register::<User>();
+    let diff = Swift::new().diff(temp_path("missing"), &types).unwrap();
+
+    assert_eq!(diff.added, vec!["User".to_string()]);
+    assert!(diff.removed.is_empty());
+}
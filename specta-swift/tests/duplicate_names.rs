@@ -1,5 +1,5 @@
 use specta::{Type, TypeCollection};
-use specta_swift::{DuplicateNameStrategy, Swift};
+use specta_swift::{DuplicateNameStrategy, ExportWarning, QualifyOptions, Swift};
 
 // Simulate the Spacedrive scenario with two different LibraryInfo structs
 mod libraries {
@@ -54,6 +54,20 @@ mod database {
     }
 }
 
+// References both LibraryInfo structs
+#[derive(Type)]
+pub struct Holder {
+    pub library: libraries::LibraryInfo,
+    pub status: Option<core::status::LibraryInfo>,
+    pub event: HolderEvent,
+}
+
+#[derive(Type)]
+pub enum HolderEvent {
+    Synced(core::status::LibraryInfo),
+    Moved { to: libraries::LibraryInfo },
+}
+
 #[test]
 fn test_duplicate_names_warn_strategy() {
     let types = TypeCollection::default()
@@ -64,34 +78,44 @@ fn test_duplicate_names_warn_strategy() {
 
     let swift = Swift::new().duplicate_name_strategy(DuplicateNameStrategy::Warn);
 
-    // Capture stderr to check for warnings
-    let output = swift.export(&types).unwrap();
+    let (output, report) = swift.export_with_report(&types).unwrap();
 
     println!("Generated Swift code with warnings:\n{}", output);
 
-    // Should only have one LibraryInfo definition (the last one)
-    let library_info_count = output.matches("public struct LibraryInfo: Codable").count();
-    assert_eq!(
-        library_info_count, 1,
-        "Should have only one LibraryInfo definition"
-    );
-
-    // Should only have one UserInfo definition (the last one)
-    let user_info_count = output.matches("public struct UserInfo: Codable").count();
-    assert_eq!(
-        user_info_count, 1,
-        "Should have only one UserInfo definition"
-    );
+    // No type is silently dropped, the conflicting ones are qualified instead
+    assert!(output.contains("public struct DuplicateNamesLibrariesLibraryInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNamesCoreStatusLibraryInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNamesApiUserInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNamesDatabaseUserInfo: Codable"));
+    assert!(!output.contains("public struct LibraryInfo: Codable"));
 
-    // The last LibraryInfo should have the core::status fields
+    // Fields from both LibraryInfo definitions survive
+    assert!(output.contains("let path: String"));
+    assert!(output.contains("let stats: UInt32"));
     assert!(output.contains("let isActive: Bool"));
     assert!(output.contains("let locationCount: UInt32"));
-    assert!(output.contains("let totalEntries: UInt32"));
-    assert!(output.contains("let lastSync: String"));
 
-    // Should NOT have the libraries::LibraryInfo fields
-    assert!(!output.contains("let path: String"));
-    assert!(!output.contains("let stats: UInt32"));
+    // Each conflict is surfaced as a structured warning
+    assert_eq!(report.warnings.len(), 2);
+    let warning = report
+        .warnings
+        .iter()
+        .find(|w| matches!(w, ExportWarning::DuplicateName { name, .. } if name == "LibraryInfo"))
+        .unwrap();
+    let ExportWarning::DuplicateName {
+        origins,
+        renamed_to,
+        ..
+    } = warning
+    else {
+        unreachable!()
+    };
+    assert_eq!(origins.len(), 2);
+    assert!(renamed_to.contains(&"DuplicateNamesLibrariesLibraryInfo".to_string()));
+    assert!(renamed_to.contains(&"DuplicateNamesCoreStatusLibraryInfo".to_string()));
+    assert!(warning
+        .to_string()
+        .contains("Duplicate struct name 'LibraryInfo'"));
 }
 
 #[test]
@@ -134,29 +158,160 @@ fn test_duplicate_names_qualify_strategy() {
     println!("Generated Swift code with qualified names:\n{}", output);
 
     // Should have qualified names for both LibraryInfo structs
-    assert!(output.contains("public struct LibrariesLibraryInfo: Codable"));
-    assert!(output.contains("public struct CoreStatusLibraryInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNamesLibrariesLibraryInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNamesCoreStatusLibraryInfo: Codable"));
 
     // Should have qualified names for both UserInfo structs
-    assert!(output.contains("public struct ApiUserInfo: Codable"));
-    assert!(output.contains("public struct DatabaseUserInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNamesApiUserInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNamesDatabaseUserInfo: Codable"));
 
     // Each should have their respective fields
-    // LibrariesLibraryInfo should have path and stats
-    let libraries_section = output.find("struct LibrariesLibraryInfo").unwrap();
+    // DuplicateNamesLibrariesLibraryInfo should have path and stats
+    let libraries_section = output
+        .find("struct DuplicateNamesLibrariesLibraryInfo")
+        .unwrap();
     let libraries_end = output[libraries_section..].find("}").unwrap() + libraries_section;
     let libraries_content = &output[libraries_section..libraries_end];
     assert!(libraries_content.contains("let path: String"));
     assert!(libraries_content.contains("let stats: UInt32"));
 
-    // CoreStatusLibraryInfo should have isActive, locationCount, etc.
-    let core_section = output.find("struct CoreStatusLibraryInfo").unwrap();
+    // DuplicateNamesCoreStatusLibraryInfo should have isActive, locationCount, etc.
+    let core_section = output
+        .find("struct DuplicateNamesCoreStatusLibraryInfo")
+        .unwrap();
     let core_end = output[core_section..].find("}").unwrap() + core_section;
     let core_content = &output[core_section..core_end];
     assert!(core_content.contains("let isActive: Bool"));
     assert!(core_content.contains("let locationCount: UInt32"));
 }
 
+#[test]
+fn test_duplicate_names_qualify_options() {
+    let types = TypeCollection::default()
+        .register::<libraries::LibraryInfo>()
+        .register::<core::status::LibraryInfo>();
+
+    let swift = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Qualify)
+        .qualify_options(
+            QualifyOptions::new()
+                .strip_prefix("duplicate_names")
+                .depth(1),
+        );
+
+    let output = swift.export(&types).unwrap();
+
+    assert!(output.contains("public struct LibrariesLibraryInfo: Codable"));
+    assert!(output.contains("public struct StatusLibraryInfo: Codable"));
+    assert!(!output.contains("CoreStatusLibraryInfo"));
+
+    let swift = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Qualify)
+        .qualify_options(QualifyOptions::new().separator("_").full_path());
+
+    let output = swift.export(&types).unwrap();
+
+    assert!(output.contains("public struct DuplicateNames_Libraries_LibraryInfo: Codable"));
+    assert!(output.contains("public struct DuplicateNames_Core_Status_LibraryInfo: Codable"));
+}
+
+mod alpha {
+    pub mod shared {
+        use specta::Type;
+
+        #[derive(Type)]
+        pub struct Config {
+            pub retries: u32,
+        }
+    }
+}
+
+mod beta {
+    pub mod shared {
+        use specta::Type;
+
+        #[derive(Type)]
+        pub struct Config {
+            pub timeout: u32,
+        }
+    }
+}
+
+#[test]
+fn test_duplicate_names_qualified_names_stay_unique() {
+    let types = TypeCollection::default()
+        .register::<alpha::shared::Config>()
+        .register::<beta::shared::Config>();
+
+    // Both types qualify to `SharedConfig` with a depth of one
+    let output = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Qualify)
+        .qualify_options(QualifyOptions::new().depth(1))
+        .export(&types)
+        .unwrap();
+
+    assert_eq!(
+        output
+            .matches("public struct SharedConfig: Codable")
+            .count(),
+        1
+    );
+    assert_eq!(
+        output
+            .matches("public struct SharedConfig2: Codable")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_duplicate_names_suffix_strategy() {
+    let types = TypeCollection::default()
+        .register::<libraries::LibraryInfo>()
+        .register::<core::status::LibraryInfo>()
+        .register::<api::UserInfo>()
+        .register::<database::UserInfo>();
+
+    let swift = Swift::new().duplicate_name_strategy(DuplicateNameStrategy::Suffix);
+
+    let (output, report) = swift.export_with_report(&types).unwrap();
+
+    println!("Generated Swift code with suffixed names:\n{}", output);
+
+    assert_eq!(
+        output.matches("public struct LibraryInfo: Codable").count(),
+        1
+    );
+    assert_eq!(
+        output
+            .matches("public struct LibraryInfo2: Codable")
+            .count(),
+        1
+    );
+    assert_eq!(output.matches("public struct UserInfo: Codable").count(), 1);
+    assert_eq!(
+        output.matches("public struct UserInfo2: Codable").count(),
+        1
+    );
+
+    // Both definitions survive
+    assert!(output.contains("let path: String"));
+    assert!(output.contains("let isActive: Bool"));
+
+    // Only the renamed types are recorded
+    assert_eq!(report.duplicate_resolutions.len(), 2);
+    let renamed = report
+        .duplicate_resolutions
+        .iter()
+        .find(|r| r.original_name == "LibraryInfo")
+        .unwrap();
+    assert_eq!(renamed.swift_name, "LibraryInfo2");
+    assert_eq!(
+        report.resolved_name(&renamed.module_path, "LibraryInfo"),
+        Some("LibraryInfo2")
+    );
+}
+
 #[test]
 fn test_duplicate_names_custom_strategy() {
     let types = TypeCollection::default()
@@ -197,6 +352,38 @@ fn test_duplicate_names_custom_strategy() {
     assert!(core_content.contains("let locationCount: UInt32"));
 }
 
+#[test]
+fn test_duplicate_names_custom_with_context_strategy() {
+    let types = TypeCollection::default()
+        .register::<libraries::LibraryInfo>()
+        .register::<core::status::LibraryInfo>()
+        .register::<api::UserInfo>();
+
+    let swift = Swift::new().duplicate_name_strategy(DuplicateNameStrategy::CustomWithContext(
+        |ndt, context| {
+            assert_eq!(context.name, "LibraryInfo");
+            assert_eq!(context.colliding.len(), 2);
+            assert!(context
+                .colliding
+                .iter()
+                .any(|other| other.sid() == ndt.sid()));
+
+            // Earlier choices are visible, so counting up always finds a free name
+            (1..)
+                .map(|i| format!("{}{}", ndt.name(), i))
+                .find(|name| !context.is_taken(name))
+                .unwrap()
+        },
+    ));
+
+    let (output, report) = swift.export_with_report(&types).unwrap();
+
+    assert!(output.contains("public struct LibraryInfo1: Codable"));
+    assert!(output.contains("public struct LibraryInfo2: Codable"));
+    assert!(output.contains("public struct UserInfo: Codable"));
+    assert_eq!(report.duplicate_resolutions.len(), 2);
+}
+
 #[test]
 fn test_no_duplicates_default_behavior() {
     // Test that non-duplicate names work normally
@@ -253,8 +440,8 @@ fn test_duplicate_names_with_enums() {
     println!("Generated Swift code for duplicate enums:\n{}", output);
 
     // Should have qualified enum names
-    assert!(output.contains("public enum FirstStatus: Codable"));
-    assert!(output.contains("public enum SecondStatus: Codable"));
+    assert!(output.contains("public enum DuplicateNamesFirstStatus: Codable"));
+    assert!(output.contains("public enum DuplicateNamesSecondStatus: Codable"));
 
     // Each should have their respective variants
     assert!(output.contains("case active"));
@@ -263,3 +450,155 @@ fn test_duplicate_names_with_enums() {
     assert!(output.contains("case offline"));
     assert!(output.contains("case away"));
 }
+
+mod tasks {
+    use super::*;
+
+    #[derive(Type)]
+    pub enum TaskStatus {
+        Pending,
+        Completed { finished_at: String },
+    }
+
+    /// Collides with the struct generated for `TaskStatus::Completed`
+    #[derive(Type)]
+    pub struct TaskStatusCompletedData {
+        pub summary: String,
+    }
+}
+
+#[test]
+fn test_variant_struct_collision_renames_generated_struct() {
+    let types = TypeCollection::default()
+        .register::<tasks::TaskStatus>()
+        .register::<tasks::TaskStatusCompletedData>();
+
+    let (output, report) = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Suffix)
+        .export_with_report(&types)
+        .unwrap();
+
+    println!(
+        "Generated Swift code for variant struct collision:\n{}",
+        output
+    );
+
+    // The user type keeps its name and the generated struct is renamed
+    assert_eq!(
+        output
+            .matches("public struct TaskStatusCompletedData: Codable")
+            .count(),
+        1
+    );
+    assert!(output.contains("public struct TaskStatusCompletedData2: Codable"));
+    assert!(output.contains("case completed(TaskStatusCompletedData2)"));
+    assert!(output.contains("container.decode(TaskStatusCompletedData2.self"));
+    assert_eq!(
+        report.resolved_name("duplicate_names::tasks", "TaskStatusCompletedData"),
+        Some("TaskStatusCompletedData2")
+    );
+}
+
+#[test]
+fn test_variant_struct_collision_error_strategy() {
+    let types = TypeCollection::default()
+        .register::<tasks::TaskStatus>()
+        .register::<tasks::TaskStatusCompletedData>();
+
+    let result = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Error)
+        .export(&types);
+
+    let error_msg = result.unwrap_err().to_string();
+    assert!(error_msg.contains("TaskStatusCompletedData"));
+    assert!(error_msg.contains("TaskStatus::Completed"));
+    assert!(error_msg.contains("duplicate_names::tasks"));
+}
+
+#[test]
+fn test_variant_struct_collision_qualify_strategy() {
+    let types = TypeCollection::default()
+        .register::<tasks::TaskStatus>()
+        .register::<tasks::TaskStatusCompletedData>();
+
+    let output = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Qualify)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct TaskStatusCompletedData: Codable"));
+    assert!(output.contains("public struct DuplicateNamesTasksTaskStatusCompletedData: Codable"));
+    assert!(output.contains("case completed(DuplicateNamesTasksTaskStatusCompletedData)"));
+}
+
+#[test]
+fn test_variant_struct_collision_warn_strategy() {
+    let types = TypeCollection::default()
+        .register::<tasks::TaskStatus>()
+        .register::<tasks::TaskStatusCompletedData>();
+
+    let (output, report) = Swift::new().export_with_report(&types).unwrap();
+
+    assert!(output.contains("public struct TaskStatusCompletedData: Codable"));
+    assert!(output.contains("public struct DuplicateNamesTasksTaskStatusCompletedData: Codable"));
+    assert!(output.contains("case completed(DuplicateNamesTasksTaskStatusCompletedData)"));
+    assert_eq!(report.warnings.len(), 1);
+}
+
+#[test]
+fn test_references_to_renamed_duplicates_every_strategy() {
+    let types = TypeCollection::default().register::<Holder>();
+    let libraries_path = module_path!().to_string() + "::libraries";
+
+    let strategies = [
+        DuplicateNameStrategy::Warn,
+        DuplicateNameStrategy::Qualify,
+        DuplicateNameStrategy::Suffix,
+        DuplicateNameStrategy::Custom(|ndt| {
+            if ndt.module_path().ends_with("libraries") {
+                "ListInfo".to_string()
+            } else {
+                "StatusInfo".to_string()
+            }
+        }),
+        DuplicateNameStrategy::CustomWithContext(|ndt, context| {
+            (1..)
+                .map(|i| format!("{}{}", ndt.name(), i))
+                .find(|name| !context.is_taken(name))
+                .unwrap()
+        }),
+    ];
+
+    for strategy in strategies {
+        let swift = Swift::new().duplicate_name_strategy(strategy);
+        let (output, report) = swift.export_with_report(&types).unwrap();
+
+        // The first LibraryInfo may keep its name, e.g. with the suffix strategy
+        let library = report
+            .resolved_name(&libraries_path, "LibraryInfo")
+            .unwrap_or("LibraryInfo");
+        let status = report
+            .duplicate_resolutions
+            .iter()
+            .find(|r| r.module_path != libraries_path)
+            .map_or("LibraryInfo", |r| r.swift_name.as_str());
+        assert_ne!(library, status);
+
+        // Each reference points at the declaration with the fields of its type
+        let library_decl = output
+            .split(&format!("public struct {}: Codable {{", library))
+            .nth(1)
+            .unwrap();
+        assert!(library_decl[..library_decl.find('}').unwrap()].contains("let path: String"));
+        let status_decl = output
+            .split(&format!("public struct {}: Codable {{", status))
+            .nth(1)
+            .unwrap();
+        assert!(status_decl[..status_decl.find('}').unwrap()].contains("let isActive: Bool"));
+
+        assert!(output.contains(&format!("public let library: {}\n", library)));
+        assert!(output.contains(&format!("public let status: {}?\n", status)));
+        assert!(output.contains(&format!("case synced({})", status)));
+        assert!(output.contains(&format!("public let to: {}\n", library)));
+    }
+}
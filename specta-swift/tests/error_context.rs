@@ -0,0 +1,93 @@
+use specta::{NamedType, Type, TypeCollection};
+use specta_swift::{Error, Swift};
+
+#[derive(Type)]
+struct Account {
+    id: u32,
+    balance: Balance,
+}
+
+#[derive(Type)]
+struct Balance {
+    amount: i128,
+}
+
+#[derive(Type)]
+struct Ledger {
+    entries: Vec<Option<u128>>,
+}
+
+#[derive(Type)]
+enum Transfer {
+    Pending(String, u128),
+    Settled { total: i128 },
+}
+
+fn export_error<T: NamedType>() -> Error {
+    let types = TypeCollection::default().register::<T>();
+    Swift::new().export(&types).unwrap_err()
+}
+
+#[test]
+fn test_struct_field_path() {
+    let err = export_error::<Balance>();
+    assert!(matches!(err, Error::UnsupportedType { .. }));
+    assert_eq!(err.path(), Some("Balance.amount"));
+    assert_eq!(
+        err.to_string(),
+        "Unsupported type at `Balance.amount`: Swift does not support 128-bit integers"
+    );
+}
+
+#[test]
+fn test_nested_collection_path() {
+    let err = export_error::<Ledger>();
+    assert_eq!(err.path(), Some("Ledger.entries"));
+}
+
+#[test]
+fn test_referenced_type_reports_its_own_path() {
+    // `Account` only references `Balance`, so the error comes from exporting `Balance`
+    let err = export_error::<Account>();
+    assert_eq!(err.path(), Some("Balance.amount"));
+}
+
+#[test]
+fn test_enum_variant_paths() {
+    let types = TypeCollection::default().register::<Transfer>();
+    let err = Swift::new().export(&types).unwrap_err();
+    assert_eq!(err.path(), Some("Transfer.Pending.1"));
+
+    #[derive(Type)]
+    enum Refund {
+        Issued { total: i128 },
+    }
+
+    let err = export_error::<Refund>();
+    assert_eq!(err.path(), Some("Refund.Issued.total"));
+}
+
+#[derive(Type)]
+struct Wallet {
+    id: u32,
+    owner: Owner,
+}
+
+#[derive(Type)]
+struct Owner {
+    name: String,
+}
+
+#[test]
+fn test_unknown_reference_names_the_referencing_field() {
+    let mut types = TypeCollection::default().register::<Wallet>();
+    types.remove(Owner::ID);
+
+    let err = Swift::new().export(&types).unwrap_err();
+    assert!(matches!(err, Error::UnknownReference { sid, .. } if sid == Owner::ID));
+    assert_eq!(err.path(), Some("Wallet.owner"));
+
+    let message = err.to_string();
+    assert!(message.starts_with("Reference to unknown type SpectaID("));
+    assert!(message.contains(" at `Wallet.owner`: `Wallet` refers to a type which isn't in the `TypeCollection`, register it with `TypeCollection::register`"));
+}
@@ -0,0 +1,90 @@
+use std::io::{self, Write};
+
+use specta::{Type, TypeCollection};
+use specta_swift::{Error, Swift};
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[derive(Type)]
+enum Event {
+    Created { id: u32 },
+    Deleted,
+}
+
+/// Records every chunk written to it.
+#[derive(Default)]
+struct Chunks(Vec<String>);
+
+impl Write for Chunks {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.push(String::from_utf8_lossy(buf).into_owned());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Fails as soon as anything is written.
+struct Broken;
+
+impl Write for Broken {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_export_into_matches_export() {
+    let swift = Swift::new().with_mocks().with_schema_hash();
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+
+    let mut output = Vec::new();
+    swift.export_into(&mut output, &types).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        swift.export(&types).unwrap()
+    );
+}
+
+#[test]
+fn test_export_into_streams_declarations() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+    let mut chunks = Chunks::default();
+    let report = Swift::new()
+        .export_into_with_report(&mut chunks, &types)
+        .unwrap();
+
+    // The prelude and one chunk per type, with nothing to write after the types
+    assert_eq!(chunks.0.len(), report.exported_types.len() + 1);
+    assert!(chunks.0[0].contains("import Foundation"));
+    let user = chunks
+        .0
+        .iter()
+        .find(|chunk| chunk.contains("public struct User"))
+        .unwrap();
+    assert!(!user.contains("enum Event"));
+}
+
+#[test]
+fn test_export_into_write_error() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+    let err = Swift::new().export_into(&mut Broken, &types).unwrap_err();
+    assert!(matches!(err, Error::Io(err) if err.kind() == io::ErrorKind::BrokenPipe));
+}
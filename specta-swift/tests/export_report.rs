@@ -0,0 +1,70 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{Swift, UnresolvedReference};
+
+mod api {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct User {
+        pub id: u32,
+        pub profile: crate::internal::Profile,
+    }
+
+    #[derive(Type)]
+    pub enum Event {
+        Created { id: u32 },
+        Deleted(u32),
+        Cleared,
+    }
+}
+
+mod internal {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct Profile {
+        pub bio: String,
+    }
+}
+
+#[test]
+fn test_report_lists_exported_types() {
+    let types = TypeCollection::default()
+        .register::<api::User>()
+        .register::<api::Event>();
+
+    let (output, report) = Swift::new().export_with_report(&types).unwrap();
+
+    let names = report
+        .exported_types
+        .iter()
+        .map(|ty| ty.swift_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Event", "Profile", "User"]);
+    assert!(report.exported_types.iter().all(|ty| ty.lines > 0));
+    assert!(report.total_lines() < output.lines().count());
+
+    assert_eq!(report.helper_types, vec!["EventCreatedData".to_string()]);
+    assert!(report.duplicate_resolutions.is_empty());
+    assert!(report.unresolved_references.is_empty());
+}
+
+#[test]
+fn test_report_lists_unresolved_references() {
+    let types = TypeCollection::default().register::<api::User>();
+
+    let (output, report) = Swift::new()
+        .exclude_modules(["export_report::internal"])
+        .export_with_report(&types)
+        .unwrap();
+
+    assert!(!output.contains("public struct Profile"));
+    assert_eq!(
+        report.unresolved_references,
+        vec![UnresolvedReference {
+            referenced_by: "User".to_string(),
+            name: "Profile".to_string(),
+            module_path: "export_report::internal".to_string(),
+        }]
+    );
+}
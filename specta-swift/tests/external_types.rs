@@ -0,0 +1,102 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Money {
+    cents: i64,
+    currency: String,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Page<T> {
+    items: Vec<T>,
+    next: Option<String>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Order {
+    id: u32,
+    total: Money,
+    refunds: Vec<Money>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Orders {
+    page: Page<Order>,
+}
+
+#[test]
+fn test_external_types_are_not_declared() {
+    let output = Swift::new()
+        .assume_external(["Money", "Page"])
+        .export(&TypeCollection::default().register::<Orders>())
+        .unwrap();
+
+    assert!(!output.contains("struct Money"));
+    assert!(!output.contains("struct Page"));
+    assert!(output.contains("public struct Order: Codable {"));
+    assert!(output.contains("public let total: Money"));
+    assert!(output.contains("public let refunds: [Money]"));
+}
+
+#[test]
+fn test_external_generic_types_keep_their_arguments() {
+    let output = Swift::new()
+        .assume_external(["Page"])
+        .monomorphize(true)
+        .export(&TypeCollection::default().register::<Orders>())
+        .unwrap();
+
+    assert!(output.contains("public let page: Page<Order>"));
+    assert!(!output.contains("struct PageOrder"));
+}
+
+#[test]
+fn test_external_types_are_not_unresolved() {
+    let (_, report) = Swift::new()
+        .filter(|ndt| ndt.name() != "Money")
+        .assume_external(["Money"])
+        .export_with_report(&TypeCollection::default().register::<Orders>())
+        .unwrap();
+
+    assert!(report.unresolved_references.is_empty());
+}
+
+#[test]
+fn test_external_types_get_no_mocks() {
+    let output = Swift::new()
+        .assume_external(["Money"])
+        .with_mocks()
+        .export(&TypeCollection::default().register::<Orders>())
+        .unwrap();
+
+    assert!(!output.contains("Money.mock"));
+}
+
+#[test]
+fn test_external_names_are_not_prefixed() {
+    #[derive(Type)]
+    #[allow(dead_code)]
+    struct Data {
+        bytes: Vec<u8>,
+    }
+
+    #[derive(Type)]
+    #[allow(dead_code)]
+    struct Upload {
+        payload: Data,
+    }
+
+    let output = Swift::new()
+        .prefix_shadowed_names("App")
+        .assume_external(["Data"])
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("public let payload: Data"));
+    assert!(!output.contains("AppData"));
+}
@@ -0,0 +1,48 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code, non_snake_case)]
+struct Session {
+    user_id: u32,
+    userId: String,
+    userId2: bool,
+}
+
+#[derive(Type)]
+#[allow(dead_code, non_snake_case)]
+enum Event {
+    Login { user_id: u32, userId: String },
+}
+
+#[test]
+fn test_colliding_fields_are_disambiguated() {
+    let types = TypeCollection::default().register::<Session>();
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(output.contains("public let userId: UInt32"));
+    // `userId2` is taken by another field, so the next free suffix is used
+    assert!(output.contains("public let userId3: String"));
+    assert!(output.contains("public let userId2: Bool"));
+    assert!(output.contains("case userId = \"user_id\""));
+    assert!(output.contains("case userId3 = \"userId\""));
+    assert_eq!(output.matches("public let userId:").count(), 1);
+}
+
+#[test]
+fn test_colliding_variant_fields_are_disambiguated() {
+    let types = TypeCollection::default().register::<Event>();
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(output.contains("public let userId: UInt32"));
+    assert!(output.contains("public let userId2: String"));
+    assert!(output.contains("case userId2 = \"userId\""));
+}
+
+#[test]
+fn test_disambiguation_is_deterministic() {
+    let types = TypeCollection::default().register::<Session>();
+    let first = Swift::new().export(&types).unwrap();
+    let second = Swift::new().export(&types).unwrap();
+    assert_eq!(first, second);
+}
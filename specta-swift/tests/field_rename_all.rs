@@ -0,0 +1,76 @@
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CamelCase {
+    pub user_id: u32,
+    #[serde(rename = "mail")]
+    pub email_address: String,
+}
+
+#[derive(Type, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PascalCase {
+    pub user_id: u32,
+}
+
+#[derive(Type, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct KebabCase {
+    pub user_id: u32,
+}
+
+#[derive(Type, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct ScreamingSnakeCase {
+    pub user_id: u32,
+    pub id: u32,
+}
+
+fn export() -> String {
+    let types = TypeCollection::default()
+        .register::<CamelCase>()
+        .register::<PascalCase>()
+        .register::<KebabCase>()
+        .register::<ScreamingSnakeCase>();
+    Swift::default().export(&types).unwrap()
+}
+
+fn declaration<'a>(output: &'a str, name: &str) -> &'a str {
+    let start = output
+        .find(&format!("public struct {}: Codable {{", name))
+        .unwrap();
+    let end = output[start..].find("\n}").unwrap();
+    &output[start..start + end]
+}
+
+#[test]
+fn test_camel_case_fields_need_no_coding_keys() {
+    let output = export();
+    let camel = declaration(&output, "CamelCase");
+
+    assert!(camel.contains("public let userId: UInt32"));
+    assert!(camel.contains("public let mail: String"));
+    assert!(!camel.contains("CodingKeys"));
+}
+
+#[test]
+fn test_coding_keys_use_serialized_names() {
+    let output = export();
+
+    let pascal = declaration(&output, "PascalCase");
+    assert!(pascal.contains("public let userId: UInt32"));
+    assert!(pascal.contains("case userId = \"UserId\""));
+
+    let kebab = declaration(&output, "KebabCase");
+    assert!(kebab.contains("public let userId: UInt32"));
+    assert!(kebab.contains("case userId = \"user-id\""));
+
+    let screaming = declaration(&output, "ScreamingSnakeCase");
+    assert!(screaming.contains("public let userId: UInt32"));
+    assert!(screaming.contains("public let id: UInt32"));
+    assert!(screaming.contains("case userId = \"USER_ID\""));
+    assert!(screaming.contains("case id = \"ID\""));
+}
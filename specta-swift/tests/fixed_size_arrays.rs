@@ -0,0 +1,63 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{MissingCollectionStrategy, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Color {
+    rgb: [u8; 3],
+    alpha: Option<[u8; 2]>,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_fixed_size_arrays_are_unchecked_by_default() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Color>())
+        .unwrap();
+
+    assert!(output.contains("public let rgb: [UInt8]"));
+    assert!(!output.contains("Expected 3 elements"));
+}
+
+#[test]
+fn test_fixed_size_array_lengths_are_validated() {
+    let output = Swift::new()
+        .validate_array_lengths(true)
+        .export(&TypeCollection::default().register::<Color>())
+        .unwrap();
+
+    assert!(output.contains("public let rgb: [UInt8]"));
+    assert!(output.contains("rgb = try container.decode([UInt8].self, forKey: .rgb)"));
+    assert!(output.contains("guard rgb.count == 3 else {"));
+    assert!(output.contains(
+        "throw DecodingError.dataCorruptedError(forKey: .rgb, in: container, debugDescription: \"Expected 3 elements\")"
+    ));
+    assert!(output.contains("if let alpha = alpha, alpha.count != 2 {"));
+    assert!(!output.contains("tags.count"));
+}
+
+#[test]
+fn test_fixed_size_arrays_are_not_wrapped_or_defaulted() {
+    let output = Swift::new()
+        .validate_array_lengths(true)
+        .resilient_decoding(true)
+        .missing_collections(MissingCollectionStrategy::Empty)
+        .export(&TypeCollection::default().register::<Color>())
+        .unwrap();
+
+    assert!(output.contains("public let rgb: [UInt8]"));
+    assert!(!output.contains("@LossyArray public var rgb"));
+    assert!(output.contains("@LossyArray public var tags: [String]"));
+    assert!(output.contains("guard rgb.count == 3 else {"));
+}
+
+#[test]
+fn test_fixed_size_array_mocks_have_their_length() {
+    let output = Swift::new()
+        .validate_array_lengths(true)
+        .with_mocks()
+        .export(&TypeCollection::default().register::<Color>())
+        .unwrap();
+
+    assert!(output.contains("Array(repeating: 1, count: 3)"));
+}
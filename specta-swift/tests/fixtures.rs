@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type, Deserialize)]
+struct Address {
+    street: String,
+    zip: Option<String>,
+}
+
+#[derive(Type, Deserialize)]
+struct User {
+    id: u32,
+    name: String,
+    address: Address,
+    tags: Vec<String>,
+    scores: HashMap<String, f64>,
+}
+
+#[derive(Type, Deserialize)]
+enum Shape {
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+    Empty,
+}
+
+#[derive(Type, Deserialize)]
+#[serde(tag = "type")]
+enum Message {
+    Text { body: String },
+    Ping,
+}
+
+#[derive(Type, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+enum Job {
+    Queued,
+    Running(u32),
+}
+
+fn export_fixtures(name: &str, types: &TypeCollection) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("specta_swift_fixtures_{}", name));
+    let _ = std::fs::remove_dir_all(&dir);
+    Swift::new().export_fixtures(&dir, types).unwrap();
+    dir
+}
+
+fn read(dir: &std::path::Path, file: &str) -> String {
+    std::fs::read_to_string(dir.join(file)).unwrap()
+}
+
+#[test]
+fn test_struct_fixtures() {
+    let dir = export_fixtures("structs", &TypeCollection::default().register::<User>());
+
+    let user = read(&dir, "User.json");
+    println!("{}", user);
+    assert_eq!(
+        user,
+        "{\n  \"id\": 1,\n  \"name\": \"name\",\n  \"address\": {\n    \"street\": \"street\",\n    \"zip\": null\n  },\n  \"tags\": [],\n  \"scores\": {}\n}\n"
+    );
+    assert!(dir.join("Address.json").exists());
+
+    // The fixtures are valid input for serde
+    serde_json::from_str::<User>(&user).unwrap();
+    serde_json::from_str::<Address>(&read(&dir, "Address.json")).unwrap();
+}
+
+#[test]
+fn test_enum_fixtures() {
+    let dir = export_fixtures(
+        "enums",
+        &TypeCollection::default()
+            .register::<Shape>()
+            .register::<Message>()
+            .register::<Job>(),
+    );
+
+    assert_eq!(read(&dir, "Shape.Circle.json"), "{\n  \"Circle\": 1.0\n}\n");
+    assert_eq!(read(&dir, "Shape.Empty.json"), "\"Empty\"\n");
+    assert_eq!(
+        read(&dir, "Message.Text.json"),
+        "{\n  \"type\": \"Text\",\n  \"body\": \"body\"\n}\n"
+    );
+    assert_eq!(
+        read(&dir, "Message.Ping.json"),
+        "{\n  \"type\": \"Ping\"\n}\n"
+    );
+    assert_eq!(
+        read(&dir, "Job.Running.json"),
+        "{\n  \"kind\": \"Running\",\n  \"data\": 1\n}\n"
+    );
+
+    for variant in ["Circle", "Rectangle", "Empty"] {
+        serde_json::from_str::<Shape>(&read(&dir, &format!("Shape.{}.json", variant))).unwrap();
+    }
+    for variant in ["Text", "Ping"] {
+        serde_json::from_str::<Message>(&read(&dir, &format!("Message.{}.json", variant))).unwrap();
+    }
+    for variant in ["Queued", "Running"] {
+        serde_json::from_str::<Job>(&read(&dir, &format!("Job.{}.json", variant))).unwrap();
+    }
+}
+
+#[derive(Type, Deserialize)]
+struct Settings {
+    #[serde(default)]
+    theme: String,
+    #[serde(default)]
+    locale: Option<String>,
+}
+
+#[test]
+fn test_fields_which_may_be_missing_are_omitted() {
+    let dir = export_fixtures(
+        "optional",
+        &TypeCollection::default().register::<Settings>(),
+    );
+
+    let settings = read(&dir, "Settings.json");
+    assert_eq!(settings, "{\n  \"locale\": null\n}\n");
+    serde_json::from_str::<Settings>(&settings).unwrap();
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use specta::{Type, TypeCollection};
+use specta_swift::{HelperRegistry, HelperType, Swift};
+
+#[derive(Type)]
+pub struct Job {
+    pub name: String,
+    pub timeout: Duration,
+}
+
+#[derive(Type)]
+pub struct Plain {
+    pub name: String,
+}
+
+fn any_codable() -> HelperType {
+    HelperType::new(
+        "AnyCodable",
+        "public struct AnyCodable: Codable {}\n\n",
+        |types| types.into_unsorted_iter().any(|ndt| ndt.name() == "Job"),
+    )
+}
+
+#[test]
+fn test_builtin_helpers() {
+    let registry = HelperRegistry::default();
+    let names = registry
+        .helpers()
+        .iter()
+        .map(|helper| helper.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names[..2], ["RustDuration", "JsonValue"]);
+    assert_eq!(
+        names[2..],
+        (2..=12).map(|n| format!("Tuple{}", n)).collect::<Vec<_>>()
+    );
+
+    let types = TypeCollection::default().register::<Job>();
+    let needed = registry
+        .needed(&types)
+        .map(|helper| helper.name())
+        .collect::<Vec<_>>();
+    assert_eq!(needed, ["RustDuration"]);
+}
+
+#[test]
+fn test_custom_helper_emitted_on_demand() {
+    let swift = Swift::new().helper(any_codable());
+
+    let (output, report) = swift
+        .export_with_report(&TypeCollection::default().register::<Job>())
+        .unwrap();
+    assert!(
+        output.contains("public struct AnyCodable: Codable {}\n\n// MARK: - Generated Types\n\n")
+    );
+    assert!(output.find("RustDuration: Codable") < output.find("AnyCodable"));
+    assert_eq!(output.matches("// MARK: - Generated Types").count(), 1);
+    assert!(report.helper_types.contains(&"AnyCodable".to_string()));
+
+    let output = swift
+        .export(&TypeCollection::default().register::<Plain>())
+        .unwrap();
+    assert!(!output.contains("AnyCodable"));
+    assert!(!output.contains("// MARK: - Generated Types"));
+}
+
+#[test]
+fn test_replace_builtin_helper() {
+    let types = TypeCollection::default().register::<Job>();
+
+    let output = Swift::new()
+        .helper(HelperType::always(
+            "RustDuration",
+            "public typealias RustDuration = TimeInterval\n\n",
+        ))
+        .export(&types)
+        .unwrap();
+    assert!(output.contains("public typealias RustDuration = TimeInterval"));
+    assert!(!output.contains("public struct RustDuration"));
+
+    let output = Swift::new()
+        .helpers(HelperRegistry::empty())
+        .export(&types)
+        .unwrap();
+    assert!(!output.contains("public struct RustDuration"));
+    assert!(output.contains("public let timeout: RustDuration"));
+}
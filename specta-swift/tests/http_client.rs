@@ -0,0 +1,73 @@
+use specta::{Type, TypeCollection};
+use specta_swift::integrations::{HttpClient, HttpMethod, Route};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[derive(Type)]
+struct CreateUser {
+    name: String,
+}
+
+#[test]
+fn test_http_client() {
+    let mut types = TypeCollection::default()
+        .register::<User>()
+        .register::<CreateUser>();
+
+    let client = HttpClient::new()
+        .route(
+            Route::new("get_user", HttpMethod::Get, "/users/{user_id}")
+                .path_param("user_id", u32::definition(&mut types))
+                .response(User::definition(&mut types))
+                .docs("Fetch a user."),
+        )
+        .route(
+            Route::new("create_user", HttpMethod::Post, "/users")
+                .request(CreateUser::definition(&mut types))
+                .response(User::definition(&mut types)),
+        )
+        .route(Route::new(
+            "delete_user",
+            HttpMethod::Delete,
+            "/users/{user_id}",
+        ));
+
+    let output = client.export(&Swift::new(), &types).unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("public struct HTTPClientError: Error {"));
+    assert!(output.contains("public final class ApiClient {"));
+    assert!(output.contains("        decoder: JSONDecoder = JSONDecoder()"));
+    assert!(output.contains(
+        "    /// Fetch a user.\n    public func getUser(userId: UInt32) async throws -> User {"
+    ));
+    assert!(output
+        .contains("        let data = try await send(\"GET\", \"/users/\\(userId)\", body: nil)"));
+    assert!(output.contains("        return try decoder.decode(User.self, from: data)"));
+    assert!(
+        output.contains("    public func createUser(_ body: CreateUser) async throws -> User {")
+    );
+    assert!(output.contains(
+        "        let data = try await send(\"POST\", \"/users\", body: try encoder.encode(body))"
+    ));
+    assert!(output.contains("    public func deleteUser(userId: String) async throws {"));
+    assert!(
+        output.contains("        _ = try await send(\"DELETE\", \"/users/\\(userId)\", body: nil)")
+    );
+}
+
+#[test]
+fn test_get_with_body_is_rejected() {
+    let mut types = TypeCollection::default().register::<CreateUser>();
+    let client = HttpClient::new().route(
+        Route::new("search", HttpMethod::Get, "/search")
+            .request(CreateUser::definition(&mut types)),
+    );
+
+    assert!(client.export(&Swift::new(), &types).is_err());
+}
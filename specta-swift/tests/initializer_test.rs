@@ -39,3 +39,88 @@ fn test_initializer_generation() {
             && !result_without_init.contains("public init(includeStats:")
     );
 }
+
+#[derive(Type)]
+pub struct Tag {
+    pub label: String,
+}
+
+#[derive(Type)]
+pub struct Article {
+    pub article_id: u32,
+    pub tags: Vec<Tag>,
+    pub cover: Option<Tag>,
+    #[specta(optional)]
+    pub subtitle: String,
+}
+
+#[test]
+fn test_initializer_matches_properties() {
+    let types = TypeCollection::default().register::<Article>();
+
+    let mut swift = Swift::new();
+    swift.generate_initializers = true;
+    let result = swift.export(&types).unwrap();
+
+    // Every property appears with the same type in the initializer
+    for property in [
+        "articleId: UInt32",
+        "tags: [Tag]",
+        "cover: Tag?",
+        "subtitle: String?",
+    ] {
+        assert!(
+            result.contains(&format!("public let {}\n", property)),
+            "missing property `{}`:\n{}",
+            property,
+            result
+        );
+    }
+    assert!(
+        result.contains(
+            "public init(articleId: UInt32, tags: [Tag], cover: Tag?, subtitle: String?) {"
+        ),
+        "{}",
+        result
+    );
+
+    // The Codable extension uses the same keys and decodes nullable fields by their base type
+    assert!(result.contains("case articleId = \"article_id\""));
+    assert!(result.contains("cover = try container.decodeIfPresent(Tag.self, forKey: .cover)"));
+    assert!(result.contains("tags = try container.decode([Tag].self, forKey: .tags)"));
+}
+
+#[derive(Type)]
+pub struct SmallDto {
+    pub id: u32,
+}
+
+#[test]
+fn test_per_type_initializers() {
+    let types = TypeCollection::default()
+        .register::<TestStruct>()
+        .register::<SmallDto>();
+
+    // Opt out of the global setting
+    let mut swift = Swift::new().skip_initializers_for(["TestStruct"]);
+    swift.generate_initializers = true;
+    let output = swift.export(&types).unwrap();
+    assert!(output.contains("public init(id: UInt32)"));
+    assert!(!output.contains("public init(jobId:"));
+
+    // Opt in without the global setting
+    let output = Swift::new()
+        .initializers_for(["SmallDto"])
+        .export(&types)
+        .unwrap();
+    assert!(output.contains("public init(id: UInt32)"));
+    assert!(!output.contains("public init(jobId:"));
+
+    // The last override wins
+    let output = Swift::new()
+        .initializers_for(["TestStruct"])
+        .skip_initializers_for(["TestStruct"])
+        .export(&types)
+        .unwrap();
+    assert!(!output.contains("public init(jobId:"));
+}
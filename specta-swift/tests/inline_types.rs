@@ -0,0 +1,219 @@
+use specta::builder::NamedDataTypeBuilder;
+use specta::datatype::{DataType, Field, Map, Primitive, Struct};
+use specta::{Type, TypeCollection};
+use specta_swift::{DuplicateNameStrategy, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+    city: Option<String>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Visibility {
+    Public,
+    Restricted { groups: Vec<String> },
+}
+
+#[derive(Type)]
+#[specta(inline)]
+#[allow(dead_code)]
+struct Badge {
+    label: String,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Profile {
+    id: u32,
+    #[specta(inline)]
+    address: Address,
+    #[specta(inline)]
+    visibility: Visibility,
+    badge: Option<Badge>,
+    badges: Vec<Badge>,
+}
+
+#[test]
+fn test_inline_fields_are_hoisted() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Profile>())
+        .unwrap();
+
+    assert!(output.contains("    public let address: ProfileAddress\n"));
+    assert!(output.contains("public struct ProfileAddress: Codable {\n    public let street: String\n    public let city: String?\n}"));
+    assert!(output.contains("extension ProfileAddress {"));
+
+    // Referenced types are still exported on their own
+    assert!(output.contains("public struct Address: Codable {"));
+}
+
+/// Collides with the type hoisted for `Profile::address`
+#[derive(Type)]
+#[allow(dead_code)]
+struct ProfileAddress {
+    line: String,
+}
+
+#[test]
+fn test_hoisted_type_collision_renames_hoisted_type() {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<ProfileAddress>();
+
+    let (output, report) = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Suffix)
+        .export_with_report(&types)
+        .unwrap();
+
+    // The user type keeps its name and the hoisted type is renamed
+    assert_eq!(
+        output
+            .matches("public struct ProfileAddress: Codable")
+            .count(),
+        1
+    );
+    assert!(
+        output.contains("public struct ProfileAddress: Codable {\n    public let line: String\n}")
+    );
+    assert!(output.contains("    public let address: ProfileAddress2\n"));
+    assert!(output
+        .contains("public struct ProfileAddress2: Codable {\n    public let street: String\n"));
+    assert_eq!(
+        report.resolved_name("inline_types", "ProfileAddress"),
+        Some("ProfileAddress2")
+    );
+}
+
+#[test]
+fn test_hoisted_type_collision_error_strategy() {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<ProfileAddress>();
+
+    let error = Swift::new()
+        .duplicate_name_strategy(DuplicateNameStrategy::Error)
+        .export(&types)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("ProfileAddress"));
+    assert!(error.contains("Profile.address"));
+}
+
+#[test]
+fn test_inline_enums_name_their_variant_structs() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Profile>())
+        .unwrap();
+
+    assert!(output.contains("    public let visibility: ProfileVisibility\n"));
+    assert!(output.contains("public enum ProfileVisibility {"));
+    assert!(output.contains("    case restricted(ProfileVisibilityRestrictedData)"));
+    assert!(output.contains("public struct ProfileVisibilityRestrictedData: Codable {"));
+    assert!(output.contains("extension ProfileVisibility: Codable {"));
+}
+
+#[test]
+fn test_inline_types_keep_their_wrappers() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Profile>())
+        .unwrap();
+
+    assert!(output.contains("    public let badge: ProfileBadge?\n"));
+    assert!(output.contains("    public let badges: [ProfileBadges]\n"));
+    assert!(
+        output.contains("public struct ProfileBadge: Codable {\n    public let label: String\n}")
+    );
+    assert!(
+        output.contains("public struct ProfileBadges: Codable {\n    public let label: String\n}")
+    );
+    assert!(
+        output.contains("badge = try container.decodeIfPresent(ProfileBadge.self, forKey: .badge)")
+    );
+}
+
+#[test]
+fn test_inline_types_are_deterministic() {
+    let types = TypeCollection::default().register::<Profile>();
+    let first = Swift::new().export(&types).unwrap();
+    let second = Swift::new().export(&types).unwrap();
+    assert_eq!(first, second);
+
+    // Hoisted types follow their parent
+    let profile = first.find("public struct Profile:").unwrap();
+    let address = first.find("public struct ProfileAddress:").unwrap();
+    let visibility = first.find("public enum ProfileVisibility").unwrap();
+    assert!(profile < address && address < visibility);
+}
+
+#[test]
+fn test_inline_fields_have_no_mock() {
+    let output = Swift::new()
+        .with_mocks()
+        .export(&TypeCollection::default().register::<Profile>())
+        .unwrap();
+
+    assert!(output.contains("extension Address {"));
+    assert!(!output.contains("public static func mock(id: UInt32"));
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Team {
+    #[specta(inline)]
+    members: std::collections::HashMap<String, Address>,
+}
+
+#[test]
+fn test_inline_map_values_are_hoisted() {
+    let types = TypeCollection::default().register::<Team>();
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(output.contains("    public let members: [String: TeamMembersValue]\n"));
+    assert!(output
+        .contains("public struct TeamMembersValue: Codable {\n    public let street: String\n"));
+}
+
+/// `struct User { settings: HashMap<String, { theme: String, enabled: Option<bool> }> }`, as
+/// produced by specta's object datatypes.
+fn anonymous_types() -> TypeCollection {
+    let setting = Struct::named()
+        .field("theme", Field::new(DataType::Primitive(Primitive::String)))
+        .field(
+            "enabled",
+            Field::new(DataType::Nullable(Box::new(DataType::Primitive(
+                Primitive::bool,
+            )))),
+        )
+        .build();
+    let ty = Struct::named()
+        .field(
+            "settings",
+            Field::new(DataType::Map(Map::new(
+                DataType::Primitive(Primitive::String),
+                setting,
+            ))),
+        )
+        .build();
+
+    let mut types = TypeCollection::default();
+    types
+        .create(NamedDataTypeBuilder::new("User", vec![], ty).module_path("users"))
+        .unwrap();
+    types
+}
+
+#[test]
+fn test_anonymous_map_values_get_named_structs() {
+    let output = Swift::new().export(&anonymous_types()).unwrap();
+
+    assert!(output.contains("    public let settings: [String: UserSettingsValue]\n"));
+    assert!(output.contains(
+        "public struct UserSettingsValue: Codable {\n    public let theme: String\n    public let enabled: Bool?\n}"
+    ));
+    assert!(output.contains("extension UserSettingsValue {"));
+    assert_eq!(output, Swift::new().export(&anonymous_types()).unwrap());
+}
@@ -0,0 +1,168 @@
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::{ExportWarning, KeyStrategy, Swift};
+
+#[derive(Type, Serialize)]
+pub struct UserProfile {
+    pub user_id: u32,
+    pub display_name: String,
+    pub html_url: String,
+}
+
+#[derive(Type, Serialize)]
+pub struct Session {
+    pub session_id: String,
+    pub expires_at: Option<String>,
+    #[serde(rename = "id")]
+    pub identifier: String,
+}
+
+#[derive(Type, Serialize)]
+#[serde(tag = "type")]
+pub enum Change {
+    #[serde(rename = "created")]
+    Created { created_at: String },
+    #[serde(rename = "deleted")]
+    Deleted,
+}
+
+#[derive(Type, Serialize)]
+pub enum Event {
+    LoggedIn { user_id: u32 },
+    LoggedOut,
+}
+
+#[derive(Type, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CamelCased {
+    pub user_id: u32,
+}
+
+#[derive(Type, Serialize)]
+pub struct Renamed {
+    pub user_id: u32,
+    #[serde(rename = "userId")]
+    pub user_id2: u32,
+}
+
+#[test]
+fn test_coding_keys_by_default() {
+    let output = Swift::default()
+        .export(&TypeCollection::default().register::<UserProfile>())
+        .unwrap();
+
+    assert!(output.contains("case userId = \"user_id\""));
+    assert!(!output.contains("extension JSONDecoder"));
+    assert!(!output.contains("extension JSONEncoder"));
+}
+
+#[test]
+fn test_convert_from_snake_case() {
+    let types = TypeCollection::default()
+        .register::<UserProfile>()
+        .register::<Session>()
+        .register::<Change>();
+    let (output, report) = Swift::default()
+        .key_strategy(KeyStrategy::ConvertFromSnakeCase)
+        .export_with_report(&types)
+        .unwrap();
+
+    // Property keys are converted by the coders
+    assert!(!output.contains("= \"user_id\""));
+    assert!(!output.contains("= \"session_id\""));
+    assert!(!output.contains("= \"html_url\""));
+    assert!(output.contains("public static var specta: JSONDecoder {"));
+    assert!(output.contains("decoder.keyDecodingStrategy = .convertFromSnakeCase"));
+    assert!(output.contains("encoder.keyEncodingStrategy = .convertToSnakeCase"));
+    assert!(report.warnings.is_empty());
+
+    // Custom implementations still need keys, without raw values
+    assert!(output.contains("        case sessionId\n"));
+    assert!(output.contains("        case expiresAt\n"));
+    assert!(output.contains("        case id\n"));
+}
+
+#[test]
+fn test_unconvertible_enum_tags_are_spelled_out() {
+    let types = TypeCollection::default()
+        .register::<UserProfile>()
+        .register::<Event>();
+    let (output, report) = Swift::default()
+        .key_strategy(KeyStrategy::ConvertFromSnakeCase)
+        .export_with_report(&types)
+        .unwrap();
+
+    // The encoder would write `LoggedIn` as `logged_in`
+    assert_eq!(
+        report.warnings,
+        vec![
+            ExportWarning::UnconvertibleKey {
+                name: "Event".into(),
+                key: "LoggedIn".into(),
+                swift_name: "loggedIn".into(),
+            },
+            ExportWarning::UnconvertibleKey {
+                name: "Event".into(),
+                key: "LoggedOut".into(),
+                swift_name: "loggedOut".into(),
+            },
+        ]
+    );
+    assert!(report.warnings[0]
+        .to_string()
+        .contains("doesn't convert to and from the Swift name 'loggedIn'"));
+
+    // Only the enum's CodingKeys spell out their keys, the other types keep converting
+    assert!(output.contains("decoder.keyDecodingStrategy = .convertFromSnakeCase"));
+    assert!(output.contains("encoder.keyEncodingStrategy = .custom(SpectaKeyEncoding.encode)"));
+    assert!(output.contains("protocol SpectaVerbatimKey: CodingKey {"));
+    assert!(output.contains("private enum CodingKeys: String, CodingKey, SpectaVerbatimKey {"));
+    assert!(output.contains("case loggedIn = \"LoggedIn\""));
+    assert!(output.contains("case .loggedIn: return \"LoggedIn\""));
+    assert!(!output.contains("= \"user_id\""));
+}
+
+#[test]
+fn test_unconvertible_field_keys_are_spelled_out() {
+    let (output, report) = Swift::default()
+        .key_strategy(KeyStrategy::ConvertFromSnakeCase)
+        .export_with_report(
+            &TypeCollection::default()
+                .register::<CamelCased>()
+                .register::<UserProfile>(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        report.warnings,
+        vec![ExportWarning::UnconvertibleKey {
+            name: "CamelCased".into(),
+            key: "userId".into(),
+            swift_name: "userId".into(),
+        }]
+    );
+    assert!(output.contains("case userId = \"userId\""));
+    assert!(output.contains("case .userId: return \"userId\""));
+    assert!(!output.contains("default: return nil"));
+    assert!(output.contains(".custom(SpectaKeyEncoding.encode)"));
+    assert!(!output.contains("= \"user_id\""));
+}
+
+#[test]
+fn test_keys_decoded_as_the_same_key_are_an_error() {
+    let result = Swift::default()
+        .key_strategy(KeyStrategy::ConvertFromSnakeCase)
+        .export(&TypeCollection::default().register::<Renamed>());
+
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("'Renamed' has the keys 'user_id' and 'userId'"));
+}
+
+#[test]
+fn test_key_strategy_from_str() {
+    assert_eq!(
+        "convert_from_snake_case".parse::<KeyStrategy>().unwrap(),
+        KeyStrategy::ConvertFromSnakeCase
+    );
+    assert!("camel".parse::<KeyStrategy>().is_err());
+}
@@ -0,0 +1,64 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{DateStrategy, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Upload {
+    name: String,
+}
+
+#[test]
+fn test_linux_coding_strategies() {
+    let output = Swift::new()
+        .with_linux_support()
+        .date_strategy(DateStrategy::Iso8601)
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("#if os(Linux)\n/// ISO 8601 and base64 coding for Foundation on Linux.\nenum SpectaLinuxCoding {"));
+    assert!(output.contains(
+        "#if os(Linux)\n        encoder.dateEncodingStrategy = .custom(SpectaLinuxCoding.encodeDate)\n#else\n        encoder.dateEncodingStrategy = .iso8601\n#endif\n"
+    ));
+    assert!(output.contains(
+        "#if os(Linux)\n        decoder.dataDecodingStrategy = .custom(SpectaLinuxCoding.decodeData)\n#else\n        decoder.dataDecodingStrategy = .base64\n#endif\n"
+    ));
+}
+
+#[test]
+fn test_linux_support_keeps_portable_strategies() {
+    let output = Swift::new()
+        .with_linux_support()
+        .date_strategy(DateStrategy::SecondsSince1970)
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("        encoder.dateEncodingStrategy = .secondsSince1970\n"));
+    assert!(output.contains(".custom(SpectaLinuxCoding.encodeData)"));
+    assert!(!output.contains("SpectaLinuxCoding.encodeDate"));
+}
+
+#[test]
+fn test_linux_support_guards_apple_frameworks() {
+    let output = Swift::new()
+        .with_linux_support()
+        .cloudkit_records_for(["Upload"])
+        .core_data_entities_for(["Upload"])
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("#if canImport(CloudKit)\nimport CloudKit\n#endif\n"));
+    assert!(output.contains("#if canImport(CoreData)\nimport CoreData\n#endif\n"));
+    assert!(output.contains("#if canImport(CloudKit)\n// MARK: - Upload CloudKit Record\n"));
+    assert!(output.contains("#if canImport(CoreData)\n// MARK: - Upload Core Data\n"));
+}
+
+#[test]
+fn test_linux_support_is_opt_in() {
+    let output = Swift::new()
+        .date_strategy(DateStrategy::Iso8601)
+        .export(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(!output.contains("#if os(Linux)"));
+    assert!(!output.contains("SpectaLinuxCoding"));
+}
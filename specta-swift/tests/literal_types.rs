@@ -0,0 +1,49 @@
+use specta::builder::NamedDataTypeBuilder;
+use specta::datatype::{DataType, Field, Literal, Primitive, Struct};
+use specta::TypeCollection;
+use specta_swift::Swift;
+
+#[test]
+fn test_literal_fields() {
+    // `struct ImageBlock { kind: "image", url: String, version: 2 }`, as produced by tagged
+    // unions and `#[specta(type = ...)]` overrides
+    let mut types = TypeCollection::default();
+    types
+        .create(
+            NamedDataTypeBuilder::new(
+                "ImageBlock",
+                vec![],
+                Struct::named()
+                    .field(
+                        "kind",
+                        Field::new(DataType::Literal(Literal::String("image".into()))),
+                    )
+                    .field("url", Field::new(DataType::Primitive(Primitive::String)))
+                    .field("version", Field::new(DataType::Literal(Literal::u8(2))))
+                    .build(),
+            )
+            .module_path("blocks"),
+        )
+        .unwrap();
+    let output = Swift::new().export(&types).unwrap();
+    println!("{}", output);
+
+    // Fields use the underlying type, with the literal as a constant
+    assert!(output.contains("    public let kind: String\n"));
+    assert!(output.contains("    public let version: UInt8\n"));
+    assert!(output.contains("    public static let kindLiteral: String = \"image\"\n"));
+    assert!(output.contains("    public static let versionLiteral: UInt8 = 2\n"));
+
+    // The literals are validated when decoding
+    assert!(output.contains("// MARK: - ImageBlock Custom Codable Implementation"));
+    assert!(output.contains(
+        "        kind = try container.decode(String.self, forKey: .kind)\n        guard kind == Self.kindLiteral else {\n            throw DecodingError.dataCorruptedError(forKey: .kind, in: container, debugDescription: \"Expected \\\"image\\\"\")\n        }\n"
+    ));
+    assert!(output.contains("guard version == Self.versionLiteral else {"));
+    assert!(output.contains("try container.encode(kind, forKey: .kind)"));
+
+    // Mocks default to the literals
+    let output = Swift::new().with_mocks().export(&types).unwrap();
+    assert!(output.contains("kind: String = \"image\""));
+    assert!(output.contains("version: UInt8 = 2"));
+}
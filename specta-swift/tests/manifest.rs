@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+mod users {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct Info {
+        pub id: u32,
+    }
+}
+
+mod jobs {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct Info {
+        pub timeout: Duration,
+    }
+
+    #[derive(Type)]
+    pub enum Status {
+        Pending,
+        Done { code: i32 },
+    }
+}
+
+fn export_manifest(name: &str, swift: &Swift, types: &TypeCollection) -> String {
+    let path = std::env::temp_dir().join(format!("specta_swift_manifest_{}.json", name));
+    swift.export_manifest(&path, types).unwrap();
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_manifest() {
+    let types = TypeCollection::default()
+        .register::<users::Info>()
+        .register::<jobs::Info>()
+        .register::<jobs::Status>();
+
+    let manifest = export_manifest("names", &Swift::new(), &types);
+    println!("{}", manifest);
+
+    assert_eq!(
+        manifest,
+        r#"{
+  "duplicate_name_strategy": "warn",
+  "types": [
+    {
+      "rust": "manifest::jobs::Info",
+      "swift": "ManifestJobsInfo",
+      "renamed": true
+    },
+    {
+      "rust": "manifest::jobs::Status",
+      "swift": "Status",
+      "renamed": false
+    },
+    {
+      "rust": "manifest::users::Info",
+      "swift": "ManifestUsersInfo",
+      "renamed": true
+    }
+  ],
+  "helper_types": [
+    "RustDuration",
+    "StatusDoneData"
+  ]
+}
+"#
+    );
+}
+
+#[test]
+fn test_manifest_is_stable() {
+    let types = TypeCollection::default()
+        .register::<jobs::Status>()
+        .register::<users::Info>();
+    let reordered = TypeCollection::default()
+        .register::<users::Info>()
+        .register::<jobs::Status>();
+
+    assert_eq!(
+        export_manifest("stable_a", &Swift::new(), &types),
+        export_manifest("stable_b", &Swift::new(), &reordered)
+    );
+}
@@ -0,0 +1,77 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{CodableFormat, KeyStrategy, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Command {
+    Ping,
+    Send { packet_id: u32 },
+    Retry(u8),
+    Move(i32, i32),
+}
+
+fn message_pack() -> Swift {
+    Swift::new().codable_format(CodableFormat::MessagePack)
+}
+
+#[test]
+fn test_message_pack_enum_codable() {
+    let types = TypeCollection::default().register::<Command>();
+    let output = message_pack().export(&types).unwrap();
+
+    assert!(output.contains("// Codable implementations target MessagePack"));
+
+    // Newtype variants hold their value directly, like serde
+    assert!(output.contains(
+        "                case .retry:\n                    self = .retry(try container.decode(UInt8.self, forKey: .retry))"
+    ));
+    assert!(output.contains(
+        "        case .retry(let value):\n            var container = encoder.container(keyedBy: CodingKeys.self)\n            try container.encode(value, forKey: .retry)"
+    ));
+
+    // Unit variants are plain strings
+    assert!(output.contains(
+        "        case .ping:\n            var container = encoder.singleValueContainer()\n            try container.encode(\"Ping\")"
+    ));
+
+    // Tuple variants are still arrays
+    assert!(output.contains(
+        "        case .move(let value0, let value1):\n            var container = encoder.container(keyedBy: CodingKeys.self)\n            var arrayContainer = container.nestedUnkeyedContainer(forKey: .move)"
+    ));
+    assert!(!output.contains("encodeNil"));
+}
+
+#[test]
+fn test_json_enum_codable_is_unchanged() {
+    let types = TypeCollection::default().register::<Command>();
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(!output.contains("MessagePack"));
+    assert!(output.contains("try container.encodeNil(forKey: .ping)"));
+    assert!(output.contains("try container.encode(value, forKey: .retry)"));
+    assert!(output.contains("var arrayContainer = container.nestedUnkeyedContainer(forKey: .move)"));
+}
+
+#[test]
+fn test_message_pack_rejects_key_conversion() {
+    let err = message_pack()
+        .key_strategy(KeyStrategy::ConvertFromSnakeCase)
+        .export(&TypeCollection::default().register::<Command>())
+        .unwrap_err();
+
+    assert!(err.to_string().contains("MessagePack"));
+}
+
+#[test]
+fn test_message_pack_round_trip_tests() {
+    let path = std::env::temp_dir().join("specta_swift_message_pack_tests.swift");
+    message_pack()
+        .export_tests(&path, &TypeCollection::default().register::<Command>())
+        .unwrap();
+    let tests = std::fs::read_to_string(path).unwrap();
+
+    assert!(tests.contains("import XCTest\nimport MessagePack\n"));
+    assert!(tests.contains("let decoded = try MessagePackDecoder().decode(T.self, from: encoded)"));
+    assert!(tests.contains("XCTAssertEqual(reencoded, encoded, file: file, line: line)"));
+    assert!(!tests.contains("JSONEncoder"));
+}
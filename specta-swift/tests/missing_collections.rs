@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use specta::{Type, TypeCollection};
+use specta_swift::{MissingCollectionStrategy, Swift};
+
+#[derive(Type)]
+struct Profile {
+    name: String,
+    tags: Vec<String>,
+    links: HashMap<String, String>,
+    aliases: Option<Vec<String>>,
+}
+
+#[derive(Type)]
+struct Plain {
+    name: String,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_missing_collections_are_errors_by_default() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Plain>())
+        .unwrap();
+
+    assert!(!output.contains("init(from decoder: Decoder)"));
+    assert!(!output.contains("decodeIfPresent"));
+}
+
+#[test]
+fn test_missing_collections_decode_empty() {
+    let output = Swift::new()
+        .missing_collections(MissingCollectionStrategy::Empty)
+        .export(&TypeCollection::default().register::<Profile>())
+        .unwrap();
+
+    assert!(output.contains("public let tags: [String]\n"));
+    assert!(output.contains("name = try container.decode(String.self, forKey: .name)"));
+    assert!(
+        output.contains("tags = try container.decodeIfPresent([String].self, forKey: .tags) ?? []")
+    );
+    assert!(output.contains(
+        "links = try container.decodeIfPresent([String: String].self, forKey: .links) ?? [:]"
+    ));
+    // Nullable collections stay `nil` when missing
+    assert!(output
+        .contains("aliases = try container.decodeIfPresent([String].self, forKey: .aliases)\n"));
+    assert!(output.contains("try container.encode(tags, forKey: .tags)"));
+}
+
+#[test]
+fn test_missing_collections_emit_codable_for_collection_structs() {
+    let output = Swift::new()
+        .missing_collections(MissingCollectionStrategy::Empty)
+        .export(&TypeCollection::default().register::<Plain>())
+        .unwrap();
+
+    assert!(output.contains("// MARK: - Plain Custom Codable Implementation"));
+    assert!(
+        output.contains("tags = try container.decodeIfPresent([String].self, forKey: .tags) ?? []")
+    );
+}
+
+#[test]
+fn test_missing_collection_strategy_from_str() {
+    assert_eq!(
+        "empty".parse::<MissingCollectionStrategy>().unwrap(),
+        MissingCollectionStrategy::Empty
+    );
+    assert!("default".parse::<MissingCollectionStrategy>().is_err());
+}
@@ -0,0 +1,72 @@
+use specta::{NamedType, Type, TypeCollection};
+use specta_swift::{ExportWarning, MissingReferenceStrategy, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Wallet {
+    balance: u64,
+    owner: Owner,
+    previous_owners: Vec<Owner>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Owner {
+    name: String,
+}
+
+fn incomplete_types() -> TypeCollection {
+    let mut types = TypeCollection::default().register::<Wallet>();
+    types.remove(Owner::ID);
+    types
+}
+
+#[test]
+fn test_missing_reference_fails_by_default() {
+    assert!(Swift::new().export(&incomplete_types()).is_err());
+}
+
+#[test]
+fn test_missing_reference_stub() {
+    let swift = Swift::new().missing_reference(MissingReferenceStrategy::Stub);
+    let (output, report) = swift.export_with_report(&incomplete_types()).unwrap();
+
+    let stub = report
+        .warnings
+        .iter()
+        .find_map(|warning| match warning {
+            ExportWarning::MissingReference {
+                referenced_by,
+                stub,
+            } if referenced_by == "Wallet" => Some(stub.clone()),
+            _ => None,
+        })
+        .expect("missing reference warning");
+    assert!(stub.starts_with("MissingType"));
+
+    // One placeholder, shared by every reference to the missing type
+    let declaration = format!("public struct {}: Codable {{", stub);
+    assert_eq!(output.matches(&declaration).count(), 1);
+    assert!(output.contains("Placeholder for a type missing from the exported `TypeCollection`"));
+    assert!(output.contains(&format!("public let owner: {}", stub)));
+    assert!(output.contains(&format!("public let previousOwners: [{}]", stub)));
+    assert_eq!(report.warnings.len(), 1);
+}
+
+#[test]
+fn test_missing_reference_stub_is_deterministic() {
+    let swift = Swift::new().missing_reference(MissingReferenceStrategy::Stub);
+    assert_eq!(
+        swift.export(&incomplete_types()).unwrap(),
+        swift.export(&incomplete_types()).unwrap()
+    );
+}
+
+#[test]
+fn test_missing_reference_strategy_parses() {
+    assert_eq!(
+        "stub".parse::<MissingReferenceStrategy>().unwrap(),
+        MissingReferenceStrategy::Stub
+    );
+    assert!("ignore".parse::<MissingReferenceStrategy>().is_err());
+}
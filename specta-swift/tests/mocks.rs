@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct Address {
+    street: String,
+    zip: Option<String>,
+}
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    display_name: String,
+    score: f64,
+    active: bool,
+    address: Address,
+    tags: Vec<String>,
+    metadata: HashMap<String, String>,
+    status: Status,
+}
+
+#[derive(Type)]
+enum Status {
+    Banned { reason: String },
+    Active,
+}
+
+#[derive(Type)]
+enum Shape {
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+}
+
+#[derive(Type)]
+struct UserId(u64);
+
+#[derive(Type)]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+#[derive(Type)]
+struct Users {
+    page: Page<User>,
+}
+
+fn export(types: &TypeCollection) -> String {
+    let output = Swift::new().with_mocks().export(types).unwrap();
+    println!("{}", output);
+    output
+}
+
+#[test]
+fn test_struct_mocks() {
+    let output = export(&TypeCollection::default().register::<User>());
+
+    assert!(output
+        .contains("#if DEBUG\nextension User {\n    public static var mock: Self { .mock() }"));
+    assert!(output.contains(
+        "    public static func mock(id: UInt32 = 1, displayName: String = \"display_name\", score: Double = 1.0, active: Bool = false, address: Address = Address.mock, tags: [String] = [], metadata: [String: String] = [:], status: Status = Status.mock) -> Self {\n        Self(id: id, displayName: displayName, score: score, active: active, address: address, tags: tags, metadata: metadata, status: status)\n    }\n}\n#endif"
+    ));
+    assert!(output.contains("zip: String? = nil"));
+}
+
+#[test]
+fn test_enum_mocks() {
+    let output = export(
+        &TypeCollection::default()
+            .register::<Status>()
+            .register::<Shape>(),
+    );
+
+    // Cases without associated values are preferred
+    assert!(output.contains("extension Status {\n    public static var mock: Self { .active }\n}"));
+    assert!(
+        output.contains("extension Shape {\n    public static var mock: Self { .circle(1.0) }\n}")
+    );
+}
+
+#[test]
+fn test_tuple_struct_mocks() {
+    let output = export(&TypeCollection::default().register::<UserId>());
+
+    assert!(output
+        .contains("extension UserId {\n    public static var mock: Self { Self(value: 1) }\n}"));
+}
+
+#[test]
+fn test_generic_types_are_skipped() {
+    let output = export(&TypeCollection::default().register::<Users>());
+
+    assert!(!output.contains("extension Page"));
+    assert!(!output.contains("extension Users"));
+}
+
+#[test]
+fn test_mocks_are_opt_in() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    assert!(!output.contains("mock"));
+}
+
+#[derive(Type)]
+struct Profile {
+    name: String,
+    bio: Option<String>,
+    address: Option<Address>,
+}
+
+#[test]
+fn test_preview_data() {
+    let output = Swift::new()
+        .with_preview_data()
+        .export(
+            &TypeCollection::default()
+                .register::<Profile>()
+                .register::<Status>()
+                .register::<Users>(),
+        )
+        .unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("#if DEBUG\n/// Sample values of the generated types for SwiftUI previews.\npublic enum PreviewData {"));
+    assert!(output.contains(
+        "    public static let profile: [Profile] = [\n        .mock,\n        .mock(bio: \"bio\", address: Address.mock),\n    ]"
+    ));
+    assert!(output.contains(
+        "    public static let status: [Status] = [\n        .banned(StatusBannedData(reason: \"reason\")),\n        .active,\n    ]"
+    ));
+    assert!(output.contains("    public static let address: [Address] = [\n        .mock,\n        .mock(zip: \"zip\"),\n    ]"));
+    // Generic types have no mocks, so neither they nor types containing them get samples
+    assert!(!output.contains("public static let users"));
+}
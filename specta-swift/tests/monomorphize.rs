@@ -0,0 +1,95 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct User {
+    id: u32,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct ApiResponse<T> {
+    data: T,
+    status: u16,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Page<T> {
+    items: Vec<T>,
+    first: ApiResponse<T>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Either<L, R> {
+    Left(L),
+    Right { value: R },
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Endpoints {
+    user: ApiResponse<User>,
+    users: ApiResponse<Vec<User>>,
+    names: Page<String>,
+    choice: Either<u32, Option<String>>,
+}
+
+#[test]
+fn test_generic_declarations_by_default() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Endpoints>())
+        .unwrap();
+
+    assert!(output.contains("public struct ApiResponse<T>: Codable {"));
+    assert!(output.contains("    public let user: ApiResponse<User>\n"));
+}
+
+#[test]
+fn test_instantiations_are_declared() {
+    let output = Swift::new()
+        .monomorphize(true)
+        .export(&TypeCollection::default().register::<Endpoints>())
+        .unwrap();
+
+    assert!(!output.contains("<T"));
+    assert!(output.contains("    public let user: ApiResponseUser\n"));
+    assert!(output.contains("    public let users: ApiResponseArrayUser\n"));
+    assert!(output.contains(
+        "public struct ApiResponseUser: Codable {\n    public let data: User\n    public let status: UInt16\n}"
+    ));
+    assert!(output
+        .contains("public struct ApiResponseArrayUser: Codable {\n    public let data: [User]\n"));
+}
+
+#[test]
+fn test_nested_instantiations() {
+    let output = Swift::new()
+        .monomorphize(true)
+        .export(&TypeCollection::default().register::<Endpoints>())
+        .unwrap();
+
+    // Instantiations inside instantiations are declared too
+    assert!(output.contains(
+        "public struct PageString: Codable {\n    public let items: [String]\n    public let first: ApiResponseString\n}"
+    ));
+    assert!(output.contains("public struct ApiResponseString: Codable {"));
+
+    // Arguments are named in declaration order, and enum variant structs follow the name
+    assert!(output.contains("    public let choice: EitherUInt32OptionalString\n"));
+    assert!(output.contains("    case right(EitherUInt32OptionalStringRightData)"));
+    assert!(output.contains("public struct EitherUInt32OptionalStringRightData: Codable {\n    public let value: String?\n"));
+    assert_eq!(
+        output.matches("public struct ApiResponseString:").count(),
+        1
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_monomorphize_from_config() {
+    let swift = Swift::from_config_str("monomorphize = true").unwrap();
+    assert!(swift.monomorphize);
+}
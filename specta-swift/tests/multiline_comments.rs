@@ -63,3 +63,25 @@ fn test_multiline_comments() {
     // The enum cases and struct fields don't have individual comments
     // because Specta doesn't extract field-level documentation by default
 }
+
+/// A session of a [`User`], see [Session::expires_at].
+///
+/// Tokens are <b>never</b> logged, see [the guide](https://docs.rs/guide) and
+/// [the token type][Token]. Comments end with `*/` in code, but */ in prose.
+///
+/// [Token]: crate::Token
+#[derive(Type)]
+struct Session {
+    token: String,
+}
+
+#[test]
+fn test_doc_comment_processing() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Session>())
+        .unwrap();
+
+    assert!(output.contains(
+        "/// A session of a `User`, see `Session::expires_at`.\n///\n/// Tokens are \\<b>never\\</b> logged, see [the guide](https://docs.rs/guide) and\n/// the token type. Comments end with `*/` in code, but *\\/ in prose.\npublic struct Session"
+    ));
+}
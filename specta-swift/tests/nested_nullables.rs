@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use specta::{Type, TypeCollection};
+use specta_swift::{assert_swift_snapshot, OptionalStyle, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Item {
+    id: u32,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Event {
+    Tagged(Option<String>, u32),
+    Timed(Option<Duration>, Option<Item>),
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Pair(Option<String>, HashMap<String, Option<u32>>);
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Inventory {
+    items: HashMap<String, Option<Item>>,
+    timeouts: HashMap<String, Option<Duration>>,
+    slots: Vec<Option<Item>>,
+    pair: Pair,
+    event: Event,
+}
+
+#[test]
+fn test_nested_nullable_snapshots() {
+    assert_swift_snapshot!(types: "nested_nullables", Swift::new().export(&TypeCollection::default().register::<Inventory>()).unwrap());
+}
+
+#[test]
+fn test_tuple_variant_payloads_keep_optionals() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Inventory>())
+        .unwrap();
+
+    assert!(output.contains("case tagged(String?, UInt32)"));
+    assert!(output.contains("case timed(RustDuration?, Item?)"));
+    assert!(output.contains("let value0 = try arrayContainer.decode(String?.self)"));
+    assert!(output.contains("let value0 = try arrayContainer.decode(RustDuration?.self)"));
+}
+
+#[test]
+fn test_map_values_keep_optionals() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Inventory>())
+        .unwrap();
+
+    assert!(output.contains("public let items: [String: Item?]"));
+    assert!(output.contains("public let timeouts: [String: RustDuration?]"));
+    assert!(output.contains("public let slots: [Item?]"));
+    assert!(output.contains("let field1: [String: UInt32?]"));
+}
+
+#[test]
+fn test_nested_optionals_follow_the_optional_style() {
+    let output = Swift::new()
+        .optionals(OptionalStyle::Optional)
+        .export(&TypeCollection::default().register::<Inventory>())
+        .unwrap();
+
+    assert!(output.contains("case tagged(Optional<String>, UInt32)"));
+    assert!(output.contains("public let items: [String: Optional<Item>]"));
+    assert!(output.contains("public let timeouts: [String: Optional<RustDuration>]"));
+}
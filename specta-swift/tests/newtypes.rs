@@ -0,0 +1,89 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{NewtypeStyle, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct UserId(uuid::Uuid);
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Tags(Vec<String>);
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Wrapper<T>(T);
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct User {
+    id: UserId,
+    tags: Tags,
+    name: Wrapper<String>,
+}
+
+#[test]
+fn test_newtypes_are_structs_by_default() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    assert!(output.contains("public struct UserId: Codable {"));
+    assert!(output.contains("let value: String"));
+    assert!(!output.contains("RawRepresentable"));
+}
+
+#[test]
+fn test_raw_representable_newtypes() {
+    let output = Swift::new()
+        .newtypes(NewtypeStyle::RawRepresentable)
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    assert!(output.contains("public struct UserId: RawRepresentable, Codable, Hashable {"));
+    assert!(output.contains("public let rawValue: String"));
+    assert!(output.contains("public init(rawValue: String) {"));
+    assert!(output.contains("rawValue = try decoder.singleValueContainer().decode(String.self)"));
+    assert!(output.contains("try container.encode(rawValue)"));
+    assert!(output.contains("public let id: UserId"));
+}
+
+#[test]
+fn test_raw_representable_newtypes_of_other_types_arent_hashable() {
+    let output = Swift::new()
+        .newtypes(NewtypeStyle::RawRepresentable)
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    assert!(output.contains("public struct Tags: RawRepresentable, Codable {"));
+    assert!(output.contains("public let rawValue: [String]"));
+}
+
+#[test]
+fn test_generic_newtypes_keep_their_value() {
+    let output = Swift::new()
+        .newtypes(NewtypeStyle::RawRepresentable)
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    assert!(output.contains("public struct Wrapper<T>: Codable {"));
+}
+
+#[test]
+fn test_raw_representable_newtype_mocks() {
+    let output = Swift::new()
+        .newtypes(NewtypeStyle::RawRepresentable)
+        .with_mocks()
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    assert!(output.contains("public static var mock: Self { Self(rawValue: \"UserId\") }"));
+}
+
+#[test]
+fn test_newtype_style_from_str() {
+    assert_eq!(
+        "raw-representable".parse::<NewtypeStyle>().unwrap(),
+        NewtypeStyle::RawRepresentable
+    );
+    assert!("raw".parse::<NewtypeStyle>().is_err());
+}
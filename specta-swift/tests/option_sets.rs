@@ -0,0 +1,86 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+/// What a user may do with a file.
+#[derive(Type)]
+#[allow(dead_code)]
+struct Permissions(u32);
+
+#[derive(Type)]
+#[specta(transparent)]
+#[allow(dead_code)]
+struct Mode(u8);
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct File {
+    permissions: Permissions,
+    mode: Mode,
+}
+
+fn swift() -> Swift {
+    Swift::new()
+        .option_set(
+            "Permissions",
+            [("READ", 1), ("WRITE", 2), ("READ_WRITE", 3)],
+        )
+        .option_set("Mode", [("EXECUTE", 1 << 7)])
+}
+
+#[test]
+fn test_newtype_option_set() {
+    let output = swift()
+        .export(&TypeCollection::default().register::<File>())
+        .unwrap();
+
+    assert!(output.contains("/// What a user may do with a file."));
+    assert!(output.contains("public struct Permissions: OptionSet, Hashable, Codable {"));
+    assert!(output.contains("public let rawValue: UInt32"));
+    assert!(output.contains("public init(rawValue: UInt32) {"));
+    assert!(output.contains("public static let read: Permissions = Self(rawValue: 1 << 0)"));
+    assert!(output.contains("public static let write: Permissions = Self(rawValue: 1 << 1)"));
+    assert!(output.contains("public static let readWrite: Permissions = Self(rawValue: 3)"));
+    assert!(output.contains("public let permissions: Permissions"));
+}
+
+#[test]
+fn test_transparent_option_set() {
+    let output = swift()
+        .export(&TypeCollection::default().register::<File>())
+        .unwrap();
+
+    assert!(output.contains("public struct Mode: OptionSet, Hashable, Codable {"));
+    assert!(output.contains("public let rawValue: UInt8"));
+    assert!(output.contains("public static let execute: Mode = Self(rawValue: 1 << 7)"));
+}
+
+#[test]
+fn test_option_set_mocks_are_empty() {
+    let output = swift()
+        .with_mocks()
+        .export(&TypeCollection::default().register::<File>())
+        .unwrap();
+
+    assert!(output.contains("extension Permissions {\n    public static var mock: Self { [] }"));
+    assert!(output.contains("permissions: Permissions = Permissions.mock"));
+}
+
+#[test]
+fn test_option_set_flags_must_fit() {
+    let err = Swift::new()
+        .option_set("Mode", [("HIGH", 1 << 8)])
+        .export(&TypeCollection::default().register::<File>())
+        .unwrap_err();
+
+    assert!(err.to_string().contains("doesn't fit in `UInt8`"));
+}
+
+#[test]
+fn test_option_sets_must_be_integers() {
+    let err = Swift::new()
+        .option_set("File", [("READ", 1)])
+        .export(&TypeCollection::default().register::<File>())
+        .unwrap_err();
+
+    assert!(err.to_string().contains("must be an integer"));
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use specta::{Type, TypeCollection};
+use specta_swift::{OptionalCollectionStrategy, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Article {
+    title: String,
+    tags: Option<Vec<String>>,
+    metadata: Option<HashMap<String, String>>,
+    #[serde(default)]
+    authors: Vec<String>,
+    subtitle: Option<String>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Change {
+    Tagged { tags: Option<Vec<String>> },
+}
+
+fn export(swift: Swift) -> String {
+    swift
+        .export(
+            &TypeCollection::default()
+                .register::<Article>()
+                .register::<Change>(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_optional_collections_are_optional_by_default() {
+    let output = export(Swift::new());
+
+    assert!(output.contains("public let tags: [String]?\n"));
+    assert!(output.contains("public let metadata: [String: String]?\n"));
+    assert!(output.contains("public let authors: [String]?\n"));
+}
+
+#[test]
+fn test_optional_collections_default_to_empty() {
+    let output = export(Swift::new().optional_collections(OptionalCollectionStrategy::Empty));
+
+    assert!(output.contains("    public let tags: [String]\n"));
+    assert!(output.contains("public let metadata: [String: String]\n"));
+    assert!(output.contains("public let authors: [String]\n"));
+    assert!(
+        output.contains("tags = try container.decodeIfPresent([String].self, forKey: .tags) ?? []")
+    );
+    assert!(output.contains(
+        "metadata = try container.decodeIfPresent([String: String].self, forKey: .metadata) ?? [:]"
+    ));
+    assert!(output.contains(
+        "authors = try container.decodeIfPresent([String].self, forKey: .authors) ?? []"
+    ));
+    assert!(output.contains("try container.encode(tags, forKey: .tags)"));
+    // Other optional fields are unchanged
+    assert!(output.contains("public let subtitle: String?\n"));
+}
+
+#[test]
+fn test_optional_collections_of_variants_stay_optional() {
+    let output = export(Swift::new().optional_collections(OptionalCollectionStrategy::Empty));
+
+    assert!(output
+        .contains("public struct ChangeTaggedData: Codable {\n    public let tags: [String]?\n"));
+}
+
+#[test]
+fn test_optional_collection_mocks_are_empty() {
+    let output = export(
+        Swift::new()
+            .optional_collections(OptionalCollectionStrategy::Empty)
+            .with_mocks(),
+    );
+
+    assert!(output.contains("tags: [String] = []"));
+    assert!(output.contains("subtitle: String? = nil"));
+}
+
+#[test]
+fn test_parse_optional_collection_strategy() {
+    assert_eq!(
+        "empty".parse::<OptionalCollectionStrategy>().unwrap(),
+        OptionalCollectionStrategy::Empty
+    );
+    assert!("none".parse::<OptionalCollectionStrategy>().is_err());
+}
@@ -0,0 +1,83 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{OptionalStyle, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Profile {
+    nickname: Option<String>,
+    #[serde(default)]
+    bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar: Option<String>,
+    #[serde(default)]
+    theme: String,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Event {
+    Updated {
+        #[specta(optional)]
+        reason: Option<String>,
+        #[serde(default)]
+        count: u32,
+    },
+}
+
+fn export(swift: Swift) -> String {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<Event>();
+    swift.export(&types).unwrap()
+}
+
+#[test]
+fn test_optional_fields_have_a_single_optional() {
+    let output = export(Swift::new());
+
+    assert!(output.contains("public let nickname: String?\n"));
+    assert!(output.contains("public let bio: String?\n"));
+    assert!(output.contains("public let avatar: String?\n"));
+    assert!(output.contains("public let theme: String?\n"));
+    assert!(!output.contains("??"));
+}
+
+#[test]
+fn test_initializers_use_the_property_types() {
+    let output = export(Swift::new().initializers_for(["Profile"]));
+
+    assert!(output
+        .contains("public init(nickname: String?, bio: String?, avatar: String?, theme: String?)"));
+}
+
+#[test]
+fn test_variant_struct_fields_are_optional() {
+    let output = export(Swift::new());
+
+    assert!(output.contains("public let reason: String?\n"));
+    assert!(output.contains("public let count: UInt32?\n"));
+}
+
+#[test]
+fn test_optional_style_applies_to_missing_fields() {
+    let output = export(Swift::new().optionals(OptionalStyle::Optional));
+
+    assert!(output.contains("public let bio: Optional<String>\n"));
+    assert!(output.contains("public let theme: Optional<String>\n"));
+    assert!(!output.contains("Optional<String>?"));
+}
+
+#[test]
+fn test_codable_omits_nil_for_fields_which_are_not_nullable() {
+    let output = export(Swift::new());
+
+    assert!(
+        output.contains("nickname = try container.decodeIfPresent(String.self, forKey: .nickname)")
+    );
+    assert!(output.contains("theme = try container.decodeIfPresent(String.self, forKey: .theme)"));
+    // Nullable fields keep writing `null`, like serde
+    assert!(output.contains("try container.encode(nickname, forKey: .nickname)"));
+    assert!(output.contains("try container.encode(bio, forKey: .bio)"));
+    // Rust can't decode `null` into a `String`
+    assert!(output.contains("try container.encodeIfPresent(theme, forKey: .theme)"));
+}
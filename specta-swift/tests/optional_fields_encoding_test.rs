@@ -155,3 +155,48 @@ fn test_documents_expected_behavior() {
     println!("      try container.encodeIfPresent(status, forKey: .status)  // omits nil!");
     println!("  }}");
 }
+
+/// Test struct with optional collection elements
+#[derive(Type)]
+struct NullableElements {
+    labels: Vec<Option<String>>,
+    scores: std::collections::HashMap<String, Option<u32>>,
+    history: Option<Vec<Option<String>>>,
+}
+
+#[test]
+fn test_nullable_collection_elements() {
+    let types = TypeCollection::default().register::<NullableElements>();
+    let result = Swift::new().export(&types).unwrap();
+
+    assert!(result.contains("public let labels: [String?]"));
+    assert!(result.contains("public let scores: [String: UInt32?]"));
+    assert!(result.contains("public let history: [String?]?"));
+
+    // Element optionals are part of the decoded type, so nulls round-trip as `nil`
+    assert!(result.contains("labels = try container.decode([String?].self, forKey: .labels)"));
+    assert!(
+        result.contains("scores = try container.decode([String: UInt32?].self, forKey: .scores)")
+    );
+    assert!(result
+        .contains("history = try container.decodeIfPresent([String?].self, forKey: .history)"));
+    assert!(result.contains("try container.encode(labels, forKey: .labels)"));
+    assert!(result.contains("try container.encode(history, forKey: .history)"));
+}
+
+#[test]
+fn test_nullable_collection_elements_optional_style() {
+    let types = TypeCollection::default().register::<NullableElements>();
+    let result = Swift::new()
+        .optionals(specta_swift::OptionalStyle::Optional)
+        .export(&types)
+        .unwrap();
+
+    assert!(result.contains("public let history: Optional<[Optional<String>]>"));
+    assert!(
+        result.contains("labels = try container.decode([Optional<String>].self, forKey: .labels)")
+    );
+    assert!(result.contains(
+        "history = try container.decodeIfPresent([Optional<String>].self, forKey: .history)"
+    ));
+}
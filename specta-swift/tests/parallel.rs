@@ -0,0 +1,94 @@
+//! Rendering with the `rayon` feature must produce exactly the sequential output.
+
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+macro_rules! structs {
+    ($($name:ident),*) => {
+        $(
+            #[derive(Type)]
+            #[allow(dead_code)]
+            struct $name {
+                id: u32,
+                label: String,
+                tags: Vec<String>,
+                next: Option<Box<$name>>,
+            }
+        )*
+    };
+}
+
+structs!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, B0, B1, B2, B3, B4, B5, B6, B7, B8, B9);
+
+#[test]
+fn test_parallel_output_matches_sequential_output() {
+    let types = TypeCollection::default()
+        .register::<A0>()
+        .register::<A1>()
+        .register::<A2>()
+        .register::<A3>()
+        .register::<A4>()
+        .register::<A5>()
+        .register::<A6>()
+        .register::<A7>()
+        .register::<A8>()
+        .register::<A9>()
+        .register::<B0>()
+        .register::<B1>()
+        .register::<B2>()
+        .register::<B3>()
+        .register::<B4>()
+        .register::<B5>()
+        .register::<B6>()
+        .register::<B7>()
+        .register::<B8>()
+        .register::<B9>();
+
+    let swift = Swift::new().with_mocks();
+    let sequential = swift.clone().parallel(false).export(&types).unwrap();
+    let parallel = swift.clone().parallel(true).export(&types).unwrap();
+    assert_eq!(parallel, sequential);
+
+    for _ in 0..5 {
+        assert_eq!(swift.export(&types).unwrap(), sequential);
+    }
+}
+
+#[test]
+fn test_declarations_follow_report_order() {
+    let types = TypeCollection::default()
+        .register::<A0>()
+        .register::<A1>()
+        .register::<A2>()
+        .register::<A3>()
+        .register::<A4>()
+        .register::<A5>()
+        .register::<A6>()
+        .register::<A7>()
+        .register::<A8>()
+        .register::<A9>()
+        .register::<B0>()
+        .register::<B1>()
+        .register::<B2>()
+        .register::<B3>()
+        .register::<B4>()
+        .register::<B5>()
+        .register::<B6>()
+        .register::<B7>()
+        .register::<B8>()
+        .register::<B9>();
+
+    let (output, report) = Swift::new().export_with_report(&types).unwrap();
+
+    let positions = report
+        .exported_types
+        .iter()
+        .map(|ty| {
+            output
+                .find(&format!("public struct {}: Codable", ty.swift_name))
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(report.exported_types.len(), 20);
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,65 @@
+use std::marker::PhantomData;
+
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type, Serialize)]
+#[serde(transparent)]
+#[allow(dead_code)]
+struct Id<T> {
+    raw: String,
+    #[serde(skip)]
+    _marker: PhantomData<T>,
+}
+
+#[derive(Type, Serialize)]
+#[allow(dead_code)]
+struct User {
+    id: Id<User>,
+    name: String,
+}
+
+#[derive(Type, Serialize)]
+#[allow(dead_code)]
+struct Post {
+    id: Id<Post>,
+    author: Id<User>,
+    likes: Vec<Id<User>>,
+}
+
+#[test]
+fn test_phantom_ids_are_generic_structs() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Post>())
+        .unwrap();
+
+    assert!(output.contains("public struct Id<T>: RawRepresentable, Codable, Hashable {"));
+    assert!(output.contains("public let rawValue: String"));
+    assert!(output.contains("public init(rawValue: String)"));
+    assert!(output.contains("rawValue = try decoder.singleValueContainer().decode(String.self)"));
+}
+
+#[test]
+fn test_phantom_id_usages_keep_their_argument() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Post>())
+        .unwrap();
+
+    assert!(output.contains("let id: Id<Post>"));
+    assert!(output.contains("let author: Id<User>"));
+    assert!(output.contains("let likes: [Id<User>]"));
+    assert!(output.contains("let id: Id<User>"));
+}
+
+#[test]
+fn test_phantom_ids_are_not_monomorphized() {
+    let output = Swift::new()
+        .monomorphize(true)
+        .export(&TypeCollection::default().register::<Post>())
+        .unwrap();
+
+    assert!(output.contains("public struct Id<T>: RawRepresentable, Codable, Hashable {"));
+    assert!(output.contains("let author: Id<User>"));
+    assert!(!output.contains("IdUser"));
+}
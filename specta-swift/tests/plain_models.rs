@@ -0,0 +1,68 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Packet {
+    packet_id: u32,
+    payload: Option<Vec<u8>>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Command {
+    Ping,
+    Send { packet_id: u32 },
+    Retry(u8),
+}
+
+#[derive(Type)]
+#[serde(tag = "kind", content = "data")]
+#[allow(dead_code)]
+enum Frame {
+    Open(u32),
+    Close,
+}
+
+#[derive(Type)]
+#[serde(rename_all = "lowercase")]
+#[allow(dead_code)]
+enum Priority {
+    Low,
+    High,
+}
+
+#[test]
+fn test_plain_models_have_no_codable() {
+    let types = TypeCollection::default()
+        .register::<Packet>()
+        .register::<Command>()
+        .register::<Frame>()
+        .register::<Priority>();
+
+    let output = Swift::new()
+        .emit_codable(false)
+        .with_json_helpers()
+        .export(&types)
+        .unwrap();
+
+    assert!(!output.contains("Codable"));
+    assert!(!output.contains("CodingKey"));
+    assert!(!output.contains("jsonData"));
+
+    // The shapes are unchanged
+    assert!(output.contains("public struct Packet {\n    public let packetId: UInt32\n    public let payload: [UInt8]?\n}"));
+    assert!(output.contains("case send(CommandSendData)"));
+    assert!(output.contains("public struct CommandSendData {\n    public let packetId: UInt32\n}"));
+    assert!(output.contains("public enum Frame {"));
+    assert!(output.contains("public enum Priority: String {\n    case low = \"low\""));
+}
+
+#[test]
+fn test_codable_is_emitted_by_default() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Packet>())
+        .unwrap();
+
+    assert!(output.contains("public struct Packet: Codable {"));
+}
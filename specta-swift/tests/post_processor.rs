@@ -0,0 +1,48 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[derive(Type)]
+enum Status {
+    Active,
+    Inactive,
+}
+
+#[test]
+fn test_post_processor_rewrites_declarations() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Status>();
+
+    let output = Swift::new()
+        .with_post_processor(|ndt, declaration| {
+            if ndt.name() == "User" {
+                declaration.replace(": Codable {", ": Codable, Identifiable {")
+            } else {
+                declaration
+            }
+        })
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct User: Codable, Identifiable {"));
+    assert!(output.contains("public enum Status: Codable {"));
+}
+
+#[test]
+fn test_post_processors_run_in_order() {
+    let types = TypeCollection::default().register::<User>();
+
+    let output = Swift::new()
+        .with_post_processor(|_, declaration| format!("// first\n{}", declaration))
+        .with_post_processor(|ndt, declaration| format!("// {}\n{}", ndt.name(), declaration))
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("// User\n// first\npublic struct User: Codable {"));
+}
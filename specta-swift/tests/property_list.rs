@@ -0,0 +1,105 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{CodableFormat, ExportWarning, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Preferences {
+    theme: String,
+    font_size: Option<u32>,
+    recent: Vec<Option<String>>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+#[serde(rename_all = "lowercase")]
+enum Appearance {
+    Light,
+    Dark,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Sync {
+    Off,
+    Every(u32),
+}
+
+fn property_list() -> Swift {
+    Swift::new().codable_format(CodableFormat::PropertyList)
+}
+
+fn warnings_for(warnings: &[ExportWarning], type_name: &str) -> Vec<String> {
+    warnings
+        .iter()
+        .filter_map(|warning| match warning {
+            ExportWarning::PropertyListIncompatible { name, reason } if name == type_name => {
+                Some(reason.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_property_list_omits_nil() {
+    let types = TypeCollection::default().register::<Preferences>();
+    let output = property_list()
+        .always_emit_codable(true)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("// Codable implementations target PropertyListEncoder"));
+    assert!(output.contains("try container.encodeIfPresent(fontSize, forKey: .fontSize)"));
+    assert!(output.contains("try container.encode(theme, forKey: .theme)"));
+}
+
+#[test]
+fn test_property_list_warnings() {
+    let types = TypeCollection::default()
+        .register::<Preferences>()
+        .register::<Appearance>()
+        .register::<Sync>();
+    let (output, report) = property_list().export_with_report(&types).unwrap();
+
+    assert_eq!(
+        warnings_for(&report.warnings, "Preferences"),
+        ["contains optional collection elements, but property lists can't store nil"]
+    );
+    assert_eq!(
+        warnings_for(&report.warnings, "Appearance"),
+        ["encodes as a single string, so it can't be the root of a property list"]
+    );
+    assert_eq!(warnings_for(&report.warnings, "Sync").len(), 1);
+
+    // Unit variants follow serde and are plain strings
+    assert!(output.contains(
+        "        case .off:\n            var container = encoder.singleValueContainer()\n            try container.encode(\"Off\")"
+    ));
+    assert!(!output.contains("encodeNil"));
+}
+
+#[test]
+fn test_json_has_no_property_list_warnings() {
+    let types = TypeCollection::default()
+        .register::<Preferences>()
+        .register::<Appearance>();
+    let (output, report) = Swift::new()
+        .always_emit_codable(true)
+        .export_with_report(&types)
+        .unwrap();
+
+    assert!(report.warnings.is_empty());
+    assert!(output.contains("try container.encode(fontSize, forKey: .fontSize)"));
+}
+
+#[test]
+fn test_property_list_round_trip_tests() {
+    let path = std::env::temp_dir().join("specta_swift_property_list_tests.swift");
+    property_list()
+        .export_tests(&path, &TypeCollection::default().register::<Preferences>())
+        .unwrap();
+    let tests = std::fs::read_to_string(path).unwrap();
+
+    assert!(tests.contains("let decoded = try PropertyListDecoder().decode(T.self, from: encoded)"));
+    assert!(!tests.contains("JSONEncoder"));
+}
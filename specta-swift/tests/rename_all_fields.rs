@@ -0,0 +1,41 @@
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type, Serialize)]
+#[serde(rename_all_fields = "camelCase")]
+pub enum Event {
+    Created {
+        user_id: u32,
+        display_name: String,
+    },
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    Deleted {
+        user_id: u32,
+    },
+    Renamed {
+        #[serde(rename = "new")]
+        new_name: String,
+    },
+}
+
+#[test]
+fn test_rename_all_fields_applies_to_variant_structs() {
+    let types = TypeCollection::default().register::<Event>();
+    let output = Swift::default().export(&types).unwrap();
+
+    // Keys already match the camelCase properties
+    let created = output
+        .split("public struct EventCreatedData: Codable {")
+        .nth(1)
+        .and_then(|rest| rest.split("\n}").next())
+        .unwrap();
+    assert!(created.contains("public let userId: UInt32"));
+    assert!(created.contains("public let displayName: String"));
+    assert!(!created.contains("CodingKeys"));
+
+    // The variant's own rename_all and field renames take precedence
+    assert!(output.contains("case userId = \"USER_ID\""));
+    assert!(output.contains("public let new: String"));
+    assert!(!output.contains("user_id"));
+}
@@ -0,0 +1,70 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{MissingCollectionStrategy, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Item {
+    id: u32,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Feed {
+    title: String,
+    archived: bool,
+    items: Vec<Item>,
+    count: u32,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Post {
+    body: String,
+    subtitle: Option<String>,
+}
+
+fn resilient() -> Swift {
+    Swift::new().resilient_decoding(true)
+}
+
+#[test]
+fn test_resilient_decoding_uses_wrappers() {
+    let types = TypeCollection::default().register::<Feed>();
+    let output = resilient()
+        .missing_collections(MissingCollectionStrategy::Empty)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct LossyArray<Element: Codable>: Codable {"));
+    assert!(output.contains("public struct DefaultEmptyString: Codable, Hashable {"));
+    assert!(output.contains("public struct DefaultFalse: Codable, Hashable {"));
+    assert!(output.contains(
+        "public struct Feed: Codable {\n    @DefaultEmptyString public var title: String\n    @DefaultFalse public var archived: Bool\n    @LossyArray public var items: [Item]\n    public let count: UInt32\n"
+    ));
+
+    // The wrappers replace the custom implementation for missing collections
+    assert!(!output.contains("Feed Custom Codable Implementation"));
+}
+
+#[test]
+fn test_resilient_decoding_in_custom_implementation() {
+    let types = TypeCollection::default().register::<Post>();
+    let output = resilient().export(&types).unwrap();
+
+    assert!(output.contains(
+        "    @DefaultEmptyString public var body: String\n    public let subtitle: String?\n"
+    ));
+    assert!(output.contains(
+        "        _body = try container.decode(DefaultEmptyString.self, forKey: .body)\n"
+    ));
+    assert!(output.contains("        try container.encode(body, forKey: .body)\n"));
+}
+
+#[test]
+fn test_resilient_decoding_is_opt_in() {
+    let types = TypeCollection::default().register::<Feed>();
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(!output.contains("LossyArray"));
+    assert!(output.contains("    public let title: String\n"));
+}
@@ -0,0 +1,96 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{Error, Swift};
+
+#[derive(Type)]
+pub struct Address {
+    pub city: String,
+}
+
+#[derive(Type)]
+pub struct User {
+    pub id: u32,
+    pub address: Option<Address>,
+}
+
+#[derive(Type)]
+pub enum Event {
+    Created(User),
+    Deleted { id: u32 },
+}
+
+#[derive(Type)]
+pub struct Invoice {
+    pub total: f64,
+}
+
+#[derive(Type)]
+pub struct AdminReport {
+    pub invoices: Vec<Invoice>,
+}
+
+#[test]
+fn test_export_roots_includes_transitive_dependencies() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<AdminReport>();
+    let output = Swift::new().export_roots::<(Event,)>(&types).unwrap();
+
+    assert!(output.contains("public enum Event"));
+    assert!(output.contains("public struct User: Codable"));
+    assert!(output.contains("public struct Address: Codable"));
+    assert!(!output.contains("Invoice"));
+    assert!(!output.contains("AdminReport"));
+}
+
+#[test]
+fn test_export_roots_tuple() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<AdminReport>();
+    let output = Swift::new()
+        .export_roots::<(User, Invoice)>(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct User: Codable"));
+    assert!(output.contains("public struct Address: Codable"));
+    assert!(output.contains("public struct Invoice: Codable"));
+    assert!(!output.contains("Event"));
+    assert!(!output.contains("AdminReport"));
+}
+
+#[test]
+fn test_roots_by_name() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<AdminReport>();
+    let output = Swift::new().roots(["AdminReport"]).export(&types).unwrap();
+
+    assert!(output.contains("public struct AdminReport: Codable"));
+    assert!(output.contains("public struct Invoice: Codable"));
+    assert!(!output.contains("User"));
+}
+
+#[test]
+fn test_roots_combine_with_filters() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<AdminReport>();
+    let (output, report) = Swift::new()
+        .root::<User>()
+        .filter(|ndt| ndt.name() != "Address")
+        .export_with_report(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct User: Codable"));
+    assert!(!output.contains("public struct Address"));
+    assert_eq!(report.unresolved_references.len(), 1);
+}
+
+#[test]
+fn test_unknown_root() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<AdminReport>();
+    let result = Swift::new().roots(["Missing"]).export(&types);
+    assert!(matches!(result, Err(Error::Configuration(_))));
+}
@@ -0,0 +1,50 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{schema_hash, Swift};
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+}
+
+mod v2 {
+    #[derive(specta::Type)]
+    pub struct User {
+        pub id: u32,
+        pub name: String,
+        pub email: String,
+    }
+}
+
+#[test]
+fn test_schema_hash_constant() {
+    let types = TypeCollection::default().register::<User>();
+    let output = Swift::new().with_schema_hash().export(&types).unwrap();
+    println!("{}", output);
+
+    let hash = schema_hash(&types);
+    assert_eq!(hash.len(), 16);
+    assert!(output.contains(&format!(
+        "public enum SpectaSchema {{\n    public static let hash = \"{}\"\n}}",
+        hash
+    )));
+
+    assert!(!Swift::new()
+        .export(&types)
+        .unwrap()
+        .contains("SpectaSchema"));
+}
+
+#[test]
+fn test_schema_hash_changes_with_types() {
+    let types = TypeCollection::default().register::<User>();
+
+    assert_eq!(
+        schema_hash(&types),
+        schema_hash(&TypeCollection::default().register::<User>())
+    );
+    assert_ne!(
+        schema_hash(&types),
+        schema_hash(&TypeCollection::default().register::<v2::User>())
+    );
+}
@@ -0,0 +1,74 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{ExportWarning, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Data {
+    bytes: Vec<u8>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Upload {
+    data: Data,
+    previous: Option<Data>,
+}
+
+#[test]
+fn test_shadowed_name_warns() {
+    let (output, report) = Swift::new()
+        .export_with_report(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("public struct Data: Codable {"));
+    assert!(output.contains("public let data: Data"));
+    assert_eq!(
+        report.warnings,
+        vec![ExportWarning::ShadowedName {
+            name: "Data".to_string(),
+            renamed_to: None,
+        }]
+    );
+}
+
+#[test]
+fn test_shadowed_name_prefix() {
+    let (output, report) = Swift::new()
+        .prefix_shadowed_names("App")
+        .export_with_report(&TypeCollection::default().register::<Upload>())
+        .unwrap();
+
+    assert!(output.contains("public struct AppData: Codable {"));
+    assert!(!output.contains("public struct Data:"));
+    assert!(output.contains("public let data: AppData"));
+    assert!(output.contains("public let previous: AppData?"));
+    // Names which don't shadow a Swift type are kept
+    assert!(output.contains("public struct Upload: Codable {"));
+    assert_eq!(
+        report.warnings,
+        vec![ExportWarning::ShadowedName {
+            name: "Data".to_string(),
+            renamed_to: Some("AppData".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn test_unshadowed_names_have_no_warning() {
+    #[derive(Type)]
+    #[allow(dead_code)]
+    struct Payload {
+        id: u32,
+    }
+
+    let types = TypeCollection::default().register::<Payload>();
+    let (_, report) = Swift::new().export_with_report(&types).unwrap();
+    assert!(report.warnings.is_empty());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_shadowed_name_prefix_config() {
+    let swift = Swift::from_config_str(r#"prefix_shadowed_names = "App""#).unwrap();
+    assert_eq!(swift.shadowed_name_prefix.as_deref(), Some("App"));
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use specta::{NamedType, Type, TypeCollection};
+use specta_swift::{NamingConvention, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct User {
+    id: u32,
+    user_name: String,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Event {
+    Created { user: User },
+    Deleted,
+}
+
+#[test]
+fn test_datatype() {
+    let mut types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+    let swift = Swift::new();
+
+    let dt = <HashMap<String, Vec<Option<User>>>>::definition(&mut types);
+    assert_eq!(swift.datatype(&types, &dt).unwrap(), "[String: [User?]]");
+    let dt = u64::definition(&mut types);
+    assert_eq!(swift.datatype(&types, &dt).unwrap(), "UInt64");
+}
+
+#[test]
+fn test_declaration_matches_export() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+    let swift = Swift::new();
+
+    let declaration = swift
+        .declaration(&types, types.get(Event::ID).unwrap())
+        .unwrap();
+    assert!(declaration.starts_with("public enum Event {"));
+    assert!(declaration.contains("public struct EventCreatedData: Codable {"));
+    assert!(declaration.contains("extension Event: Codable {"));
+    assert!(!declaration.contains("import Foundation"));
+    assert!(swift.export(&types).unwrap().contains(&declaration));
+}
+
+#[test]
+fn test_declaration_follows_configuration() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Event>();
+    let swift = Swift::new()
+        .naming(NamingConvention::SnakeCase)
+        .with_post_processor(|_, declaration| format!("// Single\n{}", declaration));
+
+    let declaration = swift
+        .declaration(&types, types.get(User::ID).unwrap())
+        .unwrap();
+    assert!(declaration.starts_with("// Single\npublic struct user: Codable {"));
+}
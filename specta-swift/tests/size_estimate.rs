@@ -0,0 +1,77 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{SizeEstimate, Swift};
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    name: String,
+    email: Option<String>,
+}
+
+#[derive(Type)]
+enum Status {
+    Active,
+    Pending { reason: String },
+    Error(String),
+}
+
+#[derive(Type)]
+struct Account {
+    user: User,
+    status: Status,
+}
+
+#[test]
+fn test_estimate_is_close_to_output() {
+    let types = TypeCollection::default().register::<Account>();
+    let output = Swift::default().export(&types).unwrap();
+    let estimate = SizeEstimate::default().for_types(&types);
+
+    assert!(
+        estimate > output.len() / 2 && estimate < output.len() * 2,
+        "estimated {} bytes for {} bytes of output",
+        estimate,
+        output.len()
+    );
+}
+
+#[test]
+fn test_estimate_scales_with_members() {
+    let types = TypeCollection::default().register::<Account>();
+    let estimate = SizeEstimate {
+        prelude: 0,
+        per_type: 10,
+        per_field: 1,
+        per_variant: 100,
+    };
+    let sizes = types
+        .into_sorted_iter()
+        .map(|ndt| (ndt.name().to_string(), estimate.for_type(&ndt)))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        sizes,
+        [
+            ("Account".to_string(), 12),
+            ("Status".to_string(), 312),
+            ("User".to_string(), 13),
+        ]
+    );
+    assert_eq!(estimate.for_types(&types), 337);
+}
+
+#[test]
+fn test_estimate_does_not_change_output() {
+    let types = TypeCollection::default().register::<Account>();
+    let tiny = SizeEstimate {
+        prelude: 0,
+        per_type: 0,
+        per_field: 0,
+        per_variant: 0,
+    };
+
+    assert_eq!(
+        Swift::default().size_estimate(tiny).export(&types).unwrap(),
+        Swift::default().export(&types).unwrap()
+    );
+}
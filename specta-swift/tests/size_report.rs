@@ -0,0 +1,153 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{CodeFeature, Swift};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Profile {
+    name: String,
+    bio: Option<String>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Permissions(u32);
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Event {
+    Created { id: u32 },
+    Deleted(u32),
+}
+
+fn features(swift: Swift, name: &str) -> (usize, Vec<(CodeFeature, usize)>) {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<Event>();
+    let (_, report) = swift.export_with_report(&types).unwrap();
+    let ty = report
+        .exported_types
+        .into_iter()
+        .find(|ty| ty.swift_name == name)
+        .unwrap();
+    (ty.lines, ty.features)
+}
+
+#[test]
+fn test_struct_features() {
+    let mut swift = Swift::new().with_mocks().with_json_helpers();
+    swift.generate_initializers = true;
+    let (lines, features) = features(swift, "Profile");
+
+    let kinds = features
+        .iter()
+        .map(|(feature, _)| *feature)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            CodeFeature::Declaration,
+            CodeFeature::Initializer,
+            CodeFeature::Codable,
+            CodeFeature::Mocks,
+            CodeFeature::JsonHelpers,
+        ]
+    );
+    assert_eq!(features[1], (CodeFeature::Initializer, 4));
+    assert_eq!(
+        features.iter().map(|(_, lines)| lines).sum::<usize>(),
+        lines
+    );
+}
+
+#[test]
+fn test_enum_features() {
+    let (_, features) = features(Swift::new(), "Event");
+    let kinds = features
+        .iter()
+        .map(|(feature, _)| *feature)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        kinds,
+        [
+            CodeFeature::Declaration,
+            CodeFeature::Codable,
+            CodeFeature::VariantStructs,
+        ]
+    );
+}
+
+#[test]
+fn test_lines_by_feature() {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<Event>();
+    let mut swift = Swift::new().with_mocks();
+    swift.generate_initializers = true;
+    let (_, report) = swift.export_with_report(&types).unwrap();
+
+    let totals = report.lines_by_feature();
+    assert_eq!(
+        totals.iter().map(|(_, lines)| lines).sum::<usize>(),
+        report
+            .exported_types
+            .iter()
+            .flat_map(|ty| &ty.features)
+            .map(|(_, lines)| lines)
+            .sum::<usize>()
+    );
+    assert!(totals.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+    let summary = report.size_summary();
+    assert!(summary.contains("Profile: "));
+    assert!(summary.contains("Total: "));
+}
+
+#[test]
+fn test_minimal_preset() {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<Event>();
+    let mut full = Swift::new().with_mocks().with_json_helpers();
+    full.generate_initializers = true;
+    let (full_output, full_report) = full.export_with_report(&types).unwrap();
+    let (minimal_output, minimal_report) = Swift::minimal().export_with_report(&types).unwrap();
+
+    assert!(minimal_report.total_lines() < full_report.total_lines());
+    assert!(full_output.contains("public static var mock"));
+    assert!(!minimal_output.contains("public static var mock"));
+    assert!(!minimal_output.contains("public init(name:"));
+    assert!(minimal_output.contains("public struct Profile: Codable {"));
+}
+
+#[test]
+fn test_minimal_preset_keeps_the_type_shapes() {
+    let types = TypeCollection::default()
+        .register::<Profile>()
+        .register::<Event>()
+        .register::<Permissions>();
+    let full = Swift::new()
+        .option_set("Permissions", [("READ", 1), ("WRITE", 2)])
+        .export(&types)
+        .unwrap();
+    let minimal = Swift::minimal()
+        .option_set("Permissions", [("READ", 1), ("WRITE", 2)])
+        .export(&types)
+        .unwrap();
+
+    // The same properties, cases and option sets are declared, so both decode the same JSON
+    let declarations = |output: &str| {
+        output
+            .lines()
+            .filter(|line| {
+                line.starts_with("public ")
+                    || line.starts_with("    public let ")
+                    || line.starts_with("    case ")
+                    || line.starts_with("    public static let ")
+            })
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(declarations(&minimal), declarations(&full));
+    assert!(minimal.contains("public struct Permissions: OptionSet, Hashable, Codable {"));
+}
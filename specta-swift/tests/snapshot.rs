@@ -0,0 +1,70 @@
+use specta::{Type, TypeCollection};
+use specta_swift::utils::testing::{assert_swift_eq, declarations, normalize_whitespace};
+use specta_swift::{assert_swift_snapshot, Swift};
+
+#[derive(Type)]
+struct User {
+    name: String,
+    age: u32,
+    active: bool,
+}
+
+#[derive(Type)]
+enum Status {
+    Active,
+    Inactive,
+    Pending { reason: String },
+    Error(String),
+}
+
+fn export() -> String {
+    Swift::default()
+        .export(
+            &TypeCollection::default()
+                .register::<User>()
+                .register::<Status>(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_full_snapshot() {
+    assert_swift_snapshot!("basic", export());
+}
+
+#[test]
+fn test_type_snapshots() {
+    assert_swift_snapshot!(types: "basic_types", export());
+}
+
+#[test]
+fn test_declarations_group_extensions() {
+    let declarations = declarations(&export());
+
+    assert_eq!(
+        declarations.keys().collect::<Vec<_>>(),
+        ["Status", "StatusPendingData", "User"]
+    );
+    assert!(declarations["Status"].contains("extension Status: Codable {"));
+}
+
+#[test]
+fn test_whitespace_is_ignored() {
+    assert_swift_eq(
+        "public struct A {   \n\n    public let a: Int32\n}\n\n",
+        "public struct A {\r\n    public let a: Int32\r\n}",
+    );
+    assert_ne!(
+        normalize_whitespace("struct A {\n    let a: Int\n}"),
+        normalize_whitespace("struct A {\nlet a: Int\n}")
+    );
+}
+
+#[test]
+#[should_panic(expected = "first difference at normalized line 2")]
+fn test_mismatch_reports_first_difference() {
+    assert_swift_eq(
+        "public struct A {\n    public let a: Int64\n}",
+        "public struct A {\n    public let a: Int32\n}",
+    );
+}
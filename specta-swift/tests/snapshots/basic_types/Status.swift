@@ -0,0 +1,71 @@
+public enum Status {
+    case active
+    case inactive
+    case pending(StatusPendingData)
+    case error(String)
+}
+
+// MARK: - Status Codable Implementation
+extension Status: Codable {
+    private enum CodingKeys: String, CodingKey {
+        case active = "Active"
+        case inactive = "Inactive"
+        case pending = "Pending"
+        case error = "Error"
+    }
+    public init(from decoder: Decoder) throws {
+        // Try externally-tagged format first (e.g., {"WaitingForConnection": null})
+        if let container = try? decoder.container(keyedBy: CodingKeys.self) {
+            if container.allKeys.count == 1 {
+                let key = container.allKeys.first!
+                switch key {
+                case .active:
+                    self = .active
+                    return
+                case .inactive:
+                    self = .inactive
+                    return
+                case .pending:
+                    let data = try container.decode(StatusPendingData.self, forKey: .pending)
+                    self = .pending(data)
+                    return
+                case .error:
+                    self = .error(try container.decode(String.self, forKey: .error))
+                    return
+                }
+                return
+            }
+        }
+        // Fallback: try decoding as plain string for unit variants (serde default)
+        if let stringContainer = try? decoder.singleValueContainer() {
+            if let variantString = try? stringContainer.decode(String.self) {
+                switch variantString {
+                case "Active":
+                    self = .active
+                    return
+                case "Inactive":
+                    self = .inactive
+                    return
+                default:
+                    break
+                }
+            }
+        }
+        throw DecodingError.dataCorrupted(
+            DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Could not decode enum - expected externally-tagged object or string for unit variants")
+        )
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .active:
+            try container.encodeNil(forKey: .active)
+        case .inactive:
+            try container.encodeNil(forKey: .inactive)
+        case .pending(let data):
+            try container.encode(data, forKey: .pending)
+        case .error(let value):
+            try container.encode(value, forKey: .error)
+        }
+    }
+}
@@ -0,0 +1,3 @@
+public struct StatusPendingData: Codable {
+    public let reason: String
+}
@@ -0,0 +1,5 @@
+public struct User: Codable {
+    public let name: String
+    public let age: UInt32
+    public let active: Bool
+}
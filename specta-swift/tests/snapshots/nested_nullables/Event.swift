@@ -0,0 +1,60 @@
+public enum Event {
+    case tagged(String?, UInt32)
+    case timed(RustDuration?, Item?)
+}
+
+// MARK: - Event Codable Implementation
+extension Event: Codable {
+    private enum CodingKeys: String, CodingKey {
+        case tagged = "Tagged"
+        case timed = "Timed"
+    }
+    public init(from decoder: Decoder) throws {
+        // Try externally-tagged format first (e.g., {"WaitingForConnection": null})
+        if let container = try? decoder.container(keyedBy: CodingKeys.self) {
+            if container.allKeys.count == 1 {
+                let key = container.allKeys.first!
+                switch key {
+                case .tagged:
+                    var arrayContainer = try container.nestedUnkeyedContainer(forKey: .tagged)
+                    let value0 = try arrayContainer.decode(String?.self)
+                    let value1 = try arrayContainer.decode(UInt32.self)
+                    self = .tagged(value0, value1)
+                    return
+                case .timed:
+                    var arrayContainer = try container.nestedUnkeyedContainer(forKey: .timed)
+                    let value0 = try arrayContainer.decode(RustDuration?.self)
+                    let value1 = try arrayContainer.decode(Item?.self)
+                    self = .timed(value0, value1)
+                    return
+                }
+                return
+            }
+        }
+        // Fallback: try decoding as plain string for unit variants (serde default)
+        if let stringContainer = try? decoder.singleValueContainer() {
+            if let variantString = try? stringContainer.decode(String.self) {
+                switch variantString {
+                default:
+                    break
+                }
+            }
+        }
+        throw DecodingError.dataCorrupted(
+            DecodingError.Context(codingPath: decoder.codingPath, debugDescription: "Could not decode enum - expected externally-tagged object or string for unit variants")
+        )
+    }
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .tagged(let value0, let value1):
+            var arrayContainer = container.nestedUnkeyedContainer(forKey: .tagged)
+            try arrayContainer.encode(value0)
+            try arrayContainer.encode(value1)
+        case .timed(let value0, let value1):
+            var arrayContainer = container.nestedUnkeyedContainer(forKey: .timed)
+            try arrayContainer.encode(value0)
+            try arrayContainer.encode(value1)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+public struct Inventory: Codable {
+    public let items: [String: Item?]
+    public let timeouts: [String: RustDuration?]
+    public let slots: [Item?]
+    public let pair: Pair
+    public let event: Event
+}
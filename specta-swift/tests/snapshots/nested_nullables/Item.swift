@@ -0,0 +1,3 @@
+public struct Item: Codable {
+    public let id: UInt32
+}
@@ -0,0 +1,4 @@
+public struct Pair: Codable {
+    public let field0: String?
+    public let field1: [String: UInt32?]
+}
@@ -0,0 +1,77 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{SpecialType, Swift};
+
+#[derive(Type)]
+pub struct Timestamp {
+    pub millis: i64,
+}
+
+#[derive(Type)]
+pub struct Event {
+    pub name: String,
+    pub at: Timestamp,
+    pub edited_at: Option<Timestamp>,
+    pub history: Vec<Timestamp>,
+}
+
+fn timestamp_as_date(swift: Swift) -> Swift {
+    swift.special_type(|ndt| {
+        (ndt.name() == "Timestamp")
+            .then(|| SpecialType::new("Date").mock("Date(timeIntervalSince1970: 0)"))
+    })
+}
+
+#[test]
+fn test_special_type_replaces_references() {
+    let output = timestamp_as_date(Swift::new())
+        .export(&TypeCollection::default().register::<Event>())
+        .unwrap();
+
+    assert!(output.contains("public let at: Date"));
+    assert!(output.contains("public let editedAt: Date?"));
+    assert!(output.contains("public let history: [Date]"));
+    assert!(!output.contains("Timestamp"));
+}
+
+#[test]
+fn test_special_type_is_not_unresolved() {
+    let (_, report) = timestamp_as_date(Swift::new())
+        .export_with_report(&TypeCollection::default().register::<Event>())
+        .unwrap();
+
+    assert!(report
+        .exported_types
+        .iter()
+        .all(|ty| ty.name != "Timestamp"));
+    assert!(report.unresolved_references.is_empty());
+}
+
+#[test]
+fn test_first_matching_handler_wins() {
+    let output = Swift::new()
+        .special_type(|ndt| (ndt.name() == "Timestamp").then(|| SpecialType::new("Date")))
+        .special_type(|ndt| (ndt.name() == "Timestamp").then(|| SpecialType::new("String")))
+        .export(&TypeCollection::default().register::<Event>())
+        .unwrap();
+
+    assert!(output.contains("public let at: Date"));
+}
+
+#[test]
+fn test_special_type_mock() {
+    let types = TypeCollection::default().register::<Event>();
+    let output = timestamp_as_date(Swift::new().with_mocks())
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("at: Date = Date(timeIntervalSince1970: 0)"));
+
+    // Without a mock value the referencing type has no mock
+    let output = Swift::new()
+        .with_mocks()
+        .special_type(|ndt| (ndt.name() == "Timestamp").then(|| SpecialType::new("Date")))
+        .export(&types)
+        .unwrap();
+
+    assert!(!output.contains("static let mock"));
+}
@@ -0,0 +1,47 @@
+use specta::datatype::DataType;
+use specta_swift::stress::{measure, Synthetic};
+use specta_swift::{Error, Swift};
+
+#[test]
+fn test_synthetic_shape() {
+    let types = Synthetic::new(40).fields(5).variants(3).build();
+    assert_eq!(types.len(), 40);
+
+    let (enums, structs): (Vec<_>, Vec<_>) = types
+        .into_unsorted_iter()
+        .partition(|ndt| matches!(ndt.ty(), DataType::Enum(_)));
+    assert_eq!(enums.len(), 10);
+    assert_eq!(structs.len(), 30);
+    assert!(enums.iter().all(|ndt| ndt.name().starts_with("Enum")));
+}
+
+#[test]
+fn test_synthetic_exports() {
+    let types = Synthetic::new(16).depth(4).build();
+    let output = Swift::default().export(&types).unwrap();
+
+    assert!(output.contains("public struct Struct0: Codable {"));
+    assert!(output.contains("public enum Enum3 {"));
+    // Chains reference the previous type of the chain
+    assert!(output.contains("public let field4: Struct0\n"));
+    assert!(!output.contains("public let field4: Enum3\n"));
+}
+
+#[test]
+fn test_measure() {
+    let types = Synthetic::new(8).build();
+    let measurement = measure(&Swift::default(), &types, 2).unwrap();
+
+    assert_eq!(measurement.types, 8);
+    assert_eq!(measurement.iterations, 2);
+    assert_eq!(
+        measurement.bytes,
+        Swift::default().export(&types).unwrap().len()
+    );
+    assert!(measurement.to_string().starts_with("8 types ("));
+
+    assert!(matches!(
+        measure(&Swift::default(), &types, 0),
+        Err(Error::Configuration(_))
+    ));
+}
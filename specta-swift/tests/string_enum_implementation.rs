@@ -102,9 +102,9 @@ fn test_string_enum_uppercase() {
 
     // Should contain string enum syntax with uppercase values
     assert!(result.contains("enum Priority: String, Codable"));
-    assert!(result.contains("case lOW = \"LOW\""));
-    assert!(result.contains("case mEDIUM = \"MEDIUM\""));
-    assert!(result.contains("case hIGH = \"HIGH\""));
+    assert!(result.contains("case low = \"LOW\""));
+    assert!(result.contains("case medium = \"MEDIUM\""));
+    assert!(result.contains("case high = \"HIGH\""));
 }
 
 #[test]
@@ -172,9 +172,9 @@ fn test_string_enum_screaming_kebab_case() {
 
     // Should contain string enum syntax with SCREAMING-KEBAB-CASE values
     assert!(result.contains("enum DatabaseStatus: String, Codable"));
-    assert!(result.contains("case cONNECTED = \"C-O-N-N-E-C-T-E-D\""));
-    assert!(result.contains("case dISCONNECTED = \"D-I-S-C-O-N-N-E-C-T-E-D\""));
-    assert!(result.contains("case rECONNECTING = \"R-E-C-O-N-N-E-C-T-I-N-G\""));
+    assert!(result.contains("case connected = \"CONNECTED\""));
+    assert!(result.contains("case disconnected = \"DISCONNECTED\""));
+    assert!(result.contains("case reconnecting = \"RECONNECTING\""));
 }
 
 #[test]
@@ -251,3 +251,27 @@ fn test_all_string_enums_together() {
     assert!(result.contains("enum RegularEnum: Codable")); // Simple enum can have Codable in declaration
     assert!(result.contains("extension MixedEnum: Codable")); // Complex enum has Codable in extension
 }
+
+#[derive(Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncState {
+    Idle,
+    Syncing,
+    #[serde(skip)]
+    Internal,
+}
+
+#[test]
+fn test_string_enum_skips_variants() {
+    let types = TypeCollection::default().register::<SyncState>();
+
+    let result = Swift::default()
+        .with_string_enum_helpers()
+        .export(&types)
+        .unwrap();
+
+    assert!(result.contains("enum SyncState: String, Codable"));
+    assert!(result.contains("case idle = \"idle\""));
+    assert!(result.contains("case syncing = \"syncing\""));
+    assert!(!result.contains("internal"));
+}
@@ -43,3 +43,27 @@ fn test_string_enum_generation() {
     assert!(output.contains("case option2"));
     assert!(output.contains("case option3"));
 }
+
+#[test]
+fn test_string_enum_helpers() {
+    let types = TypeCollection::default()
+        .register::<JobStatus>()
+        .register::<RegularEnum>();
+
+    let output = Swift::default().export(&types).unwrap();
+    assert!(!output.contains("allRawValues"));
+
+    let output = Swift::default()
+        .with_string_enum_helpers()
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains(
+        "    public static let allRawValues: [String] = [\"completed\", \"running\", \"failed\", \"pending_approval\"]\n"
+    ));
+    assert!(output.contains("    public init?(caseInsensitive rawValue: String) {\n"));
+    assert!(output.contains("        self.init(rawValue: match)\n    }\n}"));
+
+    // Only string enums have raw values
+    assert_eq!(output.matches("allRawValues: [String]").count(), 1);
+}
@@ -0,0 +1,65 @@
+use specta::{Type, TypeCollection};
+use specta_swift::integrations::{Subscription, SubscriptionStyle, Subscriptions};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct JobProgress {
+    job_id: u32,
+    percent: f32,
+}
+
+#[test]
+fn test_subscriptions() {
+    let mut types = TypeCollection::default().register::<JobProgress>();
+
+    let subscriptions = Subscriptions::new()
+        .subscription(
+            Subscription::new("job_progress", JobProgress::definition(&mut types))
+                .input(u32::definition(&mut types))
+                .docs("Progress updates for a job."),
+        )
+        .subscription(Subscription::new("heartbeat", u64::definition(&mut types)));
+
+    let output = subscriptions.export(&Swift::new(), &types).unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("public protocol SubscriptionTransport {"));
+    assert!(output.contains(
+        "    func subscribe(_ name: String, input: Data) -> AsyncThrowingStream<Data, Error>"
+    ));
+    assert!(output.contains("public struct Subscriptions {"));
+    assert!(output.contains("continuation.yield(try decoder.decode(T.self, from: message))"));
+    assert!(output.contains("continuation.onTermination = { _ in task.cancel() }"));
+    assert!(output.contains(
+        "    /// Progress updates for a job.\n    public func jobProgress(_ input: UInt32) -> AsyncThrowingStream<JobProgress, Error> {"
+    ));
+    assert!(output
+        .contains("        return stream(\"job_progress\", input: { try encoder.encode(input) })"));
+    assert!(output.contains("    public func heartbeat() -> AsyncThrowingStream<UInt64, Error> {"));
+    assert!(output.contains("        return stream(\"heartbeat\", input: { Data(\"null\".utf8) })"));
+}
+
+#[test]
+fn test_combine_subscriptions() {
+    let mut types = TypeCollection::default().register::<JobProgress>();
+
+    let subscriptions = Subscriptions::new()
+        .style(SubscriptionStyle::Combine)
+        .subscription(
+            Subscription::new("job_progress", JobProgress::definition(&mut types))
+                .input(u32::definition(&mut types)),
+        );
+
+    let output = subscriptions.export(&Swift::new(), &types).unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("import Combine"));
+    assert!(output
+        .contains("    func subscribe(_ name: String, input: Data) -> AnyPublisher<Data, Error>"));
+    assert!(output.contains("            .tryMap { try decoder.decode(T.self, from: $0) }"));
+    assert!(output.contains("            return Fail(error: error).eraseToAnyPublisher()"));
+    assert!(output.contains(
+        "    public func jobProgress(_ input: UInt32) -> AnyPublisher<JobProgress, Error> {"
+    ));
+    assert!(!output.contains("AsyncThrowingStream"));
+}
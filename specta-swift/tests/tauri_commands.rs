@@ -0,0 +1,71 @@
+use specta::{function::fn_datatype, specta, Type, TypeCollection};
+use specta_swift::integrations::TauriCommands;
+use specta_swift::Swift;
+
+#[derive(Type, serde::Serialize)]
+struct User {
+    id: u32,
+    display_name: String,
+}
+
+/// Load a user by id.
+#[specta]
+fn get_user(user_id: u32) -> Result<User, String> {
+    Err(user_id.to_string())
+}
+
+#[specta]
+fn ping() {}
+
+#[specta]
+fn rename_user(user_id: u32, new_name: String) -> Option<String> {
+    Some(format!("{user_id}{new_name}"))
+}
+
+#[test]
+fn test_command_client() {
+    let mut types = TypeCollection::default();
+    let functions = vec![
+        fn_datatype!(get_user)(&mut types),
+        fn_datatype!(ping)(&mut types),
+        fn_datatype!(rename_user)(&mut types),
+    ];
+
+    let output = TauriCommands::new(functions)
+        .export(&Swift::new(), &types)
+        .unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("public protocol TauriInvoker {"));
+    assert!(
+        output.contains("public struct GetUserArgs: Codable {\n    public let userId: UInt32\n}")
+    );
+    assert!(!output.contains("PingArgs"));
+    assert!(output.contains("public struct TauriCommands {"));
+    assert!(output.contains(
+        "    /// Load a user by id.\n    public func getUser(userId: UInt32) async throws -> User {"
+    ));
+    assert!(output.contains("        let args = try encoder.encode(GetUserArgs(userId: userId))"));
+    assert!(output
+        .contains("        let response = try await invoker.invoke(\"get_user\", args: args)"));
+    assert!(output.contains("        return try decoder.decode(User.self, from: response)"));
+
+    assert!(output.contains("    public func ping() async throws {"));
+    assert!(output.contains("        let args = Data(\"{}\".utf8)"));
+    assert!(output.contains("        _ = try await invoker.invoke(\"ping\", args: args)"));
+
+    assert!(output.contains(
+        "    public func renameUser(userId: UInt32, newName: String) async throws -> String? {"
+    ));
+}
+
+#[test]
+fn test_custom_client_name() {
+    let mut types = TypeCollection::default();
+    let output = TauriCommands::new([fn_datatype!(ping)(&mut types)])
+        .client_name("Api")
+        .export(&Swift::new(), &types)
+        .unwrap();
+
+    assert!(output.contains("public struct Api {"));
+}
@@ -0,0 +1,36 @@
+use specta::{Type, TypeCollection};
+use specta_swift::integrations::TauriEvents;
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct JobProgress {
+    job_id: u32,
+    percent: f32,
+}
+
+#[test]
+fn test_event_listeners() {
+    let mut types = TypeCollection::default().register::<JobProgress>();
+    let payload = JobProgress::definition(&mut types);
+
+    let output = TauriEvents::new()
+        .event("job-progress", payload)
+        .event("logoutRequested", <()>::definition(&mut types))
+        .export(&Swift::new(), &types)
+        .unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("public protocol TauriEventSource {"));
+    assert!(output.contains("public struct TauriEvents {"));
+    assert!(output.contains(
+        "    public func onJobProgress(_ handler: @escaping (JobProgress) -> Void) -> TauriUnlisten {"
+    ));
+    assert!(output.contains("        return source.listen(\"job-progress\") { data in"));
+    assert!(output.contains(
+        "            guard let payload = try? decoder.decode(JobProgress.self, from: data) else { return }"
+    ));
+    assert!(output.contains(
+        "    public func onLogoutRequested(_ handler: @escaping () -> Void) -> TauriUnlisten {"
+    ));
+    assert!(!output.contains("Void.self"));
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Request {
+    headers: Vec<(String, String)>,
+    range: Option<(u32, u32)>,
+    origin: (f64, f64, f64),
+    weights: HashMap<String, (String, f64)>,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Plain {
+    name: String,
+}
+
+fn export(types: &TypeCollection) -> String {
+    Swift::new().export(types).unwrap()
+}
+
+#[test]
+fn test_tuple_fields_use_helper_structs() {
+    let output = export(&TypeCollection::default().register::<Request>());
+
+    assert!(output.contains("public let headers: [Tuple2<String, String>]\n"));
+    assert!(output.contains("public let range: Tuple2<UInt32, UInt32>?\n"));
+    assert!(output.contains("public let origin: Tuple3<Double, Double, Double>\n"));
+    assert!(output.contains("public let weights: [String: Tuple2<String, Double>]\n"));
+    assert!(!output.contains("(String, String)"));
+}
+
+#[test]
+fn test_tuple_helpers_are_emitted_when_needed() {
+    let output = export(&TypeCollection::default().register::<Request>());
+
+    assert!(output.contains(
+        "public struct Tuple2<T0, T1> {\n    public let _0: T0\n    public let _1: T1\n"
+    ));
+    assert!(output.contains("public struct Tuple3<T0, T1, T2> {"));
+    assert!(!output.contains("struct Tuple4"));
+    assert!(output.contains(
+        "extension Tuple2: Codable where T0: Codable, T1: Codable {\n    public init(from decoder: Decoder) throws {\n        var container = try decoder.unkeyedContainer()\n        _0 = try container.decode(T0.self)\n        _1 = try container.decode(T1.self)\n    }"
+    ));
+    assert!(output.contains("        try container.encode(_0)\n        try container.encode(_1)\n"));
+    assert!(output.contains("extension Tuple2: Hashable where T0: Hashable, T1: Hashable {}"));
+
+    let output = export(&TypeCollection::default().register::<Plain>());
+    assert!(!output.contains("Tuple2"));
+}
+
+#[test]
+fn test_tuple_mocks_use_helper_structs() {
+    let output = Swift::new()
+        .with_mocks()
+        .export(&TypeCollection::default().register::<Request>())
+        .unwrap();
+
+    assert!(output.contains("origin: Tuple3<Double, Double, Double> = Tuple3(1.0, 1.0, 1.0)"));
+}
@@ -36,3 +36,34 @@ fn test_tuple_variant_codable_generation() {
 
     println!("✅ Tuple variant Codable generation successful!");
 }
+
+#[derive(Type, serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+enum Reply {
+    Text(String),
+    Pair(String, u32),
+}
+
+#[test]
+fn test_newtype_variants_match_serde_wire_format() {
+    // serde writes newtype variants without the array, tuple variants with it
+    for (value, json) in [
+        (Reply::Text("x".into()), r#"{"Text":"x"}"#),
+        (Reply::Pair("x".into(), 1), r#"{"Pair":["x",1]}"#),
+    ] {
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<Reply>(json).unwrap(), value);
+    }
+
+    let output = Swift::default()
+        .export(&TypeCollection::default().register::<Reply>())
+        .unwrap();
+
+    assert!(output.contains(
+        "                case .text:\n                    self = .text(try container.decode(String.self, forKey: .text))"
+    ));
+    assert!(output.contains(
+        "        case .text(let value):\n            try container.encode(value, forKey: .text)"
+    ));
+    assert!(!output.contains("nestedUnkeyedContainer(forKey: .text)"));
+    assert!(output.contains("nestedUnkeyedContainer(forKey: .pair)"));
+}
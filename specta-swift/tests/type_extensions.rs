@@ -0,0 +1,54 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct User {
+    first_name: String,
+    last_name: String,
+}
+
+#[derive(Type)]
+struct Post {
+    title: String,
+}
+
+#[test]
+fn test_extension_is_appended_after_type() {
+    let types = TypeCollection::default()
+        .register::<User>()
+        .register::<Post>();
+
+    let output = Swift::new()
+        .extend_type(
+            "User",
+            "extension User {\n    var displayName: String { \"\\(firstName) \\(lastName)\" }\n}",
+        )
+        .export(&types)
+        .unwrap();
+
+    let user = output.find("public struct User: Codable {").unwrap();
+    let extension = output.find("extension User {").unwrap();
+    let post = output.find("public struct Post: Codable {").unwrap();
+
+    // Types are sorted by name so `Post` comes before `User`
+    assert!(post < user);
+    assert!(user < extension);
+    assert!(output.contains("var displayName: String"));
+}
+
+#[test]
+fn test_multiple_extensions_keep_registration_order() {
+    let types = TypeCollection::default().register::<Post>();
+
+    let output = Swift::new()
+        .extend_type("Post", "extension Post: Identifiable {}")
+        .extend_type("Post", "extension Post: Hashable {}")
+        .extend_type("Missing", "extension Missing {}")
+        .export(&types)
+        .unwrap();
+
+    let identifiable = output.find("extension Post: Identifiable {}").unwrap();
+    let hashable = output.find("extension Post: Hashable {}").unwrap();
+    assert!(identifiable < hashable);
+    assert!(!output.contains("extension Missing"));
+}
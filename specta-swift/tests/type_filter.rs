@@ -0,0 +1,93 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+mod api {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct User {
+        pub id: u32,
+        pub name: String,
+    }
+
+    pub mod admin {
+        use super::*;
+
+        #[derive(Type)]
+        pub struct AuditEntry {
+            pub action: String,
+        }
+    }
+}
+
+mod internal {
+    use super::*;
+
+    #[derive(Type)]
+    pub struct CacheEntry {
+        pub key: String,
+    }
+}
+
+#[test]
+fn test_filter_predicate() {
+    let types = TypeCollection::default()
+        .register::<api::User>()
+        .register::<api::admin::AuditEntry>()
+        .register::<internal::CacheEntry>();
+    let output = Swift::new()
+        .filter(|ndt| ndt.name() != "CacheEntry")
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct User: Codable"));
+    assert!(output.contains("public struct AuditEntry: Codable"));
+    assert!(!output.contains("CacheEntry"));
+}
+
+#[test]
+fn test_include_modules() {
+    let types = TypeCollection::default()
+        .register::<api::User>()
+        .register::<api::admin::AuditEntry>()
+        .register::<internal::CacheEntry>();
+    let output = Swift::new()
+        .include_modules(["type_filter::api"])
+        .export(&types)
+        .unwrap();
+
+    // Submodules are included too
+    assert!(output.contains("public struct User: Codable"));
+    assert!(output.contains("public struct AuditEntry: Codable"));
+    assert!(!output.contains("CacheEntry"));
+}
+
+#[test]
+fn test_exclude_modules() {
+    let types = TypeCollection::default()
+        .register::<api::User>()
+        .register::<api::admin::AuditEntry>()
+        .register::<internal::CacheEntry>();
+    let output = Swift::new()
+        .exclude_modules(["type_filter::api::admin", "type_filter::internal"])
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct User: Codable"));
+    assert!(!output.contains("AuditEntry"));
+    assert!(!output.contains("CacheEntry"));
+}
+
+#[test]
+fn test_module_prefix_matches_whole_segments() {
+    let types = TypeCollection::default()
+        .register::<api::User>()
+        .register::<api::admin::AuditEntry>()
+        .register::<internal::CacheEntry>();
+    let output = Swift::new()
+        .include_modules(["type_filter::ap"])
+        .export(&types)
+        .unwrap();
+
+    assert!(!output.contains("public struct"));
+}
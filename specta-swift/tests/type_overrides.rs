@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+/// A type specta knows nothing about, only usable through `#[specta(type = ...)]`.
+#[derive(Serialize)]
+struct Opaque;
+
+#[derive(Type, Serialize)]
+#[allow(dead_code)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Type, Serialize)]
+#[serde(transparent)]
+#[allow(dead_code)]
+struct Timestamp(#[specta(type = String)] Opaque);
+
+#[derive(Type, Serialize)]
+#[allow(dead_code)]
+struct Shape {
+    #[specta(type = String)]
+    id: Opaque,
+    #[specta(type = Option<Point>)]
+    center: Opaque,
+    #[specta(type = HashMap<String, Vec<Point>>)]
+    layers: Opaque,
+    created: Timestamp,
+    events: Vec<Event>,
+    tagged: Vec<TaggedEvent>,
+}
+
+#[derive(Type, Serialize)]
+#[allow(dead_code)]
+enum Event {
+    Moved {
+        #[specta(type = Point)]
+        to: Opaque,
+        #[specta(type = HashMap<String, u32>)]
+        counts: Opaque,
+    },
+    Resized(#[specta(type = Vec<f64>)] Opaque),
+    Renamed(#[specta(type = String)] Opaque, u32),
+}
+
+#[derive(Type, Serialize)]
+#[serde(tag = "type")]
+#[allow(dead_code)]
+enum TaggedEvent {
+    Moved {
+        #[specta(type = Point)]
+        to: Opaque,
+    },
+}
+
+fn export() -> String {
+    Swift::new()
+        .export(&TypeCollection::default().register::<Shape>())
+        .unwrap()
+}
+
+#[test]
+fn test_struct_field_overrides() {
+    let output = export();
+
+    assert!(output.contains("public let id: String"));
+    assert!(output.contains("public let center: Point?"));
+    assert!(output.contains("public let layers: [String: [Point]]"));
+}
+
+#[test]
+fn test_variant_payload_overrides() {
+    let output = export();
+
+    assert!(output.contains("public struct EventMovedData: Codable {"));
+    assert!(output.contains("public let to: Point"));
+    assert!(output.contains("public let counts: [String: UInt32]"));
+    assert!(output.contains("case resized([Double])"));
+    assert!(output.contains("case renamed(String, UInt32)"));
+}
+
+#[test]
+fn test_internally_tagged_variant_overrides() {
+    let output = export();
+
+    assert!(output.contains("public struct TaggedEventMovedData: Codable {"));
+    assert!(!output.contains("Opaque"));
+}
+
+#[test]
+fn test_transparent_overrides_are_type_aliases() {
+    let output = export();
+
+    assert!(output.contains("public typealias Timestamp = String"));
+    assert!(output.contains("public let created: Timestamp"));
+}
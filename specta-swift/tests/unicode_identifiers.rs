@@ -0,0 +1,85 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{Swift, UnicodeIdentifiers};
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Café {
+    crème_brûlée: String,
+    // Decomposed: `i` followed by a combining diaeresis
+    #[serde(rename = "nai\u{308}ve")]
+    naive: bool,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Größe {
+    Klein,
+    Groß { höhe: u32 },
+}
+
+#[test]
+fn test_identifiers_are_nfc_normalized() {
+    let types = TypeCollection::default().register::<Café>();
+
+    let output = Swift::new().export(&types).unwrap();
+
+    assert!(output.contains("public struct Café: Codable"));
+    assert!(output.contains("public let crèmeBrûlée: String"));
+    // The combining mark is composed instead of being replaced by a separator
+    assert!(output.contains("public let na\u{ef}ve: Bool"));
+    assert!(!output.contains("nai\u{308}ve: Bool"));
+    // The original spelling is kept as the JSON key
+    assert!(output.contains("case na\u{ef}ve = \"nai\u{308}ve\""));
+}
+
+#[test]
+fn test_identifiers_are_transliterated() {
+    let types = TypeCollection::default()
+        .register::<Café>()
+        .register::<Größe>();
+
+    let output = Swift::new()
+        .unicode_identifiers(UnicodeIdentifiers::Transliterate)
+        .export(&types)
+        .unwrap();
+
+    assert!(output.contains("public struct Cafe: Codable"));
+    assert!(output.contains("public let cremeBrulee: String"));
+    assert!(output.contains("case cremeBrulee = \"crème_brûlée\""));
+    assert!(output.contains("case naive = \"nai\u{308}ve\""));
+
+    assert!(output.contains("public enum Grosse {"));
+    assert!(output.contains("case klein"));
+    assert!(output.contains("public struct GrosseGrossData: Codable"));
+    assert!(output.contains("case hohe = \"höhe\""));
+}
+
+#[test]
+fn test_unicode_identifiers_parse() {
+    assert_eq!(
+        "transliterate".parse::<UnicodeIdentifiers>().unwrap(),
+        UnicodeIdentifiers::Transliterate
+    );
+    assert!("ascii".parse::<UnicodeIdentifiers>().is_err());
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Adres {
+    #[serde(rename = "İL_KODU")]
+    il_kodu: u32,
+    #[serde(rename = "STRASSE_ß")]
+    strasse: String,
+}
+
+#[test]
+fn test_case_mappings_to_several_characters_are_kept() {
+    let types = TypeCollection::default().register::<Adres>();
+
+    let output = Swift::new().export(&types).unwrap();
+
+    // `İ` would lowercase to `i` and a combining dot
+    assert!(output.contains("public let İlKodu: UInt32"));
+    assert!(output.contains("public let strasseß: String"));
+    assert!(output.contains("case İlKodu = \"İL_KODU\""));
+}
@@ -0,0 +1,77 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+#[allow(dead_code)]
+struct Profile {
+    name: String,
+    bio: String,
+    email: String,
+    avatar_url: String,
+    followers: u64,
+    following: u64,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Response {
+    Empty,
+    Count(u32),
+    Profile(Profile),
+    Error { code: u16, message: String },
+}
+
+#[test]
+fn test_box_large_variants() {
+    let output = Swift::new()
+        .box_variants_larger_than(64)
+        .export(&TypeCollection::default().register::<Response>())
+        .unwrap();
+
+    assert!(output.contains("    case empty\n"));
+    assert!(output.contains("    case count(UInt32)\n"));
+    assert!(output.contains("    indirect case profile(Profile)\n"));
+    assert!(output.contains("    case error(ResponseErrorData)\n"));
+}
+
+#[test]
+fn test_box_variants_overrides() {
+    let types = TypeCollection::default().register::<Response>();
+    let output = Swift::new()
+        .box_variants_of(["Response"])
+        .export(&types)
+        .unwrap();
+    assert!(output.contains("    case empty\n"));
+    assert!(output.contains("    indirect case count(UInt32)\n"));
+    assert!(output.contains("    indirect case error(ResponseErrorData)\n"));
+
+    let output = Swift::new()
+        .box_variants_larger_than(0)
+        .never_box_variants_of(["Response"])
+        .export(&types)
+        .unwrap();
+    assert!(!output.contains("indirect"));
+}
+
+#[test]
+fn test_no_boxing_by_default() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Response>())
+        .unwrap();
+
+    assert!(!output.contains("indirect"));
+}
+
+#[test]
+fn test_estimated_payload_size() {
+    let types = TypeCollection::default().register::<Response>();
+    let profile = types
+        .into_unsorted_iter()
+        .find(|ndt| ndt.name() == "Profile")
+        .unwrap();
+
+    assert_eq!(
+        specta_swift::datatype::estimated_size(&types, profile.ty()),
+        4 * 16 + 2 * 8
+    );
+}
@@ -0,0 +1,83 @@
+use specta::{Type, TypeCollection};
+use specta_swift::{Swift, VariantOrdering};
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Level {
+    Warning,
+    Error,
+    Info,
+}
+
+#[derive(Type)]
+#[allow(dead_code)]
+enum Event {
+    Started { id: u32 },
+    Finished(u32),
+    Aborted,
+}
+
+/// Serialized as `ZEBRA`, `apple` and `Mango`, whose byte order differs from the case names
+#[derive(Type)]
+#[allow(dead_code)]
+enum Fruit {
+    #[serde(rename = "ZEBRA")]
+    Zebra,
+    #[serde(rename = "apple")]
+    Apple,
+    #[serde(rename = "Mango")]
+    Mango,
+}
+
+fn position(output: &str, needle: &str) -> usize {
+    output
+        .find(needle)
+        .unwrap_or_else(|| panic!("'{}' not found in:\n{}", needle, output))
+}
+
+#[test]
+fn test_declaration_order_is_kept_by_default() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Level>())
+        .unwrap();
+
+    assert!(position(&output, "case warning") < position(&output, "case error"));
+    assert!(position(&output, "case error") < position(&output, "case info"));
+}
+
+#[test]
+fn test_alphabetical_variant_ordering() {
+    let types = TypeCollection::default()
+        .register::<Level>()
+        .register::<Event>();
+
+    let output = Swift::new()
+        .variant_ordering(VariantOrdering::Alphabetical)
+        .export(&types)
+        .unwrap();
+
+    assert!(position(&output, "case error") < position(&output, "case info"));
+    assert!(position(&output, "case info") < position(&output, "case warning"));
+
+    // CodingKeys follow the cases
+    let event = &output[position(&output, "public enum Event")..];
+    let aborted = position(event, "case aborted");
+    let finished = position(event, "case finished(");
+    let started = position(event, "case started(");
+    assert!(aborted < finished && finished < started);
+
+    let coding_keys = &event[position(event, "enum CodingKeys")..];
+    assert!(position(coding_keys, "case aborted") < position(coding_keys, "case finished"));
+    assert!(position(coding_keys, "case finished") < position(coding_keys, "case started"));
+}
+
+#[test]
+fn test_alphabetical_ordering_uses_case_names() {
+    let output = Swift::new()
+        .variant_ordering(VariantOrdering::Alphabetical)
+        .export(&TypeCollection::default().register::<Fruit>())
+        .unwrap();
+
+    assert!(position(&output, "case apple") < position(&output, "case mango"));
+    assert!(position(&output, "case mango") < position(&output, "case zebra"));
+}
@@ -0,0 +1,86 @@
+use serde::Serialize;
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(dead_code)]
+enum Level {
+    #[serde(rename = "dbg")]
+    Debug,
+    InfoLevel,
+    #[serde(rename = "Warn-Level")]
+    Warn,
+}
+
+#[derive(Type, Serialize)]
+#[allow(dead_code)]
+enum Shape {
+    #[serde(rename = "circle")]
+    Circle { radius: f64 },
+    #[serde(rename = "sq")]
+    Square(f64),
+    #[serde(rename = "no-shape")]
+    Empty,
+}
+
+#[derive(Type, Serialize)]
+#[allow(dead_code)]
+enum Job {
+    #[serde(rename = "renamed-var")]
+    Renamed { id: u32 },
+    #[serde(rename = "STILL_QUEUED")]
+    Queued { position: u32 },
+}
+
+#[test]
+fn test_renamed_string_enum_variants() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Level>())
+        .unwrap();
+
+    assert!(output.contains("case dbg = \"dbg\""));
+    assert!(output.contains("case infoLevel = \"INFO_LEVEL\""));
+    assert!(output.contains("case warnLevel = \"Warn-Level\""));
+}
+
+#[test]
+fn test_renamed_variant_coding_keys() {
+    let output = Swift::new()
+        .export(&TypeCollection::default().register::<Shape>())
+        .unwrap();
+
+    assert!(output.contains("case circle = \"circle\""));
+    assert!(output.contains("case sq = \"sq\""));
+    assert!(output.contains("case noShape = \"no-shape\""));
+    assert!(output.contains("case \"no-shape\":"));
+    assert!(output.contains("self = .noShape"));
+}
+
+#[test]
+fn test_renamed_variant_struct_names() {
+    let output = Swift::new()
+        .with_mocks()
+        .export(&TypeCollection::default().register::<Job>())
+        .unwrap();
+
+    assert!(output.contains("public struct JobRenamedVarData: Codable {"));
+    assert!(output.contains("public struct JobStillQueuedData: Codable {"));
+    assert!(output.contains("case renamedVar(JobRenamedVarData)"));
+    assert!(output.contains("try container.decode(JobRenamedVarData.self, forKey: .renamedVar)"));
+    assert!(output.contains(".renamedVar(JobRenamedVarData(id: 1))"));
+    assert!(!output.contains("renamed-varData"));
+}
+
+#[test]
+fn test_renamed_variant_fixtures() {
+    let dir = std::env::temp_dir().join("specta_swift_variant_rename_fixtures");
+    let _ = std::fs::remove_dir_all(&dir);
+    Swift::new()
+        .export_fixtures(&dir, &TypeCollection::default().register::<Level>())
+        .unwrap();
+
+    let read = |file: &str| std::fs::read_to_string(dir.join(file)).unwrap();
+    assert_eq!(read("Level.dbg.json"), "\"dbg\"\n");
+    assert_eq!(read("Level.INFO_LEVEL.json"), "\"INFO_LEVEL\"\n");
+}
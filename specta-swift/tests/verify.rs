@@ -0,0 +1,76 @@
+#![cfg(all(feature = "swift-check", unix))]
+
+use std::os::unix::fs::PermissionsExt;
+use std::sync::Once;
+
+use specta::{Type, TypeCollection};
+use specta_swift::{Error, Swift};
+
+#[derive(Type)]
+struct User {
+    id: u32,
+}
+
+/// A stand-in for `swiftc` which reports an error on line 3 of files declaring a struct, so
+/// the tests don't need a Swift toolchain. It takes a moment, like the real compiler, and
+/// fails when the file is removed in the meantime.
+fn fake_compiler() -> std::path::PathBuf {
+    static WRITE: Once = Once::new();
+    let path = std::env::temp_dir().join("specta_swift_fake_swiftc.sh");
+    WRITE.call_once(|| {
+        std::fs::write(
+            &path,
+            concat!(
+                "#!/bin/sh\n",
+                "sleep 0.2\n",
+                "test -f \"$2\" || { echo \"$2:1:1: error: file removed\" >&2; exit 1; }\n",
+                "grep -q struct \"$2\" || exit 0\n",
+                "echo \"$2:3:1: error: expected declaration\" >&2\n",
+                "exit 1\n",
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    });
+    path
+}
+
+#[test]
+fn test_verify_reports_compiler_errors() {
+    std::env::set_var("SWIFTC", fake_compiler());
+
+    let swift = Swift::new();
+    let output = swift
+        .export(&TypeCollection::default().register::<User>())
+        .unwrap();
+
+    match swift.verify(&output) {
+        Err(Error::InvalidOutput {
+            line,
+            message,
+            context,
+        }) => {
+            assert_eq!(line, 3);
+            assert_eq!(message, "expected declaration");
+            assert!(context.contains("   3 | "));
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn test_parallel_verify_calls() {
+    std::env::set_var("SWIFTC", fake_compiler());
+
+    let swift = Swift::new();
+    let output = swift.export(&TypeCollection::default()).unwrap();
+
+    std::thread::scope(|scope| {
+        let checks = (0..8)
+            .map(|_| scope.spawn(|| swift.verify(&output)))
+            .collect::<Vec<_>>();
+        for check in checks {
+            check.join().unwrap().unwrap();
+        }
+    });
+}
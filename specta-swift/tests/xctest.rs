@@ -0,0 +1,121 @@
+use specta::{Type, TypeCollection};
+use specta_swift::Swift;
+
+#[derive(Type)]
+struct User {
+    id: u32,
+    nickname: Option<String>,
+}
+
+#[derive(Type)]
+enum Event {
+    Joined(User),
+    Left { user_id: u32 },
+}
+
+#[derive(Type)]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("specta_swift_xctest_{}.swift", name))
+}
+
+#[test]
+fn test_export_tests() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<Page<User>>();
+    let path = temp_path("round_trip");
+    Swift::new()
+        .test_module("MyApp")
+        .export_tests(&path, &types)
+        .unwrap();
+    let output = std::fs::read_to_string(&path).unwrap();
+    println!("{}", output);
+
+    assert!(output.contains("import XCTest\n@testable import MyApp\n"));
+    assert!(output.contains("final class SpectaCodableTests: XCTestCase {"));
+    assert!(output.contains("let decoded = try JSONDecoder().decode(T.self, from: encoded)"));
+    assert!(output.contains(
+        "    func testUserRoundTrip() throws {\n        let samples: [User] = [\n            .mock,\n            .mock(nickname: \"nickname\"),\n        ]"
+    ));
+    assert!(output.contains(
+        "        let samples: [Event] = [\n            .joined(User.mock),\n            .left(EventLeftData(userId: 1)),\n        ]"
+    ));
+    assert!(!output.contains("testPageRoundTrip"));
+
+    // The bindings don't contain mocks, so the test file brings its own
+    assert!(output.contains("#if DEBUG\nextension User {"));
+}
+
+#[test]
+fn test_export_tests_reuses_binding_mocks() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<Page<User>>();
+    let path = temp_path("with_mocks");
+    Swift::new()
+        .with_mocks()
+        .export_tests(&path, &types)
+        .unwrap();
+    let output = std::fs::read_to_string(&path).unwrap();
+
+    assert!(output.contains("func testUserRoundTrip()"));
+    assert!(!output.contains("extension User"));
+    assert!(!output.contains("@testable"));
+}
+
+#[derive(Type)]
+struct Tags {
+    labels: Vec<Option<String>>,
+    scores: std::collections::HashMap<String, Option<u32>>,
+    primary: Option<String>,
+}
+
+#[test]
+fn test_export_tests_nullable_collection_elements() {
+    let path = temp_path("nullable_elements");
+    Swift::new()
+        .export_tests(&path, &TypeCollection::default().register::<Tags>())
+        .unwrap();
+    let output = std::fs::read_to_string(&path).unwrap();
+
+    assert!(output.contains(
+        "            .mock(labels: [nil], scores: [\"scores\": nil], primary: \"primary\"),\n"
+    ));
+}
+
+#[test]
+fn test_export_tests_equatable() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<Page<User>>();
+    let path = temp_path("equatable");
+    Swift::new()
+        .equatable_tests(true)
+        .export_tests(&path, &types)
+        .unwrap();
+    let output = std::fs::read_to_string(&path).unwrap();
+
+    assert!(output.contains(
+        "    private func assertDecodesEqual<T: Codable & Equatable>(_ value: T, file: StaticString = #filePath, line: UInt = #line) throws {\n        let encoded = try JSONEncoder().encode(value)\n        let decoded = try JSONDecoder().decode(T.self, from: encoded)\n        XCTAssertEqual(decoded, value, file: file, line: line)\n    }\n"
+    ));
+    // Every variant shape is a sample, so each goes through both assertions
+    assert!(output.contains(
+        "            .left(EventLeftData(userId: 1)),\n        ]\n        for sample in samples {\n            try assertRoundTrip(sample)\n            try assertDecodesEqual(sample)\n        }"
+    ));
+}
+
+#[test]
+fn test_export_tests_without_equatable() {
+    let types = TypeCollection::default()
+        .register::<Event>()
+        .register::<Page<User>>();
+    let path = temp_path("not_equatable");
+    Swift::new().export_tests(&path, &types).unwrap();
+    let output = std::fs::read_to_string(&path).unwrap();
+
+    assert!(!output.contains("assertDecodesEqual"));
+}
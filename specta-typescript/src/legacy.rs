@@ -882,25 +882,39 @@ impl Builder {
     }
 
     pub fn push_deprecated(&mut self, typ: &DeprecatedType) {
+        let message = match typ {
+            DeprecatedType::DeprecatedWithSince { note, since } => {
+                Some((since.as_ref(), Cow::Borrowed(note.trim())))
+            }
+            // JSDoc has no tag for replacements, so they are pointed out in the message
+            DeprecatedType::DeprecatedRenamed {
+                note,
+                since,
+                renamed,
+            } if note.trim().is_empty() => Some((
+                since.as_ref(),
+                Cow::Owned(format!("Use `{}` instead", renamed.trim())),
+            )),
+            DeprecatedType::DeprecatedRenamed { note, since, .. } => {
+                Some((since.as_ref(), Cow::Borrowed(note.trim())))
+            }
+            _ => None,
+        };
+
         self.push_internal(
             ["@deprecated"].into_iter().chain(
-                match typ {
-                    DeprecatedType::DeprecatedWithSince {
-                        note: message,
-                        since,
-                    } => Some((since.as_ref(), message)),
-                    _ => None,
-                }
-                .map(|(since, message)| {
-                    [" ", message.trim()].into_iter().chain(
-                        since
-                            .map(|since| [" since ", since.trim()])
-                            .into_iter()
-                            .flatten(),
-                    )
-                })
-                .into_iter()
-                .flatten(),
+                message
+                    .as_ref()
+                    .map(|(since, message)| {
+                        [" ", message.as_ref()].into_iter().chain(
+                            since
+                                .map(|since| [" since ", since.trim()])
+                                .into_iter()
+                                .flatten(),
+                        )
+                    })
+                    .into_iter()
+                    .flatten(),
             ),
         );
     }
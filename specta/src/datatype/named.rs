@@ -138,4 +138,14 @@ pub enum DeprecatedType {
         since: Option<Cow<'static, str>>,
         note: Cow<'static, str>,
     },
+    /// A type that has been replaced by another, with an optional message and `since` version.
+    ///
+    /// Eg. `#[specta(deprecated(since = "1.0.0", note = "Use NewType", renamed = "NewType"))]`
+    ///
+    /// This variant is a public API addition. [`DeprecatedType`] is `#[non_exhaustive]`, so existing matches keep compiling.
+    DeprecatedRenamed {
+        since: Option<Cow<'static, str>>,
+        note: Cow<'static, str>,
+        renamed: Cow<'static, str>,
+    },
 }
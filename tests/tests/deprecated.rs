@@ -56,6 +56,27 @@ pub enum DeprecatedEnumVariants {
     C,
 }
 
+#[derive(Type)]
+#[specta(export = false)]
+#[deprecated(since = "1.2.0", note = "Profiles are merged into accounts")]
+struct DeprecatedTypeWithSince {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[specta(deprecated(since = "2.0.0", note = "Use Account instead", renamed = "Account"))]
+struct DeprecatedTypeRenamed {
+    a: i32,
+}
+
+#[derive(Type)]
+#[specta(export = false)]
+#[specta(deprecated(renamed = "Account"))]
+struct DeprecatedTypeRenamedWithoutNote {
+    a: i32,
+}
+
 #[test]
 fn test_deprecated_types() {
     assert_ts_export!(
@@ -70,4 +91,16 @@ fn test_deprecated_types() {
     assert_ts_export!(DeprecatedFields, "export type DeprecatedFields = { a: number; \n/**\n * @deprecated\n */\nb: string; \n/**\n * @deprecated This field is cringe!\n */\nc: string; \n/**\n * @deprecated This field is cringe!\n */\nd: string };");
     assert_ts_export!(DeprecatedTupleVariant, "export type DeprecatedTupleVariant = [\n/**\n * @deprecated\n */\nstring, \n/**\n * @deprecated Nope\n */\nstring, \n/**\n * @deprecated Nope\n */\nnumber];");
     assert_ts_export!(DeprecatedEnumVariants, "export type DeprecatedEnumVariants = \n/**\n * @deprecated\n */\n\"A\" | \n/**\n * @deprecated Nope\n */\n\"B\" | \n/**\n * @deprecated Nope\n */\n\"C\";");
+    assert_ts_export!(
+        DeprecatedTypeWithSince,
+        "/**\n * @deprecated Profiles are merged into accounts since 1.2.0\n */\nexport type DeprecatedTypeWithSince = { a: number };"
+    );
+    assert_ts_export!(
+        DeprecatedTypeRenamed,
+        "/**\n * @deprecated Use Account instead since 2.0.0\n */\nexport type DeprecatedTypeRenamed = { a: number };"
+    );
+    assert_ts_export!(
+        DeprecatedTypeRenamedWithoutNote,
+        "/**\n * @deprecated Use `Account` instead\n */\nexport type DeprecatedTypeRenamedWithoutNote = { a: number };"
+    );
 }